@@ -1,7 +1,7 @@
 use arbitrary::Arbitrary;
 use plasma_state::amm::Amm;
 use plasma_state::errors::*;
-use plasma_state::lp::LpPosition;
+use plasma_state::lp::{LpPosition, RemoveLiquidityMode, VestingSchedule};
 use std::env;
 use std::fmt::Debug;
 
@@ -344,19 +344,12 @@ pub fn perform_lp_action(amm: &mut Amm, lps: &mut [Option<LpPosition>], action:
                 match lp_position.add_liquidity(
                     amm.get_slot(),
                     amm,
+                    VestingSchedule::Cliff,
                     base_amount,
                     quote_amount,
                     None,
                 ) {
                     Ok(_) => {}
-                    Err(PlasmaStateError::VestingPeriodNotOver) => {
-                        // Check that a deposit already exists for this slot
-                        let current_slot = amm.get_slot();
-                        assert!(
-                            lp_position.pending_shares_to_vest.deposit_slot + amm.lp_vesting_window
-                                > current_slot
-                        );
-                    }
                     Err(PlasmaStateError::BelowMinimumLpSharesRequired) => {}
                     Err(e) => {
                         panic!("unexpected error: {}", e);
@@ -372,10 +365,14 @@ pub fn perform_lp_action(amm: &mut Amm, lps: &mut [Option<LpPosition>], action:
                 match lp_position.remove_liquidity(
                     amm.get_slot(),
                     amm,
+                    VestingSchedule::Cliff,
                     (pct * lp_position.lp_shares as f64) as u64,
+                    RemoveLiquidityMode::Strict,
                 ) {
                     Ok(_) => {}
                     Err(PlasmaStateError::BelowMinimumWithdrawaRequired) => {}
+                    // Requesting more than the withdrawable shares is a no-op here
+                    Err(PlasmaStateError::InsufficientWithdrawableShares(..)) => {}
                     Err(e) => {
                         panic!("unexpected error: {}", e);
                     }
@@ -386,7 +383,7 @@ pub fn perform_lp_action(amm: &mut Amm, lps: &mut [Option<LpPosition>], action:
             let lp_index = index % lps.len();
             let lp_position = &mut lps[lp_index];
             if let Some(lp_position) = lp_position {
-                match lp_position.collect_fees(amm.get_slot(), amm) {
+                match lp_position.collect_fees(amm.get_slot(), amm, VestingSchedule::Cliff) {
                     Ok(_) => {}
                     Err(e) => {
                         panic!("unexpected error: {}", e);
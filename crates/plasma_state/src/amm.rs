@@ -9,7 +9,7 @@ pub const BPS_BASE: u128 = 10000;
 use super::SlotWindow;
 
 #[cfg_attr(feature = "borsh", derive(BorshDeserialize, BorshSerialize))]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SwapResult {
     pub side: Side,
     pub base_amount_to_transfer: u64,
@@ -34,6 +34,22 @@ impl SwapResult {
             fee_in_quote: 0,
         }
     }
+
+    /// Amount of tokens sent by the trader (quote for buys, base for sells)
+    pub fn amount_in(&self) -> u64 {
+        match self.side {
+            Side::Buy => self.quote_amount_to_transfer,
+            Side::Sell => self.base_amount_to_transfer,
+        }
+    }
+
+    /// Amount of tokens received by the trader (base for buys, quote for sells)
+    pub fn amount_out(&self) -> u64 {
+        match self.side {
+            Side::Buy => self.base_amount_to_transfer,
+            Side::Sell => self.quote_amount_to_transfer,
+        }
+    }
 }
 
 #[cfg_attr(feature = "borsh", derive(BorshDeserialize, BorshSerialize))]
@@ -43,7 +59,43 @@ pub enum Side {
     Sell,
 }
 
+#[cfg_attr(feature = "borsh", derive(BorshDeserialize, BorshSerialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwapType {
+    ExactIn { amount_in: u64, min_amount_out: u64 },
+    ExactOut { amount_out: u64, max_amount_in: u64 },
+}
+
+impl SwapType {
+    /// Returns an error if the amounts of a swap do not respect the slippage limit of the swap type.
+    /// `min_amount_out` is always compared to the output token of the swap, so it is a quote amount
+    /// for a sell.
+    pub fn check_slippage(&self, amount_in: u64, amount_out: u64) -> Result<(), PlasmaStateError> {
+        match *self {
+            SwapType::ExactIn { min_amount_out, .. } => {
+                if amount_out < min_amount_out {
+                    return Err(PlasmaStateError::SlippageExceeded(
+                        min_amount_out as u128,
+                        amount_out as u128,
+                    ));
+                }
+            }
+            SwapType::ExactOut { max_amount_in, .. } => {
+                if amount_in > max_amount_in {
+                    return Err(PlasmaStateError::SlippageExceeded(
+                        max_amount_in as u128,
+                        amount_in as u128,
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
 /// Enum to differentiate between base and quote tokens
+#[cfg_attr(feature = "borsh", derive(BorshDeserialize, BorshSerialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TokenType {
     Base,
     Quote,
@@ -65,6 +117,12 @@ pub struct Amm {
     pub quote_reserves: u64,
     pub cumulative_quote_lp_fees: u64,
     pub cumulative_quote_protocol_fees: u64,
+    /// Sum of the pool price (quote atoms per base atom) weighted by the number of slots it was
+    /// active, updated whenever the snapshot rolls. This wraps around on overflow.
+    pub cumulative_price: I80F48,
+    /// Sum of the pool liquidity (square root of the product of the reserves) weighted by the
+    /// number of slots it was active. This wraps around on overflow.
+    pub cumulative_liquidity: I80F48,
 }
 
 impl Amm {
@@ -87,6 +145,8 @@ impl Amm {
             quote_reserves: 0,
             cumulative_quote_lp_fees: 0,
             cumulative_quote_protocol_fees: 0,
+            cumulative_price: I80F48::ZERO,
+            cumulative_liquidity: I80F48::ZERO,
         }
     }
 }
@@ -117,6 +177,43 @@ impl Amm {
         let mut pool_clone = self.clone();
         pool_clone.sell_exact_in(slot, base_in)
     }
+
+    pub fn simulate_buy_exact_out(&self, base_out: u64) -> Result<SwapResult, PlasmaStateError> {
+        self.simulate_buy_exact_out_with_slot(self.get_slot(), base_out)
+    }
+
+    pub fn simulate_sell_exact_out(&self, quote_out: u64) -> Result<SwapResult, PlasmaStateError> {
+        self.simulate_sell_exact_out_with_slot(self.get_slot(), quote_out)
+    }
+
+    pub fn simulate_buy_exact_out_with_slot(
+        &self,
+        slot: SlotWindow,
+        base_out: u64,
+    ) -> Result<SwapResult, PlasmaStateError> {
+        let mut pool_clone = *self;
+        pool_clone.buy_exact_out(slot, base_out)
+    }
+
+    pub fn simulate_sell_exact_out_with_slot(
+        &self,
+        slot: SlotWindow,
+        quote_out: u64,
+    ) -> Result<SwapResult, PlasmaStateError> {
+        let mut pool_clone = *self;
+        pool_clone.sell_exact_out(slot, quote_out)
+    }
+
+    /// Simulates a swap of the given side and type without checking the slippage limit
+    pub fn simulate_swap_with_slot(
+        &self,
+        slot: SlotWindow,
+        side: Side,
+        swap_type: SwapType,
+    ) -> Result<SwapResult, PlasmaStateError> {
+        let mut pool_clone = *self;
+        pool_clone.swap(slot, side, swap_type)
+    }
 }
 
 impl Amm {
@@ -124,6 +221,17 @@ impl Amm {
         self.slot_snapshot
     }
 
+    pub fn get_protocol_allocation_in_pct(&self) -> u32 {
+        self.protocol_allocation_in_pct
+    }
+
+    /// Updates the swap fee and the share of it allocated to the protocol. Fees that were
+    /// already collected are not affected.
+    pub fn set_fees(&mut self, fee_in_bps: u32, protocol_allocation_in_pct: u32) {
+        self.fee_in_bps = fee_in_bps;
+        self.protocol_allocation_in_pct = protocol_allocation_in_pct;
+    }
+
     pub fn deposit_amount_quote(&self, amount_base: u64) -> u128 {
         amount_base.upcast() * self.quote_reserves.upcast() / self.base_reserves.upcast()
     }
@@ -134,8 +242,8 @@ impl Amm {
 }
 
 pub struct LimitOrderConfiguration {
-    size_in_base: u128,
-    size_in_quote: u128,
+    pub size_in_base: u128,
+    pub size_in_quote: u128,
 }
 
 impl LimitOrderConfiguration {
@@ -333,6 +441,11 @@ impl Amm {
 impl Amm {
     pub fn maybe_update_snapshot(&mut self, new_snapshot_slot: SlotWindow) -> bool {
         if new_snapshot_slot > self.slot_snapshot {
+            // The current reserves are the state at the end of the last active window, and the
+            // state the pool has been quoting since then
+            let observation = self.observe_at(new_snapshot_slot);
+            self.cumulative_price = observation.cumulative_price;
+            self.cumulative_liquidity = observation.cumulative_liquidity;
             self.slot_snapshot = new_snapshot_slot;
             self.base_reserves_snapshot = self.base_reserves;
             self.quote_reserves_snapshot = self.quote_reserves;
@@ -499,6 +612,28 @@ impl Amm {
 }
 
 impl Amm {
+    /// Dispatches to the swap function matching `side` and `swap_type`. The slippage limit
+    /// of `swap_type` is not checked here, see `SwapType::check_slippage`.
+    pub fn swap(
+        &mut self,
+        slot: SlotWindow,
+        side: Side,
+        swap_type: SwapType,
+    ) -> Result<SwapResult, PlasmaStateError> {
+        match (side, swap_type) {
+            (Side::Buy, SwapType::ExactIn { amount_in, .. }) => self.buy_exact_in(slot, amount_in),
+            (Side::Buy, SwapType::ExactOut { amount_out, .. }) => {
+                self.buy_exact_out(slot, amount_out)
+            }
+            (Side::Sell, SwapType::ExactIn { amount_in, .. }) => {
+                self.sell_exact_in(slot, amount_in)
+            }
+            (Side::Sell, SwapType::ExactOut { amount_out, .. }) => {
+                self.sell_exact_out(slot, amount_out)
+            }
+        }
+    }
+
     pub fn buy_exact_in(
         &mut self,
         slot: SlotWindow,
@@ -990,3 +1125,91 @@ impl Amm {
         Ok(swap_result)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::initialized_pool;
+
+    #[test]
+    fn test_simulate_exact_out_matches_mutating_swaps() {
+        let mut amm = initialized_pool();
+        // Move the pool away from the snapshot price so that both the limit order
+        // and the curve are exercised
+        amm.sell_exact_in(100, 500_000_000).unwrap();
+
+        for slot in [100, 104] {
+            for amount in [1, 1_000, 10_000_000, 2_000_000_000] {
+                let pre_state = bytemuck::bytes_of(&amm).to_vec();
+
+                let simulated = amm.simulate_buy_exact_out_with_slot(slot, amount).unwrap();
+                assert_eq!(bytemuck::bytes_of(&amm), pre_state);
+                let mut pool_clone = amm;
+                let expected = pool_clone.buy_exact_out(slot, amount).unwrap();
+                assert_eq!(simulated, expected);
+
+                let simulated = amm.simulate_sell_exact_out_with_slot(slot, amount).unwrap();
+                assert_eq!(bytemuck::bytes_of(&amm), pre_state);
+                let mut pool_clone = amm;
+                let expected = pool_clone.sell_exact_out(slot, amount).unwrap();
+                assert_eq!(simulated, expected);
+            }
+        }
+
+        assert_eq!(
+            amm.simulate_buy_exact_out(1_000_000).unwrap(),
+            amm.simulate_buy_exact_out_with_slot(amm.get_slot(), 1_000_000)
+                .unwrap()
+        );
+        assert_eq!(
+            amm.simulate_sell_exact_out(1_000_000).unwrap(),
+            amm.simulate_sell_exact_out_with_slot(amm.get_slot(), 1_000_000)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_sell_exact_in_slippage_is_checked_against_quote_out() {
+        let mut amm = initialized_pool();
+        let result = amm.sell_exact_in(100, 1_000_000).unwrap();
+        let (base_in, quote_out) = (result.amount_in(), result.amount_out());
+        assert_eq!(base_in, result.base_amount_to_transfer);
+        assert_eq!(quote_out, result.quote_amount_to_transfer);
+        // Base is worth about 4 quote, so a limit on the quote out is above the base in
+        assert!(quote_out > base_in);
+
+        let swap_type = SwapType::ExactIn {
+            amount_in: base_in,
+            min_amount_out: quote_out,
+        };
+        assert!(swap_type.check_slippage(base_in, quote_out).is_ok());
+        let swap_type = SwapType::ExactIn {
+            amount_in: base_in,
+            min_amount_out: quote_out + 1,
+        };
+        assert_eq!(
+            swap_type.check_slippage(base_in, quote_out).unwrap_err(),
+            PlasmaStateError::SlippageExceeded(quote_out as u128 + 1, quote_out as u128)
+        );
+    }
+
+    #[test]
+    fn test_simulate_exact_out_errors() {
+        let amm = Amm::new(30, 20, 2, 100);
+        assert_eq!(
+            amm.simulate_buy_exact_out(1).unwrap_err(),
+            PlasmaStateError::UninitializedPool
+        );
+        assert_eq!(
+            amm.simulate_sell_exact_out(1).unwrap_err(),
+            PlasmaStateError::UninitializedPool
+        );
+
+        let amm = initialized_pool();
+        assert_eq!(
+            amm.simulate_buy_exact_out(amm.base_reserves + 1)
+                .unwrap_err(),
+            PlasmaStateError::SwapExactOutTooLarge
+        );
+    }
+}
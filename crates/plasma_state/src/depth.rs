@@ -0,0 +1,231 @@
+use crate::{
+    amm::{Amm, LimitOrderConfiguration, Side, BPS_BASE},
+    errors::PlasmaStateError,
+    fixed::I80F48,
+    Downcast, SlotWindow,
+};
+
+/// A single price level of the virtual order book.
+#[derive(Debug, Clone, Copy)]
+pub struct DepthLevel {
+    /// Price of the level in quote atoms per base atom, fees included. For curve levels, this is
+    /// the worst price at which the liquidity of the level can be taken.
+    pub price: I80F48,
+    pub base_size: u64,
+    /// Quote paid by the taker for asks or received by the taker for bids, fees included
+    pub quote_size: u64,
+    /// True if the level is the virtual limit order priced at the snapshot price
+    pub is_limit_order: bool,
+}
+
+/// L2 view of a pool. Bids are sorted by descending price and asks by ascending price.
+#[derive(Debug, Clone, Default)]
+pub struct Depth {
+    pub bids: Vec<DepthLevel>,
+    pub asks: Vec<DepthLevel>,
+}
+
+impl Amm {
+    /// Renders the pool as a discretised order book at `slot`.
+    ///
+    /// Each side starts with the virtual limit order at the snapshot price (if there is one),
+    /// followed by `num_levels` levels of curve liquidity spaced `tick_size_in_bps` apart, starting
+    /// from the pool price after the limit order is filled. The size of a curve level is the
+    /// liquidity available between the previous level and its price.
+    ///
+    /// Sizes are derived from the closed-form x * y = k curve, so they can differ from the
+    /// swap functions by a few atoms due to rounding.
+    pub fn get_depth(
+        &self,
+        slot: SlotWindow,
+        num_levels: usize,
+        tick_size_in_bps: u64,
+    ) -> Result<Depth, PlasmaStateError> {
+        if self.total_lp_shares == 0 {
+            return Err(PlasmaStateError::UninitializedPool);
+        }
+        if tick_size_in_bps == 0 {
+            return Err(PlasmaStateError::UnexpectedArgument);
+        }
+        let mut pool = *self;
+        pool.maybe_update_snapshot(slot);
+
+        Ok(Depth {
+            bids: pool.get_depth_for_side(slot, Side::Sell, num_levels, tick_size_in_bps)?,
+            asks: pool.get_depth_for_side(slot, Side::Buy, num_levels, tick_size_in_bps)?,
+        })
+    }
+
+    /// Returns the levels a taker on `side` can trade against (asks for `Side::Buy`)
+    fn get_depth_for_side(
+        &self,
+        slot: SlotWindow,
+        side: Side,
+        num_levels: usize,
+        tick_size_in_bps: u64,
+    ) -> Result<Vec<DepthLevel>, PlasmaStateError> {
+        let bps_base = BPS_BASE as u64;
+        let fee_in_bps = self.fee_in_bps as u64;
+        let fee_multiplier = match side {
+            Side::Buy => I80F48::from_fraction(bps_base, bps_base - fee_in_bps),
+            Side::Sell => I80F48::from_fraction(bps_base - fee_in_bps, bps_base),
+        };
+
+        let mut pool = *self;
+        let mut levels = Vec::with_capacity(num_levels + 1);
+
+        let LimitOrderConfiguration {
+            size_in_base,
+            size_in_quote,
+        } = pool.get_limit_order_size_in_base_and_quote(side);
+        if size_in_base > 0 {
+            let quote_size = match side {
+                Side::Buy => pool.pre_fee_adjust_rounded_down(size_in_quote),
+                Side::Sell => size_in_quote - pool.fee_rounded_down(size_in_quote),
+            };
+            levels.push(DepthLevel {
+                price: pool.get_snapshot_price() * fee_multiplier,
+                base_size: size_in_base.downcast()?,
+                quote_size: quote_size.downcast()?,
+                is_limit_order: true,
+            });
+            // Fill the limit order so that the curve levels start from the snapshot price
+            let size_in_base = size_in_base.downcast()?;
+            match side {
+                Side::Buy => pool.buy_exact_out(slot, size_in_base)?,
+                Side::Sell => pool.sell_exact_in(slot, size_in_base)?,
+            };
+        }
+
+        let start_price = pool.get_mid_price();
+        let start_base = I80F48::from_num(pool.base_reserves);
+        let start_quote = I80F48::from_num(pool.quote_reserves);
+        let mut prev_base = pool.base_reserves;
+        let mut prev_quote = pool.quote_reserves;
+
+        for i in 1..=num_levels as u64 {
+            let offset_in_bps = i * tick_size_in_bps;
+            let price_multiplier = match side {
+                Side::Buy => I80F48::from_fraction(bps_base + offset_in_bps, bps_base),
+                Side::Sell => {
+                    if offset_in_bps >= bps_base {
+                        break;
+                    }
+                    I80F48::from_fraction(bps_base - offset_in_bps, bps_base)
+                }
+            };
+            // On the curve, reserves at price p are base * sqrt(p0 / p) and quote * sqrt(p / p0)
+            let root = price_multiplier
+                .checked_sqrt()
+                .ok_or(PlasmaStateError::Overflow)?;
+            let base = (start_base / root).floor();
+            let quote = (start_quote * root).floor();
+
+            let (base_size, quote_size) = match side {
+                Side::Buy => {
+                    let quote_size = pool.pre_fee_adjust_rounded_down((quote - prev_quote) as u128);
+                    (prev_base - base, quote_size.downcast()?)
+                }
+                Side::Sell => {
+                    let quote_size = (prev_quote - quote) as u128;
+                    let quote_size = quote_size - pool.fee_rounded_down(quote_size);
+                    (base - prev_base, quote_size.downcast()?)
+                }
+            };
+            levels.push(DepthLevel {
+                price: start_price * price_multiplier * fee_multiplier,
+                base_size,
+                quote_size,
+                is_limit_order: false,
+            });
+            prev_base = base;
+            prev_quote = quote;
+        }
+
+        Ok(levels)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::initialized_pool;
+
+    #[test]
+    fn test_depth_is_sorted() {
+        let amm = initialized_pool();
+        let depth = amm.get_depth(100, 10, 10).unwrap();
+        assert_eq!(depth.bids.len(), 10);
+        assert_eq!(depth.asks.len(), 10);
+        assert!(depth.bids.iter().all(|level| !level.is_limit_order));
+        assert!(depth.asks.iter().all(|level| !level.is_limit_order));
+        assert!(depth.bids[0].price < amm.get_mid_price());
+        assert!(depth.asks[0].price > amm.get_mid_price());
+        for levels in [&depth.bids, &depth.asks] {
+            assert!(levels.iter().all(|level| level.base_size > 0));
+        }
+        for window in depth.bids.windows(2) {
+            assert!(window[0].price > window[1].price);
+        }
+        for window in depth.asks.windows(2) {
+            assert!(window[0].price < window[1].price);
+        }
+    }
+
+    #[test]
+    fn test_depth_matches_swaps() {
+        let amm = initialized_pool();
+        let depth = amm.get_depth(100, 5, 25).unwrap();
+        // Each level is rounded independently, so the totals can be off by a few atoms
+
+        let base_on_asks = depth.asks.iter().map(|level| level.base_size).sum::<u64>();
+        let quote_on_asks = depth.asks.iter().map(|level| level.quote_size).sum::<u64>();
+        let result = amm
+            .simulate_buy_exact_out_with_slot(100, base_on_asks)
+            .unwrap();
+        assert!(result.quote_amount_to_transfer.abs_diff(quote_on_asks) <= 10);
+
+        let base_on_bids = depth.bids.iter().map(|level| level.base_size).sum::<u64>();
+        let quote_on_bids = depth.bids.iter().map(|level| level.quote_size).sum::<u64>();
+        let result = amm
+            .simulate_sell_exact_in_with_slot(100, base_on_bids)
+            .unwrap();
+        assert!(result.quote_amount_to_transfer.abs_diff(quote_on_bids) <= 10);
+    }
+
+    #[test]
+    fn test_depth_limit_order_level() {
+        let mut amm = initialized_pool();
+        amm.sell_exact_in(100, 100_000_000).unwrap();
+
+        let depth = amm.get_depth(100, 3, 10).unwrap();
+        let limit_order = depth.asks[0];
+        assert!(limit_order.is_limit_order);
+        assert_eq!(depth.asks.len(), 4);
+        assert_eq!(
+            limit_order.base_size as u128,
+            amm.get_limit_order_size_in_base_and_quote(Side::Buy)
+                .size_in_base
+        );
+        assert!(limit_order.price > amm.get_snapshot_price());
+        assert!(depth.asks[1].price > limit_order.price);
+        // The pool was sold into, so there is no limit order on the bid side
+        assert!(depth.bids.iter().all(|level| !level.is_limit_order));
+
+        // The limit order disappears once the snapshot rolls
+        let depth = amm.get_depth(104, 3, 10).unwrap();
+        assert!(depth.asks.iter().all(|level| !level.is_limit_order));
+    }
+
+    #[test]
+    fn test_depth_bids_stop_at_zero_price() {
+        let amm = initialized_pool();
+        let depth = amm.get_depth(100, 10, 2_500).unwrap();
+        assert_eq!(depth.bids.len(), 3);
+        assert_eq!(depth.asks.len(), 10);
+        assert_eq!(
+            amm.get_depth(100, 10, 0).unwrap_err(),
+            PlasmaStateError::UnexpectedArgument
+        );
+    }
+}
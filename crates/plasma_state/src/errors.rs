@@ -18,6 +18,9 @@ pub enum PlasmaStateError {
     SwapExactOutTooLarge,
     SwapExactInTooLarge,
     SwapOutputGreaterThanOrEqualToReserves(u128, u128),
+    SlippageExceeded(u128, u128),
+    ObservationTooOld(u128, u128),
+    InsufficientWithdrawableShares(u64, u64),
 }
 
 impl Display for PlasmaStateError {
@@ -66,6 +69,27 @@ impl Display for PlasmaStateError {
                     input, reserves
                 )
             }
+            PlasmaStateError::SlippageExceeded(limit, actual) => {
+                write!(
+                    f,
+                    "SlippageExceeded: Limit is {} but swap amount is {}",
+                    limit, actual
+                )
+            }
+            PlasmaStateError::ObservationTooOld(slot, oldest_slot) => {
+                write!(
+                    f,
+                    "ObservationTooOld: Slot {} is before the oldest observation at slot {}",
+                    slot, oldest_slot
+                )
+            }
+            PlasmaStateError::InsufficientWithdrawableShares(requested, withdrawable) => {
+                write!(
+                    f,
+                    "InsufficientWithdrawableShares: Requested {} LP shares but only {} are withdrawable",
+                    requested, withdrawable
+                )
+            }
         }
     }
 }
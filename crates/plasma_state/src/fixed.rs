@@ -1,6 +1,6 @@
 use std::{
     fmt::{Debug, Display, Formatter},
-    ops::{Add, AddAssign, Mul, Sub},
+    ops::{Add, AddAssign, Div, Mul, Sub},
 };
 
 #[cfg(feature = "borsh")]
@@ -18,6 +18,9 @@ pub struct I80F48 {
 
 impl I80F48 {
     pub const ZERO: Self = Self { inner: 0 };
+    pub const ONE: Self = Self {
+        inner: FixedI80F48::ONE.to_bits(),
+    };
 
     pub fn from_num(value: u64) -> Self {
         let value = FixedI80F48::from_num(value);
@@ -38,6 +41,48 @@ impl I80F48 {
         value.floor().to_num()
     }
 
+    pub fn checked_sqrt(&self) -> Option<Self> {
+        let value = FixedI80F48::from_bits(self.inner);
+        value.checked_sqrt().map(|root| Self {
+            inner: root.to_bits(),
+        })
+    }
+
+    /// Division that returns `None` if `rhs` is zero or the quotient overflows
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        let lhs = FixedI80F48::from_bits(self.inner);
+        let rhs = FixedI80F48::from_bits(rhs.inner);
+        lhs.checked_div(rhs).map(|quotient| Self {
+            inner: quotient.to_bits(),
+        })
+    }
+
+    /// Addition that wraps around on overflow. Used for accumulators where only differences matter.
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        let lhs = FixedI80F48::from_bits(self.inner);
+        let rhs = FixedI80F48::from_bits(rhs.inner);
+        Self {
+            inner: lhs.wrapping_add(rhs).to_bits(),
+        }
+    }
+
+    /// Subtraction that wraps around on overflow
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        let lhs = FixedI80F48::from_bits(self.inner);
+        let rhs = FixedI80F48::from_bits(rhs.inner);
+        Self {
+            inner: lhs.wrapping_sub(rhs).to_bits(),
+        }
+    }
+
+    /// Multiplication by an integer that wraps around on overflow
+    pub fn wrapping_mul_int(self, rhs: u64) -> Self {
+        let value = FixedI80F48::from_bits(self.inner);
+        Self {
+            inner: value.wrapping_mul_int(rhs as i128).to_bits(),
+        }
+    }
+
     pub fn to_bits(&self) -> i128 {
         self.inner
     }
@@ -105,6 +150,18 @@ impl Mul for I80F48 {
     }
 }
 
+impl Div for I80F48 {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        let lhs = FixedI80F48::from_bits(self.inner);
+        let rhs = FixedI80F48::from_bits(rhs.inner);
+        let quotient = lhs / rhs;
+        Self {
+            inner: quotient.to_bits(),
+        }
+    }
+}
+
 impl Display for I80F48 {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let value = FixedI80F48::from_bits(self.inner);
@@ -145,6 +202,10 @@ mod tests {
                 assert_eq!((af - bf).to_bits(), (af_lib - bf_lib).to_bits());
                 // Test multiplication matches lib
                 assert_eq!((af * bf).to_bits(), (af_lib * bf_lib).to_bits());
+                // Test division matches lib
+                if b != 0 {
+                    assert_eq!((af / bf).to_bits(), (af_lib / bf_lib).to_bits());
+                }
 
                 let mut c_lib = FixedI80F48::ZERO;
                 let mut c = I80F48::ZERO;
@@ -161,6 +222,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_wrapping_accumulator() {
+        use crate::fixed::I80F48;
+        let price = I80F48::from_fraction(7, 2);
+        let start = I80F48::from_bits(i128::MAX - 5);
+        let end = start.wrapping_add(price.wrapping_mul_int(10));
+        // The accumulator overflowed but the difference is still correct
+        assert!(end < start);
+        assert_eq!(end.wrapping_sub(start), I80F48::from_num(35));
+    }
+
     #[test]
     fn test_floor() {
         use crate::fixed::I80F48;
@@ -174,4 +246,28 @@ mod tests {
         assert!(a < b);
         assert!(c > b);
     }
+
+    #[test]
+    fn test_checked_sqrt() {
+        use crate::fixed::I80F48;
+        assert_eq!(
+            I80F48::from_num(16).checked_sqrt(),
+            Some(I80F48::from_num(4))
+        );
+        assert_eq!(
+            I80F48::from_fraction(1, 4).checked_sqrt(),
+            Some(I80F48::from_fraction(1, 2))
+        );
+        assert_eq!((I80F48::ZERO - I80F48::ONE).checked_sqrt(), None);
+    }
+
+    #[test]
+    fn test_checked_div() {
+        use crate::fixed::I80F48;
+        assert_eq!(
+            I80F48::from_num(3).checked_div(I80F48::from_num(4)),
+            Some(I80F48::from_fraction(3, 4))
+        );
+        assert_eq!(I80F48::ONE.checked_div(I80F48::ZERO), None);
+    }
 }
@@ -1,9 +1,16 @@
 use errors::PlasmaStateError;
 
 pub mod amm;
+pub mod depth;
 pub mod errors;
 pub mod fixed;
 pub mod lp;
+pub mod oracle;
+pub mod quote;
+pub mod route;
+#[cfg(test)]
+mod test_utils;
+pub mod zap;
 
 pub type SlotWindow = u64;
 
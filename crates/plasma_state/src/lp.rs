@@ -1,9 +1,49 @@
+#[cfg(feature = "borsh")]
+use borsh::{BorshDeserialize, BorshSerialize};
 use bytemuck::{Pod, Zeroable};
 
-use crate::{amm::Amm, errors::PlasmaStateError, fixed::I80F48};
+use crate::{
+    amm::{Amm, SwapResult},
+    errors::PlasmaStateError,
+    fixed::I80F48,
+};
 
 use super::SlotWindow;
 
+/// Maximum number of deposits of an LP position that can vest at the same time. Once all
+/// tranches are in use, new deposits are merged into the latest tranche, which restarts its
+/// vesting window.
+pub const MAX_VESTING_TRANCHES: usize = 4;
+
+/// How the shares of a tranche unlock over the LP vesting window of the pool
+#[cfg_attr(feature = "borsh", derive(BorshDeserialize, BorshSerialize))]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[repr(u64)]
+pub enum VestingSchedule {
+    /// All shares unlock at the end of the window
+    #[default]
+    Cliff,
+    /// Shares unlock in proportion to the slots elapsed since the deposit
+    Linear,
+}
+
+/// Behaviour of `LpPosition::remove_liquidity` when more LP shares are requested than are
+/// withdrawable
+#[cfg_attr(feature = "borsh", derive(BorshDeserialize, BorshSerialize))]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum RemoveLiquidityMode {
+    /// Fails with `InsufficientWithdrawableShares`
+    #[default]
+    Strict,
+    /// Burns all of the withdrawable shares instead
+    UpTo,
+}
+
+/// A tranche of LP shares that vests over `lp_vesting_window` slots after its deposit. The
+/// tranche is unused if `deposit_slot` is 0.
+///
+/// `lp_shares_to_vest` is the size of the tranche when it started vesting. With a linear
+/// schedule, the shares that already unlocked stay counted until the window is over.
 #[derive(Debug, Copy, Clone, Zeroable, Pod)]
 #[repr(C)]
 pub struct PendingSharesToVest {
@@ -19,27 +59,29 @@ impl PendingSharesToVest {
         }
     }
 
-    pub fn set(&mut self, slot: SlotWindow, lp_shares: u64) -> Result<(), PlasmaStateError> {
-        if self.deposit_slot == 0 {
-            self.deposit_slot = slot;
-            self.lp_shares_to_vest = lp_shares;
-            Ok(())
-        } else {
-            Err(PlasmaStateError::VestingPeriodNotOver)
-        }
+    pub fn is_empty(&self) -> bool {
+        self.deposit_slot == 0
+    }
+
+    pub fn is_fully_vested(&self, slot: SlotWindow, amm: &Amm) -> bool {
+        self.deposit_slot + amm.lp_vesting_window <= slot
     }
 
-    pub fn maybe_vest_shares(&mut self, slot: SlotWindow, amm: &Amm) -> u64 {
-        if self.deposit_slot == 0 {
+    /// Shares of the tranche that are still locked at `slot`
+    pub fn locked_lp_shares(&self, slot: SlotWindow, amm: &Amm, schedule: VestingSchedule) -> u64 {
+        if self.is_empty() || self.is_fully_vested(slot, amm) {
             return 0;
         }
-        if self.deposit_slot + amm.lp_vesting_window <= slot {
-            let lp_shares = self.lp_shares_to_vest;
-            self.deposit_slot = 0;
-            self.lp_shares_to_vest = 0;
-            lp_shares
-        } else {
-            0
+        match schedule {
+            VestingSchedule::Cliff => self.lp_shares_to_vest,
+            VestingSchedule::Linear => {
+                // The window is not 0 here, since the tranche is not fully vested. The
+                // unlocked shares are rounded down.
+                let elapsed_slots = slot.saturating_sub(self.deposit_slot);
+                let unlocked_lp_shares = self.lp_shares_to_vest as u128 * elapsed_slots as u128
+                    / amm.lp_vesting_window as u128;
+                self.lp_shares_to_vest - unlocked_lp_shares as u64
+            }
         }
     }
 }
@@ -52,7 +94,9 @@ pub struct LpPosition {
     pub withdrawable_lp_shares: u64,
     uncollected_fees: u64,
     collected_fees: u64,
-    pub pending_shares_to_vest: PendingSharesToVest,
+    /// The first tranche is at the offset of the single tranche of the legacy layout, so legacy
+    /// positions are migrated by zero extending them
+    pub pending_shares_to_vest: [PendingSharesToVest; MAX_VESTING_TRANCHES],
 }
 
 impl LpPosition {
@@ -63,7 +107,7 @@ impl LpPosition {
             withdrawable_lp_shares: 0,
             uncollected_fees: 0,
             collected_fees: 0,
-            pending_shares_to_vest: PendingSharesToVest::new(),
+            pending_shares_to_vest: [PendingSharesToVest::new(); MAX_VESTING_TRANCHES],
         }
     }
 }
@@ -73,9 +117,16 @@ impl LpPosition {
         &mut self,
         slot: SlotWindow,
         amm: &Amm,
+        schedule: VestingSchedule,
     ) -> Result<(u64, u64), PlasmaStateError> {
-        let vested_lp_shares = self.pending_shares_to_vest.maybe_vest_shares(slot, amm);
+        let vested_lp_shares =
+            self.vested_lp_shares(slot, amm, schedule) - self.withdrawable_lp_shares;
         self.withdrawable_lp_shares += vested_lp_shares;
+        for tranche in self.pending_shares_to_vest.iter_mut() {
+            if !tranche.is_empty() && tranche.is_fully_vested(slot, amm) {
+                *tranche = PendingSharesToVest::new();
+            }
+        }
         let current_reward_factor = amm.reward_factor;
         let withdrawable_fees = if current_reward_factor > I80F48::ZERO && amm.total_lp_shares > 0 {
             let accumulated_reward = current_reward_factor - self.reward_factor_snapshot;
@@ -93,6 +144,69 @@ impl LpPosition {
 }
 
 impl LpPosition {
+    /// Returns true if the position holds no shares, vesting or not, and no uncollected fees.
+    /// Fees only accrue on shares, so an empty position cannot be owed anything later.
+    pub fn is_empty(&self) -> bool {
+        self.lp_shares == 0 && self.pending_lp_shares() == 0 && self.uncollected_fees == 0
+    }
+
+    /// LP shares that have not vested yet, across all tranches
+    pub fn pending_lp_shares(&self) -> u64 {
+        self.lp_shares - self.withdrawable_lp_shares
+    }
+
+    /// LP shares of the position that are vested at `slot`, which can be a future slot. The
+    /// shares are only withdrawable once the position is updated at or after `slot`.
+    pub fn vested_lp_shares(&self, slot: SlotWindow, amm: &Amm, schedule: VestingSchedule) -> u64 {
+        let locked_lp_shares = self
+            .pending_shares_to_vest
+            .iter()
+            .map(|tranche| tranche.locked_lp_shares(slot, amm, schedule))
+            .sum::<u64>();
+        // The tranches can lock more than the pending shares if the vesting window was
+        // extended after shares unlocked, in which case the unlocked shares stay unlocked
+        self.lp_shares - locked_lp_shares.min(self.pending_lp_shares())
+    }
+
+    /// Adds a tranche of shares deposited at `tranche.deposit_slot` to the vesting tranches.
+    /// Deposits in the same slot window share a tranche. The merged shares never vest earlier
+    /// than their deposit slot.
+    fn add_pending_lp_shares(
+        &mut self,
+        slot: SlotWindow,
+        amm: &Amm,
+        schedule: VestingSchedule,
+        tranche: PendingSharesToVest,
+    ) {
+        let tranches = &mut self.pending_shares_to_vest;
+        let index = tranches
+            .iter()
+            .position(|t| t.deposit_slot == tranche.deposit_slot)
+            .or_else(|| tranches.iter().position(|t| t.is_empty()));
+        match index {
+            Some(index) => {
+                tranches[index].deposit_slot = tranche.deposit_slot;
+                tranches[index].lp_shares_to_vest += tranche.lp_shares_to_vest;
+            }
+            // All tranches are in use, so the shares are merged into the latest one
+            None => {
+                let latest = tranches.iter_mut().max_by_key(|t| t.deposit_slot).unwrap();
+                match schedule {
+                    VestingSchedule::Cliff => {
+                        latest.deposit_slot = latest.deposit_slot.max(tranche.deposit_slot);
+                        latest.lp_shares_to_vest += tranche.lp_shares_to_vest;
+                    }
+                    // The shares that are still locked restart vesting from the current slot
+                    VestingSchedule::Linear => {
+                        latest.lp_shares_to_vest = latest.locked_lp_shares(slot, amm, schedule)
+                            + tranche.locked_lp_shares(slot, amm, schedule);
+                        latest.deposit_slot = slot;
+                    }
+                }
+            }
+        }
+    }
+
     pub fn get_withdrawable_base_and_quote_amounts(&self, amm: &Amm) -> (u64, u64) {
         let base_amount = self.withdrawable_lp_shares * amm.base_reserves / amm.total_lp_shares;
         let quote_amount = self.withdrawable_lp_shares * amm.quote_reserves / amm.total_lp_shares;
@@ -105,11 +219,13 @@ impl LpPosition {
         &mut self,
         slot: SlotWindow,
         amm: &mut Amm,
+        schedule: VestingSchedule,
         base_amount_desired: u64,
         quote_amount_desired: u64,
         initial_lp_shares: Option<u64>,
     ) -> Result<AddLiquidityResult, PlasmaStateError> {
-        let (lp_shares_vested, quote_fees_accumulated) = self.preprocess_lp_position(slot, amm)?;
+        let (lp_shares_vested, quote_fees_accumulated) =
+            self.preprocess_lp_position(slot, amm, schedule)?;
 
         let (base_amount_deposited, quote_amount_deposited, lp_shares_received) = amm.mint(
             slot,
@@ -119,7 +235,15 @@ impl LpPosition {
         )?;
 
         // Record new LP shares for the user
-        self.pending_shares_to_vest.set(slot, lp_shares_received)?;
+        self.add_pending_lp_shares(
+            slot,
+            amm,
+            schedule,
+            PendingSharesToVest {
+                deposit_slot: slot,
+                lp_shares_to_vest: lp_shares_received,
+            },
+        );
         self.lp_shares += lp_shares_received;
         Ok(AddLiquidityResult {
             base_amount_deposited,
@@ -134,19 +258,28 @@ impl LpPosition {
         &mut self,
         slot: SlotWindow,
         amm: &mut Amm,
+        schedule: VestingSchedule,
         lp_shares: u64,
+        mode: RemoveLiquidityMode,
     ) -> Result<RemoveLiquidityResult, PlasmaStateError> {
-        let (lp_shares_vested, quote_fees_accumulated) = self.preprocess_lp_position(slot, amm)?;
+        let (lp_shares_vested, quote_fees_accumulated) =
+            self.preprocess_lp_position(slot, amm, schedule)?;
 
-        if lp_shares > self.withdrawable_lp_shares {
-            return Ok(RemoveLiquidityResult {
-                base_amount_withdrawn: 0,
-                quote_amount_withdrawn: 0,
-                lp_shares_burned: 0,
-                lp_shares_vested,
-                quote_fees_accumulated,
-            });
-        }
+        let lp_shares = if lp_shares > self.withdrawable_lp_shares {
+            match mode {
+                RemoveLiquidityMode::UpTo if self.withdrawable_lp_shares > 0 => {
+                    self.withdrawable_lp_shares
+                }
+                _ => {
+                    return Err(PlasmaStateError::InsufficientWithdrawableShares(
+                        lp_shares,
+                        self.withdrawable_lp_shares,
+                    ));
+                }
+            }
+        } else {
+            lp_shares
+        };
 
         let (base_amount_withdrawn, quote_amount_withdrawn) = amm.burn(slot, lp_shares)?;
 
@@ -162,9 +295,175 @@ impl LpPosition {
         })
     }
 
-    pub fn collect_fees(&mut self, slot: SlotWindow, amm: &Amm) -> Result<u64, PlasmaStateError> {
-        self.preprocess_lp_position(slot, amm)?;
+    pub fn collect_fees(
+        &mut self,
+        slot: SlotWindow,
+        amm: &Amm,
+        schedule: VestingSchedule,
+    ) -> Result<u64, PlasmaStateError> {
+        self.preprocess_lp_position(slot, amm, schedule)?;
+        let fees = self.uncollected_fees;
+        self.collected_fees += fees;
+        self.uncollected_fees = 0;
+        Ok(fees)
+    }
+
+    /// Reinvests the uncollected fees of the position. Half of the fees buy base through the
+    /// pool, and the base and the other half of the fees are deposited for new LP shares. The
+    /// new shares are withdrawable right away since they are paid for with fees that the
+    /// position already earned.
+    ///
+    /// Quote that cannot be deposited stays in the uncollected fees, and base that cannot be
+    /// deposited is left in the pool reserves.
+    pub fn compound_fees(
+        &mut self,
+        slot: SlotWindow,
+        amm: &mut Amm,
+        schedule: VestingSchedule,
+        min_lp_shares_out: u64,
+    ) -> Result<CompoundFeesResult, PlasmaStateError> {
+        let (lp_shares_vested, quote_fees_accumulated) =
+            self.preprocess_lp_position(slot, amm, schedule)?;
+
+        let quote_fees = self.uncollected_fees;
+        let quote_amount_swapped = quote_fees / 2;
+        let swap_result = amm.buy_exact_in(slot, quote_amount_swapped)?;
+        let base_amount_bought = swap_result.base_amount_to_transfer;
+        // The position earns its part of the swap fee before its new shares are minted
+        self.preprocess_lp_position(slot, amm, schedule)?;
+
+        let (base_amount_deposited, quote_amount_deposited, lp_shares_received) = amm.mint(
+            slot,
+            base_amount_bought,
+            quote_fees - quote_amount_swapped,
+            None,
+        )?;
+        if lp_shares_received < min_lp_shares_out {
+            return Err(PlasmaStateError::SlippageExceeded(
+                min_lp_shares_out as u128,
+                lp_shares_received as u128,
+            ));
+        }
+        amm.base_reserves += base_amount_bought - base_amount_deposited;
+
+        let quote_fees_compounded = quote_amount_swapped + quote_amount_deposited;
+        self.uncollected_fees -= quote_fees_compounded;
+        self.collected_fees += quote_fees_compounded;
+        self.lp_shares += lp_shares_received;
+        self.withdrawable_lp_shares += lp_shares_received;
+
+        Ok(CompoundFeesResult {
+            quote_fees_compounded,
+            swap_result,
+            base_amount_deposited,
+            quote_amount_deposited,
+            lp_shares_received,
+            lp_shares_vested,
+            quote_fees_accumulated,
+        })
+    }
+
+    /// Moves vested shares out of the position without burning them. The fees accrued by the
+    /// shares up to the current reward factor stay in the position.
+    pub fn withdraw_vested_lp_shares(
+        &mut self,
+        slot: SlotWindow,
+        amm: &Amm,
+        schedule: VestingSchedule,
+        lp_shares: u64,
+    ) -> Result<LpSharesTransferResult, PlasmaStateError> {
+        let (lp_shares_vested, quote_fees_accumulated) =
+            self.preprocess_lp_position(slot, amm, schedule)?;
+
+        if lp_shares > self.withdrawable_lp_shares {
+            return Err(PlasmaStateError::TooManyShares);
+        }
+        self.withdrawable_lp_shares -= lp_shares;
+        self.lp_shares -= lp_shares;
+
+        Ok(LpSharesTransferResult {
+            lp_shares_vested,
+            quote_fees_accumulated,
+        })
+    }
+
+    /// Moves vested shares into the position. The shares only earn the fees accrued after
+    /// they are deposited.
+    pub fn deposit_vested_lp_shares(
+        &mut self,
+        slot: SlotWindow,
+        amm: &Amm,
+        schedule: VestingSchedule,
+        lp_shares: u64,
+    ) -> Result<LpSharesTransferResult, PlasmaStateError> {
+        let (lp_shares_vested, quote_fees_accumulated) =
+            self.preprocess_lp_position(slot, amm, schedule)?;
+
+        self.withdrawable_lp_shares += lp_shares;
+        self.lp_shares += lp_shares;
+
+        Ok(LpSharesTransferResult {
+            lp_shares_vested,
+            quote_fees_accumulated,
+        })
+    }
+
+    /// Moves the shares, pending vesting and uncollected fees of the position into `recipient`,
+    /// leaving this position empty. Vesting tranches keep their deposit slots unless the
+    /// recipient runs out of tranches.
+    pub fn transfer_to(
+        &mut self,
+        slot: SlotWindow,
+        amm: &Amm,
+        schedule: VestingSchedule,
+        recipient: &mut LpPosition,
+    ) -> Result<LpPositionTransferResult, PlasmaStateError> {
+        self.preprocess_lp_position(slot, amm, schedule)?;
+        recipient.preprocess_lp_position(slot, amm, schedule)?;
+
+        let lp_shares_vesting = self.pending_lp_shares();
+        for tranche in self.pending_shares_to_vest.iter().filter(|t| !t.is_empty()) {
+            recipient.add_pending_lp_shares(slot, amm, schedule, *tranche);
+        }
+        recipient.lp_shares += self.lp_shares;
+        recipient.withdrawable_lp_shares += self.withdrawable_lp_shares;
+        recipient.uncollected_fees += self.uncollected_fees;
+
+        let result = LpPositionTransferResult {
+            lp_shares_transferred: self.lp_shares,
+            lp_shares_vesting_transferred: lp_shares_vesting,
+            quote_fees_transferred: self.uncollected_fees,
+        };
+        self.lp_shares = 0;
+        self.withdrawable_lp_shares = 0;
+        self.uncollected_fees = 0;
+        self.pending_shares_to_vest = [PendingSharesToVest::new(); MAX_VESTING_TRANCHES];
+        Ok(result)
+    }
+
+    /// Hands the uncollected fees of the position to all other LP shares by increasing the
+    /// reward factor. This is used for positions that have no owner to collect fees, such as
+    /// the escrow of tokenized LP shares. Returns the amount of fees redistributed.
+    pub fn redistribute_fees(
+        &mut self,
+        slot: SlotWindow,
+        amm: &mut Amm,
+        schedule: VestingSchedule,
+    ) -> Result<u64, PlasmaStateError> {
+        self.preprocess_lp_position(slot, amm, schedule)?;
+
+        let other_lp_shares = amm
+            .total_lp_shares
+            .checked_sub(self.lp_shares)
+            .ok_or(PlasmaStateError::Underflow)?;
+        // If there are no other shares, the fees stay in the position until there are
+        if self.uncollected_fees == 0 || other_lp_shares == 0 {
+            return Ok(0);
+        }
         let fees = self.uncollected_fees;
+        amm.reward_factor += I80F48::from_fraction(fees, other_lp_shares);
+        // The position must not earn from its own redistribution
+        self.reward_factor_snapshot = amm.reward_factor;
         self.collected_fees += fees;
         self.uncollected_fees = 0;
         Ok(fees)
@@ -179,6 +478,29 @@ pub struct AddLiquidityResult {
     pub quote_fees_accumulated: u64,
 }
 
+pub struct CompoundFeesResult {
+    /// Fees used for the swap and the deposit
+    pub quote_fees_compounded: u64,
+    pub swap_result: SwapResult,
+    pub base_amount_deposited: u64,
+    pub quote_amount_deposited: u64,
+    pub lp_shares_received: u64,
+    pub lp_shares_vested: u64,
+    pub quote_fees_accumulated: u64,
+}
+
+pub struct LpSharesTransferResult {
+    pub lp_shares_vested: u64,
+    pub quote_fees_accumulated: u64,
+}
+
+pub struct LpPositionTransferResult {
+    /// Includes the shares that are still vesting
+    pub lp_shares_transferred: u64,
+    pub lp_shares_vesting_transferred: u64,
+    pub quote_fees_transferred: u64,
+}
+
 pub struct RemoveLiquidityResult {
     pub base_amount_withdrawn: u64,
     pub quote_amount_withdrawn: u64,
@@ -186,3 +508,401 @@ pub struct RemoveLiquidityResult {
     pub lp_shares_vested: u64,
     pub quote_fees_accumulated: u64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::initialized_pool_with_lp_position;
+
+    #[test]
+    fn test_moved_shares_keep_accrued_fees() {
+        let (mut amm, mut owner) = initialized_pool_with_lp_position();
+        let mut escrow = LpPosition::new_with_reward_factor_snapshot(amm.reward_factor);
+
+        // Shares cannot be moved before they vest
+        assert_eq!(
+            owner
+                .withdraw_vested_lp_shares(100, &amm, VestingSchedule::Cliff, 1)
+                .err(),
+            Some(PlasmaStateError::TooManyShares)
+        );
+
+        amm.sell_exact_in(104, 500_000_000).unwrap();
+        let fees_before_move = (amm.reward_factor * I80F48::from_num(owner.lp_shares)).floor();
+        assert!(fees_before_move > 0);
+
+        let moved = owner.lp_shares / 2;
+        owner
+            .withdraw_vested_lp_shares(104, &amm, VestingSchedule::Cliff, moved)
+            .unwrap();
+        escrow
+            .deposit_vested_lp_shares(104, &amm, VestingSchedule::Cliff, moved)
+            .unwrap();
+        assert_eq!(owner.uncollected_fees, fees_before_move);
+        assert_eq!(escrow.uncollected_fees, 0);
+        assert_eq!(owner.lp_shares + escrow.lp_shares, amm.total_lp_shares);
+
+        // The fees earned by the escrow after the move are handed to the owner
+        amm.buy_exact_in(108, 500_000_000).unwrap();
+        let redistributed = escrow
+            .redistribute_fees(108, &mut amm, VestingSchedule::Cliff)
+            .unwrap();
+        assert!(redistributed > 0);
+        assert_eq!(escrow.uncollected_fees, 0);
+        assert_eq!(
+            escrow
+                .redistribute_fees(108, &mut amm, VestingSchedule::Cliff)
+                .unwrap(),
+            0
+        );
+
+        let collected = owner
+            .collect_fees(108, &amm, VestingSchedule::Cliff)
+            .unwrap();
+        // Each settlement rounds the fees down by less than one atom
+        assert!(collected <= amm.cumulative_quote_lp_fees);
+        assert!(collected + 4 >= amm.cumulative_quote_lp_fees);
+    }
+
+    #[test]
+    fn test_remove_liquidity_modes() {
+        let (mut amm, mut lp_position) = initialized_pool_with_lp_position();
+        let shares = lp_position.lp_shares;
+
+        // Nothing is withdrawable before the shares vest
+        for mode in [RemoveLiquidityMode::Strict, RemoveLiquidityMode::UpTo] {
+            assert_eq!(
+                lp_position
+                    .remove_liquidity(100, &mut amm, VestingSchedule::Cliff, 1, mode)
+                    .err(),
+                Some(PlasmaStateError::InsufficientWithdrawableShares(1, 0))
+            );
+        }
+
+        assert_eq!(
+            lp_position
+                .remove_liquidity(
+                    104,
+                    &mut amm,
+                    VestingSchedule::Cliff,
+                    shares + 1,
+                    RemoveLiquidityMode::Strict,
+                )
+                .err(),
+            Some(PlasmaStateError::InsufficientWithdrawableShares(
+                shares + 1,
+                shares
+            ))
+        );
+        let result = lp_position
+            .remove_liquidity(
+                104,
+                &mut amm,
+                VestingSchedule::Cliff,
+                shares + 1,
+                RemoveLiquidityMode::UpTo,
+            )
+            .unwrap();
+        assert_eq!(result.lp_shares_burned, shares);
+        assert_eq!(lp_position.lp_shares, 0);
+        assert_eq!(amm.total_lp_shares, 0);
+    }
+
+    #[test]
+    fn test_transfer_merges_positions() {
+        let (mut amm, mut sender) = initialized_pool_with_lp_position();
+        let mut recipient = LpPosition::new_with_reward_factor_snapshot(amm.reward_factor);
+        amm.sell_exact_in(100, 500_000_000).unwrap();
+
+        // The recipient deposits after the sender, each deposit keeps its own vesting window
+        recipient
+            .add_liquidity(
+                101,
+                &mut amm,
+                VestingSchedule::Cliff,
+                1_000_000_000,
+                4_000_000_000,
+                None,
+            )
+            .unwrap();
+        let sender_fees = (amm.reward_factor * I80F48::from_num(sender.lp_shares)).floor();
+        let sender_shares = sender.lp_shares;
+        let recipient_shares = recipient.lp_shares;
+
+        let result = sender
+            .transfer_to(101, &amm, VestingSchedule::Cliff, &mut recipient)
+            .unwrap();
+        assert_eq!(result.lp_shares_transferred, sender_shares);
+        assert_eq!(result.lp_shares_vesting_transferred, sender_shares);
+        assert_eq!(result.quote_fees_transferred, sender_fees);
+        assert!(sender.is_empty());
+        assert_eq!(recipient.lp_shares, sender_shares + recipient_shares);
+        assert_eq!(recipient.pending_shares_to_vest[0].deposit_slot, 101);
+        assert_eq!(recipient.pending_shares_to_vest[1].deposit_slot, 100);
+        assert_eq!(recipient.uncollected_fees, sender_fees);
+
+        assert_eq!(
+            recipient
+                .withdraw_vested_lp_shares(102, &amm, VestingSchedule::Cliff, sender_shares + 1)
+                .err(),
+            Some(PlasmaStateError::TooManyShares)
+        );
+        assert_eq!(recipient.withdrawable_lp_shares, sender_shares);
+        recipient
+            .withdraw_vested_lp_shares(
+                103,
+                &amm,
+                VestingSchedule::Cliff,
+                sender_shares + recipient_shares,
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_deposits_vest_independently() {
+        let (mut amm, mut lp_position) = initialized_pool_with_lp_position();
+        let initial_shares = lp_position.lp_shares;
+
+        // Top ups within the vesting window are accepted
+        let second_deposit = lp_position
+            .add_liquidity(
+                101,
+                &mut amm,
+                VestingSchedule::Cliff,
+                1_000_000_000,
+                4_000_000_000,
+                None,
+            )
+            .unwrap()
+            .lp_shares_received;
+        let third_deposit = lp_position
+            .add_liquidity(
+                101,
+                &mut amm,
+                VestingSchedule::Cliff,
+                1_000_000_000,
+                4_000_000_000,
+                None,
+            )
+            .unwrap()
+            .lp_shares_received;
+        assert_eq!(lp_position.pending_shares_to_vest[1].deposit_slot, 101);
+        assert!(lp_position.pending_shares_to_vest[2].is_empty());
+
+        assert_eq!(
+            lp_position
+                .collect_fees(102, &amm, VestingSchedule::Cliff)
+                .unwrap(),
+            0
+        );
+        assert_eq!(lp_position.withdrawable_lp_shares, initial_shares);
+        assert_eq!(
+            lp_position
+                .collect_fees(103, &amm, VestingSchedule::Cliff)
+                .unwrap(),
+            0
+        );
+        assert_eq!(
+            lp_position.withdrawable_lp_shares,
+            initial_shares + second_deposit + third_deposit
+        );
+        assert_eq!(lp_position.pending_lp_shares(), 0);
+    }
+
+    #[test]
+    fn test_full_tranches_merge_into_latest() {
+        let mut amm = Amm::new(30, 20, 10, 100);
+        let mut lp_position = LpPosition::new_with_reward_factor_snapshot(amm.reward_factor);
+        lp_position
+            .add_liquidity(
+                100,
+                &mut amm,
+                VestingSchedule::Cliff,
+                10_000_000_000,
+                40_000_000_000,
+                Some(20_000_000_000),
+            )
+            .unwrap();
+        let mut deposits = vec![lp_position.lp_shares];
+        for slot in 101..=104 {
+            let result = lp_position
+                .add_liquidity(
+                    slot,
+                    &mut amm,
+                    VestingSchedule::Cliff,
+                    1_000_000_000,
+                    4_000_000_000,
+                    None,
+                )
+                .unwrap();
+            deposits.push(result.lp_shares_received);
+        }
+        // The deposit at slot 104 is merged into the tranche of slot 103
+        assert!(lp_position
+            .pending_shares_to_vest
+            .iter()
+            .all(|tranche| tranche.deposit_slot != 103));
+        assert_eq!(lp_position.pending_shares_to_vest[3].deposit_slot, 104);
+        assert_eq!(
+            lp_position.pending_shares_to_vest[3].lp_shares_to_vest,
+            deposits[3] + deposits[4]
+        );
+
+        lp_position
+            .collect_fees(112, &amm, VestingSchedule::Cliff)
+            .unwrap();
+        assert_eq!(
+            lp_position.withdrawable_lp_shares,
+            deposits[0] + deposits[1] + deposits[2]
+        );
+        lp_position
+            .collect_fees(113, &amm, VestingSchedule::Cliff)
+            .unwrap();
+        assert_eq!(
+            lp_position.withdrawable_lp_shares,
+            deposits[0] + deposits[1] + deposits[2]
+        );
+        lp_position
+            .collect_fees(114, &amm, VestingSchedule::Cliff)
+            .unwrap();
+        assert_eq!(lp_position.withdrawable_lp_shares, lp_position.lp_shares);
+    }
+
+    #[test]
+    fn test_linear_vesting_unlocks_over_window() {
+        let mut amm = Amm::new(30, 20, 10, 100);
+        let mut lp_position = LpPosition::new_with_reward_factor_snapshot(amm.reward_factor);
+        lp_position
+            .add_liquidity(
+                100,
+                &mut amm,
+                VestingSchedule::Linear,
+                10_000_000_000,
+                40_000_000_000,
+                Some(20_000_000_000),
+            )
+            .unwrap();
+        let shares = lp_position.lp_shares;
+
+        // Vested shares can be computed for future slots
+        assert_eq!(
+            lp_position.vested_lp_shares(100, &amm, VestingSchedule::Linear),
+            0
+        );
+        assert_eq!(
+            lp_position.vested_lp_shares(104, &amm, VestingSchedule::Linear),
+            shares * 4 / 10
+        );
+        assert_eq!(
+            lp_position.vested_lp_shares(104, &amm, VestingSchedule::Cliff),
+            0
+        );
+        assert_eq!(
+            lp_position.vested_lp_shares(110, &amm, VestingSchedule::Linear),
+            shares
+        );
+
+        // Half of the shares are unlocked halfway through the window
+        let result = lp_position
+            .remove_liquidity(
+                105,
+                &mut amm,
+                VestingSchedule::Linear,
+                shares / 4,
+                RemoveLiquidityMode::Strict,
+            )
+            .unwrap();
+        assert_eq!(result.lp_shares_burned, shares / 4);
+        assert_eq!(result.lp_shares_vested, shares / 2);
+        assert_eq!(lp_position.withdrawable_lp_shares, shares / 4);
+        assert_eq!(
+            lp_position.vested_lp_shares(108, &amm, VestingSchedule::Linear),
+            shares * 8 / 10 - shares / 4
+        );
+
+        lp_position
+            .collect_fees(110, &amm, VestingSchedule::Linear)
+            .unwrap();
+        assert_eq!(lp_position.withdrawable_lp_shares, lp_position.lp_shares);
+        assert_eq!(lp_position.pending_lp_shares(), 0);
+        assert!(lp_position.pending_shares_to_vest[0].is_empty());
+    }
+
+    #[test]
+    fn test_linear_vesting_merge_restarts_locked_shares() {
+        let mut amm = Amm::new(30, 20, 10, 100);
+        let mut lp_position = LpPosition::new_with_reward_factor_snapshot(amm.reward_factor);
+        lp_position
+            .add_liquidity(
+                100,
+                &mut amm,
+                VestingSchedule::Linear,
+                10_000_000_000,
+                40_000_000_000,
+                Some(20_000_000_000),
+            )
+            .unwrap();
+        let mut deposits = vec![lp_position.lp_shares];
+        for slot in 101..=104 {
+            let result = lp_position
+                .add_liquidity(
+                    slot,
+                    &mut amm,
+                    VestingSchedule::Linear,
+                    1_000_000_000,
+                    4_000_000_000,
+                    None,
+                )
+                .unwrap();
+            deposits.push(result.lp_shares_received);
+        }
+
+        // Only the shares of the slot 103 deposit that are still locked are merged
+        assert_eq!(lp_position.pending_shares_to_vest[3].deposit_slot, 104);
+        assert_eq!(
+            lp_position.pending_shares_to_vest[3].lp_shares_to_vest,
+            deposits[3] - deposits[3] / 10 + deposits[4]
+        );
+        assert_eq!(
+            lp_position.vested_lp_shares(104, &amm, VestingSchedule::Linear),
+            lp_position.withdrawable_lp_shares
+        );
+        assert_eq!(
+            lp_position.vested_lp_shares(114, &amm, VestingSchedule::Linear),
+            lp_position.lp_shares
+        );
+    }
+
+    #[test]
+    fn test_compound_fees() {
+        let (mut amm, mut lp_position) = initialized_pool_with_lp_position();
+        amm.sell_exact_in(104, 2_000_000_000).unwrap();
+        amm.buy_exact_in(104, 8_000_000_000).unwrap();
+        let fees = (amm.reward_factor * I80F48::from_num(lp_position.lp_shares)).floor();
+        let lp_shares = lp_position.lp_shares;
+
+        let (mut failed_amm, mut failed_lp_position) = (amm, lp_position);
+        assert!(matches!(
+            failed_lp_position.compound_fees(
+                104,
+                &mut failed_amm,
+                VestingSchedule::Cliff,
+                u64::MAX
+            ),
+            Err(PlasmaStateError::SlippageExceeded(..))
+        ));
+
+        let result = lp_position
+            .compound_fees(104, &mut amm, VestingSchedule::Cliff, 1)
+            .unwrap();
+        assert_eq!(result.swap_result.quote_amount_to_transfer, fees / 2);
+        assert!(result.quote_fees_compounded <= fees);
+        assert_eq!(lp_position.lp_shares, lp_shares + result.lp_shares_received);
+        assert_eq!(lp_position.withdrawable_lp_shares, lp_position.lp_shares);
+        assert_eq!(amm.total_lp_shares, lp_position.lp_shares);
+        // The leftover fees are the quote that could not be paired with the bought base, plus
+        // the part of the swap fee earned by the position
+        let fees_left = lp_position.uncollected_fees + result.quote_fees_compounded - fees;
+        assert!(fees_left <= result.swap_result.fee_in_quote);
+    }
+}
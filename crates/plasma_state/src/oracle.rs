@@ -0,0 +1,342 @@
+#[cfg(feature = "borsh")]
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+
+use crate::{amm::Amm, errors::PlasmaStateError, fixed::I80F48, SlotWindow};
+
+/// Value of the accumulators of a pool at a given slot.
+#[cfg_attr(feature = "borsh", derive(BorshDeserialize, BorshSerialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Zeroable, Pod)]
+#[repr(C)]
+pub struct Observation {
+    pub slot: SlotWindow,
+    _padding: u64,
+    pub cumulative_price: I80F48,
+    pub cumulative_liquidity: I80F48,
+}
+
+impl Observation {
+    pub fn new(slot: SlotWindow, cumulative_price: I80F48, cumulative_liquidity: I80F48) -> Self {
+        Self {
+            slot,
+            _padding: 0,
+            cumulative_price,
+            cumulative_liquidity,
+        }
+    }
+
+    /// Linearly interpolates the accumulators between `self` and a later observation `next`.
+    fn interpolate(&self, next: &Observation, slot: SlotWindow) -> Observation {
+        let slots_elapsed = I80F48::from_num(next.slot - self.slot);
+        let slots_to_target = slot - self.slot;
+        let price_rate = next.cumulative_price.wrapping_sub(self.cumulative_price) / slots_elapsed;
+        let liquidity_rate = next
+            .cumulative_liquidity
+            .wrapping_sub(self.cumulative_liquidity)
+            / slots_elapsed;
+        Observation::new(
+            slot,
+            self.cumulative_price
+                .wrapping_add(price_rate.wrapping_mul_int(slots_to_target)),
+            self.cumulative_liquidity
+                .wrapping_add(liquidity_rate.wrapping_mul_int(slots_to_target)),
+        )
+    }
+}
+
+impl Amm {
+    /// Geometric mean of the reserves
+    pub fn get_liquidity(&self) -> I80F48 {
+        let base = I80F48::from_num(self.base_reserves).checked_sqrt();
+        let quote = I80F48::from_num(self.quote_reserves).checked_sqrt();
+        match (base, quote) {
+            (Some(base), Some(quote)) => base * quote,
+            _ => I80F48::ZERO,
+        }
+    }
+
+    /// Returns the value the accumulators would have at `slot` if the snapshot was rolled at
+    /// that slot. Slots before the current snapshot return the stored accumulators.
+    pub fn observe_at(&self, slot: SlotWindow) -> Observation {
+        let slot = slot.max(self.get_slot());
+        let slots_elapsed = slot - self.get_slot();
+        Observation::new(
+            slot,
+            self.cumulative_price
+                .wrapping_add(self.get_mid_price().wrapping_mul_int(slots_elapsed)),
+            self.cumulative_liquidity
+                .wrapping_add(self.get_liquidity().wrapping_mul_int(slots_elapsed)),
+        )
+    }
+}
+
+/// Returns the time-weighted average price between two observations of the same pool, in quote
+/// atoms per base atom.
+pub fn get_twap(start: &Observation, end: &Observation) -> Result<I80F48, PlasmaStateError> {
+    if end.slot <= start.slot {
+        return Err(PlasmaStateError::UnexpectedArgument);
+    }
+    let slots_elapsed = end.slot - start.slot;
+    // The accumulator wraps around, the difference is correct as long as it fits in an I80F48
+    let price_delta = end.cumulative_price.wrapping_sub(start.cumulative_price);
+    Ok(price_delta / I80F48::from_num(slots_elapsed))
+}
+
+/// Returns the time-weighted average liquidity between two observations of the same pool.
+pub fn get_time_weighted_liquidity(
+    start: &Observation,
+    end: &Observation,
+) -> Result<I80F48, PlasmaStateError> {
+    if end.slot <= start.slot {
+        return Err(PlasmaStateError::UnexpectedArgument);
+    }
+    let slots_elapsed = end.slot - start.slot;
+    let liquidity_delta = end
+        .cumulative_liquidity
+        .wrapping_sub(start.cumulative_liquidity);
+    Ok(liquidity_delta / I80F48::from_num(slots_elapsed))
+}
+
+/// State of a ring buffer of observations. The buffer itself is a slice of `Observation` whose
+/// length is the capacity of the buffer.
+///
+/// Entries `[0, length)` are always initialized. When the capacity grows, the new entries are
+/// only used once the buffer wraps around to the end, so that the entries stay ordered by slot.
+#[cfg_attr(feature = "borsh", derive(BorshDeserialize, BorshSerialize))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Zeroable, Pod)]
+#[repr(C)]
+pub struct ObservationBufferHeader {
+    /// Index of the most recent observation
+    pub index: u64,
+    /// Number of entries in use
+    pub length: u64,
+}
+
+impl ObservationBufferHeader {
+    /// Records the accumulators of `amm` at its snapshot slot. Returns false if the most recent
+    /// observation is already at that slot.
+    pub fn write(
+        &mut self,
+        observations: &mut [Observation],
+        amm: &Amm,
+    ) -> Result<bool, PlasmaStateError> {
+        let capacity = observations.len() as u64;
+        if capacity == 0 || self.length > capacity || self.index >= capacity {
+            return Err(PlasmaStateError::UnexpectedArgument);
+        }
+        let observation = amm.observe_at(amm.get_slot());
+        if self.length == 0 {
+            observations[0] = observation;
+            self.index = 0;
+            self.length = 1;
+            return Ok(true);
+        }
+        if observations[self.index as usize].slot >= observation.slot {
+            return Ok(false);
+        }
+        if self.index == self.length - 1 && self.length < capacity {
+            self.length += 1;
+        }
+        self.index = (self.index + 1) % self.length;
+        observations[self.index as usize] = observation;
+        Ok(true)
+    }
+
+    /// Returns the accumulators of the pool `slots_ago` slots before `slot`.
+    ///
+    /// Slots after the most recent observation are read from `amm` directly. Slots between two
+    /// observations are linearly interpolated, which is exact if the pool price did not change
+    /// between the two observations.
+    pub fn observe(
+        &self,
+        observations: &[Observation],
+        amm: &Amm,
+        slot: SlotWindow,
+        slots_ago: u64,
+    ) -> Result<Observation, PlasmaStateError> {
+        let target = slot
+            .checked_sub(slots_ago)
+            .ok_or(PlasmaStateError::Underflow)?;
+        if target >= amm.get_slot() {
+            return Ok(amm.observe_at(target));
+        }
+        if self.length == 0 {
+            return Err(PlasmaStateError::ObservationTooOld(
+                target as u128,
+                amm.get_slot() as u128,
+            ));
+        }
+        if self.length > observations.len() as u64 || self.index >= self.length {
+            return Err(PlasmaStateError::UnexpectedArgument);
+        }
+
+        let length = self.length as usize;
+        let oldest_index = (self.index as usize + 1) % length;
+        // Entries ordered from the oldest to the most recent, followed by the current state
+        // of the pool
+        let get = |i: usize| {
+            if i == length {
+                amm.observe_at(amm.get_slot())
+            } else {
+                observations[(oldest_index + i) % length]
+            }
+        };
+
+        let oldest = get(0);
+        if target < oldest.slot {
+            return Err(PlasmaStateError::ObservationTooOld(
+                target as u128,
+                oldest.slot as u128,
+            ));
+        }
+
+        // Find the last entry at or before the target slot
+        let (mut low, mut high) = (0, length);
+        while low < high {
+            let mid = (low + high).div_ceil(2);
+            if get(mid).slot <= target {
+                low = mid;
+            } else {
+                high = mid - 1;
+            }
+        }
+        let before = get(low);
+        if before.slot == target {
+            return Ok(before);
+        }
+        Ok(before.interpolate(&get(low + 1), target))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::initialized_pool;
+
+    #[test]
+    fn test_accumulators_update_on_snapshot() {
+        let mut amm = initialized_pool();
+        assert_eq!(amm.cumulative_price, I80F48::ZERO);
+        assert_eq!(amm.get_liquidity(), I80F48::from_num(20_000_000_000));
+
+        // The pool was at a price of 4 for 8 slots
+        amm.maybe_update_snapshot(108);
+        assert_eq!(amm.cumulative_price, I80F48::from_num(32));
+        assert_eq!(amm.cumulative_liquidity, I80F48::from_num(160_000_000_000));
+
+        // Rolling twice in the same slot does not accumulate
+        assert!(!amm.maybe_update_snapshot(108));
+        assert_eq!(amm.cumulative_price, I80F48::from_num(32));
+    }
+
+    #[test]
+    fn test_twap() {
+        let mut amm = initialized_pool();
+        let start = amm.observe_at(100);
+
+        // Push the price up, the new price is only accounted for once the snapshot rolls
+        amm.buy_exact_in(104, 10_000_000_000).unwrap();
+        let post_swap_price = amm.get_mid_price();
+        assert!(post_swap_price > I80F48::from_num(4));
+
+        let end = amm.observe_at(112);
+        let mut rolled = amm;
+        rolled.maybe_update_snapshot(112);
+        assert_eq!(end.cumulative_price, rolled.cumulative_price);
+        assert_eq!(end.cumulative_liquidity, rolled.cumulative_liquidity);
+
+        // 4 slots at a price of 4, then 8 slots at the post swap price
+        let twap = get_twap(&start, &end).unwrap();
+        let expected =
+            (I80F48::from_num(16) + post_swap_price.wrapping_mul_int(8)) / I80F48::from_num(12);
+        assert_eq!(twap, expected);
+        assert!(
+            get_time_weighted_liquidity(&start, &end).unwrap()
+                > amm.get_liquidity() / I80F48::from_num(2)
+        );
+
+        assert_eq!(
+            get_twap(&end, &start).unwrap_err(),
+            PlasmaStateError::UnexpectedArgument
+        );
+    }
+
+    #[test]
+    fn test_observation_buffer_wraps() {
+        let mut amm = initialized_pool();
+        let mut header = ObservationBufferHeader::default();
+        let mut observations = [Observation::zeroed(); 3];
+
+        assert!(header.write(&mut observations, &amm).unwrap());
+        assert!(!header.write(&mut observations, &amm).unwrap());
+        for slot in [104, 108, 112, 116] {
+            amm.sell_exact_in(slot, 1_000_000).unwrap();
+            assert!(header.write(&mut observations, &amm).unwrap());
+        }
+        assert_eq!(header.length, 3);
+        assert_eq!(header.index, 1);
+        let slots = observations.iter().map(|o| o.slot).collect::<Vec<_>>();
+        assert_eq!(slots, vec![112, 116, 108]);
+
+        // The oldest observation is at slot 108
+        assert_eq!(
+            header.observe(&observations, &amm, 120, 13).unwrap_err(),
+            PlasmaStateError::ObservationTooOld(107, 108)
+        );
+        assert_eq!(
+            header.observe(&observations, &amm, 120, 12).unwrap(),
+            observations[2]
+        );
+        assert_eq!(
+            header.observe(&observations, &amm, 120, 0).unwrap(),
+            amm.observe_at(120)
+        );
+    }
+
+    #[test]
+    fn test_observation_buffer_grows_in_order() {
+        let mut amm = initialized_pool();
+        let mut header = ObservationBufferHeader::default();
+        let mut observations = [Observation::zeroed(); 4];
+
+        // Fill a buffer of capacity 2, then grow it to 4
+        header.write(&mut observations[..2], &amm).unwrap();
+        for slot in [104, 108] {
+            amm.sell_exact_in(slot, 1_000_000).unwrap();
+            header.write(&mut observations[..2], &amm).unwrap();
+        }
+        assert_eq!((header.index, header.length), (0, 2));
+        for slot in [112, 116, 120] {
+            amm.sell_exact_in(slot, 1_000_000).unwrap();
+            header.write(&mut observations, &amm).unwrap();
+        }
+        // The new entries are only used once the index reaches the end of the used entries
+        let slots = observations.iter().map(|o| o.slot).collect::<Vec<_>>();
+        assert_eq!(slots, vec![108, 112, 116, 120]);
+        assert_eq!((header.index, header.length), (3, 4));
+    }
+
+    #[test]
+    fn test_observe_interpolates() {
+        let mut amm = initialized_pool();
+        let mut header = ObservationBufferHeader::default();
+        let mut observations = [Observation::zeroed(); 4];
+        header.write(&mut observations, &amm).unwrap();
+
+        amm.buy_exact_in(108, 1_000_000_000).unwrap();
+        header.write(&mut observations, &amm).unwrap();
+
+        // The price was constant between slots 100 and 108, so the interpolation is exact
+        let observation = header.observe(&observations, &amm, 108, 3).unwrap();
+        assert_eq!(observation.slot, 105);
+        assert_eq!(observation.cumulative_price, I80F48::from_num(20));
+        assert_eq!(
+            observation.cumulative_liquidity,
+            I80F48::from_num(100_000_000_000)
+        );
+
+        // Slots after the last snapshot use the current pool state
+        let observation = header.observe(&observations, &amm, 116, 2).unwrap();
+        assert_eq!(observation, amm.observe_at(114));
+    }
+}
@@ -0,0 +1,201 @@
+use crate::{
+    amm::{Amm, Side, SwapResult, SwapType},
+    errors::PlasmaStateError,
+    fixed::I80F48,
+    SlotWindow,
+};
+
+/// A simulated swap along with the price metrics derived from it.
+///
+/// All prices are expressed in quote atoms per base atom.
+#[derive(Debug, Clone, Copy)]
+pub struct Quote {
+    pub swap_result: SwapResult,
+    /// Price of the pool before the swap
+    pub mid_price: I80F48,
+    /// Price of the pool after the swap
+    pub post_mid_price: I80F48,
+    /// Price of the pool at the snapshot. The virtual limit order is always quoted at this price.
+    pub snapshot_price: I80F48,
+    /// Average price paid (buy) or received (sell) by the trader, fees included
+    pub execution_price: I80F48,
+    /// Relative difference between the execution price and the snapshot price. This is positive
+    /// when the trader gets a worse price than the snapshot price, and zero if the snapshot price
+    /// is zero.
+    pub price_impact: I80F48,
+    /// Fraction of the base amount that was matched against the virtual limit order. The rest
+    /// of the fill was swapped through the curve.
+    pub limit_order_fill_ratio: I80F48,
+}
+
+impl Amm {
+    pub fn get_mid_price(&self) -> I80F48 {
+        if self.base_reserves == 0 {
+            return I80F48::ZERO;
+        }
+        I80F48::from_fraction(self.quote_reserves, self.base_reserves)
+    }
+
+    pub fn get_snapshot_price(&self) -> I80F48 {
+        if self.base_reserves_snapshot == 0 {
+            return I80F48::ZERO;
+        }
+        I80F48::from_fraction(self.quote_reserves_snapshot, self.base_reserves_snapshot)
+    }
+
+    /// Simulates a swap at `slot` and returns the derived price metrics alongside the `SwapResult`.
+    ///
+    /// This fails with `PlasmaStateError::SlippageExceeded` if the swap does not respect the limit
+    /// of `swap_type`. Use a `min_amount_out` of 0 or a `max_amount_in` of `u64::MAX` to get an
+    /// unconstrained quote.
+    pub fn quote(
+        &self,
+        side: Side,
+        swap_type: SwapType,
+        slot: SlotWindow,
+    ) -> Result<Quote, PlasmaStateError> {
+        let mut pool_clone = *self;
+        if pool_clone.total_lp_shares == 0 {
+            return Err(PlasmaStateError::UninitializedPool);
+        }
+        // The snapshot needs to be updated before reading the prices, the swap will otherwise
+        // roll it forward and the snapshot price would be stale
+        pool_clone.maybe_update_snapshot(slot);
+        let mid_price = pool_clone.get_mid_price();
+        let snapshot_price = pool_clone.get_snapshot_price();
+
+        let swap_result = pool_clone.swap(slot, side, swap_type)?;
+        swap_type.check_slippage(swap_result.amount_in(), swap_result.amount_out())?;
+
+        let (execution_price, price_impact, limit_order_fill_ratio) =
+            if swap_result.base_amount_to_transfer == 0 {
+                (I80F48::ZERO, I80F48::ZERO, I80F48::ZERO)
+            } else {
+                let execution_price = I80F48::from_fraction(
+                    swap_result.quote_amount_to_transfer,
+                    swap_result.base_amount_to_transfer,
+                );
+                // There is no reference price if the snapshot price is zero
+                let price_impact = match (side, execution_price.checked_div(snapshot_price)) {
+                    (_, None) => I80F48::ZERO,
+                    (Side::Buy, Some(price_ratio)) => price_ratio - I80F48::ONE,
+                    (Side::Sell, Some(price_ratio)) => I80F48::ONE - price_ratio,
+                };
+                let limit_order_fill_ratio = I80F48::from_fraction(
+                    swap_result.base_matched_as_limit_order,
+                    swap_result.base_amount_to_transfer,
+                );
+                (execution_price, price_impact, limit_order_fill_ratio)
+            };
+
+        Ok(Quote {
+            swap_result,
+            mid_price,
+            post_mid_price: pool_clone.get_mid_price(),
+            snapshot_price,
+            execution_price,
+            price_impact,
+            limit_order_fill_ratio,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::initialized_pool;
+
+    #[test]
+    fn test_quote_matches_swap() {
+        let amm = initialized_pool();
+        let swap_type = SwapType::ExactIn {
+            amount_in: 1_000_000_000,
+            min_amount_out: 0,
+        };
+        let quote = amm.quote(Side::Buy, swap_type, 100).unwrap();
+        assert_eq!(
+            quote.swap_result,
+            amm.simulate_buy_exact_in_with_slot(100, 1_000_000_000)
+                .unwrap()
+        );
+        assert_eq!(quote.mid_price, I80F48::from_num(4));
+        assert_eq!(quote.snapshot_price, I80F48::from_num(4));
+        assert!(quote.post_mid_price > quote.mid_price);
+        assert!(quote.execution_price > quote.snapshot_price);
+        assert!(quote.price_impact > I80F48::ZERO);
+        // The pool is at the snapshot price, so there is no limit order to match against
+        assert_eq!(quote.limit_order_fill_ratio, I80F48::ZERO);
+    }
+
+    #[test]
+    fn test_quote_limit_order_fill() {
+        let mut amm = initialized_pool();
+        amm.sell_exact_in(100, 100_000_000).unwrap();
+
+        // A small buy in the same slot is entirely matched against the ask at the snapshot price
+        let quote = amm
+            .quote(
+                Side::Buy,
+                SwapType::ExactOut {
+                    amount_out: 1_000_000,
+                    max_amount_in: u64::MAX,
+                },
+                100,
+            )
+            .unwrap();
+        assert_eq!(quote.limit_order_fill_ratio, I80F48::ONE);
+        assert!(quote.mid_price < quote.snapshot_price);
+
+        // Once the snapshot rolls, the limit order disappears
+        let quote = amm
+            .quote(
+                Side::Buy,
+                SwapType::ExactOut {
+                    amount_out: 1_000_000,
+                    max_amount_in: u64::MAX,
+                },
+                104,
+            )
+            .unwrap();
+        assert_eq!(quote.limit_order_fill_ratio, I80F48::ZERO);
+        assert_eq!(quote.mid_price, quote.snapshot_price);
+    }
+
+    #[test]
+    fn test_quote_zero_snapshot_price() {
+        let mut amm = initialized_pool();
+        amm.quote_reserves_snapshot = 0;
+        let quote = amm
+            .quote(
+                Side::Buy,
+                SwapType::ExactIn {
+                    amount_in: 1_000_000,
+                    min_amount_out: 0,
+                },
+                100,
+            )
+            .unwrap();
+        assert_eq!(quote.snapshot_price, I80F48::ZERO);
+        assert!(quote.execution_price > I80F48::ZERO);
+        assert_eq!(quote.price_impact, I80F48::ZERO);
+    }
+
+    #[test]
+    fn test_quote_slippage() {
+        let amm = initialized_pool();
+        let result = amm
+            .simulate_sell_exact_in_with_slot(100, 1_000_000)
+            .unwrap();
+        let swap_type = SwapType::ExactIn {
+            amount_in: 1_000_000,
+            min_amount_out: result.quote_amount_to_transfer + 1,
+        };
+        assert_eq!(
+            amm.quote(Side::Sell, swap_type, 100).unwrap_err(),
+            PlasmaStateError::SlippageExceeded(
+                result.quote_amount_to_transfer as u128 + 1,
+                result.quote_amount_to_transfer as u128,
+            )
+        );
+    }
+}
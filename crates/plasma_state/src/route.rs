@@ -0,0 +1,333 @@
+use crate::{
+    amm::{Amm, Side, SwapResult, SwapType},
+    errors::PlasmaStateError,
+    fixed::I80F48,
+    quote::Quote,
+    SlotWindow,
+};
+
+/// Result of quoting a swap through a sequence of pools.
+#[derive(Debug, Clone)]
+pub struct RouteQuote {
+    /// Quote of each hop, in the order of the route
+    pub hops: Vec<Quote>,
+    /// Amount of tokens sent by the trader to the first pool
+    pub amount_in: u64,
+    /// Amount of tokens received by the trader from the last pool
+    pub amount_out: u64,
+    /// Relative shortfall of `amount_out` compared to trading `amount_in` through every hop at
+    /// its snapshot price. This is positive when the route gets a worse price than the snapshots.
+    pub price_impact: I80F48,
+}
+
+/// Quotes a swap through `hops`, where the output token of each hop is the input token of the next.
+///
+/// For `SwapType::ExactIn`, the output of each hop is swapped exact in through the next hop. For
+/// `SwapType::ExactOut`, the route is solved backwards so that every hop is swapped exact out for
+/// precisely the input amount required by the next hop. The slippage limit of `swap_type` applies
+/// to the route as a whole.
+///
+/// Each pool is simulated independently, so a pool should not appear more than once in a route.
+pub fn quote_route(
+    hops: &[(&Amm, Side)],
+    swap_type: SwapType,
+    slot: SlotWindow,
+) -> Result<RouteQuote, PlasmaStateError> {
+    if hops.is_empty() {
+        return Err(PlasmaStateError::MissingExpectedArgument);
+    }
+
+    let quotes = match swap_type {
+        SwapType::ExactIn { amount_in, .. } => {
+            let mut quotes = Vec::with_capacity(hops.len());
+            let mut hop_amount_in = amount_in;
+            for (amm, side) in hops.iter() {
+                let quote = amm.quote(
+                    *side,
+                    SwapType::ExactIn {
+                        amount_in: hop_amount_in,
+                        min_amount_out: 0,
+                    },
+                    slot,
+                )?;
+                hop_amount_in = quote.swap_result.amount_out();
+                quotes.push(quote);
+            }
+            quotes
+        }
+        SwapType::ExactOut { amount_out, .. } => {
+            let mut quotes = Vec::with_capacity(hops.len());
+            let mut hop_amount_out = amount_out;
+            for (amm, side) in hops.iter().rev() {
+                let quote = amm.quote(
+                    *side,
+                    SwapType::ExactOut {
+                        amount_out: hop_amount_out,
+                        max_amount_in: u64::MAX,
+                    },
+                    slot,
+                )?;
+                hop_amount_out = quote.swap_result.amount_in();
+                quotes.push(quote);
+            }
+            quotes.reverse();
+            quotes
+        }
+    };
+
+    let amount_in = quotes[0].swap_result.amount_in();
+    let amount_out = quotes[quotes.len() - 1].swap_result.amount_out();
+    swap_type.check_slippage(amount_in, amount_out)?;
+
+    // Number of output tokens received per input token if every hop traded at its snapshot price
+    let mut snapshot_rate = I80F48::ONE;
+    for ((_, side), quote) in hops.iter().zip(quotes.iter()) {
+        snapshot_rate = match side {
+            Side::Buy => snapshot_rate / quote.snapshot_price,
+            Side::Sell => snapshot_rate * quote.snapshot_price,
+        };
+    }
+    let price_impact = if amount_in == 0 {
+        I80F48::ZERO
+    } else {
+        I80F48::ONE - I80F48::from_num(amount_out) / (I80F48::from_num(amount_in) * snapshot_rate)
+    };
+
+    Ok(RouteQuote {
+        hops: quotes,
+        amount_in,
+        amount_out,
+        price_impact,
+    })
+}
+
+/// Result of splitting a swap across several pools of the same pair.
+#[derive(Debug, Clone)]
+pub struct SplitQuote {
+    /// Amount allocated to each pool, in the order of the input pools. This is an input amount
+    /// for `SwapType::ExactIn` and an output amount for `SwapType::ExactOut`.
+    pub amounts: Vec<u64>,
+    /// Simulated swap for each pool, `None` if no amount was allocated to the pool
+    pub swap_results: Vec<Option<SwapResult>>,
+    pub amount_in: u64,
+    pub amount_out: u64,
+}
+
+/// Finds the split of a swap across `pools` that maximizes the amount out (exact in) or minimizes
+/// the amount in (exact out).
+///
+/// The swap amount is divided into `num_chunks` chunks and each chunk is greedily allocated to the
+/// pool with the best marginal price. Because every pool is convex, this converges to the optimal
+/// split as the number of chunks grows. Pools that cannot fill a chunk are skipped.
+pub fn find_best_split(
+    pools: &[&Amm],
+    side: Side,
+    swap_type: SwapType,
+    slot: SlotWindow,
+    num_chunks: u64,
+) -> Result<SplitQuote, PlasmaStateError> {
+    if pools.is_empty() || num_chunks == 0 {
+        return Err(PlasmaStateError::MissingExpectedArgument);
+    }
+    let (total_amount, is_exact_in) = match swap_type {
+        SwapType::ExactIn { amount_in, .. } => (amount_in, true),
+        SwapType::ExactOut { amount_out, .. } => (amount_out, false),
+    };
+    let chunk_size = total_amount / num_chunks;
+    let remainder = total_amount % num_chunks;
+
+    let simulate = |amm: &Amm, amount: u64| {
+        let swap_type = if is_exact_in {
+            SwapType::ExactIn {
+                amount_in: amount,
+                min_amount_out: 0,
+            }
+        } else {
+            SwapType::ExactOut {
+                amount_out: amount,
+                max_amount_in: u64::MAX,
+            }
+        };
+        amm.simulate_swap_with_slot(slot, side, swap_type)
+    };
+
+    let mut amounts = vec![0_u64; pools.len()];
+    let mut swap_results: Vec<Option<SwapResult>> = vec![None; pools.len()];
+
+    let chunks = (0..num_chunks)
+        .map(|_| chunk_size)
+        .chain(std::iter::once(remainder))
+        .filter(|chunk| *chunk > 0);
+    for chunk in chunks {
+        // (pool index, marginal amount, swap result)
+        let mut best: Option<(usize, u64, SwapResult)> = None;
+        let mut last_error = None;
+        for (i, amm) in pools.iter().enumerate() {
+            let result = match simulate(amm, amounts[i] + chunk) {
+                Ok(result) => result,
+                Err(e) => {
+                    last_error = Some(e);
+                    continue;
+                }
+            };
+            let (current_in, current_out) = swap_results[i]
+                .map(|r| (r.amount_in(), r.amount_out()))
+                .unwrap_or((0, 0));
+            let marginal = if is_exact_in {
+                result.amount_out().saturating_sub(current_out)
+            } else {
+                result.amount_in().saturating_sub(current_in)
+            };
+            let is_better = match best {
+                None => true,
+                Some((_, best_marginal, _)) => {
+                    if is_exact_in {
+                        marginal > best_marginal
+                    } else {
+                        marginal < best_marginal
+                    }
+                }
+            };
+            if is_better {
+                best = Some((i, marginal, result));
+            }
+        }
+        let Some((i, _, result)) = best else {
+            return Err(last_error.unwrap_or(PlasmaStateError::MissingExpectedArgument));
+        };
+        amounts[i] += chunk;
+        swap_results[i] = Some(result);
+    }
+
+    let (amount_in, amount_out) = swap_results.iter().flatten().try_fold(
+        (0_u64, 0_u64),
+        |(amount_in, amount_out), result| {
+            Ok((
+                amount_in
+                    .checked_add(result.amount_in())
+                    .ok_or(PlasmaStateError::Overflow)?,
+                amount_out
+                    .checked_add(result.amount_out())
+                    .ok_or(PlasmaStateError::Overflow)?,
+            ))
+        },
+    )?;
+    swap_type.check_slippage(amount_in, amount_out)?;
+
+    Ok(SplitQuote {
+        amounts,
+        swap_results,
+        amount_in,
+        amount_out,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::initialized_pool_with_reserves;
+
+    #[test]
+    fn test_quote_route_exact_in() {
+        // A/USDC at 4 USDC per A and B/USDC at 2 USDC per B
+        let a_usdc = initialized_pool_with_reserves(10_000_000_000, 40_000_000_000, 20_000_000_000);
+        let b_usdc = initialized_pool_with_reserves(10_000_000_000, 20_000_000_000, 14_142_135_623);
+
+        let route = quote_route(
+            &[(&a_usdc, Side::Sell), (&b_usdc, Side::Buy)],
+            SwapType::ExactIn {
+                amount_in: 1_000_000,
+                min_amount_out: 0,
+            },
+            100,
+        )
+        .unwrap();
+
+        let first = a_usdc
+            .simulate_sell_exact_in_with_slot(100, 1_000_000)
+            .unwrap();
+        let second = b_usdc
+            .simulate_buy_exact_in_with_slot(100, first.quote_amount_to_transfer)
+            .unwrap();
+        assert_eq!(route.hops[0].swap_result, first);
+        assert_eq!(route.hops[1].swap_result, second);
+        assert_eq!(route.amount_in, 1_000_000);
+        assert_eq!(route.amount_out, second.base_amount_to_transfer);
+        assert!(route.amount_out < 2_000_000);
+        assert!(route.price_impact > I80F48::ZERO);
+
+        let err = quote_route(
+            &[(&a_usdc, Side::Sell), (&b_usdc, Side::Buy)],
+            SwapType::ExactIn {
+                amount_in: 1_000_000,
+                min_amount_out: route.amount_out + 1,
+            },
+            100,
+        )
+        .unwrap_err();
+        assert!(matches!(err, PlasmaStateError::SlippageExceeded(_, _)));
+    }
+
+    #[test]
+    fn test_quote_route_exact_out() {
+        let a_usdc = initialized_pool_with_reserves(10_000_000_000, 40_000_000_000, 20_000_000_000);
+        let b_usdc = initialized_pool_with_reserves(10_000_000_000, 20_000_000_000, 14_142_135_623);
+
+        let route = quote_route(
+            &[(&a_usdc, Side::Sell), (&b_usdc, Side::Buy)],
+            SwapType::ExactOut {
+                amount_out: 2_000_000,
+                max_amount_in: u64::MAX,
+            },
+            100,
+        )
+        .unwrap();
+        assert_eq!(route.amount_out, 2_000_000);
+        assert!(route.amount_in > 1_000_000);
+
+        // Executing the hops in order must produce exactly the intermediate amounts of the quote
+        let mut a_usdc_clone = a_usdc;
+        let mut b_usdc_clone = b_usdc;
+        let first = a_usdc_clone
+            .sell_exact_out(100, route.hops[1].swap_result.quote_amount_to_transfer)
+            .unwrap();
+        assert_eq!(first.base_amount_to_transfer, route.amount_in);
+        let second = b_usdc_clone
+            .buy_exact_in(100, first.quote_amount_to_transfer)
+            .unwrap();
+        assert!(second.base_amount_to_transfer >= route.amount_out);
+    }
+
+    #[test]
+    fn test_find_best_split() {
+        let deep = initialized_pool_with_reserves(10_000_000_000, 40_000_000_000, 20_000_000_000);
+        let shallow = initialized_pool_with_reserves(1_000_000_000, 4_000_000_000, 2_000_000_000);
+        let swap_type = SwapType::ExactIn {
+            amount_in: 1_000_000_001,
+            min_amount_out: 0,
+        };
+
+        let split = find_best_split(&[&deep, &shallow], Side::Buy, swap_type, 100, 100).unwrap();
+        assert_eq!(split.amounts.iter().sum::<u64>(), 1_000_000_001);
+        assert_eq!(split.amount_in, 1_000_000_001);
+        // The deeper pool should receive most of the order
+        assert!(split.amounts[0] > split.amounts[1]);
+        assert!(split.amounts[1] > 0);
+
+        let single = deep
+            .simulate_buy_exact_in_with_slot(100, 1_000_000_001)
+            .unwrap();
+        assert!(split.amount_out > single.base_amount_to_transfer);
+
+        let swap_type = SwapType::ExactOut {
+            amount_out: 100_000_000,
+            max_amount_in: u64::MAX,
+        };
+        let split = find_best_split(&[&deep, &shallow], Side::Sell, swap_type, 100, 50).unwrap();
+        assert_eq!(split.amount_out, 100_000_000);
+        let single = deep
+            .simulate_sell_exact_out_with_slot(100, 100_000_000)
+            .unwrap();
+        assert!(split.amount_in <= single.base_amount_to_transfer);
+    }
+}
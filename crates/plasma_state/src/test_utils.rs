@@ -0,0 +1,34 @@
+//! Pools shared by the unit tests
+
+use crate::{
+    amm::Amm,
+    lp::{LpPosition, VestingSchedule},
+};
+
+/// Pool at 4 quote per base, with a 30 bps fee, initialized at slot 100
+pub(crate) fn initialized_pool() -> Amm {
+    initialized_pool_with_reserves(10_000_000_000, 40_000_000_000, 20_000_000_000)
+}
+
+pub(crate) fn initialized_pool_with_reserves(base: u64, quote: u64, lp_shares: u64) -> Amm {
+    let mut amm = Amm::new(30, 20, 2, 100);
+    amm.mint(100, base, quote, Some(lp_shares)).unwrap();
+    amm
+}
+
+/// Same pool as `initialized_pool`, along with the LP position that holds all of its shares
+pub(crate) fn initialized_pool_with_lp_position() -> (Amm, LpPosition) {
+    let mut amm = Amm::new(30, 20, 2, 100);
+    let mut lp_position = LpPosition::new_with_reward_factor_snapshot(amm.reward_factor);
+    lp_position
+        .add_liquidity(
+            100,
+            &mut amm,
+            VestingSchedule::Cliff,
+            10_000_000_000,
+            40_000_000_000,
+            Some(20_000_000_000),
+        )
+        .unwrap();
+    (amm, lp_position)
+}
@@ -0,0 +1,427 @@
+//! Single-sided liquidity. A deposit of one token is partly swapped into the other token before
+//! it is deposited ("zap in"), and a withdrawal swaps one side of the burned liquidity into the
+//! other token ("zap out").
+
+use crate::{
+    amm::{Amm, SwapResult, TokenType, BPS_BASE},
+    errors::PlasmaStateError,
+    fixed::I80F48,
+    lp::{
+        AddLiquidityResult, LpPosition, RemoveLiquidityMode, RemoveLiquidityResult, VestingSchedule,
+    },
+    SlotWindow,
+};
+
+/// Number of steps that swap the share of the input in excess that a swap on the curve would
+/// deposit, before the single-sided swap amount is bracketed
+const SINGLE_SIDED_SWAP_CORRECTIONS: u32 = 4;
+
+/// Width of the first window around the corrected single-sided swap amount. The window doubles
+/// until it brackets the swap amount, and is then bisected.
+const SINGLE_SIDED_SWAP_WINDOW: u64 = 16;
+
+/// Maximum number of swaps simulated to find a single-sided swap amount. If the search stops
+/// early, the best amount found so far is used and more of the input token is left over.
+const MAX_SINGLE_SIDED_SWAP_SIMULATIONS: u32 = 24;
+
+impl Amm {
+    /// Amount of `amount_in` to swap so that the rest of `amount_in` and the swap output can be
+    /// deposited at the pool ratio after the swap, with as little left over as possible.
+    ///
+    /// The search starts from the closed-form amount of a swap on the curve, see
+    /// `estimate_single_sided_swap_amount`, and is refined with a bounded number of simulated
+    /// swaps since the virtual limit order and rounding move the exact amount. The output of the
+    /// swap is always fully deposited, and only a few atoms of the input token are left over.
+    pub fn get_single_sided_swap_amount(
+        &self,
+        slot: SlotWindow,
+        token_in: TokenType,
+        amount_in: u64,
+    ) -> Result<u64, PlasmaStateError> {
+        self.find_single_sided_swap_amount(slot, token_in, amount_in)
+            .map(|(swap_amount, _)| swap_amount)
+    }
+
+    /// Returns the single-sided swap amount and the number of swaps simulated to find it
+    fn find_single_sided_swap_amount(
+        &self,
+        slot: SlotWindow,
+        token_in: TokenType,
+        amount_in: u64,
+    ) -> Result<(u64, u32), PlasmaStateError> {
+        if self.total_lp_shares == 0 {
+            return Err(PlasmaStateError::UninitializedPool);
+        }
+        // Swapping nothing always leaves the input token in excess, and swapping everything
+        // leaves nothing of it to deposit
+        let (mut low, mut high) = (0, amount_in);
+        let mut num_simulations = 0;
+        let mut swap_amount = self.estimate_single_sided_swap_amount(token_in, amount_in);
+        let mut in_excess = true;
+        // The first correction swaps the input in excess as a single-sided deposit at the
+        // margin of the curve, the next ones follow the secant of the last two amounts
+        let mut previous = None;
+        while high - low > 1 && num_simulations < SINGLE_SIDED_SWAP_CORRECTIONS {
+            if swap_amount <= low || swap_amount >= high {
+                break;
+            }
+            num_simulations += 1;
+            let excess =
+                self.get_input_excess_after_swap(slot, token_in, amount_in, swap_amount)?;
+            in_excess = excess >= 0;
+            if in_excess {
+                low = swap_amount;
+            } else {
+                high = swap_amount;
+            }
+            let correction = previous
+                .filter(|&(_, previous_excess)| previous_excess != excess)
+                .and_then(|(previous_amount, previous_excess): (u64, i128)| {
+                    excess
+                        .checked_mul(swap_amount as i128 - previous_amount as i128)
+                        .map(|product| product / (previous_excess - excess))
+                })
+                .unwrap_or_else(|| {
+                    excess * BPS_BASE as i128 / (2 * BPS_BASE - self.fee_in_bps as u128) as i128
+                });
+            if correction == 0 {
+                break;
+            }
+            previous = Some((swap_amount, excess));
+            swap_amount = (swap_amount as i128 + correction).clamp(0, amount_in as i128) as u64;
+        }
+        // The window steps away from the last amount until the excess flips, then the bracket is
+        // bisected
+        let mut window = SINGLE_SIDED_SWAP_WINDOW;
+        let first_in_excess = in_excess;
+        let mut bracketing = true;
+        swap_amount = if in_excess {
+            low.saturating_add(window)
+        } else {
+            high.saturating_sub(window)
+        };
+        while high - low > 1 && num_simulations < MAX_SINGLE_SIDED_SWAP_SIMULATIONS {
+            if swap_amount <= low || swap_amount >= high {
+                bracketing = false;
+                swap_amount = low + (high - low) / 2;
+            }
+            num_simulations += 1;
+            let in_excess =
+                self.get_input_excess_after_swap(slot, token_in, amount_in, swap_amount)? >= 0;
+            if in_excess {
+                low = swap_amount;
+            } else {
+                high = swap_amount;
+            }
+            if in_excess != first_in_excess {
+                bracketing = false;
+            }
+            window = window.saturating_mul(2);
+            swap_amount = if !bracketing {
+                low + (high - low) / 2
+            } else if in_excess {
+                low.saturating_add(window)
+            } else {
+                high.saturating_sub(window)
+            };
+        }
+        Ok((low, num_simulations))
+    }
+
+    /// Swap amount for a single-sided deposit on the curve, ignoring the virtual limit order and
+    /// rounding. With `r` the reserves of the input token and `u = 1 - fee`, the amount `s`
+    /// solves `k * s^2 + r * (1 + u) * s - amount_in * r = 0`, where `k = u^2` for a buy since
+    /// the fee is taken from the quote in, and `k = u` for a sell since it is taken from the
+    /// quote out. The root is computed as
+    ///
+    /// ```text
+    /// s = 2 * amount_in / ((1 + u) + sqrt((1 + u)^2 + 4 * k * amount_in / r))
+    /// ```
+    ///
+    /// which stays in range for any `u64` amount.
+    fn estimate_single_sided_swap_amount(&self, token_in: TokenType, amount_in: u64) -> u64 {
+        let (reserves_in, k_is_squared) = match token_in {
+            TokenType::Quote => (self.quote_reserves, true),
+            TokenType::Base => (self.base_reserves, false),
+        };
+        if reserves_in == 0 {
+            return 0;
+        }
+        let u = I80F48::from_fraction(BPS_BASE as u64 - self.fee_in_bps as u64, BPS_BASE as u64);
+        let k = if k_is_squared { u * u } else { u };
+        let one_plus_u = I80F48::ONE + u;
+        let discriminant = one_plus_u * one_plus_u
+            + k * I80F48::from_num(4) * I80F48::from_fraction(amount_in, reserves_in);
+        let Some(root) = discriminant.checked_sqrt() else {
+            return 0;
+        };
+        (I80F48::from_num(amount_in) * I80F48::from_num(2) / (one_plus_u + root))
+            .floor()
+            .min(amount_in)
+    }
+
+    /// Input left after swapping `swap_amount` of `amount_in`, minus the input that the pool
+    /// ratio after the swap requires for the swap output. The input is in excess if this is not
+    /// negative.
+    fn get_input_excess_after_swap(
+        &self,
+        slot: SlotWindow,
+        token_in: TokenType,
+        amount_in: u64,
+        swap_amount: u64,
+    ) -> Result<i128, PlasmaStateError> {
+        let mut pool = *self;
+        let swap_result = match token_in {
+            TokenType::Quote => pool.buy_exact_in(slot, swap_amount)?,
+            TokenType::Base => pool.sell_exact_in(slot, swap_amount)?,
+        };
+        let (reserves_in, reserves_out) = match token_in {
+            TokenType::Quote => (pool.quote_reserves as u128, pool.base_reserves as u128),
+            TokenType::Base => (pool.base_reserves as u128, pool.quote_reserves as u128),
+        };
+        let available = (amount_in - swap_amount) as u128 * reserves_out;
+        let required = swap_result.amount_out() as u128 * reserves_in;
+        // Rounded down, so that the excess is only negative if the input is short
+        Ok(if available >= required {
+            ((available - required) / reserves_out) as i128
+        } else {
+            -(((required - available) / reserves_out) as i128) - 1
+        })
+    }
+}
+
+pub struct AddLiquiditySingleSidedResult {
+    /// Amount of the input token taken from the trader, including the swapped amount
+    pub amount_in: u64,
+    pub swap_result: SwapResult,
+    pub add_liquidity_result: AddLiquidityResult,
+}
+
+pub struct RemoveLiquiditySingleSidedResult {
+    /// Amount of the output token owed to the trader, including the swap output
+    pub amount_out: u64,
+    pub swap_result: SwapResult,
+    pub remove_liquidity_result: RemoveLiquidityResult,
+}
+
+impl LpPosition {
+    /// Deposits up to `amount_in` of a single token. Part of it is swapped through the pool
+    /// into the other token, see `Amm::get_single_sided_swap_amount`, and the rest is deposited
+    /// together with the swap output. Fails if fewer than `min_lp_shares_out` shares are minted.
+    pub fn add_liquidity_single_sided(
+        &mut self,
+        slot: SlotWindow,
+        amm: &mut Amm,
+        schedule: VestingSchedule,
+        token_in: TokenType,
+        amount_in: u64,
+        min_lp_shares_out: u64,
+    ) -> Result<AddLiquiditySingleSidedResult, PlasmaStateError> {
+        let swap_amount = amm.get_single_sided_swap_amount(slot, token_in, amount_in)?;
+        let swap_result = match token_in {
+            TokenType::Quote => amm.buy_exact_in(slot, swap_amount)?,
+            TokenType::Base => amm.sell_exact_in(slot, swap_amount)?,
+        };
+        let (base_amount, quote_amount) = match token_in {
+            TokenType::Quote => (swap_result.amount_out(), amount_in - swap_amount),
+            TokenType::Base => (amount_in - swap_amount, swap_result.amount_out()),
+        };
+        let add_liquidity_result =
+            self.add_liquidity(slot, amm, schedule, base_amount, quote_amount, None)?;
+        if add_liquidity_result.lp_shares_received < min_lp_shares_out {
+            return Err(PlasmaStateError::SlippageExceeded(
+                min_lp_shares_out as u128,
+                add_liquidity_result.lp_shares_received as u128,
+            ));
+        }
+
+        let (amount_deposited, amount_out_deposited) = match token_in {
+            TokenType::Quote => (
+                add_liquidity_result.quote_amount_deposited,
+                add_liquidity_result.base_amount_deposited,
+            ),
+            TokenType::Base => (
+                add_liquidity_result.base_amount_deposited,
+                add_liquidity_result.quote_amount_deposited,
+            ),
+        };
+        // The swap output never leaves the vaults, so all of it must be deposited
+        if amount_out_deposited != swap_result.amount_out() {
+            return Err(PlasmaStateError::SwapAmountMismatch);
+        }
+
+        Ok(AddLiquiditySingleSidedResult {
+            amount_in: swap_amount + amount_deposited,
+            swap_result,
+            add_liquidity_result,
+        })
+    }
+
+    /// Burns `lp_shares` and swaps the withdrawn amount of the other token into `token_out`
+    /// through the pool
+    pub fn remove_liquidity_single_sided(
+        &mut self,
+        slot: SlotWindow,
+        amm: &mut Amm,
+        schedule: VestingSchedule,
+        token_out: TokenType,
+        lp_shares: u64,
+    ) -> Result<RemoveLiquiditySingleSidedResult, PlasmaStateError> {
+        let remove_liquidity_result =
+            self.remove_liquidity(slot, amm, schedule, lp_shares, RemoveLiquidityMode::Strict)?;
+        let (swap_result, amount_withdrawn) = match token_out {
+            TokenType::Quote => (
+                amm.sell_exact_in(slot, remove_liquidity_result.base_amount_withdrawn)?,
+                remove_liquidity_result.quote_amount_withdrawn,
+            ),
+            TokenType::Base => (
+                amm.buy_exact_in(slot, remove_liquidity_result.quote_amount_withdrawn)?,
+                remove_liquidity_result.base_amount_withdrawn,
+            ),
+        };
+
+        Ok(RemoveLiquiditySingleSidedResult {
+            amount_out: amount_withdrawn + swap_result.amount_out(),
+            swap_result,
+            remove_liquidity_result,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::amm::Side;
+    use crate::test_utils::initialized_pool_with_lp_position;
+
+    #[test]
+    fn test_single_sided_deposit_leaves_dust() {
+        for token_in in [TokenType::Quote, TokenType::Base] {
+            let (mut amm, _) = initialized_pool_with_lp_position();
+            // Move the pool away from the snapshot so that the virtual limit order is used
+            amm.buy_exact_in(104, 1_000_000_000).unwrap();
+
+            let mut lp_position = LpPosition::new_with_reward_factor_snapshot(amm.reward_factor);
+            let amount_in = 3_000_000_000;
+            let swap_amount = amm
+                .get_single_sided_swap_amount(104, token_in, amount_in)
+                .unwrap();
+            let result = lp_position
+                .add_liquidity_single_sided(
+                    104,
+                    &mut amm,
+                    VestingSchedule::Cliff,
+                    token_in,
+                    amount_in,
+                    1,
+                )
+                .unwrap();
+            assert!(result.amount_in <= amount_in);
+            // The search stops within one atom of the swap amount, and one atom of base swaps
+            // for several atoms of quote
+            assert!(amount_in - result.amount_in <= 4, "{:?}", token_in);
+            assert_eq!(
+                lp_position.lp_shares,
+                result.add_liquidity_result.lp_shares_received
+            );
+            assert_eq!(result.swap_result.amount_in(), swap_amount);
+        }
+    }
+
+    #[test]
+    fn test_single_sided_swap_amount_of_large_deposit() {
+        for token_in in [TokenType::Quote, TokenType::Base] {
+            let mut amm = Amm::new(30, 20, 2, 100);
+            let mut lp_position = LpPosition::new_with_reward_factor_snapshot(amm.reward_factor);
+            lp_position
+                .add_liquidity(
+                    100,
+                    &mut amm,
+                    VestingSchedule::Cliff,
+                    2_000_000_000_000_000_000,
+                    8_000_000_000_000_000_000,
+                    Some(4_000_000_000_000_000_000),
+                )
+                .unwrap();
+            amm.buy_exact_in(104, 20_000_000_000_000_000).unwrap();
+
+            let amount_in = 8_000_000_000_000_000_000;
+            let (swap_amount, num_simulations) = amm
+                .find_single_sided_swap_amount(104, token_in, amount_in)
+                .unwrap();
+            // Bisecting the whole amount would simulate 63 swaps, the search converges before
+            // reaching its bound
+            assert!(
+                num_simulations < MAX_SINGLE_SIDED_SWAP_SIMULATIONS,
+                "{:?} {}",
+                token_in,
+                num_simulations
+            );
+
+            let mut lp_position = LpPosition::new_with_reward_factor_snapshot(amm.reward_factor);
+            let result = lp_position
+                .add_liquidity_single_sided(
+                    104,
+                    &mut amm,
+                    VestingSchedule::Cliff,
+                    token_in,
+                    amount_in,
+                    1,
+                )
+                .unwrap();
+            assert_eq!(result.swap_result.amount_in(), swap_amount);
+            assert!(amount_in - result.amount_in <= 8, "{:?}", token_in);
+        }
+    }
+
+    #[test]
+    fn test_single_sided_round_trip_only_pays_fees() {
+        let (mut amm, _) = initialized_pool_with_lp_position();
+        let mut lp_position = LpPosition::new_with_reward_factor_snapshot(amm.reward_factor);
+        let amount_in = 1_000_000_000;
+        let lp_shares = lp_position
+            .add_liquidity_single_sided(
+                100,
+                &mut amm,
+                VestingSchedule::Cliff,
+                TokenType::Quote,
+                amount_in,
+                1,
+            )
+            .unwrap()
+            .add_liquidity_result
+            .lp_shares_received;
+
+        let result = lp_position
+            .remove_liquidity_single_sided(
+                102,
+                &mut amm,
+                VestingSchedule::Cliff,
+                TokenType::Quote,
+                lp_shares,
+            )
+            .unwrap();
+        assert_eq!(result.remove_liquidity_result.lp_shares_burned, lp_shares);
+        assert_eq!(result.swap_result.side, Side::Sell);
+        // Two swaps of about half of the deposit each pay the 0.3% fee
+        assert!(result.amount_out < amount_in);
+        assert!(result.amount_out > amount_in * 9_960 / 10_000);
+    }
+
+    #[test]
+    fn test_single_sided_deposit_slippage() {
+        let (mut amm, _) = initialized_pool_with_lp_position();
+        let mut lp_position = LpPosition::new_with_reward_factor_snapshot(amm.reward_factor);
+        assert!(matches!(
+            lp_position.add_liquidity_single_sided(
+                100,
+                &mut amm,
+                VestingSchedule::Cliff,
+                TokenType::Base,
+                1_000_000_000,
+                u64::MAX,
+            ),
+            Err(PlasmaStateError::SlippageExceeded(..))
+        ));
+    }
+}
@@ -58,11 +58,6 @@ async function main() {
     generateClient.on("close", resolve);
   });
 
-  const removeErrorFolder = spawn("rm", ["-rf", "../plasma-sdk/generated/errors"]);
-  await new Promise((resolve) => {
-    removeErrorFolder.on("close", resolve);
-  });
-
   recursivelyMutateClientFiles("../plasma-sdk/generated");
 
   console.log("Done");
@@ -0,0 +1,127 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface FeeTierConfigAccountFields {
+  sequenceNumber: BN
+  admin: PublicKey
+  numFeeTiers: BN
+  feeTiers: Array<types.FeeTierFields>
+  padding: Array<BN>
+}
+
+export interface FeeTierConfigAccountJSON {
+  sequenceNumber: string
+  admin: string
+  numFeeTiers: string
+  feeTiers: Array<types.FeeTierJSON>
+  padding: Array<string>
+}
+
+export class FeeTierConfigAccount {
+  readonly sequenceNumber: BN
+  readonly admin: PublicKey
+  readonly numFeeTiers: BN
+  readonly feeTiers: Array<types.FeeTier>
+  readonly padding: Array<BN>
+
+  static readonly discriminator = Buffer.from([
+    188, 5, 219, 206, 243, 133, 123, 15,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.u64("sequenceNumber"),
+    borsh.publicKey("admin"),
+    borsh.u64("numFeeTiers"),
+    borsh.array(types.FeeTier.layout(), 16, "feeTiers"),
+    borsh.array(borsh.u64(), 8, "padding"),
+  ])
+
+  constructor(fields: FeeTierConfigAccountFields) {
+    this.sequenceNumber = fields.sequenceNumber
+    this.admin = fields.admin
+    this.numFeeTiers = fields.numFeeTiers
+    this.feeTiers = fields.feeTiers.map(
+      (item) => new types.FeeTier({ ...item })
+    )
+    this.padding = fields.padding
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<FeeTierConfigAccount | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<FeeTierConfigAccount | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): FeeTierConfigAccount {
+    if (!data.slice(0, 8).equals(FeeTierConfigAccount.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = FeeTierConfigAccount.layout.decode(data.slice(8))
+
+    return new FeeTierConfigAccount({
+      sequenceNumber: dec.sequenceNumber,
+      admin: dec.admin,
+      numFeeTiers: dec.numFeeTiers,
+      feeTiers: dec.feeTiers.map(
+        (
+          item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
+        ) => types.FeeTier.fromDecoded(item)
+      ),
+      padding: dec.padding,
+    })
+  }
+
+  toJSON(): FeeTierConfigAccountJSON {
+    return {
+      sequenceNumber: this.sequenceNumber.toString(),
+      admin: this.admin.toString(),
+      numFeeTiers: this.numFeeTiers.toString(),
+      feeTiers: this.feeTiers.map((item) => item.toJSON()),
+      padding: this.padding.map((item) => item.toString()),
+    }
+  }
+
+  static fromJSON(obj: FeeTierConfigAccountJSON): FeeTierConfigAccount {
+    return new FeeTierConfigAccount({
+      sequenceNumber: new BN(obj.sequenceNumber),
+      admin: new PublicKey(obj.admin),
+      numFeeTiers: new BN(obj.numFeeTiers),
+      feeTiers: obj.feeTiers.map((item) => types.FeeTier.fromJSON(item)),
+      padding: obj.padding.map((item) => new BN(item)),
+    })
+  }
+}
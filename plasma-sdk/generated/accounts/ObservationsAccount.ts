@@ -0,0 +1,105 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ObservationsAccountFields {
+  pool: PublicKey
+  buffer: types.ObservationBufferHeaderFields
+  padding: Array<BN>
+}
+
+export interface ObservationsAccountJSON {
+  pool: string
+  buffer: types.ObservationBufferHeaderJSON
+  padding: Array<string>
+}
+
+export class ObservationsAccount {
+  readonly pool: PublicKey
+  readonly buffer: types.ObservationBufferHeader
+  readonly padding: Array<BN>
+
+  static readonly discriminator = Buffer.from([
+    235, 12, 79, 82, 47, 161, 49, 29,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.publicKey("pool"),
+    types.ObservationBufferHeader.layout("buffer"),
+    borsh.array(borsh.u64(), 1, "padding"),
+  ])
+
+  constructor(fields: ObservationsAccountFields) {
+    this.pool = fields.pool
+    this.buffer = new types.ObservationBufferHeader({ ...fields.buffer })
+    this.padding = fields.padding
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<ObservationsAccount | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<ObservationsAccount | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): ObservationsAccount {
+    if (!data.slice(0, 8).equals(ObservationsAccount.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = ObservationsAccount.layout.decode(data.slice(8))
+
+    return new ObservationsAccount({
+      pool: dec.pool,
+      buffer: types.ObservationBufferHeader.fromDecoded(dec.buffer),
+      padding: dec.padding,
+    })
+  }
+
+  toJSON(): ObservationsAccountJSON {
+    return {
+      pool: this.pool.toString(),
+      buffer: this.buffer.toJSON(),
+      padding: this.padding.map((item) => item.toString()),
+    }
+  }
+
+  static fromJSON(obj: ObservationsAccountJSON): ObservationsAccount {
+    return new ObservationsAccount({
+      pool: new PublicKey(obj.pool),
+      buffer: types.ObservationBufferHeader.fromJSON(obj.buffer),
+      padding: obj.padding.map((item) => new BN(item)),
+    })
+  }
+}
@@ -5,3 +5,13 @@ export type {
   LpPositionAccountFields,
   LpPositionAccountJSON,
 } from "./LpPositionAccount"
+export { ObservationsAccount } from "./ObservationsAccount"
+export type {
+  ObservationsAccountFields,
+  ObservationsAccountJSON,
+} from "./ObservationsAccount"
+export { FeeTierConfigAccount } from "./FeeTierConfigAccount"
+export type {
+  FeeTierConfigAccountFields,
+  FeeTierConfigAccountJSON,
+} from "./FeeTierConfigAccount"
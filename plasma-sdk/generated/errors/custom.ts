@@ -0,0 +1,1166 @@
+export type CustomError =
+  | InvariantViolation
+  | MismatchedFees
+  | UninitializedPool
+  | SwapAmountMismatch
+  | Overflow
+  | Underflow
+  | UnexpectedArgument
+  | MissingExpectedArgument
+  | BelowMinimumLpSharesRequired
+  | BelowMinimumWithdrawalRequired
+  | VestingPeriodNotOver
+  | IncorrectProtocolFeeRecipient
+  | TooManyShares
+  | SwapExactOutTooLarge
+  | SwapExactInTooLarge
+  | SwapOutputGreaterThanOrEqualToReserves
+  | SlippageExceeded
+  | ObservationTooOld
+  | IncorrectProgramId
+  | InvalidLogAuthority
+  | IncorrectAccountKey
+  | MissingRequiredSignature
+  | PayerNotWritable
+  | AccountAlreadyInitialized
+  | EmptyAccountNotOwnedBySystemProgram
+  | NotOwnedByTokenProgram
+  | InvalidTokenAccountData
+  | TokenAccountMintMismatch
+  | TokenAccountOwnerMismatch
+  | IncorrectTokenAccountKey
+  | NotOwnedByPlasmaProgram
+  | InvalidPoolAccount
+  | PoolAlreadyInitialized
+  | InvalidLpPositionAddress
+  | InvalidLpPositionAccount
+  | LpPositionAuthorityMismatch
+  | LpPositionPoolMismatch
+  | InvalidObservationsAddress
+  | InvalidObservationsAccount
+  | ObservationsPoolMismatch
+  | InvalidVaultAddress
+  | LpFeeTooHigh
+  | ProtocolFeeAllocationTooHigh
+  | TooManyProtocolFeeShares
+  | DuplicateProtocolFeeRecipients
+  | IdenticalBaseAndQuoteMints
+  | ProtocolFeeAccountingError
+  | CumulativeFeesDecreased
+  | InvalidInitialLpShares
+  | LpPositionRenounced
+  | LpPositionFeesBurned
+  | InsufficientBalance
+  | EmptySwapRoute
+  | DuplicatePoolInSwapRoute
+  | SwapRouteMintMismatch
+  | InvalidObservationsCapacity
+  | ObservationsGrowthTooLarge
+  | MissingPoolAuthority
+  | InvalidPoolAuthority
+  | UncollectedProtocolFees
+  | PoolPaused
+  | UnsupportedMintExtension
+  | InvalidPoolAddress
+  | InvalidFeeTierConfigAccount
+  | InvalidFeeTierConfigAdmin
+  | FeeTierNotFound
+  | FeeTierDisabled
+  | TooManyFeeTiers
+  | LpPositionNotEmpty
+  | PoolNotEmpty
+  | LpTokensNotEnabled
+  | InvalidLpMintAddress
+  | InvalidLpPositionRecipient
+  | LpPositionMigrationRequired
+  | LpPositionAlreadyMigrated
+  | SlotDeadlineExceeded
+  | InsufficientWithdrawableShares
+  | PoolMigrationRequired
+  | PoolAlreadyMigrated
+  | MissingSystemProgram
+  | MissingFeeTierConfig
+  | LpSharesStillVesting
+
+export class InvariantViolation extends Error {
+  static readonly code = 0
+  readonly code = 0
+  readonly name = "InvariantViolation"
+  readonly msg = "Swap violated the constant product invariant"
+
+  constructor(readonly logs?: string[]) {
+    super("0: Swap violated the constant product invariant")
+  }
+}
+
+export class MismatchedFees extends Error {
+  static readonly code = 1
+  readonly code = 1
+  readonly name = "MismatchedFees"
+  readonly msg = "Fees do not match the expected amount"
+
+  constructor(readonly logs?: string[]) {
+    super("1: Fees do not match the expected amount")
+  }
+}
+
+export class UninitializedPool extends Error {
+  static readonly code = 2
+  readonly code = 2
+  readonly name = "UninitializedPool"
+  readonly msg = "Pool is uninitialized"
+
+  constructor(readonly logs?: string[]) {
+    super("2: Pool is uninitialized")
+  }
+}
+
+export class SwapAmountMismatch extends Error {
+  static readonly code = 3
+  readonly code = 3
+  readonly name = "SwapAmountMismatch"
+  readonly msg = "Swap amounts do not match"
+
+  constructor(readonly logs?: string[]) {
+    super("3: Swap amounts do not match")
+  }
+}
+
+export class Overflow extends Error {
+  static readonly code = 4
+  readonly code = 4
+  readonly name = "Overflow"
+  readonly msg = "Calculation overflowed"
+
+  constructor(readonly logs?: string[]) {
+    super("4: Calculation overflowed")
+  }
+}
+
+export class Underflow extends Error {
+  static readonly code = 5
+  readonly code = 5
+  readonly name = "Underflow"
+  readonly msg = "Calculation underflowed"
+
+  constructor(readonly logs?: string[]) {
+    super("5: Calculation underflowed")
+  }
+}
+
+export class UnexpectedArgument extends Error {
+  static readonly code = 6
+  readonly code = 6
+  readonly name = "UnexpectedArgument"
+  readonly msg = "Unexpected argument"
+
+  constructor(readonly logs?: string[]) {
+    super("6: Unexpected argument")
+  }
+}
+
+export class MissingExpectedArgument extends Error {
+  static readonly code = 7
+  readonly code = 7
+  readonly name = "MissingExpectedArgument"
+  readonly msg = "Missing expected argument"
+
+  constructor(readonly logs?: string[]) {
+    super("7: Missing expected argument")
+  }
+}
+
+export class BelowMinimumLpSharesRequired extends Error {
+  static readonly code = 8
+  readonly code = 8
+  readonly name = "BelowMinimumLpSharesRequired"
+  readonly msg = "Must mint at least 1 LP share"
+
+  constructor(readonly logs?: string[]) {
+    super("8: Must mint at least 1 LP share")
+  }
+}
+
+export class BelowMinimumWithdrawalRequired extends Error {
+  static readonly code = 9
+  readonly code = 9
+  readonly name = "BelowMinimumWithdrawalRequired"
+  readonly msg = "Must withdraw at least 1 base token and 1 quote token"
+
+  constructor(readonly logs?: string[]) {
+    super("9: Must withdraw at least 1 base token and 1 quote token")
+  }
+}
+
+export class VestingPeriodNotOver extends Error {
+  static readonly code = 10
+  readonly code = 10
+  readonly name = "VestingPeriodNotOver"
+  readonly msg = "Previous vesting period not over"
+
+  constructor(readonly logs?: string[]) {
+    super("10: Previous vesting period not over")
+  }
+}
+
+export class IncorrectProtocolFeeRecipient extends Error {
+  static readonly code = 11
+  readonly code = 11
+  readonly name = "IncorrectProtocolFeeRecipient"
+  readonly msg = "Signer is not one of the protocol fee recipients"
+
+  constructor(readonly logs?: string[]) {
+    super("11: Signer is not one of the protocol fee recipients")
+  }
+}
+
+export class TooManyShares extends Error {
+  static readonly code = 12
+  readonly code = 12
+  readonly name = "TooManyShares"
+  readonly msg = "Too many LP shares supplied"
+
+  constructor(readonly logs?: string[]) {
+    super("12: Too many LP shares supplied")
+  }
+}
+
+export class SwapExactOutTooLarge extends Error {
+  static readonly code = 13
+  readonly code = 13
+  readonly name = "SwapExactOutTooLarge"
+  readonly msg = "SwapExactOut amount too large"
+
+  constructor(readonly logs?: string[]) {
+    super("13: SwapExactOut amount too large")
+  }
+}
+
+export class SwapExactInTooLarge extends Error {
+  static readonly code = 14
+  readonly code = 14
+  readonly name = "SwapExactInTooLarge"
+  readonly msg = "SwapExactIn amount too large"
+
+  constructor(readonly logs?: string[]) {
+    super("14: SwapExactIn amount too large")
+  }
+}
+
+export class SwapOutputGreaterThanOrEqualToReserves extends Error {
+  static readonly code = 15
+  readonly code = 15
+  readonly name = "SwapOutputGreaterThanOrEqualToReserves"
+  readonly msg = "Swap output is greater than or equal to the pool reserves"
+
+  constructor(readonly logs?: string[]) {
+    super("15: Swap output is greater than or equal to the pool reserves")
+  }
+}
+
+export class SlippageExceeded extends Error {
+  static readonly code = 16
+  readonly code = 16
+  readonly name = "SlippageExceeded"
+  readonly msg = "Slippage limit exceeded"
+
+  constructor(readonly logs?: string[]) {
+    super("16: Slippage limit exceeded")
+  }
+}
+
+export class ObservationTooOld extends Error {
+  static readonly code = 17
+  readonly code = 17
+  readonly name = "ObservationTooOld"
+  readonly msg = "Requested slot is before the oldest observation"
+
+  constructor(readonly logs?: string[]) {
+    super("17: Requested slot is before the oldest observation")
+  }
+}
+
+export class IncorrectProgramId extends Error {
+  static readonly code = 18
+  readonly code = 18
+  readonly name = "IncorrectProgramId"
+  readonly msg = "Incorrect program id"
+
+  constructor(readonly logs?: string[]) {
+    super("18: Incorrect program id")
+  }
+}
+
+export class InvalidLogAuthority extends Error {
+  static readonly code = 19
+  readonly code = 19
+  readonly name = "InvalidLogAuthority"
+  readonly msg = "Invalid log authority"
+
+  constructor(readonly logs?: string[]) {
+    super("19: Invalid log authority")
+  }
+}
+
+export class IncorrectAccountKey extends Error {
+  static readonly code = 20
+  readonly code = 20
+  readonly name = "IncorrectAccountKey"
+  readonly msg = "Incorrect account key"
+
+  constructor(readonly logs?: string[]) {
+    super("20: Incorrect account key")
+  }
+}
+
+export class MissingRequiredSignature extends Error {
+  static readonly code = 21
+  readonly code = 21
+  readonly name = "MissingRequiredSignature"
+  readonly msg = "Missing required signature"
+
+  constructor(readonly logs?: string[]) {
+    super("21: Missing required signature")
+  }
+}
+
+export class PayerNotWritable extends Error {
+  static readonly code = 22
+  readonly code = 22
+  readonly name = "PayerNotWritable"
+  readonly msg = "Payer must be writable"
+
+  constructor(readonly logs?: string[]) {
+    super("22: Payer must be writable")
+  }
+}
+
+export class AccountAlreadyInitialized extends Error {
+  static readonly code = 23
+  readonly code = 23
+  readonly name = "AccountAlreadyInitialized"
+  readonly msg = "Account must be uninitialized"
+
+  constructor(readonly logs?: string[]) {
+    super("23: Account must be uninitialized")
+  }
+}
+
+export class EmptyAccountNotOwnedBySystemProgram extends Error {
+  static readonly code = 24
+  readonly code = 24
+  readonly name = "EmptyAccountNotOwnedBySystemProgram"
+  readonly msg = "Empty accounts must be owned by the system program"
+
+  constructor(readonly logs?: string[]) {
+    super("24: Empty accounts must be owned by the system program")
+  }
+}
+
+export class NotOwnedByTokenProgram extends Error {
+  static readonly code = 25
+  readonly code = 25
+  readonly name = "NotOwnedByTokenProgram"
+  readonly msg = "Token account or mint must be owned by the Token program"
+
+  constructor(readonly logs?: string[]) {
+    super("25: Token account or mint must be owned by the Token program")
+  }
+}
+
+export class InvalidTokenAccountData extends Error {
+  static readonly code = 26
+  readonly code = 26
+  readonly name = "InvalidTokenAccountData"
+  readonly msg = "Invalid token account data"
+
+  constructor(readonly logs?: string[]) {
+    super("26: Invalid token account data")
+  }
+}
+
+export class TokenAccountMintMismatch extends Error {
+  static readonly code = 27
+  readonly code = 27
+  readonly name = "TokenAccountMintMismatch"
+  readonly msg = "Token account mint mismatch"
+
+  constructor(readonly logs?: string[]) {
+    super("27: Token account mint mismatch")
+  }
+}
+
+export class TokenAccountOwnerMismatch extends Error {
+  static readonly code = 28
+  readonly code = 28
+  readonly name = "TokenAccountOwnerMismatch"
+  readonly msg = "Token account owner mismatch"
+
+  constructor(readonly logs?: string[]) {
+    super("28: Token account owner mismatch")
+  }
+}
+
+export class IncorrectTokenAccountKey extends Error {
+  static readonly code = 29
+  readonly code = 29
+  readonly name = "IncorrectTokenAccountKey"
+  readonly msg = "Incorrect token account key"
+
+  constructor(readonly logs?: string[]) {
+    super("29: Incorrect token account key")
+  }
+}
+
+export class NotOwnedByPlasmaProgram extends Error {
+  static readonly code = 30
+  readonly code = 30
+  readonly name = "NotOwnedByPlasmaProgram"
+  readonly msg = "Account must be owned by the Plasma program"
+
+  constructor(readonly logs?: string[]) {
+    super("30: Account must be owned by the Plasma program")
+  }
+}
+
+export class InvalidPoolAccount extends Error {
+  static readonly code = 31
+  readonly code = 31
+  readonly name = "InvalidPoolAccount"
+  readonly msg = "Invalid pool account"
+
+  constructor(readonly logs?: string[]) {
+    super("31: Invalid pool account")
+  }
+}
+
+export class PoolAlreadyInitialized extends Error {
+  static readonly code = 32
+  readonly code = 32
+  readonly name = "PoolAlreadyInitialized"
+  readonly msg = "Pool is already initialized"
+
+  constructor(readonly logs?: string[]) {
+    super("32: Pool is already initialized")
+  }
+}
+
+export class InvalidLpPositionAddress extends Error {
+  static readonly code = 33
+  readonly code = 33
+  readonly name = "InvalidLpPositionAddress"
+  readonly msg = "Invalid LP position address"
+
+  constructor(readonly logs?: string[]) {
+    super("33: Invalid LP position address")
+  }
+}
+
+export class InvalidLpPositionAccount extends Error {
+  static readonly code = 34
+  readonly code = 34
+  readonly name = "InvalidLpPositionAccount"
+  readonly msg = "Invalid LP position account"
+
+  constructor(readonly logs?: string[]) {
+    super("34: Invalid LP position account")
+  }
+}
+
+export class LpPositionAuthorityMismatch extends Error {
+  static readonly code = 35
+  readonly code = 35
+  readonly name = "LpPositionAuthorityMismatch"
+  readonly msg = "LP position authority mismatch"
+
+  constructor(readonly logs?: string[]) {
+    super("35: LP position authority mismatch")
+  }
+}
+
+export class LpPositionPoolMismatch extends Error {
+  static readonly code = 36
+  readonly code = 36
+  readonly name = "LpPositionPoolMismatch"
+  readonly msg = "LP position pool mismatch"
+
+  constructor(readonly logs?: string[]) {
+    super("36: LP position pool mismatch")
+  }
+}
+
+export class InvalidObservationsAddress extends Error {
+  static readonly code = 37
+  readonly code = 37
+  readonly name = "InvalidObservationsAddress"
+  readonly msg = "Invalid observations account address"
+
+  constructor(readonly logs?: string[]) {
+    super("37: Invalid observations account address")
+  }
+}
+
+export class InvalidObservationsAccount extends Error {
+  static readonly code = 38
+  readonly code = 38
+  readonly name = "InvalidObservationsAccount"
+  readonly msg = "Invalid observations account"
+
+  constructor(readonly logs?: string[]) {
+    super("38: Invalid observations account")
+  }
+}
+
+export class ObservationsPoolMismatch extends Error {
+  static readonly code = 39
+  readonly code = 39
+  readonly name = "ObservationsPoolMismatch"
+  readonly msg = "Observations account pool mismatch"
+
+  constructor(readonly logs?: string[]) {
+    super("39: Observations account pool mismatch")
+  }
+}
+
+export class InvalidVaultAddress extends Error {
+  static readonly code = 40
+  readonly code = 40
+  readonly name = "InvalidVaultAddress"
+  readonly msg = "Vault address does not match the expected PDA"
+
+  constructor(readonly logs?: string[]) {
+    super("40: Vault address does not match the expected PDA")
+  }
+}
+
+export class LpFeeTooHigh extends Error {
+  static readonly code = 41
+  readonly code = 41
+  readonly name = "LpFeeTooHigh"
+  readonly msg = "LP fee is capped at 5%"
+
+  constructor(readonly logs?: string[]) {
+    super("41: LP fee is capped at 5%")
+  }
+}
+
+export class ProtocolFeeAllocationTooHigh extends Error {
+  static readonly code = 42
+  readonly code = 42
+  readonly name = "ProtocolFeeAllocationTooHigh"
+  readonly msg = "Protocol fee allocation is capped at 50% of the LP fee"
+
+  constructor(readonly logs?: string[]) {
+    super("42: Protocol fee allocation is capped at 50% of the LP fee")
+  }
+}
+
+export class TooManyProtocolFeeShares extends Error {
+  static readonly code = 43
+  readonly code = 43
+  readonly name = "TooManyProtocolFeeShares"
+  readonly msg = "Protocol fee recipient shares must sum to less than 10000"
+
+  constructor(readonly logs?: string[]) {
+    super("43: Protocol fee recipient shares must sum to less than 10000")
+  }
+}
+
+export class DuplicateProtocolFeeRecipients extends Error {
+  static readonly code = 44
+  readonly code = 44
+  readonly name = "DuplicateProtocolFeeRecipients"
+  readonly msg = "Protocol fee recipients must be different"
+
+  constructor(readonly logs?: string[]) {
+    super("44: Protocol fee recipients must be different")
+  }
+}
+
+export class IdenticalBaseAndQuoteMints extends Error {
+  static readonly code = 45
+  readonly code = 45
+  readonly name = "IdenticalBaseAndQuoteMints"
+  readonly msg = "Base mint and quote mint must be different"
+
+  constructor(readonly logs?: string[]) {
+    super("45: Base mint and quote mint must be different")
+  }
+}
+
+export class ProtocolFeeAccountingError extends Error {
+  static readonly code = 46
+  readonly code = 46
+  readonly name = "ProtocolFeeAccountingError"
+  readonly msg = "Protocol fee accounting is inconsistent"
+
+  constructor(readonly logs?: string[]) {
+    super("46: Protocol fee accounting is inconsistent")
+  }
+}
+
+export class CumulativeFeesDecreased extends Error {
+  static readonly code = 47
+  readonly code = 47
+  readonly name = "CumulativeFeesDecreased"
+  readonly msg = "Cumulative fees decreased after swap"
+
+  constructor(readonly logs?: string[]) {
+    super("47: Cumulative fees decreased after swap")
+  }
+}
+
+export class InvalidInitialLpShares extends Error {
+  static readonly code = 48
+  readonly code = 48
+  readonly name = "InvalidInitialLpShares"
+  readonly msg = "Initial LP shares must be provided for the first deposit only"
+
+  constructor(readonly logs?: string[]) {
+    super("48: Initial LP shares must be provided for the first deposit only")
+  }
+}
+
+export class LpPositionRenounced extends Error {
+  static readonly code = 49
+  readonly code = 49
+  readonly name = "LpPositionRenounced"
+  readonly msg = "LP position has been renounced"
+
+  constructor(readonly logs?: string[]) {
+    super("49: LP position has been renounced")
+  }
+}
+
+export class LpPositionFeesBurned extends Error {
+  static readonly code = 50
+  readonly code = 50
+  readonly name = "LpPositionFeesBurned"
+  readonly msg = "LP position fees have been burned"
+
+  constructor(readonly logs?: string[]) {
+    super("50: LP position fees have been burned")
+  }
+}
+
+export class InsufficientBalance extends Error {
+  static readonly code = 51
+  readonly code = 51
+  readonly name = "InsufficientBalance"
+  readonly msg = "Insufficient token balance"
+
+  constructor(readonly logs?: string[]) {
+    super("51: Insufficient token balance")
+  }
+}
+
+export class EmptySwapRoute extends Error {
+  static readonly code = 52
+  readonly code = 52
+  readonly name = "EmptySwapRoute"
+  readonly msg = "Swap route must contain at least one hop"
+
+  constructor(readonly logs?: string[]) {
+    super("52: Swap route must contain at least one hop")
+  }
+}
+
+export class DuplicatePoolInSwapRoute extends Error {
+  static readonly code = 53
+  readonly code = 53
+  readonly name = "DuplicatePoolInSwapRoute"
+  readonly msg = "A pool can only appear once in a swap route"
+
+  constructor(readonly logs?: string[]) {
+    super("53: A pool can only appear once in a swap route")
+  }
+}
+
+export class SwapRouteMintMismatch extends Error {
+  static readonly code = 54
+  readonly code = 54
+  readonly name = "SwapRouteMintMismatch"
+  readonly msg =
+    "The output mint of each hop must be the input mint of the next hop"
+
+  constructor(readonly logs?: string[]) {
+    super(
+      "54: The output mint of each hop must be the input mint of the next hop"
+    )
+  }
+}
+
+export class InvalidObservationsCapacity extends Error {
+  static readonly code = 55
+  readonly code = 55
+  readonly name = "InvalidObservationsCapacity"
+  readonly msg =
+    "New observations capacity must be greater than the current capacity"
+
+  constructor(readonly logs?: string[]) {
+    super(
+      "55: New observations capacity must be greater than the current capacity"
+    )
+  }
+}
+
+export class ObservationsGrowthTooLarge extends Error {
+  static readonly code = 56
+  readonly code = 56
+  readonly name = "ObservationsGrowthTooLarge"
+  readonly msg = "Observations account can only grow by 10KB per instruction"
+
+  constructor(readonly logs?: string[]) {
+    super("56: Observations account can only grow by 10KB per instruction")
+  }
+}
+
+export class MissingPoolAuthority extends Error {
+  static readonly code = 57
+  readonly code = 57
+  readonly name = "MissingPoolAuthority"
+  readonly msg = "Pool has no authority and cannot be updated"
+
+  constructor(readonly logs?: string[]) {
+    super("57: Pool has no authority and cannot be updated")
+  }
+}
+
+export class InvalidPoolAuthority extends Error {
+  static readonly code = 58
+  readonly code = 58
+  readonly name = "InvalidPoolAuthority"
+  readonly msg = "Signer is not the pool authority"
+
+  constructor(readonly logs?: string[]) {
+    super("58: Signer is not the pool authority")
+  }
+}
+
+export class UncollectedProtocolFees extends Error {
+  static readonly code = 59
+  readonly code = 59
+  readonly name = "UncollectedProtocolFees"
+  readonly msg =
+    "Protocol fee recipient must withdraw its fees before being removed"
+
+  constructor(readonly logs?: string[]) {
+    super(
+      "59: Protocol fee recipient must withdraw its fees before being removed"
+    )
+  }
+}
+
+export class PoolPaused extends Error {
+  static readonly code = 60
+  readonly code = 60
+  readonly name = "PoolPaused"
+  readonly msg = "Pool is paused"
+
+  constructor(readonly logs?: string[]) {
+    super("60: Pool is paused")
+  }
+}
+
+export class UnsupportedMintExtension extends Error {
+  static readonly code = 61
+  readonly code = 61
+  readonly name = "UnsupportedMintExtension"
+  readonly msg =
+    "Mint has a Token-2022 extension that is not supported by pools"
+
+  constructor(readonly logs?: string[]) {
+    super("61: Mint has a Token-2022 extension that is not supported by pools")
+  }
+}
+
+export class InvalidPoolAddress extends Error {
+  static readonly code = 62
+  readonly code = 62
+  readonly name = "InvalidPoolAddress"
+  readonly msg = "Pool address does not match the expected PDA"
+
+  constructor(readonly logs?: string[]) {
+    super("62: Pool address does not match the expected PDA")
+  }
+}
+
+export class InvalidFeeTierConfigAccount extends Error {
+  static readonly code = 63
+  readonly code = 63
+  readonly name = "InvalidFeeTierConfigAccount"
+  readonly msg = "Invalid fee tier config account"
+
+  constructor(readonly logs?: string[]) {
+    super("63: Invalid fee tier config account")
+  }
+}
+
+export class InvalidFeeTierConfigAdmin extends Error {
+  static readonly code = 64
+  readonly code = 64
+  readonly name = "InvalidFeeTierConfigAdmin"
+  readonly msg = "Signer is not the fee tier config admin"
+
+  constructor(readonly logs?: string[]) {
+    super("64: Signer is not the fee tier config admin")
+  }
+}
+
+export class FeeTierNotFound extends Error {
+  static readonly code = 65
+  readonly code = 65
+  readonly name = "FeeTierNotFound"
+  readonly msg = "Fee tier does not exist"
+
+  constructor(readonly logs?: string[]) {
+    super("65: Fee tier does not exist")
+  }
+}
+
+export class FeeTierDisabled extends Error {
+  static readonly code = 66
+  readonly code = 66
+  readonly name = "FeeTierDisabled"
+  readonly msg = "Fee tier is disabled"
+
+  constructor(readonly logs?: string[]) {
+    super("66: Fee tier is disabled")
+  }
+}
+
+export class TooManyFeeTiers extends Error {
+  static readonly code = 67
+  readonly code = 67
+  readonly name = "TooManyFeeTiers"
+  readonly msg = "Fee tier config is full"
+
+  constructor(readonly logs?: string[]) {
+    super("67: Fee tier config is full")
+  }
+}
+
+export class LpPositionNotEmpty extends Error {
+  static readonly code = 68
+  readonly code = 68
+  readonly name = "LpPositionNotEmpty"
+  readonly msg = "LP position still holds shares or uncollected fees"
+
+  constructor(readonly logs?: string[]) {
+    super("68: LP position still holds shares or uncollected fees")
+  }
+}
+
+export class PoolNotEmpty extends Error {
+  static readonly code = 69
+  readonly code = 69
+  readonly name = "PoolNotEmpty"
+  readonly msg = "Pool still holds liquidity or uncollected fees"
+
+  constructor(readonly logs?: string[]) {
+    super("69: Pool still holds liquidity or uncollected fees")
+  }
+}
+
+export class LpTokensNotEnabled extends Error {
+  static readonly code = 70
+  readonly code = 70
+  readonly name = "LpTokensNotEnabled"
+  readonly msg = "LP tokens are not enabled for this pool"
+
+  constructor(readonly logs?: string[]) {
+    super("70: LP tokens are not enabled for this pool")
+  }
+}
+
+export class InvalidLpMintAddress extends Error {
+  static readonly code = 71
+  readonly code = 71
+  readonly name = "InvalidLpMintAddress"
+  readonly msg = "Invalid LP mint address"
+
+  constructor(readonly logs?: string[]) {
+    super("71: Invalid LP mint address")
+  }
+}
+
+export class InvalidLpPositionRecipient extends Error {
+  static readonly code = 72
+  readonly code = 72
+  readonly name = "InvalidLpPositionRecipient"
+  readonly msg = "LP positions cannot be transferred to their owner"
+
+  constructor(readonly logs?: string[]) {
+    super("72: LP positions cannot be transferred to their owner")
+  }
+}
+
+export class LpPositionMigrationRequired extends Error {
+  static readonly code = 73
+  readonly code = 73
+  readonly name = "LpPositionMigrationRequired"
+  readonly msg = "LP position must be migrated to the current layout"
+
+  constructor(readonly logs?: string[]) {
+    super("73: LP position must be migrated to the current layout")
+  }
+}
+
+export class LpPositionAlreadyMigrated extends Error {
+  static readonly code = 74
+  readonly code = 74
+  readonly name = "LpPositionAlreadyMigrated"
+  readonly msg = "LP position does not need to be migrated"
+
+  constructor(readonly logs?: string[]) {
+    super("74: LP position does not need to be migrated")
+  }
+}
+
+export class SlotDeadlineExceeded extends Error {
+  static readonly code = 75
+  readonly code = 75
+  readonly name = "SlotDeadlineExceeded"
+  readonly msg = "Instruction landed outside of its slot deadline"
+
+  constructor(readonly logs?: string[]) {
+    super("75: Instruction landed outside of its slot deadline")
+  }
+}
+
+export class InsufficientWithdrawableShares extends Error {
+  static readonly code = 76
+  readonly code = 76
+  readonly name = "InsufficientWithdrawableShares"
+  readonly msg = "Not enough withdrawable LP shares"
+
+  constructor(readonly logs?: string[]) {
+    super("76: Not enough withdrawable LP shares")
+  }
+}
+
+export class PoolMigrationRequired extends Error {
+  static readonly code = 77
+  readonly code = 77
+  readonly name = "PoolMigrationRequired"
+  readonly msg = "Pool must be migrated to the current layout"
+
+  constructor(readonly logs?: string[]) {
+    super("77: Pool must be migrated to the current layout")
+  }
+}
+
+export class PoolAlreadyMigrated extends Error {
+  static readonly code = 78
+  readonly code = 78
+  readonly name = "PoolAlreadyMigrated"
+  readonly msg = "Pool does not need to be migrated"
+
+  constructor(readonly logs?: string[]) {
+    super("78: Pool does not need to be migrated")
+  }
+}
+
+export class MissingSystemProgram extends Error {
+  static readonly code = 79
+  readonly code = 79
+  readonly name = "MissingSystemProgram"
+  readonly msg = "System program is required to wrap or unwrap native SOL"
+
+  constructor(readonly logs?: string[]) {
+    super("79: System program is required to wrap or unwrap native SOL")
+  }
+}
+
+export class MissingFeeTierConfig extends Error {
+  static readonly code = 80
+  readonly code = 80
+  readonly name = "MissingFeeTierConfig"
+  readonly msg = "Fee tier config account is required"
+
+  constructor(readonly logs?: string[]) {
+    super("80: Fee tier config account is required")
+  }
+}
+
+export class LpSharesStillVesting extends Error {
+  static readonly code = 81
+  readonly code = 81
+  readonly name = "LpSharesStillVesting"
+  readonly msg = "LP shares are still vesting"
+
+  constructor(readonly logs?: string[]) {
+    super("81: LP shares are still vesting")
+  }
+}
+
+export function fromCode(code: number, logs?: string[]): CustomError | null {
+  switch (code) {
+    case 0:
+      return new InvariantViolation(logs)
+    case 1:
+      return new MismatchedFees(logs)
+    case 2:
+      return new UninitializedPool(logs)
+    case 3:
+      return new SwapAmountMismatch(logs)
+    case 4:
+      return new Overflow(logs)
+    case 5:
+      return new Underflow(logs)
+    case 6:
+      return new UnexpectedArgument(logs)
+    case 7:
+      return new MissingExpectedArgument(logs)
+    case 8:
+      return new BelowMinimumLpSharesRequired(logs)
+    case 9:
+      return new BelowMinimumWithdrawalRequired(logs)
+    case 10:
+      return new VestingPeriodNotOver(logs)
+    case 11:
+      return new IncorrectProtocolFeeRecipient(logs)
+    case 12:
+      return new TooManyShares(logs)
+    case 13:
+      return new SwapExactOutTooLarge(logs)
+    case 14:
+      return new SwapExactInTooLarge(logs)
+    case 15:
+      return new SwapOutputGreaterThanOrEqualToReserves(logs)
+    case 16:
+      return new SlippageExceeded(logs)
+    case 17:
+      return new ObservationTooOld(logs)
+    case 18:
+      return new IncorrectProgramId(logs)
+    case 19:
+      return new InvalidLogAuthority(logs)
+    case 20:
+      return new IncorrectAccountKey(logs)
+    case 21:
+      return new MissingRequiredSignature(logs)
+    case 22:
+      return new PayerNotWritable(logs)
+    case 23:
+      return new AccountAlreadyInitialized(logs)
+    case 24:
+      return new EmptyAccountNotOwnedBySystemProgram(logs)
+    case 25:
+      return new NotOwnedByTokenProgram(logs)
+    case 26:
+      return new InvalidTokenAccountData(logs)
+    case 27:
+      return new TokenAccountMintMismatch(logs)
+    case 28:
+      return new TokenAccountOwnerMismatch(logs)
+    case 29:
+      return new IncorrectTokenAccountKey(logs)
+    case 30:
+      return new NotOwnedByPlasmaProgram(logs)
+    case 31:
+      return new InvalidPoolAccount(logs)
+    case 32:
+      return new PoolAlreadyInitialized(logs)
+    case 33:
+      return new InvalidLpPositionAddress(logs)
+    case 34:
+      return new InvalidLpPositionAccount(logs)
+    case 35:
+      return new LpPositionAuthorityMismatch(logs)
+    case 36:
+      return new LpPositionPoolMismatch(logs)
+    case 37:
+      return new InvalidObservationsAddress(logs)
+    case 38:
+      return new InvalidObservationsAccount(logs)
+    case 39:
+      return new ObservationsPoolMismatch(logs)
+    case 40:
+      return new InvalidVaultAddress(logs)
+    case 41:
+      return new LpFeeTooHigh(logs)
+    case 42:
+      return new ProtocolFeeAllocationTooHigh(logs)
+    case 43:
+      return new TooManyProtocolFeeShares(logs)
+    case 44:
+      return new DuplicateProtocolFeeRecipients(logs)
+    case 45:
+      return new IdenticalBaseAndQuoteMints(logs)
+    case 46:
+      return new ProtocolFeeAccountingError(logs)
+    case 47:
+      return new CumulativeFeesDecreased(logs)
+    case 48:
+      return new InvalidInitialLpShares(logs)
+    case 49:
+      return new LpPositionRenounced(logs)
+    case 50:
+      return new LpPositionFeesBurned(logs)
+    case 51:
+      return new InsufficientBalance(logs)
+    case 52:
+      return new EmptySwapRoute(logs)
+    case 53:
+      return new DuplicatePoolInSwapRoute(logs)
+    case 54:
+      return new SwapRouteMintMismatch(logs)
+    case 55:
+      return new InvalidObservationsCapacity(logs)
+    case 56:
+      return new ObservationsGrowthTooLarge(logs)
+    case 57:
+      return new MissingPoolAuthority(logs)
+    case 58:
+      return new InvalidPoolAuthority(logs)
+    case 59:
+      return new UncollectedProtocolFees(logs)
+    case 60:
+      return new PoolPaused(logs)
+    case 61:
+      return new UnsupportedMintExtension(logs)
+    case 62:
+      return new InvalidPoolAddress(logs)
+    case 63:
+      return new InvalidFeeTierConfigAccount(logs)
+    case 64:
+      return new InvalidFeeTierConfigAdmin(logs)
+    case 65:
+      return new FeeTierNotFound(logs)
+    case 66:
+      return new FeeTierDisabled(logs)
+    case 67:
+      return new TooManyFeeTiers(logs)
+    case 68:
+      return new LpPositionNotEmpty(logs)
+    case 69:
+      return new PoolNotEmpty(logs)
+    case 70:
+      return new LpTokensNotEnabled(logs)
+    case 71:
+      return new InvalidLpMintAddress(logs)
+    case 72:
+      return new InvalidLpPositionRecipient(logs)
+    case 73:
+      return new LpPositionMigrationRequired(logs)
+    case 74:
+      return new LpPositionAlreadyMigrated(logs)
+    case 75:
+      return new SlotDeadlineExceeded(logs)
+    case 76:
+      return new InsufficientWithdrawableShares(logs)
+    case 77:
+      return new PoolMigrationRequired(logs)
+    case 78:
+      return new PoolAlreadyMigrated(logs)
+    case 79:
+      return new MissingSystemProgram(logs)
+    case 80:
+      return new MissingFeeTierConfig(logs)
+    case 81:
+      return new LpSharesStillVesting(logs)
+  }
+
+  return null
+}
@@ -0,0 +1,82 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface AddLiquiditySingleSidedArgs {
+  params: types.AddLiquiditySingleSidedIxParamsFields
+}
+
+export interface AddLiquiditySingleSidedAccounts {
+  /** Plasma program */
+  plasmaProgram: PublicKey
+  /** Plasma log authority */
+  logAuthority: PublicKey
+  /** This account holds the pool state */
+  pool: PublicKey
+  trader: PublicKey
+  lpPosition: PublicKey
+  /** Trader base token account. If the base mint is the native mint, it can be the temporary wSOL PDA, seeds are [b'wsol', trader_address] */
+  baseAccount: PublicKey
+  /** Trader quote token account. If the quote mint is the native mint, it can be the temporary wSOL PDA, seeds are [b'wsol', trader_address] */
+  quoteAccount: PublicKey
+  /** Base vault PDA, seeds are [b'vault', pool_address, base_mint_address] */
+  baseVault: PublicKey
+  /** Quote vault PDA, seeds are [b'vault', pool_address, quote_mint_address] */
+  quoteVault: PublicKey
+  /** Base mint account */
+  baseMint: PublicKey
+  /** Quote mint account */
+  quoteMint: PublicKey
+  /** Token program of the base mint */
+  baseTokenProgram: PublicKey
+  /** Token program of the quote mint */
+  quoteTokenProgram: PublicKey
+  /** System program, only required to wrap native SOL or to create the temporary wSOL account */
+  systemProgram?: PublicKey
+}
+
+export const layout = borsh.struct([
+  types.AddLiquiditySingleSidedIxParams.layout("params"),
+])
+
+export function AddLiquiditySingleSided(
+  args: AddLiquiditySingleSidedArgs,
+  accounts: AddLiquiditySingleSidedAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.plasmaProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.logAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.pool, isSigner: false, isWritable: true },
+    { pubkey: accounts.trader, isSigner: true, isWritable: false },
+    { pubkey: accounts.lpPosition, isSigner: false, isWritable: true },
+    { pubkey: accounts.baseAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.quoteAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.baseVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.quoteVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.baseMint, isSigner: false, isWritable: false },
+    { pubkey: accounts.quoteMint, isSigner: false, isWritable: false },
+    { pubkey: accounts.baseTokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.quoteTokenProgram, isSigner: false, isWritable: false },
+  ]
+  if (accounts.systemProgram) {
+    keys.push({
+      pubkey: accounts.systemProgram,
+      isSigner: false,
+      isWritable: false,
+    })
+  }
+  const identifier = Buffer.from([24])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      params: types.AddLiquiditySingleSidedIxParams.toEncodable(args.params),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 1 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
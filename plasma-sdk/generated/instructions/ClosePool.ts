@@ -0,0 +1,56 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ClosePoolAccounts {
+  /** Plasma program */
+  plasmaProgram: PublicKey
+  /** Plasma log authority */
+  logAuthority: PublicKey
+  /** This account holds the pool state */
+  pool: PublicKey
+  /** Authority of the pool, receives the rent */
+  poolAuthority: PublicKey
+  /** Base token account of the pool authority */
+  baseAccount: PublicKey
+  /** Quote token account of the pool authority */
+  quoteAccount: PublicKey
+  /** Base vault PDA, seeds are [b'vault', pool_address, base_mint_address] */
+  baseVault: PublicKey
+  /** Quote vault PDA, seeds are [b'vault', pool_address, quote_mint_address] */
+  quoteVault: PublicKey
+  /** Base mint account, receives the withheld transfer fees of the vault */
+  baseMint: PublicKey
+  /** Quote mint account, receives the withheld transfer fees of the vault */
+  quoteMint: PublicKey
+  /** Token program of the base mint */
+  baseTokenProgram: PublicKey
+  /** Token program of the quote mint */
+  quoteTokenProgram: PublicKey
+}
+
+export function ClosePool(
+  accounts: ClosePoolAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.plasmaProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.logAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.pool, isSigner: false, isWritable: true },
+    { pubkey: accounts.poolAuthority, isSigner: true, isWritable: true },
+    { pubkey: accounts.baseAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.quoteAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.baseVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.quoteVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.baseMint, isSigner: false, isWritable: true },
+    { pubkey: accounts.quoteMint, isSigner: false, isWritable: true },
+    { pubkey: accounts.baseTokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.quoteTokenProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([17])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
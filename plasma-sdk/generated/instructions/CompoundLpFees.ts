@@ -0,0 +1,51 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface CompoundLpFeesArgs {
+  params: types.CompoundLpFeesIxParamsFields
+}
+
+export interface CompoundLpFeesAccounts {
+  /** Plasma program */
+  plasmaProgram: PublicKey
+  /** Plasma log authority */
+  logAuthority: PublicKey
+  /** This account holds the pool state */
+  pool: PublicKey
+  /** Owner of the LP position */
+  trader: PublicKey
+  /** LP position of the trader */
+  lpPosition: PublicKey
+}
+
+export const layout = borsh.struct([
+  types.CompoundLpFeesIxParams.layout("params"),
+])
+
+export function CompoundLpFees(
+  args: CompoundLpFeesArgs,
+  accounts: CompoundLpFeesAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.plasmaProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.logAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.pool, isSigner: false, isWritable: true },
+    { pubkey: accounts.trader, isSigner: true, isWritable: false },
+    { pubkey: accounts.lpPosition, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([23])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      params: types.CompoundLpFeesIxParams.toEncodable(args.params),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 1 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
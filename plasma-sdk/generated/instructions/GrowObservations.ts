@@ -0,0 +1,53 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface GrowObservationsArgs {
+  params: types.GrowObservationsIxParamsFields
+}
+
+export interface GrowObservationsAccounts {
+  /** Plasma program */
+  plasmaProgram: PublicKey
+  /** Plasma log authority */
+  logAuthority: PublicKey
+  /** This account holds the pool state */
+  pool: PublicKey
+  payer: PublicKey
+  /** Observations PDA, seeds are [b'observations', pool_address] */
+  observations: PublicKey
+  /** System program */
+  systemProgram: PublicKey
+}
+
+export const layout = borsh.struct([
+  types.GrowObservationsIxParams.layout("params"),
+])
+
+export function GrowObservations(
+  args: GrowObservationsArgs,
+  accounts: GrowObservationsAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.plasmaProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.logAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.pool, isSigner: false, isWritable: true },
+    { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.observations, isSigner: false, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([10])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      params: types.GrowObservationsIxParams.toEncodable(args.params),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 1 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,38 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface InitializeFeeTierConfigAccounts {
+  /** Plasma program */
+  plasmaProgram: PublicKey
+  /** Plasma log authority */
+  logAuthority: PublicKey
+  /** Fee tier config PDA, seeds are [b'fee_tier_config'] */
+  feeTierConfig: PublicKey
+  /** Upgrade authority of the program */
+  admin: PublicKey
+  /** Program data account of the Plasma program */
+  programData: PublicKey
+  /** System program */
+  systemProgram: PublicKey
+}
+
+export function InitializeFeeTierConfig(
+  accounts: InitializeFeeTierConfigAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.plasmaProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.logAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.feeTierConfig, isSigner: false, isWritable: true },
+    { pubkey: accounts.admin, isSigner: true, isWritable: true },
+    { pubkey: accounts.programData, isSigner: false, isWritable: false },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([14])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -13,7 +13,7 @@ export interface InitializePoolAccounts {
   plasmaProgram: PublicKey
   /** Plasma log authority */
   logAuthority: PublicKey
-  /** This account holds the pool state */
+  /** Pool PDA, seeds are [b'pool', lower mint address, higher mint address, lp_fee_in_bps as u64 le bytes] */
   pool: PublicKey
   /** The pool_creator account must sign for the creation of new vaults */
   poolCreator: PublicKey
@@ -27,8 +27,12 @@ export interface InitializePoolAccounts {
   quoteVault: PublicKey
   /** System program */
   systemProgram: PublicKey
-  /** Token program */
-  tokenProgram: PublicKey
+  /** Token program of the base mint */
+  baseTokenProgram: PublicKey
+  /** Token program of the quote mint */
+  quoteTokenProgram: PublicKey
+  /** Fee tier config PDA, seeds are [b'fee_tier_config'] */
+  feeTierConfig: PublicKey
 }
 
 export const layout = borsh.struct([
@@ -50,7 +54,9 @@ export function InitializePool(
     { pubkey: accounts.baseVault, isSigner: false, isWritable: true },
     { pubkey: accounts.quoteVault, isSigner: false, isWritable: true },
     { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
-    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.baseTokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.quoteTokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.feeTierConfig, isSigner: false, isWritable: false },
   ]
   const identifier = Buffer.from([6])
   const buffer = Buffer.alloc(1000)
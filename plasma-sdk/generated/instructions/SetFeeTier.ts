@@ -0,0 +1,46 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SetFeeTierArgs {
+  params: types.SetFeeTierIxParamsFields
+}
+
+export interface SetFeeTierAccounts {
+  /** Plasma program */
+  plasmaProgram: PublicKey
+  /** Plasma log authority */
+  logAuthority: PublicKey
+  /** Fee tier config PDA, seeds are [b'fee_tier_config'] */
+  feeTierConfig: PublicKey
+  /** Admin of the fee tier config */
+  admin: PublicKey
+}
+
+export const layout = borsh.struct([types.SetFeeTierIxParams.layout("params")])
+
+export function SetFeeTier(
+  args: SetFeeTierArgs,
+  accounts: SetFeeTierAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.plasmaProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.logAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.feeTierConfig, isSigner: false, isWritable: true },
+    { pubkey: accounts.admin, isSigner: true, isWritable: false },
+  ]
+  const identifier = Buffer.from([15])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      params: types.SetFeeTierIxParams.toEncodable(args.params),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 1 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
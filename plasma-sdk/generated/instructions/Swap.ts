@@ -16,16 +16,26 @@ export interface SwapAccounts {
   /** This account holds the pool state */
   pool: PublicKey
   trader: PublicKey
-  /** Trader base token account */
+  /** Trader base token account. If the base mint is the native mint, it can be the temporary wSOL PDA, seeds are [b'wsol', trader_address] */
   baseAccount: PublicKey
-  /** Trader quote token account */
+  /** Trader quote token account. If the quote mint is the native mint, it can be the temporary wSOL PDA, seeds are [b'wsol', trader_address] */
   quoteAccount: PublicKey
   /** Base vault PDA, seeds are [b'vault', pool_address, base_mint_address] */
   baseVault: PublicKey
   /** Quote vault PDA, seeds are [b'vault', pool_address, quote_mint_address] */
   quoteVault: PublicKey
-  /** Token program */
-  tokenProgram: PublicKey
+  /** Base mint account */
+  baseMint: PublicKey
+  /** Quote mint account */
+  quoteMint: PublicKey
+  /** Token program of the base mint */
+  baseTokenProgram: PublicKey
+  /** Token program of the quote mint */
+  quoteTokenProgram: PublicKey
+  /** Optional observations PDA, seeds are [b'observations', pool_address] */
+  observations?: PublicKey
+  /** System program, only required to wrap native SOL or to create the temporary wSOL account. It can be passed without the observations account */
+  systemProgram?: PublicKey
 }
 
 export const layout = borsh.struct([types.SwapIxParams.layout("params")])
@@ -44,8 +54,25 @@ export function Swap(
     { pubkey: accounts.quoteAccount, isSigner: false, isWritable: true },
     { pubkey: accounts.baseVault, isSigner: false, isWritable: true },
     { pubkey: accounts.quoteVault, isSigner: false, isWritable: true },
-    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.baseMint, isSigner: false, isWritable: false },
+    { pubkey: accounts.quoteMint, isSigner: false, isWritable: false },
+    { pubkey: accounts.baseTokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.quoteTokenProgram, isSigner: false, isWritable: false },
   ]
+  if (accounts.observations) {
+    keys.push({
+      pubkey: accounts.observations,
+      isSigner: false,
+      isWritable: true,
+    })
+  }
+  if (accounts.systemProgram) {
+    keys.push({
+      pubkey: accounts.systemProgram,
+      isSigner: false,
+      isWritable: false,
+    })
+  }
   const identifier = Buffer.from([0])
   const buffer = Buffer.alloc(1000)
   const len = layout.encode(
@@ -0,0 +1,69 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SwapRouteArgs {
+  params: types.SwapRouteIxParamsFields
+}
+
+export interface SwapRouteAccounts {
+  /** Plasma program */
+  plasmaProgram: PublicKey
+  /** Plasma log authority */
+  logAuthority: PublicKey
+  /** This account holds the state of the first pool of the route */
+  pool: PublicKey
+  trader: PublicKey
+  /** Trader token account of the input mint of the route. If it is the native mint, it can be the temporary wSOL PDA, seeds are [b'wsol', trader_address] */
+  inputAccount: PublicKey
+  /** Trader token account of the output mint of the route. If it is the native mint, it can be the temporary wSOL PDA, seeds are [b'wsol', trader_address] */
+  outputAccount: PublicKey
+  /** Base vault PDA of the first pool, seeds are [b'vault', pool_address, base_mint_address] */
+  baseVault: PublicKey
+  /** Quote vault PDA of the first pool, seeds are [b'vault', pool_address, quote_mint_address] */
+  quoteVault: PublicKey
+  /** Base mint of the first pool */
+  baseMint: PublicKey
+  /** Quote mint of the first pool */
+  quoteMint: PublicKey
+  /** Token program of the base mint of the first pool */
+  baseTokenProgram: PublicKey
+  /** Token program of the quote mint of the first pool */
+  quoteTokenProgram: PublicKey
+}
+
+export const layout = borsh.struct([types.SwapRouteIxParams.layout("params")])
+
+export function SwapRoute(
+  args: SwapRouteArgs,
+  accounts: SwapRouteAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.plasmaProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.logAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.pool, isSigner: false, isWritable: true },
+    { pubkey: accounts.trader, isSigner: true, isWritable: false },
+    { pubkey: accounts.inputAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.outputAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.baseVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.quoteVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.baseMint, isSigner: false, isWritable: false },
+    { pubkey: accounts.quoteMint, isSigner: false, isWritable: false },
+    { pubkey: accounts.baseTokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.quoteTokenProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([9])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      params: types.SwapRouteIxParams.toEncodable(args.params),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 1 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,63 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface TokenizeLpSharesArgs {
+  params: types.TokenizeLpSharesIxParamsFields
+}
+
+export interface TokenizeLpSharesAccounts {
+  /** Plasma program */
+  plasmaProgram: PublicKey
+  /** Plasma log authority */
+  logAuthority: PublicKey
+  /** This account holds the pool state */
+  pool: PublicKey
+  /** Owner of the LP position */
+  trader: PublicKey
+  /** LP position of the trader */
+  lpPosition: PublicKey
+  /** Escrow LP position PDA, seeds are [b'lp_escrow', pool_address] */
+  lpEscrow: PublicKey
+  /** LP mint PDA, seeds are [b'lp_mint', pool_address] */
+  lpMint: PublicKey
+  /** Trader LP token account */
+  lpTokenAccount: PublicKey
+  /** Token program */
+  tokenProgram: PublicKey
+}
+
+export const layout = borsh.struct([
+  types.TokenizeLpSharesIxParams.layout("params"),
+])
+
+export function TokenizeLpShares(
+  args: TokenizeLpSharesArgs,
+  accounts: TokenizeLpSharesAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.plasmaProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.logAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.pool, isSigner: false, isWritable: true },
+    { pubkey: accounts.trader, isSigner: true, isWritable: false },
+    { pubkey: accounts.lpPosition, isSigner: false, isWritable: true },
+    { pubkey: accounts.lpEscrow, isSigner: false, isWritable: true },
+    { pubkey: accounts.lpMint, isSigner: false, isWritable: true },
+    { pubkey: accounts.lpTokenAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([19])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      params: types.TokenizeLpSharesIxParams.toEncodable(args.params),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 1 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,54 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface TransferProtocolFeeRecipientArgs {
+  params: types.TransferProtocolFeeRecipientIxParamsFields
+}
+
+export interface TransferProtocolFeeRecipientAccounts {
+  /** Plasma program */
+  plasmaProgram: PublicKey
+  /** Plasma log authority */
+  logAuthority: PublicKey
+  /** This account holds the pool state */
+  pool: PublicKey
+  /** Current recipient of the protocol fee slot */
+  protocolFeeRecipient: PublicKey
+}
+
+export const layout = borsh.struct([
+  types.TransferProtocolFeeRecipientIxParams.layout("params"),
+])
+
+export function TransferProtocolFeeRecipient(
+  args: TransferProtocolFeeRecipientArgs,
+  accounts: TransferProtocolFeeRecipientAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.plasmaProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.logAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.pool, isSigner: false, isWritable: true },
+    {
+      pubkey: accounts.protocolFeeRecipient,
+      isSigner: true,
+      isWritable: false,
+    },
+  ]
+  const identifier = Buffer.from([12])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      params: types.TransferProtocolFeeRecipientIxParams.toEncodable(
+        args.params
+      ),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 1 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
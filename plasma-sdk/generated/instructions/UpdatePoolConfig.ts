@@ -0,0 +1,57 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface UpdatePoolConfigArgs {
+  params: types.UpdatePoolConfigIxParamsFields
+}
+
+export interface UpdatePoolConfigAccounts {
+  /** Plasma program */
+  plasmaProgram: PublicKey
+  /** Plasma log authority */
+  logAuthority: PublicKey
+  /** This account holds the pool state */
+  pool: PublicKey
+  /** Authority of the pool */
+  poolAuthority: PublicKey
+  /** Fee tier config PDA, seeds are [b'fee_tier_config'], only required to update the fees */
+  feeTierConfig?: PublicKey
+}
+
+export const layout = borsh.struct([
+  types.UpdatePoolConfigIxParams.layout("params"),
+])
+
+export function UpdatePoolConfig(
+  args: UpdatePoolConfigArgs,
+  accounts: UpdatePoolConfigAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.plasmaProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.logAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.pool, isSigner: false, isWritable: true },
+    { pubkey: accounts.poolAuthority, isSigner: true, isWritable: false },
+  ]
+  if (accounts.feeTierConfig) {
+    keys.push({
+      pubkey: accounts.feeTierConfig,
+      isSigner: false,
+      isWritable: false,
+    })
+  }
+  const identifier = Buffer.from([11])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      params: types.UpdatePoolConfigIxParams.toEncodable(args.params),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 1 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -18,7 +18,9 @@ export interface WithdrawLpFeesAccounts {
   quoteAccount: PublicKey
   /** Quote vault PDA, seeds are [b'vault', pool_address, quote_mint_address] */
   quoteVault: PublicKey
-  /** Token program */
+  /** Quote mint account */
+  quoteMint: PublicKey
+  /** Token program of the quote mint */
   tokenProgram: PublicKey
 }
 
@@ -35,6 +37,7 @@ export function WithdrawLpFees(
     { pubkey: accounts.lpPosition, isSigner: false, isWritable: true },
     { pubkey: accounts.quoteAccount, isSigner: false, isWritable: true },
     { pubkey: accounts.quoteVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.quoteMint, isSigner: false, isWritable: false },
     { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
   ]
   const identifier = Buffer.from([4])
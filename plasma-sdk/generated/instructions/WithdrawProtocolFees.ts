@@ -17,7 +17,9 @@ export interface WithdrawProtocolFeesAccounts {
   quoteAccount: PublicKey
   /** Quote vault PDA, seeds are [b'vault', pool_address, quote_mint_address] */
   quoteVault: PublicKey
-  /** Token program */
+  /** Quote mint account */
+  quoteMint: PublicKey
+  /** Token program of the quote mint */
   tokenProgram: PublicKey
 }
 
@@ -36,6 +38,7 @@ export function WithdrawProtocolFees(
     },
     { pubkey: accounts.quoteAccount, isSigner: false, isWritable: true },
     { pubkey: accounts.quoteVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.quoteMint, isSigner: false, isWritable: false },
     { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
   ]
   const identifier = Buffer.from([7])
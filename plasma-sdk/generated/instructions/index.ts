@@ -25,3 +25,63 @@ export { WithdrawProtocolFees } from "./WithdrawProtocolFees"
 export type { WithdrawProtocolFeesAccounts } from "./WithdrawProtocolFees"
 export { Log } from "./Log"
 export type { LogAccounts } from "./Log"
+export { SwapRoute } from "./SwapRoute"
+export type { SwapRouteArgs, SwapRouteAccounts } from "./SwapRoute"
+export { GrowObservations } from "./GrowObservations"
+export type {
+  GrowObservationsArgs,
+  GrowObservationsAccounts,
+} from "./GrowObservations"
+export { UpdatePoolConfig } from "./UpdatePoolConfig"
+export type {
+  UpdatePoolConfigArgs,
+  UpdatePoolConfigAccounts,
+} from "./UpdatePoolConfig"
+export { TransferProtocolFeeRecipient } from "./TransferProtocolFeeRecipient"
+export type {
+  TransferProtocolFeeRecipientArgs,
+  TransferProtocolFeeRecipientAccounts,
+} from "./TransferProtocolFeeRecipient"
+export { SetPoolStatus } from "./SetPoolStatus"
+export type { SetPoolStatusArgs, SetPoolStatusAccounts } from "./SetPoolStatus"
+export { InitializeFeeTierConfig } from "./InitializeFeeTierConfig"
+export type { InitializeFeeTierConfigAccounts } from "./InitializeFeeTierConfig"
+export { SetFeeTier } from "./SetFeeTier"
+export type { SetFeeTierArgs, SetFeeTierAccounts } from "./SetFeeTier"
+export { CloseLpPosition } from "./CloseLpPosition"
+export type { CloseLpPositionAccounts } from "./CloseLpPosition"
+export { ClosePool } from "./ClosePool"
+export type { ClosePoolAccounts } from "./ClosePool"
+export { InitializeLpMint } from "./InitializeLpMint"
+export type { InitializeLpMintAccounts } from "./InitializeLpMint"
+export { TokenizeLpShares } from "./TokenizeLpShares"
+export type {
+  TokenizeLpSharesArgs,
+  TokenizeLpSharesAccounts,
+} from "./TokenizeLpShares"
+export { RedeemLpTokens } from "./RedeemLpTokens"
+export type {
+  RedeemLpTokensArgs,
+  RedeemLpTokensAccounts,
+} from "./RedeemLpTokens"
+export { TransferLpPosition } from "./TransferLpPosition"
+export type { TransferLpPositionAccounts } from "./TransferLpPosition"
+export { MigrateLpPosition } from "./MigrateLpPosition"
+export type { MigrateLpPositionAccounts } from "./MigrateLpPosition"
+export { CompoundLpFees } from "./CompoundLpFees"
+export type {
+  CompoundLpFeesArgs,
+  CompoundLpFeesAccounts,
+} from "./CompoundLpFees"
+export { AddLiquiditySingleSided } from "./AddLiquiditySingleSided"
+export type {
+  AddLiquiditySingleSidedArgs,
+  AddLiquiditySingleSidedAccounts,
+} from "./AddLiquiditySingleSided"
+export { RemoveLiquiditySingleSided } from "./RemoveLiquiditySingleSided"
+export type {
+  RemoveLiquiditySingleSidedArgs,
+  RemoveLiquiditySingleSidedAccounts,
+} from "./RemoveLiquiditySingleSided"
+export { MigratePool } from "./MigratePool"
+export type { MigratePoolAccounts } from "./MigratePool"
@@ -7,23 +7,27 @@ export interface AddLiquidityIxParamsFields {
   desiredBaseAmountIn: BN
   desiredQuoteAmountIn: BN
   initialLpShares: BN | null
+  extension: types.AddLiquidityParamsExtensionKind
 }
 
 export interface AddLiquidityIxParamsJSON {
   desiredBaseAmountIn: string
   desiredQuoteAmountIn: string
   initialLpShares: string | null
+  extension: types.AddLiquidityParamsExtensionJSON
 }
 
 export class AddLiquidityIxParams {
   readonly desiredBaseAmountIn: BN
   readonly desiredQuoteAmountIn: BN
   readonly initialLpShares: BN | null
+  readonly extension: types.AddLiquidityParamsExtensionKind
 
   constructor(fields: AddLiquidityIxParamsFields) {
     this.desiredBaseAmountIn = fields.desiredBaseAmountIn
     this.desiredQuoteAmountIn = fields.desiredQuoteAmountIn
     this.initialLpShares = fields.initialLpShares
+    this.extension = fields.extension
   }
 
   static layout(property?: string) {
@@ -32,6 +36,7 @@ export class AddLiquidityIxParams {
         borsh.u64("desiredBaseAmountIn"),
         borsh.u64("desiredQuoteAmountIn"),
         borsh.option(borsh.u64(), "initialLpShares"),
+        types.AddLiquidityParamsExtension.layout("extension"),
       ],
       property
     )
@@ -43,6 +48,7 @@ export class AddLiquidityIxParams {
       desiredBaseAmountIn: obj.desiredBaseAmountIn,
       desiredQuoteAmountIn: obj.desiredQuoteAmountIn,
       initialLpShares: obj.initialLpShares,
+      extension: types.AddLiquidityParamsExtension.fromDecoded(obj.extension),
     })
   }
 
@@ -51,6 +57,7 @@ export class AddLiquidityIxParams {
       desiredBaseAmountIn: fields.desiredBaseAmountIn,
       desiredQuoteAmountIn: fields.desiredQuoteAmountIn,
       initialLpShares: fields.initialLpShares,
+      extension: fields.extension.toEncodable(),
     }
   }
 
@@ -60,6 +67,7 @@ export class AddLiquidityIxParams {
       desiredQuoteAmountIn: this.desiredQuoteAmountIn.toString(),
       initialLpShares:
         (this.initialLpShares && this.initialLpShares.toString()) || null,
+      extension: this.extension.toJSON(),
     }
   }
 
@@ -69,6 +77,7 @@ export class AddLiquidityIxParams {
       desiredQuoteAmountIn: new BN(obj.desiredQuoteAmountIn),
       initialLpShares:
         (obj.initialLpShares && new BN(obj.initialLpShares)) || null,
+      extension: types.AddLiquidityParamsExtension.fromJSON(obj.extension),
     })
   }
 
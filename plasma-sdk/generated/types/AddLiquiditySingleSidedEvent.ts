@@ -0,0 +1,146 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface AddLiquiditySingleSidedEventFields {
+  tokenIn: types.TokenTypeKind
+  userAmountDeposited: BN
+  swapResult: types.SwapResultFields
+  poolTotalLpShares: BN
+  poolTotalBaseLiquidity: BN
+  poolTotalQuoteLiquidity: BN
+  userLpSharesReceived: BN
+  userLpSharesAvailable: BN
+  userLpSharesLocked: BN
+  userLpSharesUnlockedForWithdrawal: BN
+}
+
+export interface AddLiquiditySingleSidedEventJSON {
+  tokenIn: types.TokenTypeJSON
+  userAmountDeposited: string
+  swapResult: types.SwapResultJSON
+  poolTotalLpShares: string
+  poolTotalBaseLiquidity: string
+  poolTotalQuoteLiquidity: string
+  userLpSharesReceived: string
+  userLpSharesAvailable: string
+  userLpSharesLocked: string
+  userLpSharesUnlockedForWithdrawal: string
+}
+
+export class AddLiquiditySingleSidedEvent {
+  readonly tokenIn: types.TokenTypeKind
+  readonly userAmountDeposited: BN
+  readonly swapResult: types.SwapResult
+  readonly poolTotalLpShares: BN
+  readonly poolTotalBaseLiquidity: BN
+  readonly poolTotalQuoteLiquidity: BN
+  readonly userLpSharesReceived: BN
+  readonly userLpSharesAvailable: BN
+  readonly userLpSharesLocked: BN
+  readonly userLpSharesUnlockedForWithdrawal: BN
+
+  constructor(fields: AddLiquiditySingleSidedEventFields) {
+    this.tokenIn = fields.tokenIn
+    this.userAmountDeposited = fields.userAmountDeposited
+    this.swapResult = new types.SwapResult({ ...fields.swapResult })
+    this.poolTotalLpShares = fields.poolTotalLpShares
+    this.poolTotalBaseLiquidity = fields.poolTotalBaseLiquidity
+    this.poolTotalQuoteLiquidity = fields.poolTotalQuoteLiquidity
+    this.userLpSharesReceived = fields.userLpSharesReceived
+    this.userLpSharesAvailable = fields.userLpSharesAvailable
+    this.userLpSharesLocked = fields.userLpSharesLocked
+    this.userLpSharesUnlockedForWithdrawal =
+      fields.userLpSharesUnlockedForWithdrawal
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        types.TokenType.layout("tokenIn"),
+        borsh.u64("userAmountDeposited"),
+        types.SwapResult.layout("swapResult"),
+        borsh.u64("poolTotalLpShares"),
+        borsh.u64("poolTotalBaseLiquidity"),
+        borsh.u64("poolTotalQuoteLiquidity"),
+        borsh.u64("userLpSharesReceived"),
+        borsh.u64("userLpSharesAvailable"),
+        borsh.u64("userLpSharesLocked"),
+        borsh.u64("userLpSharesUnlockedForWithdrawal"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new AddLiquiditySingleSidedEvent({
+      tokenIn: types.TokenType.fromDecoded(obj.tokenIn),
+      userAmountDeposited: obj.userAmountDeposited,
+      swapResult: types.SwapResult.fromDecoded(obj.swapResult),
+      poolTotalLpShares: obj.poolTotalLpShares,
+      poolTotalBaseLiquidity: obj.poolTotalBaseLiquidity,
+      poolTotalQuoteLiquidity: obj.poolTotalQuoteLiquidity,
+      userLpSharesReceived: obj.userLpSharesReceived,
+      userLpSharesAvailable: obj.userLpSharesAvailable,
+      userLpSharesLocked: obj.userLpSharesLocked,
+      userLpSharesUnlockedForWithdrawal: obj.userLpSharesUnlockedForWithdrawal,
+    })
+  }
+
+  static toEncodable(fields: AddLiquiditySingleSidedEventFields) {
+    return {
+      tokenIn: fields.tokenIn.toEncodable(),
+      userAmountDeposited: fields.userAmountDeposited,
+      swapResult: types.SwapResult.toEncodable(fields.swapResult),
+      poolTotalLpShares: fields.poolTotalLpShares,
+      poolTotalBaseLiquidity: fields.poolTotalBaseLiquidity,
+      poolTotalQuoteLiquidity: fields.poolTotalQuoteLiquidity,
+      userLpSharesReceived: fields.userLpSharesReceived,
+      userLpSharesAvailable: fields.userLpSharesAvailable,
+      userLpSharesLocked: fields.userLpSharesLocked,
+      userLpSharesUnlockedForWithdrawal:
+        fields.userLpSharesUnlockedForWithdrawal,
+    }
+  }
+
+  toJSON(): AddLiquiditySingleSidedEventJSON {
+    return {
+      tokenIn: this.tokenIn.toJSON(),
+      userAmountDeposited: this.userAmountDeposited.toString(),
+      swapResult: this.swapResult.toJSON(),
+      poolTotalLpShares: this.poolTotalLpShares.toString(),
+      poolTotalBaseLiquidity: this.poolTotalBaseLiquidity.toString(),
+      poolTotalQuoteLiquidity: this.poolTotalQuoteLiquidity.toString(),
+      userLpSharesReceived: this.userLpSharesReceived.toString(),
+      userLpSharesAvailable: this.userLpSharesAvailable.toString(),
+      userLpSharesLocked: this.userLpSharesLocked.toString(),
+      userLpSharesUnlockedForWithdrawal:
+        this.userLpSharesUnlockedForWithdrawal.toString(),
+    }
+  }
+
+  static fromJSON(
+    obj: AddLiquiditySingleSidedEventJSON
+  ): AddLiquiditySingleSidedEvent {
+    return new AddLiquiditySingleSidedEvent({
+      tokenIn: types.TokenType.fromJSON(obj.tokenIn),
+      userAmountDeposited: new BN(obj.userAmountDeposited),
+      swapResult: types.SwapResult.fromJSON(obj.swapResult),
+      poolTotalLpShares: new BN(obj.poolTotalLpShares),
+      poolTotalBaseLiquidity: new BN(obj.poolTotalBaseLiquidity),
+      poolTotalQuoteLiquidity: new BN(obj.poolTotalQuoteLiquidity),
+      userLpSharesReceived: new BN(obj.userLpSharesReceived),
+      userLpSharesAvailable: new BN(obj.userLpSharesAvailable),
+      userLpSharesLocked: new BN(obj.userLpSharesLocked),
+      userLpSharesUnlockedForWithdrawal: new BN(
+        obj.userLpSharesUnlockedForWithdrawal
+      ),
+    })
+  }
+
+  toEncodable() {
+    return AddLiquiditySingleSidedEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,91 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface AddLiquiditySingleSidedIxParamsFields {
+  tokenIn: types.TokenTypeKind
+  amountIn: BN
+  minLpSharesOut: BN
+  extension: types.AddLiquiditySingleSidedParamsExtensionKind
+}
+
+export interface AddLiquiditySingleSidedIxParamsJSON {
+  tokenIn: types.TokenTypeJSON
+  amountIn: string
+  minLpSharesOut: string
+  extension: types.AddLiquiditySingleSidedParamsExtensionJSON
+}
+
+export class AddLiquiditySingleSidedIxParams {
+  readonly tokenIn: types.TokenTypeKind
+  readonly amountIn: BN
+  readonly minLpSharesOut: BN
+  readonly extension: types.AddLiquiditySingleSidedParamsExtensionKind
+
+  constructor(fields: AddLiquiditySingleSidedIxParamsFields) {
+    this.tokenIn = fields.tokenIn
+    this.amountIn = fields.amountIn
+    this.minLpSharesOut = fields.minLpSharesOut
+    this.extension = fields.extension
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        types.TokenType.layout("tokenIn"),
+        borsh.u64("amountIn"),
+        borsh.u64("minLpSharesOut"),
+        types.AddLiquiditySingleSidedParamsExtension.layout("extension"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new AddLiquiditySingleSidedIxParams({
+      tokenIn: types.TokenType.fromDecoded(obj.tokenIn),
+      amountIn: obj.amountIn,
+      minLpSharesOut: obj.minLpSharesOut,
+      extension: types.AddLiquiditySingleSidedParamsExtension.fromDecoded(
+        obj.extension
+      ),
+    })
+  }
+
+  static toEncodable(fields: AddLiquiditySingleSidedIxParamsFields) {
+    return {
+      tokenIn: fields.tokenIn.toEncodable(),
+      amountIn: fields.amountIn,
+      minLpSharesOut: fields.minLpSharesOut,
+      extension: fields.extension.toEncodable(),
+    }
+  }
+
+  toJSON(): AddLiquiditySingleSidedIxParamsJSON {
+    return {
+      tokenIn: this.tokenIn.toJSON(),
+      amountIn: this.amountIn.toString(),
+      minLpSharesOut: this.minLpSharesOut.toString(),
+      extension: this.extension.toJSON(),
+    }
+  }
+
+  static fromJSON(
+    obj: AddLiquiditySingleSidedIxParamsJSON
+  ): AddLiquiditySingleSidedIxParams {
+    return new AddLiquiditySingleSidedIxParams({
+      tokenIn: types.TokenType.fromJSON(obj.tokenIn),
+      amountIn: new BN(obj.amountIn),
+      minLpSharesOut: new BN(obj.minLpSharesOut),
+      extension: types.AddLiquiditySingleSidedParamsExtension.fromJSON(
+        obj.extension
+      ),
+    })
+  }
+
+  toEncodable() {
+    return AddLiquiditySingleSidedIxParams.toEncodable(this)
+  }
+}
@@ -16,6 +16,8 @@ export interface AmmFields {
   quoteReserves: BN
   cumulativeQuoteLpFees: BN
   cumulativeQuoteProtocolFees: BN
+  cumulativePrice: BN
+  cumulativeLiquidity: BN
 }
 
 export interface AmmJSON {
@@ -31,6 +33,8 @@ export interface AmmJSON {
   quoteReserves: string
   cumulativeQuoteLpFees: string
   cumulativeQuoteProtocolFees: string
+  cumulativePrice: string
+  cumulativeLiquidity: string
 }
 
 export class Amm {
@@ -46,6 +50,8 @@ export class Amm {
   readonly quoteReserves: BN
   readonly cumulativeQuoteLpFees: BN
   readonly cumulativeQuoteProtocolFees: BN
+  readonly cumulativePrice: BN
+  readonly cumulativeLiquidity: BN
 
   constructor(fields: AmmFields) {
     this.feeInBps = fields.feeInBps
@@ -60,6 +66,8 @@ export class Amm {
     this.quoteReserves = fields.quoteReserves
     this.cumulativeQuoteLpFees = fields.cumulativeQuoteLpFees
     this.cumulativeQuoteProtocolFees = fields.cumulativeQuoteProtocolFees
+    this.cumulativePrice = fields.cumulativePrice
+    this.cumulativeLiquidity = fields.cumulativeLiquidity
   }
 
   static layout(property?: string) {
@@ -77,6 +85,8 @@ export class Amm {
         borsh.u64("quoteReserves"),
         borsh.u64("cumulativeQuoteLpFees"),
         borsh.u64("cumulativeQuoteProtocolFees"),
+        borsh.u128("cumulativePrice"),
+        borsh.u128("cumulativeLiquidity"),
       ],
       property
     )
@@ -97,6 +107,8 @@ export class Amm {
       quoteReserves: obj.quoteReserves,
       cumulativeQuoteLpFees: obj.cumulativeQuoteLpFees,
       cumulativeQuoteProtocolFees: obj.cumulativeQuoteProtocolFees,
+      cumulativePrice: obj.cumulativePrice,
+      cumulativeLiquidity: obj.cumulativeLiquidity,
     })
   }
 
@@ -114,6 +126,8 @@ export class Amm {
       quoteReserves: fields.quoteReserves,
       cumulativeQuoteLpFees: fields.cumulativeQuoteLpFees,
       cumulativeQuoteProtocolFees: fields.cumulativeQuoteProtocolFees,
+      cumulativePrice: fields.cumulativePrice,
+      cumulativeLiquidity: fields.cumulativeLiquidity,
     }
   }
 
@@ -131,6 +145,8 @@ export class Amm {
       quoteReserves: this.quoteReserves.toString(),
       cumulativeQuoteLpFees: this.cumulativeQuoteLpFees.toString(),
       cumulativeQuoteProtocolFees: this.cumulativeQuoteProtocolFees.toString(),
+      cumulativePrice: this.cumulativePrice.toString(),
+      cumulativeLiquidity: this.cumulativeLiquidity.toString(),
     }
   }
 
@@ -148,6 +164,8 @@ export class Amm {
       quoteReserves: new BN(obj.quoteReserves),
       cumulativeQuoteLpFees: new BN(obj.cumulativeQuoteLpFees),
       cumulativeQuoteProtocolFees: new BN(obj.cumulativeQuoteProtocolFees),
+      cumulativePrice: new BN(obj.cumulativePrice),
+      cumulativeLiquidity: new BN(obj.cumulativeLiquidity),
     })
   }
 
@@ -0,0 +1,64 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface CloseLpPositionEventFields {
+  lpPosition: PublicKey
+  lamportsReclaimed: BN
+}
+
+export interface CloseLpPositionEventJSON {
+  lpPosition: string
+  lamportsReclaimed: string
+}
+
+export class CloseLpPositionEvent {
+  readonly lpPosition: PublicKey
+  readonly lamportsReclaimed: BN
+
+  constructor(fields: CloseLpPositionEventFields) {
+    this.lpPosition = fields.lpPosition
+    this.lamportsReclaimed = fields.lamportsReclaimed
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [borsh.publicKey("lpPosition"), borsh.u64("lamportsReclaimed")],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new CloseLpPositionEvent({
+      lpPosition: obj.lpPosition,
+      lamportsReclaimed: obj.lamportsReclaimed,
+    })
+  }
+
+  static toEncodable(fields: CloseLpPositionEventFields) {
+    return {
+      lpPosition: fields.lpPosition,
+      lamportsReclaimed: fields.lamportsReclaimed,
+    }
+  }
+
+  toJSON(): CloseLpPositionEventJSON {
+    return {
+      lpPosition: this.lpPosition.toString(),
+      lamportsReclaimed: this.lamportsReclaimed.toString(),
+    }
+  }
+
+  static fromJSON(obj: CloseLpPositionEventJSON): CloseLpPositionEvent {
+    return new CloseLpPositionEvent({
+      lpPosition: new PublicKey(obj.lpPosition),
+      lamportsReclaimed: new BN(obj.lamportsReclaimed),
+    })
+  }
+
+  toEncodable() {
+    return CloseLpPositionEvent.toEncodable(this)
+  }
+}
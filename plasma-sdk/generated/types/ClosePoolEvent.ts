@@ -0,0 +1,76 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface ClosePoolEventFields {
+  lamportsReclaimed: BN
+  baseAmountSwept: BN
+  quoteAmountSwept: BN
+}
+
+export interface ClosePoolEventJSON {
+  lamportsReclaimed: string
+  baseAmountSwept: string
+  quoteAmountSwept: string
+}
+
+export class ClosePoolEvent {
+  readonly lamportsReclaimed: BN
+  readonly baseAmountSwept: BN
+  readonly quoteAmountSwept: BN
+
+  constructor(fields: ClosePoolEventFields) {
+    this.lamportsReclaimed = fields.lamportsReclaimed
+    this.baseAmountSwept = fields.baseAmountSwept
+    this.quoteAmountSwept = fields.quoteAmountSwept
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u64("lamportsReclaimed"),
+        borsh.u64("baseAmountSwept"),
+        borsh.u64("quoteAmountSwept"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new ClosePoolEvent({
+      lamportsReclaimed: obj.lamportsReclaimed,
+      baseAmountSwept: obj.baseAmountSwept,
+      quoteAmountSwept: obj.quoteAmountSwept,
+    })
+  }
+
+  static toEncodable(fields: ClosePoolEventFields) {
+    return {
+      lamportsReclaimed: fields.lamportsReclaimed,
+      baseAmountSwept: fields.baseAmountSwept,
+      quoteAmountSwept: fields.quoteAmountSwept,
+    }
+  }
+
+  toJSON(): ClosePoolEventJSON {
+    return {
+      lamportsReclaimed: this.lamportsReclaimed.toString(),
+      baseAmountSwept: this.baseAmountSwept.toString(),
+      quoteAmountSwept: this.quoteAmountSwept.toString(),
+    }
+  }
+
+  static fromJSON(obj: ClosePoolEventJSON): ClosePoolEvent {
+    return new ClosePoolEvent({
+      lamportsReclaimed: new BN(obj.lamportsReclaimed),
+      baseAmountSwept: new BN(obj.baseAmountSwept),
+      quoteAmountSwept: new BN(obj.quoteAmountSwept),
+    })
+  }
+
+  toEncodable() {
+    return ClosePoolEvent.toEncodable(this)
+  }
+}
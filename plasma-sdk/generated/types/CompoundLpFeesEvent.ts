@@ -0,0 +1,162 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface CompoundLpFeesEventFields {
+  quoteFeesCompounded: BN
+  quoteFeesSwapped: BN
+  baseAmountBought: BN
+  userBaseDeposited: BN
+  userQuoteDeposited: BN
+  userLpSharesReceived: BN
+  userLpSharesAvailable: BN
+  userLpSharesLocked: BN
+  userLpSharesUnlockedForWithdrawal: BN
+  poolTotalLpShares: BN
+  poolTotalBaseLiquidity: BN
+  poolTotalQuoteLiquidity: BN
+}
+
+export interface CompoundLpFeesEventJSON {
+  quoteFeesCompounded: string
+  quoteFeesSwapped: string
+  baseAmountBought: string
+  userBaseDeposited: string
+  userQuoteDeposited: string
+  userLpSharesReceived: string
+  userLpSharesAvailable: string
+  userLpSharesLocked: string
+  userLpSharesUnlockedForWithdrawal: string
+  poolTotalLpShares: string
+  poolTotalBaseLiquidity: string
+  poolTotalQuoteLiquidity: string
+}
+
+export class CompoundLpFeesEvent {
+  readonly quoteFeesCompounded: BN
+  readonly quoteFeesSwapped: BN
+  readonly baseAmountBought: BN
+  readonly userBaseDeposited: BN
+  readonly userQuoteDeposited: BN
+  readonly userLpSharesReceived: BN
+  readonly userLpSharesAvailable: BN
+  readonly userLpSharesLocked: BN
+  readonly userLpSharesUnlockedForWithdrawal: BN
+  readonly poolTotalLpShares: BN
+  readonly poolTotalBaseLiquidity: BN
+  readonly poolTotalQuoteLiquidity: BN
+
+  constructor(fields: CompoundLpFeesEventFields) {
+    this.quoteFeesCompounded = fields.quoteFeesCompounded
+    this.quoteFeesSwapped = fields.quoteFeesSwapped
+    this.baseAmountBought = fields.baseAmountBought
+    this.userBaseDeposited = fields.userBaseDeposited
+    this.userQuoteDeposited = fields.userQuoteDeposited
+    this.userLpSharesReceived = fields.userLpSharesReceived
+    this.userLpSharesAvailable = fields.userLpSharesAvailable
+    this.userLpSharesLocked = fields.userLpSharesLocked
+    this.userLpSharesUnlockedForWithdrawal =
+      fields.userLpSharesUnlockedForWithdrawal
+    this.poolTotalLpShares = fields.poolTotalLpShares
+    this.poolTotalBaseLiquidity = fields.poolTotalBaseLiquidity
+    this.poolTotalQuoteLiquidity = fields.poolTotalQuoteLiquidity
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u64("quoteFeesCompounded"),
+        borsh.u64("quoteFeesSwapped"),
+        borsh.u64("baseAmountBought"),
+        borsh.u64("userBaseDeposited"),
+        borsh.u64("userQuoteDeposited"),
+        borsh.u64("userLpSharesReceived"),
+        borsh.u64("userLpSharesAvailable"),
+        borsh.u64("userLpSharesLocked"),
+        borsh.u64("userLpSharesUnlockedForWithdrawal"),
+        borsh.u64("poolTotalLpShares"),
+        borsh.u64("poolTotalBaseLiquidity"),
+        borsh.u64("poolTotalQuoteLiquidity"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new CompoundLpFeesEvent({
+      quoteFeesCompounded: obj.quoteFeesCompounded,
+      quoteFeesSwapped: obj.quoteFeesSwapped,
+      baseAmountBought: obj.baseAmountBought,
+      userBaseDeposited: obj.userBaseDeposited,
+      userQuoteDeposited: obj.userQuoteDeposited,
+      userLpSharesReceived: obj.userLpSharesReceived,
+      userLpSharesAvailable: obj.userLpSharesAvailable,
+      userLpSharesLocked: obj.userLpSharesLocked,
+      userLpSharesUnlockedForWithdrawal: obj.userLpSharesUnlockedForWithdrawal,
+      poolTotalLpShares: obj.poolTotalLpShares,
+      poolTotalBaseLiquidity: obj.poolTotalBaseLiquidity,
+      poolTotalQuoteLiquidity: obj.poolTotalQuoteLiquidity,
+    })
+  }
+
+  static toEncodable(fields: CompoundLpFeesEventFields) {
+    return {
+      quoteFeesCompounded: fields.quoteFeesCompounded,
+      quoteFeesSwapped: fields.quoteFeesSwapped,
+      baseAmountBought: fields.baseAmountBought,
+      userBaseDeposited: fields.userBaseDeposited,
+      userQuoteDeposited: fields.userQuoteDeposited,
+      userLpSharesReceived: fields.userLpSharesReceived,
+      userLpSharesAvailable: fields.userLpSharesAvailable,
+      userLpSharesLocked: fields.userLpSharesLocked,
+      userLpSharesUnlockedForWithdrawal:
+        fields.userLpSharesUnlockedForWithdrawal,
+      poolTotalLpShares: fields.poolTotalLpShares,
+      poolTotalBaseLiquidity: fields.poolTotalBaseLiquidity,
+      poolTotalQuoteLiquidity: fields.poolTotalQuoteLiquidity,
+    }
+  }
+
+  toJSON(): CompoundLpFeesEventJSON {
+    return {
+      quoteFeesCompounded: this.quoteFeesCompounded.toString(),
+      quoteFeesSwapped: this.quoteFeesSwapped.toString(),
+      baseAmountBought: this.baseAmountBought.toString(),
+      userBaseDeposited: this.userBaseDeposited.toString(),
+      userQuoteDeposited: this.userQuoteDeposited.toString(),
+      userLpSharesReceived: this.userLpSharesReceived.toString(),
+      userLpSharesAvailable: this.userLpSharesAvailable.toString(),
+      userLpSharesLocked: this.userLpSharesLocked.toString(),
+      userLpSharesUnlockedForWithdrawal:
+        this.userLpSharesUnlockedForWithdrawal.toString(),
+      poolTotalLpShares: this.poolTotalLpShares.toString(),
+      poolTotalBaseLiquidity: this.poolTotalBaseLiquidity.toString(),
+      poolTotalQuoteLiquidity: this.poolTotalQuoteLiquidity.toString(),
+    }
+  }
+
+  static fromJSON(obj: CompoundLpFeesEventJSON): CompoundLpFeesEvent {
+    return new CompoundLpFeesEvent({
+      quoteFeesCompounded: new BN(obj.quoteFeesCompounded),
+      quoteFeesSwapped: new BN(obj.quoteFeesSwapped),
+      baseAmountBought: new BN(obj.baseAmountBought),
+      userBaseDeposited: new BN(obj.userBaseDeposited),
+      userQuoteDeposited: new BN(obj.userQuoteDeposited),
+      userLpSharesReceived: new BN(obj.userLpSharesReceived),
+      userLpSharesAvailable: new BN(obj.userLpSharesAvailable),
+      userLpSharesLocked: new BN(obj.userLpSharesLocked),
+      userLpSharesUnlockedForWithdrawal: new BN(
+        obj.userLpSharesUnlockedForWithdrawal
+      ),
+      poolTotalLpShares: new BN(obj.poolTotalLpShares),
+      poolTotalBaseLiquidity: new BN(obj.poolTotalBaseLiquidity),
+      poolTotalQuoteLiquidity: new BN(obj.poolTotalQuoteLiquidity),
+    })
+  }
+
+  toEncodable() {
+    return CompoundLpFeesEvent.toEncodable(this)
+  }
+}
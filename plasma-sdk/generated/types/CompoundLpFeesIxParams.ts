@@ -0,0 +1,53 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface CompoundLpFeesIxParamsFields {
+  minLpSharesOut: BN
+}
+
+export interface CompoundLpFeesIxParamsJSON {
+  minLpSharesOut: string
+}
+
+export class CompoundLpFeesIxParams {
+  readonly minLpSharesOut: BN
+
+  constructor(fields: CompoundLpFeesIxParamsFields) {
+    this.minLpSharesOut = fields.minLpSharesOut
+  }
+
+  static layout(property?: string) {
+    return borsh.struct([borsh.u64("minLpSharesOut")], property)
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new CompoundLpFeesIxParams({
+      minLpSharesOut: obj.minLpSharesOut,
+    })
+  }
+
+  static toEncodable(fields: CompoundLpFeesIxParamsFields) {
+    return {
+      minLpSharesOut: fields.minLpSharesOut,
+    }
+  }
+
+  toJSON(): CompoundLpFeesIxParamsJSON {
+    return {
+      minLpSharesOut: this.minLpSharesOut.toString(),
+    }
+  }
+
+  static fromJSON(obj: CompoundLpFeesIxParamsJSON): CompoundLpFeesIxParams {
+    return new CompoundLpFeesIxParams({
+      minLpSharesOut: new BN(obj.minLpSharesOut),
+    })
+  }
+
+  toEncodable() {
+    return CompoundLpFeesIxParams.toEncodable(this)
+  }
+}
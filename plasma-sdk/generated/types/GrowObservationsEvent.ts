@@ -0,0 +1,64 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface GrowObservationsEventFields {
+  previousCapacity: BN
+  newCapacity: BN
+}
+
+export interface GrowObservationsEventJSON {
+  previousCapacity: string
+  newCapacity: string
+}
+
+export class GrowObservationsEvent {
+  readonly previousCapacity: BN
+  readonly newCapacity: BN
+
+  constructor(fields: GrowObservationsEventFields) {
+    this.previousCapacity = fields.previousCapacity
+    this.newCapacity = fields.newCapacity
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [borsh.u64("previousCapacity"), borsh.u64("newCapacity")],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new GrowObservationsEvent({
+      previousCapacity: obj.previousCapacity,
+      newCapacity: obj.newCapacity,
+    })
+  }
+
+  static toEncodable(fields: GrowObservationsEventFields) {
+    return {
+      previousCapacity: fields.previousCapacity,
+      newCapacity: fields.newCapacity,
+    }
+  }
+
+  toJSON(): GrowObservationsEventJSON {
+    return {
+      previousCapacity: this.previousCapacity.toString(),
+      newCapacity: this.newCapacity.toString(),
+    }
+  }
+
+  static fromJSON(obj: GrowObservationsEventJSON): GrowObservationsEvent {
+    return new GrowObservationsEvent({
+      previousCapacity: new BN(obj.previousCapacity),
+      newCapacity: new BN(obj.newCapacity),
+    })
+  }
+
+  toEncodable() {
+    return GrowObservationsEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,53 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface GrowObservationsIxParamsFields {
+  newCapacity: BN
+}
+
+export interface GrowObservationsIxParamsJSON {
+  newCapacity: string
+}
+
+export class GrowObservationsIxParams {
+  readonly newCapacity: BN
+
+  constructor(fields: GrowObservationsIxParamsFields) {
+    this.newCapacity = fields.newCapacity
+  }
+
+  static layout(property?: string) {
+    return borsh.struct([borsh.u64("newCapacity")], property)
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new GrowObservationsIxParams({
+      newCapacity: obj.newCapacity,
+    })
+  }
+
+  static toEncodable(fields: GrowObservationsIxParamsFields) {
+    return {
+      newCapacity: fields.newCapacity,
+    }
+  }
+
+  toJSON(): GrowObservationsIxParamsJSON {
+    return {
+      newCapacity: this.newCapacity.toString(),
+    }
+  }
+
+  static fromJSON(obj: GrowObservationsIxParamsJSON): GrowObservationsIxParams {
+    return new GrowObservationsIxParams({
+      newCapacity: new BN(obj.newCapacity),
+    })
+  }
+
+  toEncodable() {
+    return GrowObservationsIxParams.toEncodable(this)
+  }
+}
@@ -0,0 +1,55 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface InitializeFeeTierConfigEventFields {
+  admin: PublicKey
+}
+
+export interface InitializeFeeTierConfigEventJSON {
+  admin: string
+}
+
+export class InitializeFeeTierConfigEvent {
+  readonly admin: PublicKey
+
+  constructor(fields: InitializeFeeTierConfigEventFields) {
+    this.admin = fields.admin
+  }
+
+  static layout(property?: string) {
+    return borsh.struct([borsh.publicKey("admin")], property)
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new InitializeFeeTierConfigEvent({
+      admin: obj.admin,
+    })
+  }
+
+  static toEncodable(fields: InitializeFeeTierConfigEventFields) {
+    return {
+      admin: fields.admin,
+    }
+  }
+
+  toJSON(): InitializeFeeTierConfigEventJSON {
+    return {
+      admin: this.admin.toString(),
+    }
+  }
+
+  static fromJSON(
+    obj: InitializeFeeTierConfigEventJSON
+  ): InitializeFeeTierConfigEvent {
+    return new InitializeFeeTierConfigEvent({
+      admin: new PublicKey(obj.admin),
+    })
+  }
+
+  toEncodable() {
+    return InitializeFeeTierConfigEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,64 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface InitializeLpMintEventFields {
+  lpMint: PublicKey
+  decimals: number
+}
+
+export interface InitializeLpMintEventJSON {
+  lpMint: string
+  decimals: number
+}
+
+export class InitializeLpMintEvent {
+  readonly lpMint: PublicKey
+  readonly decimals: number
+
+  constructor(fields: InitializeLpMintEventFields) {
+    this.lpMint = fields.lpMint
+    this.decimals = fields.decimals
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [borsh.publicKey("lpMint"), borsh.u8("decimals")],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new InitializeLpMintEvent({
+      lpMint: obj.lpMint,
+      decimals: obj.decimals,
+    })
+  }
+
+  static toEncodable(fields: InitializeLpMintEventFields) {
+    return {
+      lpMint: fields.lpMint,
+      decimals: fields.decimals,
+    }
+  }
+
+  toJSON(): InitializeLpMintEventJSON {
+    return {
+      lpMint: this.lpMint.toString(),
+      decimals: this.decimals,
+    }
+  }
+
+  static fromJSON(obj: InitializeLpMintEventJSON): InitializeLpMintEvent {
+    return new InitializeLpMintEvent({
+      lpMint: new PublicKey(obj.lpMint),
+      decimals: obj.decimals,
+    })
+  }
+
+  toEncodable() {
+    return InitializeLpMintEvent.toEncodable(this)
+  }
+}
@@ -7,18 +7,21 @@ export interface InitializePoolEventFields {
   lpFeeInBps: BN
   protocolFeeInPct: BN
   feeRecipientParams: Array<types.ProtocolFeeRecipientParamsFields>
+  poolAuthority: PublicKey
 }
 
 export interface InitializePoolEventJSON {
   lpFeeInBps: string
   protocolFeeInPct: string
   feeRecipientParams: Array<types.ProtocolFeeRecipientParamsJSON>
+  poolAuthority: string
 }
 
 export class InitializePoolEvent {
   readonly lpFeeInBps: BN
   readonly protocolFeeInPct: BN
   readonly feeRecipientParams: Array<types.ProtocolFeeRecipientParams>
+  readonly poolAuthority: PublicKey
 
   constructor(fields: InitializePoolEventFields) {
     this.lpFeeInBps = fields.lpFeeInBps
@@ -26,6 +29,7 @@ export class InitializePoolEvent {
     this.feeRecipientParams = fields.feeRecipientParams.map(
       (item) => new types.ProtocolFeeRecipientParams({ ...item })
     )
+    this.poolAuthority = fields.poolAuthority
   }
 
   static layout(property?: string) {
@@ -38,6 +42,7 @@ export class InitializePoolEvent {
           3,
           "feeRecipientParams"
         ),
+        borsh.publicKey("poolAuthority"),
       ],
       property
     )
@@ -53,6 +58,7 @@ export class InitializePoolEvent {
           item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
         ) => types.ProtocolFeeRecipientParams.fromDecoded(item)
       ),
+      poolAuthority: obj.poolAuthority,
     })
   }
 
@@ -63,6 +69,7 @@ export class InitializePoolEvent {
       feeRecipientParams: fields.feeRecipientParams.map((item) =>
         types.ProtocolFeeRecipientParams.toEncodable(item)
       ),
+      poolAuthority: fields.poolAuthority,
     }
   }
 
@@ -71,6 +78,7 @@ export class InitializePoolEvent {
       lpFeeInBps: this.lpFeeInBps.toString(),
       protocolFeeInPct: this.protocolFeeInPct.toString(),
       feeRecipientParams: this.feeRecipientParams.map((item) => item.toJSON()),
+      poolAuthority: this.poolAuthority.toString(),
     }
   }
 
@@ -81,6 +89,7 @@ export class InitializePoolEvent {
       feeRecipientParams: obj.feeRecipientParams.map((item) =>
         types.ProtocolFeeRecipientParams.fromJSON(item)
       ),
+      poolAuthority: new PublicKey(obj.poolAuthority),
     })
   }
 
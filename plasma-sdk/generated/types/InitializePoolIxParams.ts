@@ -4,45 +4,40 @@ import * as types from "../types" // eslint-disable-line @typescript-eslint/no-u
 import * as borsh from "@coral-xyz/borsh"
 
 export interface InitializePoolIxParamsFields {
-  lpFeeInBps: BN
-  protocolLpFeeAllocationInPct: BN
+  feeTier: BN
   feeRecipientsParams: Array<types.ProtocolFeeRecipientParamsFields>
-  numSlotsToVestLpShares: BN | null
+  poolAuthority: PublicKey | null
 }
 
 export interface InitializePoolIxParamsJSON {
-  lpFeeInBps: string
-  protocolLpFeeAllocationInPct: string
+  feeTier: string
   feeRecipientsParams: Array<types.ProtocolFeeRecipientParamsJSON>
-  numSlotsToVestLpShares: string | null
+  poolAuthority: string | null
 }
 
 export class InitializePoolIxParams {
-  readonly lpFeeInBps: BN
-  readonly protocolLpFeeAllocationInPct: BN
+  readonly feeTier: BN
   readonly feeRecipientsParams: Array<types.ProtocolFeeRecipientParams>
-  readonly numSlotsToVestLpShares: BN | null
+  readonly poolAuthority: PublicKey | null
 
   constructor(fields: InitializePoolIxParamsFields) {
-    this.lpFeeInBps = fields.lpFeeInBps
-    this.protocolLpFeeAllocationInPct = fields.protocolLpFeeAllocationInPct
+    this.feeTier = fields.feeTier
     this.feeRecipientsParams = fields.feeRecipientsParams.map(
       (item) => new types.ProtocolFeeRecipientParams({ ...item })
     )
-    this.numSlotsToVestLpShares = fields.numSlotsToVestLpShares
+    this.poolAuthority = fields.poolAuthority
   }
 
   static layout(property?: string) {
     return borsh.struct(
       [
-        borsh.u64("lpFeeInBps"),
-        borsh.u64("protocolLpFeeAllocationInPct"),
+        borsh.u64("feeTier"),
         borsh.array(
           types.ProtocolFeeRecipientParams.layout(),
           3,
           "feeRecipientsParams"
         ),
-        borsh.option(borsh.u64(), "numSlotsToVestLpShares"),
+        borsh.option(borsh.publicKey(), "poolAuthority"),
       ],
       property
     )
@@ -51,53 +46,45 @@ export class InitializePoolIxParams {
   // eslint-disable-next-line @typescript-eslint/no-explicit-any
   static fromDecoded(obj: any) {
     return new InitializePoolIxParams({
-      lpFeeInBps: obj.lpFeeInBps,
-      protocolLpFeeAllocationInPct: obj.protocolLpFeeAllocationInPct,
+      feeTier: obj.feeTier,
       feeRecipientsParams: obj.feeRecipientsParams.map(
         (
           item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
         ) => types.ProtocolFeeRecipientParams.fromDecoded(item)
       ),
-      numSlotsToVestLpShares: obj.numSlotsToVestLpShares,
+      poolAuthority: obj.poolAuthority,
     })
   }
 
   static toEncodable(fields: InitializePoolIxParamsFields) {
     return {
-      lpFeeInBps: fields.lpFeeInBps,
-      protocolLpFeeAllocationInPct: fields.protocolLpFeeAllocationInPct,
+      feeTier: fields.feeTier,
       feeRecipientsParams: fields.feeRecipientsParams.map((item) =>
         types.ProtocolFeeRecipientParams.toEncodable(item)
       ),
-      numSlotsToVestLpShares: fields.numSlotsToVestLpShares,
+      poolAuthority: fields.poolAuthority,
     }
   }
 
   toJSON(): InitializePoolIxParamsJSON {
     return {
-      lpFeeInBps: this.lpFeeInBps.toString(),
-      protocolLpFeeAllocationInPct:
-        this.protocolLpFeeAllocationInPct.toString(),
+      feeTier: this.feeTier.toString(),
       feeRecipientsParams: this.feeRecipientsParams.map((item) =>
         item.toJSON()
       ),
-      numSlotsToVestLpShares:
-        (this.numSlotsToVestLpShares &&
-          this.numSlotsToVestLpShares.toString()) ||
-        null,
+      poolAuthority:
+        (this.poolAuthority && this.poolAuthority.toString()) || null,
     }
   }
 
   static fromJSON(obj: InitializePoolIxParamsJSON): InitializePoolIxParams {
     return new InitializePoolIxParams({
-      lpFeeInBps: new BN(obj.lpFeeInBps),
-      protocolLpFeeAllocationInPct: new BN(obj.protocolLpFeeAllocationInPct),
+      feeTier: new BN(obj.feeTier),
       feeRecipientsParams: obj.feeRecipientsParams.map((item) =>
         types.ProtocolFeeRecipientParams.fromJSON(item)
       ),
-      numSlotsToVestLpShares:
-        (obj.numSlotsToVestLpShares && new BN(obj.numSlotsToVestLpShares)) ||
-        null,
+      poolAuthority:
+        (obj.poolAuthority && new PublicKey(obj.poolAuthority)) || null,
     })
   }
 
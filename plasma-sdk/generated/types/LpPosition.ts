@@ -9,7 +9,7 @@ export interface LpPositionFields {
   withdrawableLpShares: BN
   uncollectedFees: BN
   collectedFees: BN
-  pendingSharesToVest: types.PendingSharesToVestFields
+  pendingSharesToVest: Array<types.PendingSharesToVestFields>
 }
 
 export interface LpPositionJSON {
@@ -18,7 +18,7 @@ export interface LpPositionJSON {
   withdrawableLpShares: string
   uncollectedFees: string
   collectedFees: string
-  pendingSharesToVest: types.PendingSharesToVestJSON
+  pendingSharesToVest: Array<types.PendingSharesToVestJSON>
 }
 
 export class LpPosition {
@@ -27,7 +27,7 @@ export class LpPosition {
   readonly withdrawableLpShares: BN
   readonly uncollectedFees: BN
   readonly collectedFees: BN
-  readonly pendingSharesToVest: types.PendingSharesToVest
+  readonly pendingSharesToVest: Array<types.PendingSharesToVest>
 
   constructor(fields: LpPositionFields) {
     this.rewardFactorSnapshot = fields.rewardFactorSnapshot
@@ -35,9 +35,9 @@ export class LpPosition {
     this.withdrawableLpShares = fields.withdrawableLpShares
     this.uncollectedFees = fields.uncollectedFees
     this.collectedFees = fields.collectedFees
-    this.pendingSharesToVest = new types.PendingSharesToVest({
-      ...fields.pendingSharesToVest,
-    })
+    this.pendingSharesToVest = fields.pendingSharesToVest.map(
+      (item) => new types.PendingSharesToVest({ ...item })
+    )
   }
 
   static layout(property?: string) {
@@ -48,7 +48,11 @@ export class LpPosition {
         borsh.u64("withdrawableLpShares"),
         borsh.u64("uncollectedFees"),
         borsh.u64("collectedFees"),
-        types.PendingSharesToVest.layout("pendingSharesToVest"),
+        borsh.array(
+          types.PendingSharesToVest.layout(),
+          4,
+          "pendingSharesToVest"
+        ),
       ],
       property
     )
@@ -62,8 +66,10 @@ export class LpPosition {
       withdrawableLpShares: obj.withdrawableLpShares,
       uncollectedFees: obj.uncollectedFees,
       collectedFees: obj.collectedFees,
-      pendingSharesToVest: types.PendingSharesToVest.fromDecoded(
-        obj.pendingSharesToVest
+      pendingSharesToVest: obj.pendingSharesToVest.map(
+        (
+          item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
+        ) => types.PendingSharesToVest.fromDecoded(item)
       ),
     })
   }
@@ -75,8 +81,8 @@ export class LpPosition {
       withdrawableLpShares: fields.withdrawableLpShares,
       uncollectedFees: fields.uncollectedFees,
       collectedFees: fields.collectedFees,
-      pendingSharesToVest: types.PendingSharesToVest.toEncodable(
-        fields.pendingSharesToVest
+      pendingSharesToVest: fields.pendingSharesToVest.map((item) =>
+        types.PendingSharesToVest.toEncodable(item)
       ),
     }
   }
@@ -88,7 +94,9 @@ export class LpPosition {
       withdrawableLpShares: this.withdrawableLpShares.toString(),
       uncollectedFees: this.uncollectedFees.toString(),
       collectedFees: this.collectedFees.toString(),
-      pendingSharesToVest: this.pendingSharesToVest.toJSON(),
+      pendingSharesToVest: this.pendingSharesToVest.map((item) =>
+        item.toJSON()
+      ),
     }
   }
 
@@ -99,8 +107,8 @@ export class LpPosition {
       withdrawableLpShares: new BN(obj.withdrawableLpShares),
       uncollectedFees: new BN(obj.uncollectedFees),
       collectedFees: new BN(obj.collectedFees),
-      pendingSharesToVest: types.PendingSharesToVest.fromJSON(
-        obj.pendingSharesToVest
+      pendingSharesToVest: obj.pendingSharesToVest.map((item) =>
+        types.PendingSharesToVest.fromJSON(item)
       ),
     })
   }
@@ -0,0 +1,64 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface MigrateLpPositionEventFields {
+  lpPosition: PublicKey
+  owner: PublicKey
+}
+
+export interface MigrateLpPositionEventJSON {
+  lpPosition: string
+  owner: string
+}
+
+export class MigrateLpPositionEvent {
+  readonly lpPosition: PublicKey
+  readonly owner: PublicKey
+
+  constructor(fields: MigrateLpPositionEventFields) {
+    this.lpPosition = fields.lpPosition
+    this.owner = fields.owner
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [borsh.publicKey("lpPosition"), borsh.publicKey("owner")],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new MigrateLpPositionEvent({
+      lpPosition: obj.lpPosition,
+      owner: obj.owner,
+    })
+  }
+
+  static toEncodable(fields: MigrateLpPositionEventFields) {
+    return {
+      lpPosition: fields.lpPosition,
+      owner: fields.owner,
+    }
+  }
+
+  toJSON(): MigrateLpPositionEventJSON {
+    return {
+      lpPosition: this.lpPosition.toString(),
+      owner: this.owner.toString(),
+    }
+  }
+
+  static fromJSON(obj: MigrateLpPositionEventJSON): MigrateLpPositionEvent {
+    return new MigrateLpPositionEvent({
+      lpPosition: new PublicKey(obj.lpPosition),
+      owner: new PublicKey(obj.owner),
+    })
+  }
+
+  toEncodable() {
+    return MigrateLpPositionEvent.toEncodable(this)
+  }
+}
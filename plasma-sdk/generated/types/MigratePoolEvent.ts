@@ -0,0 +1,53 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface MigratePoolEventFields {
+  lamportsPaid: BN
+}
+
+export interface MigratePoolEventJSON {
+  lamportsPaid: string
+}
+
+export class MigratePoolEvent {
+  readonly lamportsPaid: BN
+
+  constructor(fields: MigratePoolEventFields) {
+    this.lamportsPaid = fields.lamportsPaid
+  }
+
+  static layout(property?: string) {
+    return borsh.struct([borsh.u64("lamportsPaid")], property)
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new MigratePoolEvent({
+      lamportsPaid: obj.lamportsPaid,
+    })
+  }
+
+  static toEncodable(fields: MigratePoolEventFields) {
+    return {
+      lamportsPaid: fields.lamportsPaid,
+    }
+  }
+
+  toJSON(): MigratePoolEventJSON {
+    return {
+      lamportsPaid: this.lamportsPaid.toString(),
+    }
+  }
+
+  static fromJSON(obj: MigratePoolEventJSON): MigratePoolEvent {
+    return new MigratePoolEvent({
+      lamportsPaid: new BN(obj.lamportsPaid),
+    })
+  }
+
+  toEncodable() {
+    return MigratePoolEvent.toEncodable(this)
+  }
+}
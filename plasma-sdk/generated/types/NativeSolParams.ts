@@ -0,0 +1,61 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface NativeSolParamsFields {
+  wrap: boolean
+  unwrap: boolean
+}
+
+export interface NativeSolParamsJSON {
+  wrap: boolean
+  unwrap: boolean
+}
+
+export class NativeSolParams {
+  readonly wrap: boolean
+  readonly unwrap: boolean
+
+  constructor(fields: NativeSolParamsFields) {
+    this.wrap = fields.wrap
+    this.unwrap = fields.unwrap
+  }
+
+  static layout(property?: string) {
+    return borsh.struct([borsh.bool("wrap"), borsh.bool("unwrap")], property)
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new NativeSolParams({
+      wrap: obj.wrap,
+      unwrap: obj.unwrap,
+    })
+  }
+
+  static toEncodable(fields: NativeSolParamsFields) {
+    return {
+      wrap: fields.wrap,
+      unwrap: fields.unwrap,
+    }
+  }
+
+  toJSON(): NativeSolParamsJSON {
+    return {
+      wrap: this.wrap,
+      unwrap: this.unwrap,
+    }
+  }
+
+  static fromJSON(obj: NativeSolParamsJSON): NativeSolParams {
+    return new NativeSolParams({
+      wrap: obj.wrap,
+      unwrap: obj.unwrap,
+    })
+  }
+
+  toEncodable() {
+    return NativeSolParams.toEncodable(this)
+  }
+}
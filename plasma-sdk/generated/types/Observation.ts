@@ -0,0 +1,85 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface ObservationFields {
+  slot: BN
+  padding: BN
+  cumulativePrice: BN
+  cumulativeLiquidity: BN
+}
+
+export interface ObservationJSON {
+  slot: string
+  padding: string
+  cumulativePrice: string
+  cumulativeLiquidity: string
+}
+
+export class Observation {
+  readonly slot: BN
+  readonly padding: BN
+  readonly cumulativePrice: BN
+  readonly cumulativeLiquidity: BN
+
+  constructor(fields: ObservationFields) {
+    this.slot = fields.slot
+    this.padding = fields.padding
+    this.cumulativePrice = fields.cumulativePrice
+    this.cumulativeLiquidity = fields.cumulativeLiquidity
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u64("slot"),
+        borsh.u64("padding"),
+        borsh.u128("cumulativePrice"),
+        borsh.u128("cumulativeLiquidity"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new Observation({
+      slot: obj.slot,
+      padding: obj.padding,
+      cumulativePrice: obj.cumulativePrice,
+      cumulativeLiquidity: obj.cumulativeLiquidity,
+    })
+  }
+
+  static toEncodable(fields: ObservationFields) {
+    return {
+      slot: fields.slot,
+      padding: fields.padding,
+      cumulativePrice: fields.cumulativePrice,
+      cumulativeLiquidity: fields.cumulativeLiquidity,
+    }
+  }
+
+  toJSON(): ObservationJSON {
+    return {
+      slot: this.slot.toString(),
+      padding: this.padding.toString(),
+      cumulativePrice: this.cumulativePrice.toString(),
+      cumulativeLiquidity: this.cumulativeLiquidity.toString(),
+    }
+  }
+
+  static fromJSON(obj: ObservationJSON): Observation {
+    return new Observation({
+      slot: new BN(obj.slot),
+      padding: new BN(obj.padding),
+      cumulativePrice: new BN(obj.cumulativePrice),
+      cumulativeLiquidity: new BN(obj.cumulativeLiquidity),
+    })
+  }
+
+  toEncodable() {
+    return Observation.toEncodable(this)
+  }
+}
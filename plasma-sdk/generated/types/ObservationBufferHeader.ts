@@ -0,0 +1,61 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface ObservationBufferHeaderFields {
+  index: BN
+  length: BN
+}
+
+export interface ObservationBufferHeaderJSON {
+  index: string
+  length: string
+}
+
+export class ObservationBufferHeader {
+  readonly index: BN
+  readonly length: BN
+
+  constructor(fields: ObservationBufferHeaderFields) {
+    this.index = fields.index
+    this.length = fields.length
+  }
+
+  static layout(property?: string) {
+    return borsh.struct([borsh.u64("index"), borsh.u64("length")], property)
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new ObservationBufferHeader({
+      index: obj.index,
+      length: obj.length,
+    })
+  }
+
+  static toEncodable(fields: ObservationBufferHeaderFields) {
+    return {
+      index: fields.index,
+      length: fields.length,
+    }
+  }
+
+  toJSON(): ObservationBufferHeaderJSON {
+    return {
+      index: this.index.toString(),
+      length: this.length.toString(),
+    }
+  }
+
+  static fromJSON(obj: ObservationBufferHeaderJSON): ObservationBufferHeader {
+    return new ObservationBufferHeader({
+      index: new BN(obj.index),
+      length: new BN(obj.length),
+    })
+  }
+
+  toEncodable() {
+    return ObservationBufferHeader.toEncodable(this)
+  }
+}
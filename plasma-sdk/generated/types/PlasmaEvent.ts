@@ -411,6 +411,951 @@ export class WithdrawProtocolFees {
   }
 }
 
+export interface LogJSON {
+  kind: "Log"
+}
+
+export class Log {
+  static readonly discriminator = 8
+  static readonly kind = "Log"
+  readonly discriminator = 8
+  readonly kind = "Log"
+
+  toJSON(): LogJSON {
+    return {
+      kind: "Log",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Log: {},
+    }
+  }
+}
+
+export type SwapRouteFields = {
+  header: types.PlasmaEventHeaderFields
+  event: types.SwapEventFields
+}
+export type SwapRouteValue = {
+  header: types.PlasmaEventHeader
+  event: types.SwapEvent
+}
+
+export interface SwapRouteJSON {
+  kind: "SwapRoute"
+  value: {
+    header: types.PlasmaEventHeaderJSON
+    event: types.SwapEventJSON
+  }
+}
+
+export class SwapRoute {
+  static readonly discriminator = 9
+  static readonly kind = "SwapRoute"
+  readonly discriminator = 9
+  readonly kind = "SwapRoute"
+  readonly value: SwapRouteValue
+
+  constructor(value: SwapRouteFields) {
+    this.value = {
+      header: new types.PlasmaEventHeader({ ...value.header }),
+      event: new types.SwapEvent({ ...value.event }),
+    }
+  }
+
+  toJSON(): SwapRouteJSON {
+    return {
+      kind: "SwapRoute",
+      value: {
+        header: this.value.header.toJSON(),
+        event: this.value.event.toJSON(),
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      SwapRoute: {
+        header: types.PlasmaEventHeader.toEncodable(this.value.header),
+        event: types.SwapEvent.toEncodable(this.value.event),
+      },
+    }
+  }
+}
+
+export type GrowObservationsFields = {
+  header: types.PlasmaEventHeaderFields
+  event: types.GrowObservationsEventFields
+}
+export type GrowObservationsValue = {
+  header: types.PlasmaEventHeader
+  event: types.GrowObservationsEvent
+}
+
+export interface GrowObservationsJSON {
+  kind: "GrowObservations"
+  value: {
+    header: types.PlasmaEventHeaderJSON
+    event: types.GrowObservationsEventJSON
+  }
+}
+
+export class GrowObservations {
+  static readonly discriminator = 10
+  static readonly kind = "GrowObservations"
+  readonly discriminator = 10
+  readonly kind = "GrowObservations"
+  readonly value: GrowObservationsValue
+
+  constructor(value: GrowObservationsFields) {
+    this.value = {
+      header: new types.PlasmaEventHeader({ ...value.header }),
+      event: new types.GrowObservationsEvent({ ...value.event }),
+    }
+  }
+
+  toJSON(): GrowObservationsJSON {
+    return {
+      kind: "GrowObservations",
+      value: {
+        header: this.value.header.toJSON(),
+        event: this.value.event.toJSON(),
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      GrowObservations: {
+        header: types.PlasmaEventHeader.toEncodable(this.value.header),
+        event: types.GrowObservationsEvent.toEncodable(this.value.event),
+      },
+    }
+  }
+}
+
+export type UpdatePoolConfigFields = {
+  header: types.PlasmaEventHeaderFields
+  event: types.UpdatePoolConfigEventFields
+}
+export type UpdatePoolConfigValue = {
+  header: types.PlasmaEventHeader
+  event: types.UpdatePoolConfigEvent
+}
+
+export interface UpdatePoolConfigJSON {
+  kind: "UpdatePoolConfig"
+  value: {
+    header: types.PlasmaEventHeaderJSON
+    event: types.UpdatePoolConfigEventJSON
+  }
+}
+
+export class UpdatePoolConfig {
+  static readonly discriminator = 11
+  static readonly kind = "UpdatePoolConfig"
+  readonly discriminator = 11
+  readonly kind = "UpdatePoolConfig"
+  readonly value: UpdatePoolConfigValue
+
+  constructor(value: UpdatePoolConfigFields) {
+    this.value = {
+      header: new types.PlasmaEventHeader({ ...value.header }),
+      event: new types.UpdatePoolConfigEvent({ ...value.event }),
+    }
+  }
+
+  toJSON(): UpdatePoolConfigJSON {
+    return {
+      kind: "UpdatePoolConfig",
+      value: {
+        header: this.value.header.toJSON(),
+        event: this.value.event.toJSON(),
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      UpdatePoolConfig: {
+        header: types.PlasmaEventHeader.toEncodable(this.value.header),
+        event: types.UpdatePoolConfigEvent.toEncodable(this.value.event),
+      },
+    }
+  }
+}
+
+export type TransferProtocolFeeRecipientFields = {
+  header: types.PlasmaEventHeaderFields
+  event: types.TransferProtocolFeeRecipientEventFields
+}
+export type TransferProtocolFeeRecipientValue = {
+  header: types.PlasmaEventHeader
+  event: types.TransferProtocolFeeRecipientEvent
+}
+
+export interface TransferProtocolFeeRecipientJSON {
+  kind: "TransferProtocolFeeRecipient"
+  value: {
+    header: types.PlasmaEventHeaderJSON
+    event: types.TransferProtocolFeeRecipientEventJSON
+  }
+}
+
+export class TransferProtocolFeeRecipient {
+  static readonly discriminator = 12
+  static readonly kind = "TransferProtocolFeeRecipient"
+  readonly discriminator = 12
+  readonly kind = "TransferProtocolFeeRecipient"
+  readonly value: TransferProtocolFeeRecipientValue
+
+  constructor(value: TransferProtocolFeeRecipientFields) {
+    this.value = {
+      header: new types.PlasmaEventHeader({ ...value.header }),
+      event: new types.TransferProtocolFeeRecipientEvent({ ...value.event }),
+    }
+  }
+
+  toJSON(): TransferProtocolFeeRecipientJSON {
+    return {
+      kind: "TransferProtocolFeeRecipient",
+      value: {
+        header: this.value.header.toJSON(),
+        event: this.value.event.toJSON(),
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      TransferProtocolFeeRecipient: {
+        header: types.PlasmaEventHeader.toEncodable(this.value.header),
+        event: types.TransferProtocolFeeRecipientEvent.toEncodable(
+          this.value.event
+        ),
+      },
+    }
+  }
+}
+
+export type SetPoolStatusFields = {
+  header: types.PlasmaEventHeaderFields
+  event: types.SetPoolStatusEventFields
+}
+export type SetPoolStatusValue = {
+  header: types.PlasmaEventHeader
+  event: types.SetPoolStatusEvent
+}
+
+export interface SetPoolStatusJSON {
+  kind: "SetPoolStatus"
+  value: {
+    header: types.PlasmaEventHeaderJSON
+    event: types.SetPoolStatusEventJSON
+  }
+}
+
+export class SetPoolStatus {
+  static readonly discriminator = 13
+  static readonly kind = "SetPoolStatus"
+  readonly discriminator = 13
+  readonly kind = "SetPoolStatus"
+  readonly value: SetPoolStatusValue
+
+  constructor(value: SetPoolStatusFields) {
+    this.value = {
+      header: new types.PlasmaEventHeader({ ...value.header }),
+      event: new types.SetPoolStatusEvent({ ...value.event }),
+    }
+  }
+
+  toJSON(): SetPoolStatusJSON {
+    return {
+      kind: "SetPoolStatus",
+      value: {
+        header: this.value.header.toJSON(),
+        event: this.value.event.toJSON(),
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      SetPoolStatus: {
+        header: types.PlasmaEventHeader.toEncodable(this.value.header),
+        event: types.SetPoolStatusEvent.toEncodable(this.value.event),
+      },
+    }
+  }
+}
+
+export type InitializeFeeTierConfigFields = {
+  header: types.PlasmaEventHeaderFields
+  event: types.InitializeFeeTierConfigEventFields
+}
+export type InitializeFeeTierConfigValue = {
+  header: types.PlasmaEventHeader
+  event: types.InitializeFeeTierConfigEvent
+}
+
+export interface InitializeFeeTierConfigJSON {
+  kind: "InitializeFeeTierConfig"
+  value: {
+    header: types.PlasmaEventHeaderJSON
+    event: types.InitializeFeeTierConfigEventJSON
+  }
+}
+
+export class InitializeFeeTierConfig {
+  static readonly discriminator = 14
+  static readonly kind = "InitializeFeeTierConfig"
+  readonly discriminator = 14
+  readonly kind = "InitializeFeeTierConfig"
+  readonly value: InitializeFeeTierConfigValue
+
+  constructor(value: InitializeFeeTierConfigFields) {
+    this.value = {
+      header: new types.PlasmaEventHeader({ ...value.header }),
+      event: new types.InitializeFeeTierConfigEvent({ ...value.event }),
+    }
+  }
+
+  toJSON(): InitializeFeeTierConfigJSON {
+    return {
+      kind: "InitializeFeeTierConfig",
+      value: {
+        header: this.value.header.toJSON(),
+        event: this.value.event.toJSON(),
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      InitializeFeeTierConfig: {
+        header: types.PlasmaEventHeader.toEncodable(this.value.header),
+        event: types.InitializeFeeTierConfigEvent.toEncodable(this.value.event),
+      },
+    }
+  }
+}
+
+export type SetFeeTierFields = {
+  header: types.PlasmaEventHeaderFields
+  event: types.SetFeeTierEventFields
+}
+export type SetFeeTierValue = {
+  header: types.PlasmaEventHeader
+  event: types.SetFeeTierEvent
+}
+
+export interface SetFeeTierJSON {
+  kind: "SetFeeTier"
+  value: {
+    header: types.PlasmaEventHeaderJSON
+    event: types.SetFeeTierEventJSON
+  }
+}
+
+export class SetFeeTier {
+  static readonly discriminator = 15
+  static readonly kind = "SetFeeTier"
+  readonly discriminator = 15
+  readonly kind = "SetFeeTier"
+  readonly value: SetFeeTierValue
+
+  constructor(value: SetFeeTierFields) {
+    this.value = {
+      header: new types.PlasmaEventHeader({ ...value.header }),
+      event: new types.SetFeeTierEvent({ ...value.event }),
+    }
+  }
+
+  toJSON(): SetFeeTierJSON {
+    return {
+      kind: "SetFeeTier",
+      value: {
+        header: this.value.header.toJSON(),
+        event: this.value.event.toJSON(),
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      SetFeeTier: {
+        header: types.PlasmaEventHeader.toEncodable(this.value.header),
+        event: types.SetFeeTierEvent.toEncodable(this.value.event),
+      },
+    }
+  }
+}
+
+export type CloseLpPositionFields = {
+  header: types.PlasmaEventHeaderFields
+  event: types.CloseLpPositionEventFields
+}
+export type CloseLpPositionValue = {
+  header: types.PlasmaEventHeader
+  event: types.CloseLpPositionEvent
+}
+
+export interface CloseLpPositionJSON {
+  kind: "CloseLpPosition"
+  value: {
+    header: types.PlasmaEventHeaderJSON
+    event: types.CloseLpPositionEventJSON
+  }
+}
+
+export class CloseLpPosition {
+  static readonly discriminator = 16
+  static readonly kind = "CloseLpPosition"
+  readonly discriminator = 16
+  readonly kind = "CloseLpPosition"
+  readonly value: CloseLpPositionValue
+
+  constructor(value: CloseLpPositionFields) {
+    this.value = {
+      header: new types.PlasmaEventHeader({ ...value.header }),
+      event: new types.CloseLpPositionEvent({ ...value.event }),
+    }
+  }
+
+  toJSON(): CloseLpPositionJSON {
+    return {
+      kind: "CloseLpPosition",
+      value: {
+        header: this.value.header.toJSON(),
+        event: this.value.event.toJSON(),
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      CloseLpPosition: {
+        header: types.PlasmaEventHeader.toEncodable(this.value.header),
+        event: types.CloseLpPositionEvent.toEncodable(this.value.event),
+      },
+    }
+  }
+}
+
+export type ClosePoolFields = {
+  header: types.PlasmaEventHeaderFields
+  event: types.ClosePoolEventFields
+}
+export type ClosePoolValue = {
+  header: types.PlasmaEventHeader
+  event: types.ClosePoolEvent
+}
+
+export interface ClosePoolJSON {
+  kind: "ClosePool"
+  value: {
+    header: types.PlasmaEventHeaderJSON
+    event: types.ClosePoolEventJSON
+  }
+}
+
+export class ClosePool {
+  static readonly discriminator = 17
+  static readonly kind = "ClosePool"
+  readonly discriminator = 17
+  readonly kind = "ClosePool"
+  readonly value: ClosePoolValue
+
+  constructor(value: ClosePoolFields) {
+    this.value = {
+      header: new types.PlasmaEventHeader({ ...value.header }),
+      event: new types.ClosePoolEvent({ ...value.event }),
+    }
+  }
+
+  toJSON(): ClosePoolJSON {
+    return {
+      kind: "ClosePool",
+      value: {
+        header: this.value.header.toJSON(),
+        event: this.value.event.toJSON(),
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      ClosePool: {
+        header: types.PlasmaEventHeader.toEncodable(this.value.header),
+        event: types.ClosePoolEvent.toEncodable(this.value.event),
+      },
+    }
+  }
+}
+
+export type InitializeLpMintFields = {
+  header: types.PlasmaEventHeaderFields
+  event: types.InitializeLpMintEventFields
+}
+export type InitializeLpMintValue = {
+  header: types.PlasmaEventHeader
+  event: types.InitializeLpMintEvent
+}
+
+export interface InitializeLpMintJSON {
+  kind: "InitializeLpMint"
+  value: {
+    header: types.PlasmaEventHeaderJSON
+    event: types.InitializeLpMintEventJSON
+  }
+}
+
+export class InitializeLpMint {
+  static readonly discriminator = 18
+  static readonly kind = "InitializeLpMint"
+  readonly discriminator = 18
+  readonly kind = "InitializeLpMint"
+  readonly value: InitializeLpMintValue
+
+  constructor(value: InitializeLpMintFields) {
+    this.value = {
+      header: new types.PlasmaEventHeader({ ...value.header }),
+      event: new types.InitializeLpMintEvent({ ...value.event }),
+    }
+  }
+
+  toJSON(): InitializeLpMintJSON {
+    return {
+      kind: "InitializeLpMint",
+      value: {
+        header: this.value.header.toJSON(),
+        event: this.value.event.toJSON(),
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      InitializeLpMint: {
+        header: types.PlasmaEventHeader.toEncodable(this.value.header),
+        event: types.InitializeLpMintEvent.toEncodable(this.value.event),
+      },
+    }
+  }
+}
+
+export type TokenizeLpSharesFields = {
+  header: types.PlasmaEventHeaderFields
+  event: types.TokenizeLpSharesEventFields
+}
+export type TokenizeLpSharesValue = {
+  header: types.PlasmaEventHeader
+  event: types.TokenizeLpSharesEvent
+}
+
+export interface TokenizeLpSharesJSON {
+  kind: "TokenizeLpShares"
+  value: {
+    header: types.PlasmaEventHeaderJSON
+    event: types.TokenizeLpSharesEventJSON
+  }
+}
+
+export class TokenizeLpShares {
+  static readonly discriminator = 19
+  static readonly kind = "TokenizeLpShares"
+  readonly discriminator = 19
+  readonly kind = "TokenizeLpShares"
+  readonly value: TokenizeLpSharesValue
+
+  constructor(value: TokenizeLpSharesFields) {
+    this.value = {
+      header: new types.PlasmaEventHeader({ ...value.header }),
+      event: new types.TokenizeLpSharesEvent({ ...value.event }),
+    }
+  }
+
+  toJSON(): TokenizeLpSharesJSON {
+    return {
+      kind: "TokenizeLpShares",
+      value: {
+        header: this.value.header.toJSON(),
+        event: this.value.event.toJSON(),
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      TokenizeLpShares: {
+        header: types.PlasmaEventHeader.toEncodable(this.value.header),
+        event: types.TokenizeLpSharesEvent.toEncodable(this.value.event),
+      },
+    }
+  }
+}
+
+export type RedeemLpTokensFields = {
+  header: types.PlasmaEventHeaderFields
+  event: types.RedeemLpTokensEventFields
+}
+export type RedeemLpTokensValue = {
+  header: types.PlasmaEventHeader
+  event: types.RedeemLpTokensEvent
+}
+
+export interface RedeemLpTokensJSON {
+  kind: "RedeemLpTokens"
+  value: {
+    header: types.PlasmaEventHeaderJSON
+    event: types.RedeemLpTokensEventJSON
+  }
+}
+
+export class RedeemLpTokens {
+  static readonly discriminator = 20
+  static readonly kind = "RedeemLpTokens"
+  readonly discriminator = 20
+  readonly kind = "RedeemLpTokens"
+  readonly value: RedeemLpTokensValue
+
+  constructor(value: RedeemLpTokensFields) {
+    this.value = {
+      header: new types.PlasmaEventHeader({ ...value.header }),
+      event: new types.RedeemLpTokensEvent({ ...value.event }),
+    }
+  }
+
+  toJSON(): RedeemLpTokensJSON {
+    return {
+      kind: "RedeemLpTokens",
+      value: {
+        header: this.value.header.toJSON(),
+        event: this.value.event.toJSON(),
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      RedeemLpTokens: {
+        header: types.PlasmaEventHeader.toEncodable(this.value.header),
+        event: types.RedeemLpTokensEvent.toEncodable(this.value.event),
+      },
+    }
+  }
+}
+
+export type TransferLpPositionFields = {
+  header: types.PlasmaEventHeaderFields
+  event: types.TransferLpPositionEventFields
+}
+export type TransferLpPositionValue = {
+  header: types.PlasmaEventHeader
+  event: types.TransferLpPositionEvent
+}
+
+export interface TransferLpPositionJSON {
+  kind: "TransferLpPosition"
+  value: {
+    header: types.PlasmaEventHeaderJSON
+    event: types.TransferLpPositionEventJSON
+  }
+}
+
+export class TransferLpPosition {
+  static readonly discriminator = 21
+  static readonly kind = "TransferLpPosition"
+  readonly discriminator = 21
+  readonly kind = "TransferLpPosition"
+  readonly value: TransferLpPositionValue
+
+  constructor(value: TransferLpPositionFields) {
+    this.value = {
+      header: new types.PlasmaEventHeader({ ...value.header }),
+      event: new types.TransferLpPositionEvent({ ...value.event }),
+    }
+  }
+
+  toJSON(): TransferLpPositionJSON {
+    return {
+      kind: "TransferLpPosition",
+      value: {
+        header: this.value.header.toJSON(),
+        event: this.value.event.toJSON(),
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      TransferLpPosition: {
+        header: types.PlasmaEventHeader.toEncodable(this.value.header),
+        event: types.TransferLpPositionEvent.toEncodable(this.value.event),
+      },
+    }
+  }
+}
+
+export type MigrateLpPositionFields = {
+  header: types.PlasmaEventHeaderFields
+  event: types.MigrateLpPositionEventFields
+}
+export type MigrateLpPositionValue = {
+  header: types.PlasmaEventHeader
+  event: types.MigrateLpPositionEvent
+}
+
+export interface MigrateLpPositionJSON {
+  kind: "MigrateLpPosition"
+  value: {
+    header: types.PlasmaEventHeaderJSON
+    event: types.MigrateLpPositionEventJSON
+  }
+}
+
+export class MigrateLpPosition {
+  static readonly discriminator = 22
+  static readonly kind = "MigrateLpPosition"
+  readonly discriminator = 22
+  readonly kind = "MigrateLpPosition"
+  readonly value: MigrateLpPositionValue
+
+  constructor(value: MigrateLpPositionFields) {
+    this.value = {
+      header: new types.PlasmaEventHeader({ ...value.header }),
+      event: new types.MigrateLpPositionEvent({ ...value.event }),
+    }
+  }
+
+  toJSON(): MigrateLpPositionJSON {
+    return {
+      kind: "MigrateLpPosition",
+      value: {
+        header: this.value.header.toJSON(),
+        event: this.value.event.toJSON(),
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      MigrateLpPosition: {
+        header: types.PlasmaEventHeader.toEncodable(this.value.header),
+        event: types.MigrateLpPositionEvent.toEncodable(this.value.event),
+      },
+    }
+  }
+}
+
+export type CompoundLpFeesFields = {
+  header: types.PlasmaEventHeaderFields
+  event: types.CompoundLpFeesEventFields
+}
+export type CompoundLpFeesValue = {
+  header: types.PlasmaEventHeader
+  event: types.CompoundLpFeesEvent
+}
+
+export interface CompoundLpFeesJSON {
+  kind: "CompoundLpFees"
+  value: {
+    header: types.PlasmaEventHeaderJSON
+    event: types.CompoundLpFeesEventJSON
+  }
+}
+
+export class CompoundLpFees {
+  static readonly discriminator = 23
+  static readonly kind = "CompoundLpFees"
+  readonly discriminator = 23
+  readonly kind = "CompoundLpFees"
+  readonly value: CompoundLpFeesValue
+
+  constructor(value: CompoundLpFeesFields) {
+    this.value = {
+      header: new types.PlasmaEventHeader({ ...value.header }),
+      event: new types.CompoundLpFeesEvent({ ...value.event }),
+    }
+  }
+
+  toJSON(): CompoundLpFeesJSON {
+    return {
+      kind: "CompoundLpFees",
+      value: {
+        header: this.value.header.toJSON(),
+        event: this.value.event.toJSON(),
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      CompoundLpFees: {
+        header: types.PlasmaEventHeader.toEncodable(this.value.header),
+        event: types.CompoundLpFeesEvent.toEncodable(this.value.event),
+      },
+    }
+  }
+}
+
+export type AddLiquiditySingleSidedFields = {
+  header: types.PlasmaEventHeaderFields
+  event: types.AddLiquiditySingleSidedEventFields
+}
+export type AddLiquiditySingleSidedValue = {
+  header: types.PlasmaEventHeader
+  event: types.AddLiquiditySingleSidedEvent
+}
+
+export interface AddLiquiditySingleSidedJSON {
+  kind: "AddLiquiditySingleSided"
+  value: {
+    header: types.PlasmaEventHeaderJSON
+    event: types.AddLiquiditySingleSidedEventJSON
+  }
+}
+
+export class AddLiquiditySingleSided {
+  static readonly discriminator = 24
+  static readonly kind = "AddLiquiditySingleSided"
+  readonly discriminator = 24
+  readonly kind = "AddLiquiditySingleSided"
+  readonly value: AddLiquiditySingleSidedValue
+
+  constructor(value: AddLiquiditySingleSidedFields) {
+    this.value = {
+      header: new types.PlasmaEventHeader({ ...value.header }),
+      event: new types.AddLiquiditySingleSidedEvent({ ...value.event }),
+    }
+  }
+
+  toJSON(): AddLiquiditySingleSidedJSON {
+    return {
+      kind: "AddLiquiditySingleSided",
+      value: {
+        header: this.value.header.toJSON(),
+        event: this.value.event.toJSON(),
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      AddLiquiditySingleSided: {
+        header: types.PlasmaEventHeader.toEncodable(this.value.header),
+        event: types.AddLiquiditySingleSidedEvent.toEncodable(this.value.event),
+      },
+    }
+  }
+}
+
+export type RemoveLiquiditySingleSidedFields = {
+  header: types.PlasmaEventHeaderFields
+  event: types.RemoveLiquiditySingleSidedEventFields
+}
+export type RemoveLiquiditySingleSidedValue = {
+  header: types.PlasmaEventHeader
+  event: types.RemoveLiquiditySingleSidedEvent
+}
+
+export interface RemoveLiquiditySingleSidedJSON {
+  kind: "RemoveLiquiditySingleSided"
+  value: {
+    header: types.PlasmaEventHeaderJSON
+    event: types.RemoveLiquiditySingleSidedEventJSON
+  }
+}
+
+export class RemoveLiquiditySingleSided {
+  static readonly discriminator = 25
+  static readonly kind = "RemoveLiquiditySingleSided"
+  readonly discriminator = 25
+  readonly kind = "RemoveLiquiditySingleSided"
+  readonly value: RemoveLiquiditySingleSidedValue
+
+  constructor(value: RemoveLiquiditySingleSidedFields) {
+    this.value = {
+      header: new types.PlasmaEventHeader({ ...value.header }),
+      event: new types.RemoveLiquiditySingleSidedEvent({ ...value.event }),
+    }
+  }
+
+  toJSON(): RemoveLiquiditySingleSidedJSON {
+    return {
+      kind: "RemoveLiquiditySingleSided",
+      value: {
+        header: this.value.header.toJSON(),
+        event: this.value.event.toJSON(),
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      RemoveLiquiditySingleSided: {
+        header: types.PlasmaEventHeader.toEncodable(this.value.header),
+        event: types.RemoveLiquiditySingleSidedEvent.toEncodable(
+          this.value.event
+        ),
+      },
+    }
+  }
+}
+
+export type MigratePoolFields = {
+  header: types.PlasmaEventHeaderFields
+  event: types.MigratePoolEventFields
+}
+export type MigratePoolValue = {
+  header: types.PlasmaEventHeader
+  event: types.MigratePoolEvent
+}
+
+export interface MigratePoolJSON {
+  kind: "MigratePool"
+  value: {
+    header: types.PlasmaEventHeaderJSON
+    event: types.MigratePoolEventJSON
+  }
+}
+
+export class MigratePool {
+  static readonly discriminator = 26
+  static readonly kind = "MigratePool"
+  readonly discriminator = 26
+  readonly kind = "MigratePool"
+  readonly value: MigratePoolValue
+
+  constructor(value: MigratePoolFields) {
+    this.value = {
+      header: new types.PlasmaEventHeader({ ...value.header }),
+      event: new types.MigratePoolEvent({ ...value.event }),
+    }
+  }
+
+  toJSON(): MigratePoolJSON {
+    return {
+      kind: "MigratePool",
+      value: {
+        header: this.value.header.toJSON(),
+        event: this.value.event.toJSON(),
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      MigratePool: {
+        header: types.PlasmaEventHeader.toEncodable(this.value.header),
+        event: types.MigratePoolEvent.toEncodable(this.value.event),
+      },
+    }
+  }
+}
+
 // eslint-disable-next-line @typescript-eslint/no-explicit-any
 export function fromDecoded(obj: any): types.PlasmaEventKind {
   if (typeof obj !== "object") {
@@ -473,6 +1418,135 @@ export function fromDecoded(obj: any): types.PlasmaEventKind {
       event: types.WithdrawProtocolFeesEvent.fromDecoded(val["event"]),
     })
   }
+  if ("Log" in obj) {
+    return new Log()
+  }
+  if ("SwapRoute" in obj) {
+    const val = obj["SwapRoute"]
+    return new SwapRoute({
+      header: types.PlasmaEventHeader.fromDecoded(val["header"]),
+      event: types.SwapEvent.fromDecoded(val["event"]),
+    })
+  }
+  if ("GrowObservations" in obj) {
+    const val = obj["GrowObservations"]
+    return new GrowObservations({
+      header: types.PlasmaEventHeader.fromDecoded(val["header"]),
+      event: types.GrowObservationsEvent.fromDecoded(val["event"]),
+    })
+  }
+  if ("UpdatePoolConfig" in obj) {
+    const val = obj["UpdatePoolConfig"]
+    return new UpdatePoolConfig({
+      header: types.PlasmaEventHeader.fromDecoded(val["header"]),
+      event: types.UpdatePoolConfigEvent.fromDecoded(val["event"]),
+    })
+  }
+  if ("TransferProtocolFeeRecipient" in obj) {
+    const val = obj["TransferProtocolFeeRecipient"]
+    return new TransferProtocolFeeRecipient({
+      header: types.PlasmaEventHeader.fromDecoded(val["header"]),
+      event: types.TransferProtocolFeeRecipientEvent.fromDecoded(val["event"]),
+    })
+  }
+  if ("SetPoolStatus" in obj) {
+    const val = obj["SetPoolStatus"]
+    return new SetPoolStatus({
+      header: types.PlasmaEventHeader.fromDecoded(val["header"]),
+      event: types.SetPoolStatusEvent.fromDecoded(val["event"]),
+    })
+  }
+  if ("InitializeFeeTierConfig" in obj) {
+    const val = obj["InitializeFeeTierConfig"]
+    return new InitializeFeeTierConfig({
+      header: types.PlasmaEventHeader.fromDecoded(val["header"]),
+      event: types.InitializeFeeTierConfigEvent.fromDecoded(val["event"]),
+    })
+  }
+  if ("SetFeeTier" in obj) {
+    const val = obj["SetFeeTier"]
+    return new SetFeeTier({
+      header: types.PlasmaEventHeader.fromDecoded(val["header"]),
+      event: types.SetFeeTierEvent.fromDecoded(val["event"]),
+    })
+  }
+  if ("CloseLpPosition" in obj) {
+    const val = obj["CloseLpPosition"]
+    return new CloseLpPosition({
+      header: types.PlasmaEventHeader.fromDecoded(val["header"]),
+      event: types.CloseLpPositionEvent.fromDecoded(val["event"]),
+    })
+  }
+  if ("ClosePool" in obj) {
+    const val = obj["ClosePool"]
+    return new ClosePool({
+      header: types.PlasmaEventHeader.fromDecoded(val["header"]),
+      event: types.ClosePoolEvent.fromDecoded(val["event"]),
+    })
+  }
+  if ("InitializeLpMint" in obj) {
+    const val = obj["InitializeLpMint"]
+    return new InitializeLpMint({
+      header: types.PlasmaEventHeader.fromDecoded(val["header"]),
+      event: types.InitializeLpMintEvent.fromDecoded(val["event"]),
+    })
+  }
+  if ("TokenizeLpShares" in obj) {
+    const val = obj["TokenizeLpShares"]
+    return new TokenizeLpShares({
+      header: types.PlasmaEventHeader.fromDecoded(val["header"]),
+      event: types.TokenizeLpSharesEvent.fromDecoded(val["event"]),
+    })
+  }
+  if ("RedeemLpTokens" in obj) {
+    const val = obj["RedeemLpTokens"]
+    return new RedeemLpTokens({
+      header: types.PlasmaEventHeader.fromDecoded(val["header"]),
+      event: types.RedeemLpTokensEvent.fromDecoded(val["event"]),
+    })
+  }
+  if ("TransferLpPosition" in obj) {
+    const val = obj["TransferLpPosition"]
+    return new TransferLpPosition({
+      header: types.PlasmaEventHeader.fromDecoded(val["header"]),
+      event: types.TransferLpPositionEvent.fromDecoded(val["event"]),
+    })
+  }
+  if ("MigrateLpPosition" in obj) {
+    const val = obj["MigrateLpPosition"]
+    return new MigrateLpPosition({
+      header: types.PlasmaEventHeader.fromDecoded(val["header"]),
+      event: types.MigrateLpPositionEvent.fromDecoded(val["event"]),
+    })
+  }
+  if ("CompoundLpFees" in obj) {
+    const val = obj["CompoundLpFees"]
+    return new CompoundLpFees({
+      header: types.PlasmaEventHeader.fromDecoded(val["header"]),
+      event: types.CompoundLpFeesEvent.fromDecoded(val["event"]),
+    })
+  }
+  if ("AddLiquiditySingleSided" in obj) {
+    const val = obj["AddLiquiditySingleSided"]
+    return new AddLiquiditySingleSided({
+      header: types.PlasmaEventHeader.fromDecoded(val["header"]),
+      event: types.AddLiquiditySingleSidedEvent.fromDecoded(val["event"]),
+    })
+  }
+  if ("RemoveLiquiditySingleSided" in obj) {
+    const val = obj["RemoveLiquiditySingleSided"]
+    return new RemoveLiquiditySingleSided({
+      header: types.PlasmaEventHeader.fromDecoded(val["header"]),
+      event: types.RemoveLiquiditySingleSidedEvent.fromDecoded(val["event"]),
+    })
+  }
+  if ("MigratePool" in obj) {
+    const val = obj["MigratePool"]
+    return new MigratePool({
+      header: types.PlasmaEventHeader.fromDecoded(val["header"]),
+      event: types.MigratePoolEvent.fromDecoded(val["event"]),
+    })
+  }
 
   throw new Error("Invalid enum object")
 }
@@ -527,6 +1601,119 @@ export function fromJSON(obj: types.PlasmaEventJSON): types.PlasmaEventKind {
         event: types.WithdrawProtocolFeesEvent.fromJSON(obj.value.event),
       })
     }
+    case "Log": {
+      return new Log()
+    }
+    case "SwapRoute": {
+      return new SwapRoute({
+        header: types.PlasmaEventHeader.fromJSON(obj.value.header),
+        event: types.SwapEvent.fromJSON(obj.value.event),
+      })
+    }
+    case "GrowObservations": {
+      return new GrowObservations({
+        header: types.PlasmaEventHeader.fromJSON(obj.value.header),
+        event: types.GrowObservationsEvent.fromJSON(obj.value.event),
+      })
+    }
+    case "UpdatePoolConfig": {
+      return new UpdatePoolConfig({
+        header: types.PlasmaEventHeader.fromJSON(obj.value.header),
+        event: types.UpdatePoolConfigEvent.fromJSON(obj.value.event),
+      })
+    }
+    case "TransferProtocolFeeRecipient": {
+      return new TransferProtocolFeeRecipient({
+        header: types.PlasmaEventHeader.fromJSON(obj.value.header),
+        event: types.TransferProtocolFeeRecipientEvent.fromJSON(
+          obj.value.event
+        ),
+      })
+    }
+    case "SetPoolStatus": {
+      return new SetPoolStatus({
+        header: types.PlasmaEventHeader.fromJSON(obj.value.header),
+        event: types.SetPoolStatusEvent.fromJSON(obj.value.event),
+      })
+    }
+    case "InitializeFeeTierConfig": {
+      return new InitializeFeeTierConfig({
+        header: types.PlasmaEventHeader.fromJSON(obj.value.header),
+        event: types.InitializeFeeTierConfigEvent.fromJSON(obj.value.event),
+      })
+    }
+    case "SetFeeTier": {
+      return new SetFeeTier({
+        header: types.PlasmaEventHeader.fromJSON(obj.value.header),
+        event: types.SetFeeTierEvent.fromJSON(obj.value.event),
+      })
+    }
+    case "CloseLpPosition": {
+      return new CloseLpPosition({
+        header: types.PlasmaEventHeader.fromJSON(obj.value.header),
+        event: types.CloseLpPositionEvent.fromJSON(obj.value.event),
+      })
+    }
+    case "ClosePool": {
+      return new ClosePool({
+        header: types.PlasmaEventHeader.fromJSON(obj.value.header),
+        event: types.ClosePoolEvent.fromJSON(obj.value.event),
+      })
+    }
+    case "InitializeLpMint": {
+      return new InitializeLpMint({
+        header: types.PlasmaEventHeader.fromJSON(obj.value.header),
+        event: types.InitializeLpMintEvent.fromJSON(obj.value.event),
+      })
+    }
+    case "TokenizeLpShares": {
+      return new TokenizeLpShares({
+        header: types.PlasmaEventHeader.fromJSON(obj.value.header),
+        event: types.TokenizeLpSharesEvent.fromJSON(obj.value.event),
+      })
+    }
+    case "RedeemLpTokens": {
+      return new RedeemLpTokens({
+        header: types.PlasmaEventHeader.fromJSON(obj.value.header),
+        event: types.RedeemLpTokensEvent.fromJSON(obj.value.event),
+      })
+    }
+    case "TransferLpPosition": {
+      return new TransferLpPosition({
+        header: types.PlasmaEventHeader.fromJSON(obj.value.header),
+        event: types.TransferLpPositionEvent.fromJSON(obj.value.event),
+      })
+    }
+    case "MigrateLpPosition": {
+      return new MigrateLpPosition({
+        header: types.PlasmaEventHeader.fromJSON(obj.value.header),
+        event: types.MigrateLpPositionEvent.fromJSON(obj.value.event),
+      })
+    }
+    case "CompoundLpFees": {
+      return new CompoundLpFees({
+        header: types.PlasmaEventHeader.fromJSON(obj.value.header),
+        event: types.CompoundLpFeesEvent.fromJSON(obj.value.event),
+      })
+    }
+    case "AddLiquiditySingleSided": {
+      return new AddLiquiditySingleSided({
+        header: types.PlasmaEventHeader.fromJSON(obj.value.header),
+        event: types.AddLiquiditySingleSidedEvent.fromJSON(obj.value.event),
+      })
+    }
+    case "RemoveLiquiditySingleSided": {
+      return new RemoveLiquiditySingleSided({
+        header: types.PlasmaEventHeader.fromJSON(obj.value.header),
+        event: types.RemoveLiquiditySingleSidedEvent.fromJSON(obj.value.event),
+      })
+    }
+    case "MigratePool": {
+      return new MigratePool({
+        header: types.PlasmaEventHeader.fromJSON(obj.value.header),
+        event: types.MigratePoolEvent.fromJSON(obj.value.event),
+      })
+    }
   }
 }
 
@@ -588,6 +1775,133 @@ export function layout(property?: string) {
       ],
       "WithdrawProtocolFees"
     ),
+    borsh.struct([], "Log"),
+    borsh.struct(
+      [
+        types.PlasmaEventHeader.layout("header"),
+        types.SwapEvent.layout("event"),
+      ],
+      "SwapRoute"
+    ),
+    borsh.struct(
+      [
+        types.PlasmaEventHeader.layout("header"),
+        types.GrowObservationsEvent.layout("event"),
+      ],
+      "GrowObservations"
+    ),
+    borsh.struct(
+      [
+        types.PlasmaEventHeader.layout("header"),
+        types.UpdatePoolConfigEvent.layout("event"),
+      ],
+      "UpdatePoolConfig"
+    ),
+    borsh.struct(
+      [
+        types.PlasmaEventHeader.layout("header"),
+        types.TransferProtocolFeeRecipientEvent.layout("event"),
+      ],
+      "TransferProtocolFeeRecipient"
+    ),
+    borsh.struct(
+      [
+        types.PlasmaEventHeader.layout("header"),
+        types.SetPoolStatusEvent.layout("event"),
+      ],
+      "SetPoolStatus"
+    ),
+    borsh.struct(
+      [
+        types.PlasmaEventHeader.layout("header"),
+        types.InitializeFeeTierConfigEvent.layout("event"),
+      ],
+      "InitializeFeeTierConfig"
+    ),
+    borsh.struct(
+      [
+        types.PlasmaEventHeader.layout("header"),
+        types.SetFeeTierEvent.layout("event"),
+      ],
+      "SetFeeTier"
+    ),
+    borsh.struct(
+      [
+        types.PlasmaEventHeader.layout("header"),
+        types.CloseLpPositionEvent.layout("event"),
+      ],
+      "CloseLpPosition"
+    ),
+    borsh.struct(
+      [
+        types.PlasmaEventHeader.layout("header"),
+        types.ClosePoolEvent.layout("event"),
+      ],
+      "ClosePool"
+    ),
+    borsh.struct(
+      [
+        types.PlasmaEventHeader.layout("header"),
+        types.InitializeLpMintEvent.layout("event"),
+      ],
+      "InitializeLpMint"
+    ),
+    borsh.struct(
+      [
+        types.PlasmaEventHeader.layout("header"),
+        types.TokenizeLpSharesEvent.layout("event"),
+      ],
+      "TokenizeLpShares"
+    ),
+    borsh.struct(
+      [
+        types.PlasmaEventHeader.layout("header"),
+        types.RedeemLpTokensEvent.layout("event"),
+      ],
+      "RedeemLpTokens"
+    ),
+    borsh.struct(
+      [
+        types.PlasmaEventHeader.layout("header"),
+        types.TransferLpPositionEvent.layout("event"),
+      ],
+      "TransferLpPosition"
+    ),
+    borsh.struct(
+      [
+        types.PlasmaEventHeader.layout("header"),
+        types.MigrateLpPositionEvent.layout("event"),
+      ],
+      "MigrateLpPosition"
+    ),
+    borsh.struct(
+      [
+        types.PlasmaEventHeader.layout("header"),
+        types.CompoundLpFeesEvent.layout("event"),
+      ],
+      "CompoundLpFees"
+    ),
+    borsh.struct(
+      [
+        types.PlasmaEventHeader.layout("header"),
+        types.AddLiquiditySingleSidedEvent.layout("event"),
+      ],
+      "AddLiquiditySingleSided"
+    ),
+    borsh.struct(
+      [
+        types.PlasmaEventHeader.layout("header"),
+        types.RemoveLiquiditySingleSidedEvent.layout("event"),
+      ],
+      "RemoveLiquiditySingleSided"
+    ),
+    borsh.struct(
+      [
+        types.PlasmaEventHeader.layout("header"),
+        types.MigratePoolEvent.layout("event"),
+      ],
+      "MigratePool"
+    ),
   ])
   if (property !== undefined) {
     return ret.replicate(property)
@@ -8,7 +8,14 @@ export interface PoolHeaderFields {
   baseParams: types.TokenParamsFields
   quoteParams: types.TokenParamsFields
   feeRecipients: types.ProtocolFeeRecipientsFields
-  padding: Array<BN>
+  poolAuthority: PublicKey
+  status: BN
+  feeTier: BN
+  lpMint: PublicKey
+  lpMintBump: number
+  lpVestingSchedule: number
+  quoteLpFeesPaid: BN
+  lastLpVestingSlot: BN
 }
 
 export interface PoolHeaderJSON {
@@ -16,7 +23,14 @@ export interface PoolHeaderJSON {
   baseParams: types.TokenParamsJSON
   quoteParams: types.TokenParamsJSON
   feeRecipients: types.ProtocolFeeRecipientsJSON
-  padding: Array<string>
+  poolAuthority: string
+  status: string
+  feeTier: string
+  lpMint: string
+  lpMintBump: number
+  lpVestingSchedule: number
+  quoteLpFeesPaid: string
+  lastLpVestingSlot: string
 }
 
 export class PoolHeader {
@@ -24,7 +38,14 @@ export class PoolHeader {
   readonly baseParams: types.TokenParams
   readonly quoteParams: types.TokenParams
   readonly feeRecipients: types.ProtocolFeeRecipients
-  readonly padding: Array<BN>
+  readonly poolAuthority: PublicKey
+  readonly status: BN
+  readonly feeTier: BN
+  readonly lpMint: PublicKey
+  readonly lpMintBump: number
+  readonly lpVestingSchedule: number
+  readonly quoteLpFeesPaid: BN
+  readonly lastLpVestingSlot: BN
 
   constructor(fields: PoolHeaderFields) {
     this.sequenceNumber = fields.sequenceNumber
@@ -33,7 +54,14 @@ export class PoolHeader {
     this.feeRecipients = new types.ProtocolFeeRecipients({
       ...fields.feeRecipients,
     })
-    this.padding = fields.padding
+    this.poolAuthority = fields.poolAuthority
+    this.status = fields.status
+    this.feeTier = fields.feeTier
+    this.lpMint = fields.lpMint
+    this.lpMintBump = fields.lpMintBump
+    this.lpVestingSchedule = fields.lpVestingSchedule
+    this.quoteLpFeesPaid = fields.quoteLpFeesPaid
+    this.lastLpVestingSlot = fields.lastLpVestingSlot
   }
 
   static layout(property?: string) {
@@ -43,7 +71,14 @@ export class PoolHeader {
         types.TokenParams.layout("baseParams"),
         types.TokenParams.layout("quoteParams"),
         types.ProtocolFeeRecipients.layout("feeRecipients"),
-        borsh.array(borsh.u64(), 13, "padding"),
+        borsh.publicKey("poolAuthority"),
+        borsh.u64("status"),
+        borsh.u64("feeTier"),
+        borsh.publicKey("lpMint"),
+        borsh.u32("lpMintBump"),
+        borsh.u32("lpVestingSchedule"),
+        borsh.u64("quoteLpFeesPaid"),
+        borsh.u64("lastLpVestingSlot"),
       ],
       property
     )
@@ -56,7 +91,14 @@ export class PoolHeader {
       baseParams: types.TokenParams.fromDecoded(obj.baseParams),
       quoteParams: types.TokenParams.fromDecoded(obj.quoteParams),
       feeRecipients: types.ProtocolFeeRecipients.fromDecoded(obj.feeRecipients),
-      padding: obj.padding,
+      poolAuthority: obj.poolAuthority,
+      status: obj.status,
+      feeTier: obj.feeTier,
+      lpMint: obj.lpMint,
+      lpMintBump: obj.lpMintBump,
+      lpVestingSchedule: obj.lpVestingSchedule,
+      quoteLpFeesPaid: obj.quoteLpFeesPaid,
+      lastLpVestingSlot: obj.lastLpVestingSlot,
     })
   }
 
@@ -68,7 +110,14 @@ export class PoolHeader {
       feeRecipients: types.ProtocolFeeRecipients.toEncodable(
         fields.feeRecipients
       ),
-      padding: fields.padding,
+      poolAuthority: fields.poolAuthority,
+      status: fields.status,
+      feeTier: fields.feeTier,
+      lpMint: fields.lpMint,
+      lpMintBump: fields.lpMintBump,
+      lpVestingSchedule: fields.lpVestingSchedule,
+      quoteLpFeesPaid: fields.quoteLpFeesPaid,
+      lastLpVestingSlot: fields.lastLpVestingSlot,
     }
   }
 
@@ -78,7 +127,14 @@ export class PoolHeader {
       baseParams: this.baseParams.toJSON(),
       quoteParams: this.quoteParams.toJSON(),
       feeRecipients: this.feeRecipients.toJSON(),
-      padding: this.padding.map((item) => item.toString()),
+      poolAuthority: this.poolAuthority.toString(),
+      status: this.status.toString(),
+      feeTier: this.feeTier.toString(),
+      lpMint: this.lpMint.toString(),
+      lpMintBump: this.lpMintBump,
+      lpVestingSchedule: this.lpVestingSchedule,
+      quoteLpFeesPaid: this.quoteLpFeesPaid.toString(),
+      lastLpVestingSlot: this.lastLpVestingSlot.toString(),
     }
   }
 
@@ -88,7 +144,14 @@ export class PoolHeader {
       baseParams: types.TokenParams.fromJSON(obj.baseParams),
       quoteParams: types.TokenParams.fromJSON(obj.quoteParams),
       feeRecipients: types.ProtocolFeeRecipients.fromJSON(obj.feeRecipients),
-      padding: obj.padding.map((item) => new BN(item)),
+      poolAuthority: new PublicKey(obj.poolAuthority),
+      status: new BN(obj.status),
+      feeTier: new BN(obj.feeTier),
+      lpMint: new PublicKey(obj.lpMint),
+      lpMintBump: obj.lpMintBump,
+      lpVestingSchedule: obj.lpVestingSchedule,
+      quoteLpFeesPaid: new BN(obj.quoteLpFeesPaid),
+      lastLpVestingSlot: new BN(obj.lastLpVestingSlot),
     })
   }
 
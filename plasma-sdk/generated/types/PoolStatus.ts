@@ -0,0 +1,118 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface ActiveJSON {
+  kind: "Active"
+}
+
+export class Active {
+  static readonly discriminator = 0
+  static readonly kind = "Active"
+  readonly discriminator = 0
+  readonly kind = "Active"
+
+  toJSON(): ActiveJSON {
+    return {
+      kind: "Active",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Active: {},
+    }
+  }
+}
+
+export interface SwapsPausedJSON {
+  kind: "SwapsPaused"
+}
+
+export class SwapsPaused {
+  static readonly discriminator = 1
+  static readonly kind = "SwapsPaused"
+  readonly discriminator = 1
+  readonly kind = "SwapsPaused"
+
+  toJSON(): SwapsPausedJSON {
+    return {
+      kind: "SwapsPaused",
+    }
+  }
+
+  toEncodable() {
+    return {
+      SwapsPaused: {},
+    }
+  }
+}
+
+export interface FullyPausedJSON {
+  kind: "FullyPaused"
+}
+
+export class FullyPaused {
+  static readonly discriminator = 2
+  static readonly kind = "FullyPaused"
+  readonly discriminator = 2
+  readonly kind = "FullyPaused"
+
+  toJSON(): FullyPausedJSON {
+    return {
+      kind: "FullyPaused",
+    }
+  }
+
+  toEncodable() {
+    return {
+      FullyPaused: {},
+    }
+  }
+}
+
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+export function fromDecoded(obj: any): types.PoolStatusKind {
+  if (typeof obj !== "object") {
+    throw new Error("Invalid enum object")
+  }
+
+  if ("Active" in obj) {
+    return new Active()
+  }
+  if ("SwapsPaused" in obj) {
+    return new SwapsPaused()
+  }
+  if ("FullyPaused" in obj) {
+    return new FullyPaused()
+  }
+
+  throw new Error("Invalid enum object")
+}
+
+export function fromJSON(obj: types.PoolStatusJSON): types.PoolStatusKind {
+  switch (obj.kind) {
+    case "Active": {
+      return new Active()
+    }
+    case "SwapsPaused": {
+      return new SwapsPaused()
+    }
+    case "FullyPaused": {
+      return new FullyPaused()
+    }
+  }
+}
+
+export function layout(property?: string) {
+  const ret = borsh.rustEnum([
+    borsh.struct([], "Active"),
+    borsh.struct([], "SwapsPaused"),
+    borsh.struct([], "FullyPaused"),
+  ])
+  if (property !== undefined) {
+    return ret.replicate(property)
+  }
+  return ret
+}
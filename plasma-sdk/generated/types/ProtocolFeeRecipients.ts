@@ -5,22 +5,31 @@ import * as borsh from "@coral-xyz/borsh"
 
 export interface ProtocolFeeRecipientsFields {
   recipients: Array<types.ProtocolFeeRecipientFields>
+  cumulativeQuoteFeesCheckpoint: BN
+  accumulatedQuoteFeesCheckpoints: Array<BN>
   padding: Array<BN>
 }
 
 export interface ProtocolFeeRecipientsJSON {
   recipients: Array<types.ProtocolFeeRecipientJSON>
+  cumulativeQuoteFeesCheckpoint: string
+  accumulatedQuoteFeesCheckpoints: Array<string>
   padding: Array<string>
 }
 
 export class ProtocolFeeRecipients {
   readonly recipients: Array<types.ProtocolFeeRecipient>
+  readonly cumulativeQuoteFeesCheckpoint: BN
+  readonly accumulatedQuoteFeesCheckpoints: Array<BN>
   readonly padding: Array<BN>
 
   constructor(fields: ProtocolFeeRecipientsFields) {
     this.recipients = fields.recipients.map(
       (item) => new types.ProtocolFeeRecipient({ ...item })
     )
+    this.cumulativeQuoteFeesCheckpoint = fields.cumulativeQuoteFeesCheckpoint
+    this.accumulatedQuoteFeesCheckpoints =
+      fields.accumulatedQuoteFeesCheckpoints
     this.padding = fields.padding
   }
 
@@ -28,7 +37,9 @@ export class ProtocolFeeRecipients {
     return borsh.struct(
       [
         borsh.array(types.ProtocolFeeRecipient.layout(), 3, "recipients"),
-        borsh.array(borsh.u64(), 12, "padding"),
+        borsh.u64("cumulativeQuoteFeesCheckpoint"),
+        borsh.array(borsh.u64(), 3, "accumulatedQuoteFeesCheckpoints"),
+        borsh.array(borsh.u64(), 8, "padding"),
       ],
       property
     )
@@ -42,6 +53,8 @@ export class ProtocolFeeRecipients {
           item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
         ) => types.ProtocolFeeRecipient.fromDecoded(item)
       ),
+      cumulativeQuoteFeesCheckpoint: obj.cumulativeQuoteFeesCheckpoint,
+      accumulatedQuoteFeesCheckpoints: obj.accumulatedQuoteFeesCheckpoints,
       padding: obj.padding,
     })
   }
@@ -51,6 +64,8 @@ export class ProtocolFeeRecipients {
       recipients: fields.recipients.map((item) =>
         types.ProtocolFeeRecipient.toEncodable(item)
       ),
+      cumulativeQuoteFeesCheckpoint: fields.cumulativeQuoteFeesCheckpoint,
+      accumulatedQuoteFeesCheckpoints: fields.accumulatedQuoteFeesCheckpoints,
       padding: fields.padding,
     }
   }
@@ -58,6 +73,11 @@ export class ProtocolFeeRecipients {
   toJSON(): ProtocolFeeRecipientsJSON {
     return {
       recipients: this.recipients.map((item) => item.toJSON()),
+      cumulativeQuoteFeesCheckpoint:
+        this.cumulativeQuoteFeesCheckpoint.toString(),
+      accumulatedQuoteFeesCheckpoints: this.accumulatedQuoteFeesCheckpoints.map(
+        (item) => item.toString()
+      ),
       padding: this.padding.map((item) => item.toString()),
     }
   }
@@ -67,6 +87,10 @@ export class ProtocolFeeRecipients {
       recipients: obj.recipients.map((item) =>
         types.ProtocolFeeRecipient.fromJSON(item)
       ),
+      cumulativeQuoteFeesCheckpoint: new BN(obj.cumulativeQuoteFeesCheckpoint),
+      accumulatedQuoteFeesCheckpoints: obj.accumulatedQuoteFeesCheckpoints.map(
+        (item) => new BN(item)
+      ),
       padding: obj.padding.map((item) => new BN(item)),
     })
   }
@@ -0,0 +1,108 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface RedeemLpTokensEventFields {
+  lpTokensRedeemed: BN
+  lpTokenFeesRedistributed: BN
+  totalLpSharesTokenized: BN
+  userLpSharesAvailable: BN
+  userLpSharesLocked: BN
+  userLpSharesUnlockedForWithdrawal: BN
+}
+
+export interface RedeemLpTokensEventJSON {
+  lpTokensRedeemed: string
+  lpTokenFeesRedistributed: string
+  totalLpSharesTokenized: string
+  userLpSharesAvailable: string
+  userLpSharesLocked: string
+  userLpSharesUnlockedForWithdrawal: string
+}
+
+export class RedeemLpTokensEvent {
+  readonly lpTokensRedeemed: BN
+  readonly lpTokenFeesRedistributed: BN
+  readonly totalLpSharesTokenized: BN
+  readonly userLpSharesAvailable: BN
+  readonly userLpSharesLocked: BN
+  readonly userLpSharesUnlockedForWithdrawal: BN
+
+  constructor(fields: RedeemLpTokensEventFields) {
+    this.lpTokensRedeemed = fields.lpTokensRedeemed
+    this.lpTokenFeesRedistributed = fields.lpTokenFeesRedistributed
+    this.totalLpSharesTokenized = fields.totalLpSharesTokenized
+    this.userLpSharesAvailable = fields.userLpSharesAvailable
+    this.userLpSharesLocked = fields.userLpSharesLocked
+    this.userLpSharesUnlockedForWithdrawal =
+      fields.userLpSharesUnlockedForWithdrawal
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u64("lpTokensRedeemed"),
+        borsh.u64("lpTokenFeesRedistributed"),
+        borsh.u64("totalLpSharesTokenized"),
+        borsh.u64("userLpSharesAvailable"),
+        borsh.u64("userLpSharesLocked"),
+        borsh.u64("userLpSharesUnlockedForWithdrawal"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new RedeemLpTokensEvent({
+      lpTokensRedeemed: obj.lpTokensRedeemed,
+      lpTokenFeesRedistributed: obj.lpTokenFeesRedistributed,
+      totalLpSharesTokenized: obj.totalLpSharesTokenized,
+      userLpSharesAvailable: obj.userLpSharesAvailable,
+      userLpSharesLocked: obj.userLpSharesLocked,
+      userLpSharesUnlockedForWithdrawal: obj.userLpSharesUnlockedForWithdrawal,
+    })
+  }
+
+  static toEncodable(fields: RedeemLpTokensEventFields) {
+    return {
+      lpTokensRedeemed: fields.lpTokensRedeemed,
+      lpTokenFeesRedistributed: fields.lpTokenFeesRedistributed,
+      totalLpSharesTokenized: fields.totalLpSharesTokenized,
+      userLpSharesAvailable: fields.userLpSharesAvailable,
+      userLpSharesLocked: fields.userLpSharesLocked,
+      userLpSharesUnlockedForWithdrawal:
+        fields.userLpSharesUnlockedForWithdrawal,
+    }
+  }
+
+  toJSON(): RedeemLpTokensEventJSON {
+    return {
+      lpTokensRedeemed: this.lpTokensRedeemed.toString(),
+      lpTokenFeesRedistributed: this.lpTokenFeesRedistributed.toString(),
+      totalLpSharesTokenized: this.totalLpSharesTokenized.toString(),
+      userLpSharesAvailable: this.userLpSharesAvailable.toString(),
+      userLpSharesLocked: this.userLpSharesLocked.toString(),
+      userLpSharesUnlockedForWithdrawal:
+        this.userLpSharesUnlockedForWithdrawal.toString(),
+    }
+  }
+
+  static fromJSON(obj: RedeemLpTokensEventJSON): RedeemLpTokensEvent {
+    return new RedeemLpTokensEvent({
+      lpTokensRedeemed: new BN(obj.lpTokensRedeemed),
+      lpTokenFeesRedistributed: new BN(obj.lpTokenFeesRedistributed),
+      totalLpSharesTokenized: new BN(obj.totalLpSharesTokenized),
+      userLpSharesAvailable: new BN(obj.userLpSharesAvailable),
+      userLpSharesLocked: new BN(obj.userLpSharesLocked),
+      userLpSharesUnlockedForWithdrawal: new BN(
+        obj.userLpSharesUnlockedForWithdrawal
+      ),
+    })
+  }
+
+  toEncodable() {
+    return RedeemLpTokensEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,53 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface RedeemLpTokensIxParamsFields {
+  lpTokens: BN
+}
+
+export interface RedeemLpTokensIxParamsJSON {
+  lpTokens: string
+}
+
+export class RedeemLpTokensIxParams {
+  readonly lpTokens: BN
+
+  constructor(fields: RedeemLpTokensIxParamsFields) {
+    this.lpTokens = fields.lpTokens
+  }
+
+  static layout(property?: string) {
+    return borsh.struct([borsh.u64("lpTokens")], property)
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new RedeemLpTokensIxParams({
+      lpTokens: obj.lpTokens,
+    })
+  }
+
+  static toEncodable(fields: RedeemLpTokensIxParamsFields) {
+    return {
+      lpTokens: fields.lpTokens,
+    }
+  }
+
+  toJSON(): RedeemLpTokensIxParamsJSON {
+    return {
+      lpTokens: this.lpTokens.toString(),
+    }
+  }
+
+  static fromJSON(obj: RedeemLpTokensIxParamsJSON): RedeemLpTokensIxParams {
+    return new RedeemLpTokensIxParams({
+      lpTokens: new BN(obj.lpTokens),
+    })
+  }
+
+  toEncodable() {
+    return RedeemLpTokensIxParams.toEncodable(this)
+  }
+}
@@ -17,6 +17,8 @@ export interface RemoveLiquidityEventFields {
   userQuoteWithdrawn: BN
   userTotalWithdrawableBase: BN
   userTotalWithdrawableQuote: BN
+  userLpSharesRequested: BN
+  mode: types.RemoveLiquidityModeKind
 }
 
 export interface RemoveLiquidityEventJSON {
@@ -33,6 +35,8 @@ export interface RemoveLiquidityEventJSON {
   userQuoteWithdrawn: string
   userTotalWithdrawableBase: string
   userTotalWithdrawableQuote: string
+  userLpSharesRequested: string
+  mode: types.RemoveLiquidityModeJSON
 }
 
 export class RemoveLiquidityEvent {
@@ -49,6 +53,8 @@ export class RemoveLiquidityEvent {
   readonly userQuoteWithdrawn: BN
   readonly userTotalWithdrawableBase: BN
   readonly userTotalWithdrawableQuote: BN
+  readonly userLpSharesRequested: BN
+  readonly mode: types.RemoveLiquidityModeKind
 
   constructor(fields: RemoveLiquidityEventFields) {
     this.poolTotalLpShares = fields.poolTotalLpShares
@@ -65,6 +71,8 @@ export class RemoveLiquidityEvent {
     this.userQuoteWithdrawn = fields.userQuoteWithdrawn
     this.userTotalWithdrawableBase = fields.userTotalWithdrawableBase
     this.userTotalWithdrawableQuote = fields.userTotalWithdrawableQuote
+    this.userLpSharesRequested = fields.userLpSharesRequested
+    this.mode = fields.mode
   }
 
   static layout(property?: string) {
@@ -83,6 +91,8 @@ export class RemoveLiquidityEvent {
         borsh.u64("userQuoteWithdrawn"),
         borsh.u64("userTotalWithdrawableBase"),
         borsh.u64("userTotalWithdrawableQuote"),
+        borsh.u64("userLpSharesRequested"),
+        types.RemoveLiquidityMode.layout("mode"),
       ],
       property
     )
@@ -104,6 +114,8 @@ export class RemoveLiquidityEvent {
       userQuoteWithdrawn: obj.userQuoteWithdrawn,
       userTotalWithdrawableBase: obj.userTotalWithdrawableBase,
       userTotalWithdrawableQuote: obj.userTotalWithdrawableQuote,
+      userLpSharesRequested: obj.userLpSharesRequested,
+      mode: types.RemoveLiquidityMode.fromDecoded(obj.mode),
     })
   }
 
@@ -123,6 +135,8 @@ export class RemoveLiquidityEvent {
       userQuoteWithdrawn: fields.userQuoteWithdrawn,
       userTotalWithdrawableBase: fields.userTotalWithdrawableBase,
       userTotalWithdrawableQuote: fields.userTotalWithdrawableQuote,
+      userLpSharesRequested: fields.userLpSharesRequested,
+      mode: fields.mode.toEncodable(),
     }
   }
 
@@ -142,6 +156,8 @@ export class RemoveLiquidityEvent {
       userQuoteWithdrawn: this.userQuoteWithdrawn.toString(),
       userTotalWithdrawableBase: this.userTotalWithdrawableBase.toString(),
       userTotalWithdrawableQuote: this.userTotalWithdrawableQuote.toString(),
+      userLpSharesRequested: this.userLpSharesRequested.toString(),
+      mode: this.mode.toJSON(),
     }
   }
 
@@ -162,6 +178,8 @@ export class RemoveLiquidityEvent {
       userQuoteWithdrawn: new BN(obj.userQuoteWithdrawn),
       userTotalWithdrawableBase: new BN(obj.userTotalWithdrawableBase),
       userTotalWithdrawableQuote: new BN(obj.userTotalWithdrawableQuote),
+      userLpSharesRequested: new BN(obj.userLpSharesRequested),
+      mode: types.RemoveLiquidityMode.fromJSON(obj.mode),
     })
   }
 
@@ -5,45 +5,61 @@ import * as borsh from "@coral-xyz/borsh"
 
 export interface RemoveLiquidityIxParamsFields {
   lpShares: BN
+  extension: types.RemoveLiquidityParamsExtensionKind
 }
 
 export interface RemoveLiquidityIxParamsJSON {
   lpShares: string
+  extension: types.RemoveLiquidityParamsExtensionJSON
 }
 
 export class RemoveLiquidityIxParams {
   readonly lpShares: BN
+  readonly extension: types.RemoveLiquidityParamsExtensionKind
 
   constructor(fields: RemoveLiquidityIxParamsFields) {
     this.lpShares = fields.lpShares
+    this.extension = fields.extension
   }
 
   static layout(property?: string) {
-    return borsh.struct([borsh.u64("lpShares")], property)
+    return borsh.struct(
+      [
+        borsh.u64("lpShares"),
+        types.RemoveLiquidityParamsExtension.layout("extension"),
+      ],
+      property
+    )
   }
 
   // eslint-disable-next-line @typescript-eslint/no-explicit-any
   static fromDecoded(obj: any) {
     return new RemoveLiquidityIxParams({
       lpShares: obj.lpShares,
+      extension: types.RemoveLiquidityParamsExtension.fromDecoded(
+        obj.extension
+      ),
     })
   }
 
   static toEncodable(fields: RemoveLiquidityIxParamsFields) {
     return {
       lpShares: fields.lpShares,
+      extension: fields.extension.toEncodable(),
     }
   }
 
   toJSON(): RemoveLiquidityIxParamsJSON {
     return {
       lpShares: this.lpShares.toString(),
+      extension: this.extension.toJSON(),
     }
   }
 
   static fromJSON(obj: RemoveLiquidityIxParamsJSON): RemoveLiquidityIxParams {
     return new RemoveLiquidityIxParams({
       lpShares: new BN(obj.lpShares),
+      extension: types.RemoveLiquidityParamsExtension.fromJSON(obj.extension),
     })
   }
 
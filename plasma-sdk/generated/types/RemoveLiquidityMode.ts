@@ -0,0 +1,90 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface StrictJSON {
+  kind: "Strict"
+}
+
+export class Strict {
+  static readonly discriminator = 0
+  static readonly kind = "Strict"
+  readonly discriminator = 0
+  readonly kind = "Strict"
+
+  toJSON(): StrictJSON {
+    return {
+      kind: "Strict",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Strict: {},
+    }
+  }
+}
+
+export interface UpToJSON {
+  kind: "UpTo"
+}
+
+export class UpTo {
+  static readonly discriminator = 1
+  static readonly kind = "UpTo"
+  readonly discriminator = 1
+  readonly kind = "UpTo"
+
+  toJSON(): UpToJSON {
+    return {
+      kind: "UpTo",
+    }
+  }
+
+  toEncodable() {
+    return {
+      UpTo: {},
+    }
+  }
+}
+
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+export function fromDecoded(obj: any): types.RemoveLiquidityModeKind {
+  if (typeof obj !== "object") {
+    throw new Error("Invalid enum object")
+  }
+
+  if ("Strict" in obj) {
+    return new Strict()
+  }
+  if ("UpTo" in obj) {
+    return new UpTo()
+  }
+
+  throw new Error("Invalid enum object")
+}
+
+export function fromJSON(
+  obj: types.RemoveLiquidityModeJSON
+): types.RemoveLiquidityModeKind {
+  switch (obj.kind) {
+    case "Strict": {
+      return new Strict()
+    }
+    case "UpTo": {
+      return new UpTo()
+    }
+  }
+}
+
+export function layout(property?: string) {
+  const ret = borsh.rustEnum([
+    borsh.struct([], "Strict"),
+    borsh.struct([], "UpTo"),
+  ])
+  if (property !== undefined) {
+    return ret.replicate(property)
+  }
+  return ret
+}
@@ -0,0 +1,160 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface V0JSON {
+  kind: "V0"
+}
+
+export class V0 {
+  static readonly discriminator = 0
+  static readonly kind = "V0"
+  readonly discriminator = 0
+  readonly kind = "V0"
+
+  toJSON(): V0JSON {
+    return {
+      kind: "V0",
+    }
+  }
+
+  toEncodable() {
+    return {
+      V0: {},
+    }
+  }
+}
+
+export type V1Fields = {
+  nativeSol: types.NativeSolParamsFields
+  minBaseOut: BN
+  minQuoteOut: BN
+  deadline: types.SlotDeadlineFields
+  mode: types.RemoveLiquidityModeKind
+}
+export type V1Value = {
+  nativeSol: types.NativeSolParams
+  minBaseOut: BN
+  minQuoteOut: BN
+  deadline: types.SlotDeadline
+  mode: types.RemoveLiquidityModeKind
+}
+
+export interface V1JSON {
+  kind: "V1"
+  value: {
+    nativeSol: types.NativeSolParamsJSON
+    minBaseOut: string
+    minQuoteOut: string
+    deadline: types.SlotDeadlineJSON
+    mode: types.RemoveLiquidityModeJSON
+  }
+}
+
+export class V1 {
+  static readonly discriminator = 1
+  static readonly kind = "V1"
+  readonly discriminator = 1
+  readonly kind = "V1"
+  readonly value: V1Value
+
+  constructor(value: V1Fields) {
+    this.value = {
+      nativeSol: new types.NativeSolParams({ ...value.nativeSol }),
+      minBaseOut: value.minBaseOut,
+      minQuoteOut: value.minQuoteOut,
+      deadline: new types.SlotDeadline({ ...value.deadline }),
+      mode: value.mode,
+    }
+  }
+
+  toJSON(): V1JSON {
+    return {
+      kind: "V1",
+      value: {
+        nativeSol: this.value.nativeSol.toJSON(),
+        minBaseOut: this.value.minBaseOut.toString(),
+        minQuoteOut: this.value.minQuoteOut.toString(),
+        deadline: this.value.deadline.toJSON(),
+        mode: this.value.mode.toJSON(),
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      V1: {
+        nativeSol: types.NativeSolParams.toEncodable(this.value.nativeSol),
+        minBaseOut: this.value.minBaseOut,
+        minQuoteOut: this.value.minQuoteOut,
+        deadline: types.SlotDeadline.toEncodable(this.value.deadline),
+        mode: this.value.mode.toEncodable(),
+      },
+    }
+  }
+}
+
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+export function fromDecoded(
+  obj: any
+): types.RemoveLiquidityParamsExtensionKind {
+  if (typeof obj !== "object") {
+    throw new Error("Invalid enum object")
+  }
+
+  if ("V0" in obj) {
+    return new V0()
+  }
+  if ("V1" in obj) {
+    const val = obj["V1"]
+    return new V1({
+      nativeSol: types.NativeSolParams.fromDecoded(val["nativeSol"]),
+      minBaseOut: val["minBaseOut"],
+      minQuoteOut: val["minQuoteOut"],
+      deadline: types.SlotDeadline.fromDecoded(val["deadline"]),
+      mode: types.RemoveLiquidityMode.fromDecoded(val["mode"]),
+    })
+  }
+
+  throw new Error("Invalid enum object")
+}
+
+export function fromJSON(
+  obj: types.RemoveLiquidityParamsExtensionJSON
+): types.RemoveLiquidityParamsExtensionKind {
+  switch (obj.kind) {
+    case "V0": {
+      return new V0()
+    }
+    case "V1": {
+      return new V1({
+        nativeSol: types.NativeSolParams.fromJSON(obj.value.nativeSol),
+        minBaseOut: new BN(obj.value.minBaseOut),
+        minQuoteOut: new BN(obj.value.minQuoteOut),
+        deadline: types.SlotDeadline.fromJSON(obj.value.deadline),
+        mode: types.RemoveLiquidityMode.fromJSON(obj.value.mode),
+      })
+    }
+  }
+}
+
+export function layout(property?: string) {
+  const ret = borsh.rustEnum([
+    borsh.struct([], "V0"),
+    borsh.struct(
+      [
+        types.NativeSolParams.layout("nativeSol"),
+        borsh.u64("minBaseOut"),
+        borsh.u64("minQuoteOut"),
+        types.SlotDeadline.layout("deadline"),
+        types.RemoveLiquidityMode.layout("mode"),
+      ],
+      "V1"
+    ),
+  ])
+  if (property !== undefined) {
+    return ret.replicate(property)
+  }
+  return ret
+}
@@ -0,0 +1,146 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface RemoveLiquiditySingleSidedEventFields {
+  tokenOut: types.TokenTypeKind
+  userAmountWithdrawn: BN
+  swapResult: types.SwapResultFields
+  poolTotalLpShares: BN
+  poolTotalBaseLiquidity: BN
+  poolTotalQuoteLiquidity: BN
+  userLpSharesBurned: BN
+  userLpSharesAvailable: BN
+  userLpSharesLocked: BN
+  userLpSharesUnlockedForWithdrawal: BN
+}
+
+export interface RemoveLiquiditySingleSidedEventJSON {
+  tokenOut: types.TokenTypeJSON
+  userAmountWithdrawn: string
+  swapResult: types.SwapResultJSON
+  poolTotalLpShares: string
+  poolTotalBaseLiquidity: string
+  poolTotalQuoteLiquidity: string
+  userLpSharesBurned: string
+  userLpSharesAvailable: string
+  userLpSharesLocked: string
+  userLpSharesUnlockedForWithdrawal: string
+}
+
+export class RemoveLiquiditySingleSidedEvent {
+  readonly tokenOut: types.TokenTypeKind
+  readonly userAmountWithdrawn: BN
+  readonly swapResult: types.SwapResult
+  readonly poolTotalLpShares: BN
+  readonly poolTotalBaseLiquidity: BN
+  readonly poolTotalQuoteLiquidity: BN
+  readonly userLpSharesBurned: BN
+  readonly userLpSharesAvailable: BN
+  readonly userLpSharesLocked: BN
+  readonly userLpSharesUnlockedForWithdrawal: BN
+
+  constructor(fields: RemoveLiquiditySingleSidedEventFields) {
+    this.tokenOut = fields.tokenOut
+    this.userAmountWithdrawn = fields.userAmountWithdrawn
+    this.swapResult = new types.SwapResult({ ...fields.swapResult })
+    this.poolTotalLpShares = fields.poolTotalLpShares
+    this.poolTotalBaseLiquidity = fields.poolTotalBaseLiquidity
+    this.poolTotalQuoteLiquidity = fields.poolTotalQuoteLiquidity
+    this.userLpSharesBurned = fields.userLpSharesBurned
+    this.userLpSharesAvailable = fields.userLpSharesAvailable
+    this.userLpSharesLocked = fields.userLpSharesLocked
+    this.userLpSharesUnlockedForWithdrawal =
+      fields.userLpSharesUnlockedForWithdrawal
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        types.TokenType.layout("tokenOut"),
+        borsh.u64("userAmountWithdrawn"),
+        types.SwapResult.layout("swapResult"),
+        borsh.u64("poolTotalLpShares"),
+        borsh.u64("poolTotalBaseLiquidity"),
+        borsh.u64("poolTotalQuoteLiquidity"),
+        borsh.u64("userLpSharesBurned"),
+        borsh.u64("userLpSharesAvailable"),
+        borsh.u64("userLpSharesLocked"),
+        borsh.u64("userLpSharesUnlockedForWithdrawal"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new RemoveLiquiditySingleSidedEvent({
+      tokenOut: types.TokenType.fromDecoded(obj.tokenOut),
+      userAmountWithdrawn: obj.userAmountWithdrawn,
+      swapResult: types.SwapResult.fromDecoded(obj.swapResult),
+      poolTotalLpShares: obj.poolTotalLpShares,
+      poolTotalBaseLiquidity: obj.poolTotalBaseLiquidity,
+      poolTotalQuoteLiquidity: obj.poolTotalQuoteLiquidity,
+      userLpSharesBurned: obj.userLpSharesBurned,
+      userLpSharesAvailable: obj.userLpSharesAvailable,
+      userLpSharesLocked: obj.userLpSharesLocked,
+      userLpSharesUnlockedForWithdrawal: obj.userLpSharesUnlockedForWithdrawal,
+    })
+  }
+
+  static toEncodable(fields: RemoveLiquiditySingleSidedEventFields) {
+    return {
+      tokenOut: fields.tokenOut.toEncodable(),
+      userAmountWithdrawn: fields.userAmountWithdrawn,
+      swapResult: types.SwapResult.toEncodable(fields.swapResult),
+      poolTotalLpShares: fields.poolTotalLpShares,
+      poolTotalBaseLiquidity: fields.poolTotalBaseLiquidity,
+      poolTotalQuoteLiquidity: fields.poolTotalQuoteLiquidity,
+      userLpSharesBurned: fields.userLpSharesBurned,
+      userLpSharesAvailable: fields.userLpSharesAvailable,
+      userLpSharesLocked: fields.userLpSharesLocked,
+      userLpSharesUnlockedForWithdrawal:
+        fields.userLpSharesUnlockedForWithdrawal,
+    }
+  }
+
+  toJSON(): RemoveLiquiditySingleSidedEventJSON {
+    return {
+      tokenOut: this.tokenOut.toJSON(),
+      userAmountWithdrawn: this.userAmountWithdrawn.toString(),
+      swapResult: this.swapResult.toJSON(),
+      poolTotalLpShares: this.poolTotalLpShares.toString(),
+      poolTotalBaseLiquidity: this.poolTotalBaseLiquidity.toString(),
+      poolTotalQuoteLiquidity: this.poolTotalQuoteLiquidity.toString(),
+      userLpSharesBurned: this.userLpSharesBurned.toString(),
+      userLpSharesAvailable: this.userLpSharesAvailable.toString(),
+      userLpSharesLocked: this.userLpSharesLocked.toString(),
+      userLpSharesUnlockedForWithdrawal:
+        this.userLpSharesUnlockedForWithdrawal.toString(),
+    }
+  }
+
+  static fromJSON(
+    obj: RemoveLiquiditySingleSidedEventJSON
+  ): RemoveLiquiditySingleSidedEvent {
+    return new RemoveLiquiditySingleSidedEvent({
+      tokenOut: types.TokenType.fromJSON(obj.tokenOut),
+      userAmountWithdrawn: new BN(obj.userAmountWithdrawn),
+      swapResult: types.SwapResult.fromJSON(obj.swapResult),
+      poolTotalLpShares: new BN(obj.poolTotalLpShares),
+      poolTotalBaseLiquidity: new BN(obj.poolTotalBaseLiquidity),
+      poolTotalQuoteLiquidity: new BN(obj.poolTotalQuoteLiquidity),
+      userLpSharesBurned: new BN(obj.userLpSharesBurned),
+      userLpSharesAvailable: new BN(obj.userLpSharesAvailable),
+      userLpSharesLocked: new BN(obj.userLpSharesLocked),
+      userLpSharesUnlockedForWithdrawal: new BN(
+        obj.userLpSharesUnlockedForWithdrawal
+      ),
+    })
+  }
+
+  toEncodable() {
+    return RemoveLiquiditySingleSidedEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,91 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface RemoveLiquiditySingleSidedIxParamsFields {
+  tokenOut: types.TokenTypeKind
+  lpShares: BN
+  minAmountOut: BN
+  extension: types.RemoveLiquiditySingleSidedParamsExtensionKind
+}
+
+export interface RemoveLiquiditySingleSidedIxParamsJSON {
+  tokenOut: types.TokenTypeJSON
+  lpShares: string
+  minAmountOut: string
+  extension: types.RemoveLiquiditySingleSidedParamsExtensionJSON
+}
+
+export class RemoveLiquiditySingleSidedIxParams {
+  readonly tokenOut: types.TokenTypeKind
+  readonly lpShares: BN
+  readonly minAmountOut: BN
+  readonly extension: types.RemoveLiquiditySingleSidedParamsExtensionKind
+
+  constructor(fields: RemoveLiquiditySingleSidedIxParamsFields) {
+    this.tokenOut = fields.tokenOut
+    this.lpShares = fields.lpShares
+    this.minAmountOut = fields.minAmountOut
+    this.extension = fields.extension
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        types.TokenType.layout("tokenOut"),
+        borsh.u64("lpShares"),
+        borsh.u64("minAmountOut"),
+        types.RemoveLiquiditySingleSidedParamsExtension.layout("extension"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new RemoveLiquiditySingleSidedIxParams({
+      tokenOut: types.TokenType.fromDecoded(obj.tokenOut),
+      lpShares: obj.lpShares,
+      minAmountOut: obj.minAmountOut,
+      extension: types.RemoveLiquiditySingleSidedParamsExtension.fromDecoded(
+        obj.extension
+      ),
+    })
+  }
+
+  static toEncodable(fields: RemoveLiquiditySingleSidedIxParamsFields) {
+    return {
+      tokenOut: fields.tokenOut.toEncodable(),
+      lpShares: fields.lpShares,
+      minAmountOut: fields.minAmountOut,
+      extension: fields.extension.toEncodable(),
+    }
+  }
+
+  toJSON(): RemoveLiquiditySingleSidedIxParamsJSON {
+    return {
+      tokenOut: this.tokenOut.toJSON(),
+      lpShares: this.lpShares.toString(),
+      minAmountOut: this.minAmountOut.toString(),
+      extension: this.extension.toJSON(),
+    }
+  }
+
+  static fromJSON(
+    obj: RemoveLiquiditySingleSidedIxParamsJSON
+  ): RemoveLiquiditySingleSidedIxParams {
+    return new RemoveLiquiditySingleSidedIxParams({
+      tokenOut: types.TokenType.fromJSON(obj.tokenOut),
+      lpShares: new BN(obj.lpShares),
+      minAmountOut: new BN(obj.minAmountOut),
+      extension: types.RemoveLiquiditySingleSidedParamsExtension.fromJSON(
+        obj.extension
+      ),
+    })
+  }
+
+  toEncodable() {
+    return RemoveLiquiditySingleSidedIxParams.toEncodable(this)
+  }
+}
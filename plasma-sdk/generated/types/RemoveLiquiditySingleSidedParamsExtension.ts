@@ -0,0 +1,133 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface V0JSON {
+  kind: "V0"
+}
+
+export class V0 {
+  static readonly discriminator = 0
+  static readonly kind = "V0"
+  readonly discriminator = 0
+  readonly kind = "V0"
+
+  toJSON(): V0JSON {
+    return {
+      kind: "V0",
+    }
+  }
+
+  toEncodable() {
+    return {
+      V0: {},
+    }
+  }
+}
+
+export type V1Fields = {
+  nativeSol: types.NativeSolParamsFields
+  deadline: types.SlotDeadlineFields
+}
+export type V1Value = {
+  nativeSol: types.NativeSolParams
+  deadline: types.SlotDeadline
+}
+
+export interface V1JSON {
+  kind: "V1"
+  value: {
+    nativeSol: types.NativeSolParamsJSON
+    deadline: types.SlotDeadlineJSON
+  }
+}
+
+export class V1 {
+  static readonly discriminator = 1
+  static readonly kind = "V1"
+  readonly discriminator = 1
+  readonly kind = "V1"
+  readonly value: V1Value
+
+  constructor(value: V1Fields) {
+    this.value = {
+      nativeSol: new types.NativeSolParams({ ...value.nativeSol }),
+      deadline: new types.SlotDeadline({ ...value.deadline }),
+    }
+  }
+
+  toJSON(): V1JSON {
+    return {
+      kind: "V1",
+      value: {
+        nativeSol: this.value.nativeSol.toJSON(),
+        deadline: this.value.deadline.toJSON(),
+      },
+    }
+  }
+
+  toEncodable() {
+    return {
+      V1: {
+        nativeSol: types.NativeSolParams.toEncodable(this.value.nativeSol),
+        deadline: types.SlotDeadline.toEncodable(this.value.deadline),
+      },
+    }
+  }
+}
+
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+export function fromDecoded(
+  obj: any
+): types.RemoveLiquiditySingleSidedParamsExtensionKind {
+  if (typeof obj !== "object") {
+    throw new Error("Invalid enum object")
+  }
+
+  if ("V0" in obj) {
+    return new V0()
+  }
+  if ("V1" in obj) {
+    const val = obj["V1"]
+    return new V1({
+      nativeSol: types.NativeSolParams.fromDecoded(val["nativeSol"]),
+      deadline: types.SlotDeadline.fromDecoded(val["deadline"]),
+    })
+  }
+
+  throw new Error("Invalid enum object")
+}
+
+export function fromJSON(
+  obj: types.RemoveLiquiditySingleSidedParamsExtensionJSON
+): types.RemoveLiquiditySingleSidedParamsExtensionKind {
+  switch (obj.kind) {
+    case "V0": {
+      return new V0()
+    }
+    case "V1": {
+      return new V1({
+        nativeSol: types.NativeSolParams.fromJSON(obj.value.nativeSol),
+        deadline: types.SlotDeadline.fromJSON(obj.value.deadline),
+      })
+    }
+  }
+}
+
+export function layout(property?: string) {
+  const ret = borsh.rustEnum([
+    borsh.struct([], "V0"),
+    borsh.struct(
+      [
+        types.NativeSolParams.layout("nativeSol"),
+        types.SlotDeadline.layout("deadline"),
+      ],
+      "V1"
+    ),
+  ])
+  if (property !== undefined) {
+    return ret.replicate(property)
+  }
+  return ret
+}
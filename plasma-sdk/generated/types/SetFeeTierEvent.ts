@@ -0,0 +1,95 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface SetFeeTierEventFields {
+  lpFeeInBps: BN
+  protocolLpFeeAllocationInPct: BN
+  numSlotsToVestLpShares: BN
+  enabled: boolean
+  isNewFeeTier: boolean
+}
+
+export interface SetFeeTierEventJSON {
+  lpFeeInBps: string
+  protocolLpFeeAllocationInPct: string
+  numSlotsToVestLpShares: string
+  enabled: boolean
+  isNewFeeTier: boolean
+}
+
+export class SetFeeTierEvent {
+  readonly lpFeeInBps: BN
+  readonly protocolLpFeeAllocationInPct: BN
+  readonly numSlotsToVestLpShares: BN
+  readonly enabled: boolean
+  readonly isNewFeeTier: boolean
+
+  constructor(fields: SetFeeTierEventFields) {
+    this.lpFeeInBps = fields.lpFeeInBps
+    this.protocolLpFeeAllocationInPct = fields.protocolLpFeeAllocationInPct
+    this.numSlotsToVestLpShares = fields.numSlotsToVestLpShares
+    this.enabled = fields.enabled
+    this.isNewFeeTier = fields.isNewFeeTier
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u64("lpFeeInBps"),
+        borsh.u64("protocolLpFeeAllocationInPct"),
+        borsh.u64("numSlotsToVestLpShares"),
+        borsh.bool("enabled"),
+        borsh.bool("isNewFeeTier"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new SetFeeTierEvent({
+      lpFeeInBps: obj.lpFeeInBps,
+      protocolLpFeeAllocationInPct: obj.protocolLpFeeAllocationInPct,
+      numSlotsToVestLpShares: obj.numSlotsToVestLpShares,
+      enabled: obj.enabled,
+      isNewFeeTier: obj.isNewFeeTier,
+    })
+  }
+
+  static toEncodable(fields: SetFeeTierEventFields) {
+    return {
+      lpFeeInBps: fields.lpFeeInBps,
+      protocolLpFeeAllocationInPct: fields.protocolLpFeeAllocationInPct,
+      numSlotsToVestLpShares: fields.numSlotsToVestLpShares,
+      enabled: fields.enabled,
+      isNewFeeTier: fields.isNewFeeTier,
+    }
+  }
+
+  toJSON(): SetFeeTierEventJSON {
+    return {
+      lpFeeInBps: this.lpFeeInBps.toString(),
+      protocolLpFeeAllocationInPct:
+        this.protocolLpFeeAllocationInPct.toString(),
+      numSlotsToVestLpShares: this.numSlotsToVestLpShares.toString(),
+      enabled: this.enabled,
+      isNewFeeTier: this.isNewFeeTier,
+    }
+  }
+
+  static fromJSON(obj: SetFeeTierEventJSON): SetFeeTierEvent {
+    return new SetFeeTierEvent({
+      lpFeeInBps: new BN(obj.lpFeeInBps),
+      protocolLpFeeAllocationInPct: new BN(obj.protocolLpFeeAllocationInPct),
+      numSlotsToVestLpShares: new BN(obj.numSlotsToVestLpShares),
+      enabled: obj.enabled,
+      isNewFeeTier: obj.isNewFeeTier,
+    })
+  }
+
+  toEncodable() {
+    return SetFeeTierEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,86 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface SetFeeTierIxParamsFields {
+  lpFeeInBps: BN
+  protocolLpFeeAllocationInPct: BN
+  numSlotsToVestLpShares: BN
+  enabled: boolean
+}
+
+export interface SetFeeTierIxParamsJSON {
+  lpFeeInBps: string
+  protocolLpFeeAllocationInPct: string
+  numSlotsToVestLpShares: string
+  enabled: boolean
+}
+
+export class SetFeeTierIxParams {
+  readonly lpFeeInBps: BN
+  readonly protocolLpFeeAllocationInPct: BN
+  readonly numSlotsToVestLpShares: BN
+  readonly enabled: boolean
+
+  constructor(fields: SetFeeTierIxParamsFields) {
+    this.lpFeeInBps = fields.lpFeeInBps
+    this.protocolLpFeeAllocationInPct = fields.protocolLpFeeAllocationInPct
+    this.numSlotsToVestLpShares = fields.numSlotsToVestLpShares
+    this.enabled = fields.enabled
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u64("lpFeeInBps"),
+        borsh.u64("protocolLpFeeAllocationInPct"),
+        borsh.u64("numSlotsToVestLpShares"),
+        borsh.bool("enabled"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new SetFeeTierIxParams({
+      lpFeeInBps: obj.lpFeeInBps,
+      protocolLpFeeAllocationInPct: obj.protocolLpFeeAllocationInPct,
+      numSlotsToVestLpShares: obj.numSlotsToVestLpShares,
+      enabled: obj.enabled,
+    })
+  }
+
+  static toEncodable(fields: SetFeeTierIxParamsFields) {
+    return {
+      lpFeeInBps: fields.lpFeeInBps,
+      protocolLpFeeAllocationInPct: fields.protocolLpFeeAllocationInPct,
+      numSlotsToVestLpShares: fields.numSlotsToVestLpShares,
+      enabled: fields.enabled,
+    }
+  }
+
+  toJSON(): SetFeeTierIxParamsJSON {
+    return {
+      lpFeeInBps: this.lpFeeInBps.toString(),
+      protocolLpFeeAllocationInPct:
+        this.protocolLpFeeAllocationInPct.toString(),
+      numSlotsToVestLpShares: this.numSlotsToVestLpShares.toString(),
+      enabled: this.enabled,
+    }
+  }
+
+  static fromJSON(obj: SetFeeTierIxParamsJSON): SetFeeTierIxParams {
+    return new SetFeeTierIxParams({
+      lpFeeInBps: new BN(obj.lpFeeInBps),
+      protocolLpFeeAllocationInPct: new BN(obj.protocolLpFeeAllocationInPct),
+      numSlotsToVestLpShares: new BN(obj.numSlotsToVestLpShares),
+      enabled: obj.enabled,
+    })
+  }
+
+  toEncodable() {
+    return SetFeeTierIxParams.toEncodable(this)
+  }
+}
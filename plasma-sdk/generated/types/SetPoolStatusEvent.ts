@@ -0,0 +1,67 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface SetPoolStatusEventFields {
+  previousStatus: types.PoolStatusKind
+  newStatus: types.PoolStatusKind
+}
+
+export interface SetPoolStatusEventJSON {
+  previousStatus: types.PoolStatusJSON
+  newStatus: types.PoolStatusJSON
+}
+
+export class SetPoolStatusEvent {
+  readonly previousStatus: types.PoolStatusKind
+  readonly newStatus: types.PoolStatusKind
+
+  constructor(fields: SetPoolStatusEventFields) {
+    this.previousStatus = fields.previousStatus
+    this.newStatus = fields.newStatus
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        types.PoolStatus.layout("previousStatus"),
+        types.PoolStatus.layout("newStatus"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new SetPoolStatusEvent({
+      previousStatus: types.PoolStatus.fromDecoded(obj.previousStatus),
+      newStatus: types.PoolStatus.fromDecoded(obj.newStatus),
+    })
+  }
+
+  static toEncodable(fields: SetPoolStatusEventFields) {
+    return {
+      previousStatus: fields.previousStatus.toEncodable(),
+      newStatus: fields.newStatus.toEncodable(),
+    }
+  }
+
+  toJSON(): SetPoolStatusEventJSON {
+    return {
+      previousStatus: this.previousStatus.toJSON(),
+      newStatus: this.newStatus.toJSON(),
+    }
+  }
+
+  static fromJSON(obj: SetPoolStatusEventJSON): SetPoolStatusEvent {
+    return new SetPoolStatusEvent({
+      previousStatus: types.PoolStatus.fromJSON(obj.previousStatus),
+      newStatus: types.PoolStatus.fromJSON(obj.newStatus),
+    })
+  }
+
+  toEncodable() {
+    return SetPoolStatusEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,53 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface SetPoolStatusIxParamsFields {
+  status: types.PoolStatusKind
+}
+
+export interface SetPoolStatusIxParamsJSON {
+  status: types.PoolStatusJSON
+}
+
+export class SetPoolStatusIxParams {
+  readonly status: types.PoolStatusKind
+
+  constructor(fields: SetPoolStatusIxParamsFields) {
+    this.status = fields.status
+  }
+
+  static layout(property?: string) {
+    return borsh.struct([types.PoolStatus.layout("status")], property)
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new SetPoolStatusIxParams({
+      status: types.PoolStatus.fromDecoded(obj.status),
+    })
+  }
+
+  static toEncodable(fields: SetPoolStatusIxParamsFields) {
+    return {
+      status: fields.status.toEncodable(),
+    }
+  }
+
+  toJSON(): SetPoolStatusIxParamsJSON {
+    return {
+      status: this.status.toJSON(),
+    }
+  }
+
+  static fromJSON(obj: SetPoolStatusIxParamsJSON): SetPoolStatusIxParams {
+    return new SetPoolStatusIxParams({
+      status: types.PoolStatus.fromJSON(obj.status),
+    })
+  }
+
+  toEncodable() {
+    return SetPoolStatusIxParams.toEncodable(this)
+  }
+}
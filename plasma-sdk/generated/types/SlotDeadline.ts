@@ -0,0 +1,69 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface SlotDeadlineFields {
+  maxSlot: BN | null
+  minSnapshotSlot: BN | null
+}
+
+export interface SlotDeadlineJSON {
+  maxSlot: string | null
+  minSnapshotSlot: string | null
+}
+
+export class SlotDeadline {
+  readonly maxSlot: BN | null
+  readonly minSnapshotSlot: BN | null
+
+  constructor(fields: SlotDeadlineFields) {
+    this.maxSlot = fields.maxSlot
+    this.minSnapshotSlot = fields.minSnapshotSlot
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.option(borsh.u64(), "maxSlot"),
+        borsh.option(borsh.u64(), "minSnapshotSlot"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new SlotDeadline({
+      maxSlot: obj.maxSlot,
+      minSnapshotSlot: obj.minSnapshotSlot,
+    })
+  }
+
+  static toEncodable(fields: SlotDeadlineFields) {
+    return {
+      maxSlot: fields.maxSlot,
+      minSnapshotSlot: fields.minSnapshotSlot,
+    }
+  }
+
+  toJSON(): SlotDeadlineJSON {
+    return {
+      maxSlot: (this.maxSlot && this.maxSlot.toString()) || null,
+      minSnapshotSlot:
+        (this.minSnapshotSlot && this.minSnapshotSlot.toString()) || null,
+    }
+  }
+
+  static fromJSON(obj: SlotDeadlineJSON): SlotDeadline {
+    return new SlotDeadline({
+      maxSlot: (obj.maxSlot && new BN(obj.maxSlot)) || null,
+      minSnapshotSlot:
+        (obj.minSnapshotSlot && new BN(obj.minSnapshotSlot)) || null,
+    })
+  }
+
+  toEncodable() {
+    return SlotDeadline.toEncodable(this)
+  }
+}
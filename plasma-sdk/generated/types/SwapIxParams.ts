@@ -6,25 +6,33 @@ import * as borsh from "@coral-xyz/borsh"
 export interface SwapIxParamsFields {
   side: types.SideKind
   swapType: types.SwapTypeKind
+  extension: types.SwapParamsExtensionKind
 }
 
 export interface SwapIxParamsJSON {
   side: types.SideJSON
   swapType: types.SwapTypeJSON
+  extension: types.SwapParamsExtensionJSON
 }
 
 export class SwapIxParams {
   readonly side: types.SideKind
   readonly swapType: types.SwapTypeKind
+  readonly extension: types.SwapParamsExtensionKind
 
   constructor(fields: SwapIxParamsFields) {
     this.side = fields.side
     this.swapType = fields.swapType
+    this.extension = fields.extension
   }
 
   static layout(property?: string) {
     return borsh.struct(
-      [types.Side.layout("side"), types.SwapType.layout("swapType")],
+      [
+        types.Side.layout("side"),
+        types.SwapType.layout("swapType"),
+        types.SwapParamsExtension.layout("extension"),
+      ],
       property
     )
   }
@@ -34,6 +42,7 @@ export class SwapIxParams {
     return new SwapIxParams({
       side: types.Side.fromDecoded(obj.side),
       swapType: types.SwapType.fromDecoded(obj.swapType),
+      extension: types.SwapParamsExtension.fromDecoded(obj.extension),
     })
   }
 
@@ -41,6 +50,7 @@ export class SwapIxParams {
     return {
       side: fields.side.toEncodable(),
       swapType: fields.swapType.toEncodable(),
+      extension: fields.extension.toEncodable(),
     }
   }
 
@@ -48,6 +58,7 @@ export class SwapIxParams {
     return {
       side: this.side.toJSON(),
       swapType: this.swapType.toJSON(),
+      extension: this.extension.toJSON(),
     }
   }
 
@@ -55,6 +66,7 @@ export class SwapIxParams {
     return new SwapIxParams({
       side: types.Side.fromJSON(obj.side),
       swapType: types.SwapType.fromJSON(obj.swapType),
+      extension: types.SwapParamsExtension.fromJSON(obj.extension),
     })
   }
 
@@ -0,0 +1,80 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface SwapRouteIxParamsFields {
+  sides: Array<types.SideKind>
+  swapType: types.SwapTypeKind
+  extension: types.SwapRouteParamsExtensionKind
+}
+
+export interface SwapRouteIxParamsJSON {
+  sides: Array<types.SideJSON>
+  swapType: types.SwapTypeJSON
+  extension: types.SwapRouteParamsExtensionJSON
+}
+
+export class SwapRouteIxParams {
+  readonly sides: Array<types.SideKind>
+  readonly swapType: types.SwapTypeKind
+  readonly extension: types.SwapRouteParamsExtensionKind
+
+  constructor(fields: SwapRouteIxParamsFields) {
+    this.sides = fields.sides
+    this.swapType = fields.swapType
+    this.extension = fields.extension
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.vec(types.Side.layout(), "sides"),
+        types.SwapType.layout("swapType"),
+        types.SwapRouteParamsExtension.layout("extension"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new SwapRouteIxParams({
+      sides: obj.sides.map(
+        (
+          item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
+        ) => types.Side.fromDecoded(item)
+      ),
+      swapType: types.SwapType.fromDecoded(obj.swapType),
+      extension: types.SwapRouteParamsExtension.fromDecoded(obj.extension),
+    })
+  }
+
+  static toEncodable(fields: SwapRouteIxParamsFields) {
+    return {
+      sides: fields.sides.map((item) => item.toEncodable()),
+      swapType: fields.swapType.toEncodable(),
+      extension: fields.extension.toEncodable(),
+    }
+  }
+
+  toJSON(): SwapRouteIxParamsJSON {
+    return {
+      sides: this.sides.map((item) => item.toJSON()),
+      swapType: this.swapType.toJSON(),
+      extension: this.extension.toJSON(),
+    }
+  }
+
+  static fromJSON(obj: SwapRouteIxParamsJSON): SwapRouteIxParams {
+    return new SwapRouteIxParams({
+      sides: obj.sides.map((item) => types.Side.fromJSON(item)),
+      swapType: types.SwapType.fromJSON(obj.swapType),
+      extension: types.SwapRouteParamsExtension.fromJSON(obj.extension),
+    })
+  }
+
+  toEncodable() {
+    return SwapRouteIxParams.toEncodable(this)
+  }
+}
@@ -0,0 +1,53 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface TokenizeLpSharesIxParamsFields {
+  lpShares: BN
+}
+
+export interface TokenizeLpSharesIxParamsJSON {
+  lpShares: string
+}
+
+export class TokenizeLpSharesIxParams {
+  readonly lpShares: BN
+
+  constructor(fields: TokenizeLpSharesIxParamsFields) {
+    this.lpShares = fields.lpShares
+  }
+
+  static layout(property?: string) {
+    return borsh.struct([borsh.u64("lpShares")], property)
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new TokenizeLpSharesIxParams({
+      lpShares: obj.lpShares,
+    })
+  }
+
+  static toEncodable(fields: TokenizeLpSharesIxParamsFields) {
+    return {
+      lpShares: fields.lpShares,
+    }
+  }
+
+  toJSON(): TokenizeLpSharesIxParamsJSON {
+    return {
+      lpShares: this.lpShares.toString(),
+    }
+  }
+
+  static fromJSON(obj: TokenizeLpSharesIxParamsJSON): TokenizeLpSharesIxParams {
+    return new TokenizeLpSharesIxParams({
+      lpShares: new BN(obj.lpShares),
+    })
+  }
+
+  toEncodable() {
+    return TokenizeLpSharesIxParams.toEncodable(this)
+  }
+}
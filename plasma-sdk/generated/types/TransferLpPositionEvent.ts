@@ -0,0 +1,112 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface TransferLpPositionEventFields {
+  recipient: PublicKey
+  recipientLpPositionCreated: boolean
+  lpSharesTransferred: BN
+  lpSharesVestingTransferred: BN
+  quoteFeesTransferred: BN
+  recipientLpSharesAvailable: BN
+  recipientLpSharesLocked: BN
+}
+
+export interface TransferLpPositionEventJSON {
+  recipient: string
+  recipientLpPositionCreated: boolean
+  lpSharesTransferred: string
+  lpSharesVestingTransferred: string
+  quoteFeesTransferred: string
+  recipientLpSharesAvailable: string
+  recipientLpSharesLocked: string
+}
+
+export class TransferLpPositionEvent {
+  readonly recipient: PublicKey
+  readonly recipientLpPositionCreated: boolean
+  readonly lpSharesTransferred: BN
+  readonly lpSharesVestingTransferred: BN
+  readonly quoteFeesTransferred: BN
+  readonly recipientLpSharesAvailable: BN
+  readonly recipientLpSharesLocked: BN
+
+  constructor(fields: TransferLpPositionEventFields) {
+    this.recipient = fields.recipient
+    this.recipientLpPositionCreated = fields.recipientLpPositionCreated
+    this.lpSharesTransferred = fields.lpSharesTransferred
+    this.lpSharesVestingTransferred = fields.lpSharesVestingTransferred
+    this.quoteFeesTransferred = fields.quoteFeesTransferred
+    this.recipientLpSharesAvailable = fields.recipientLpSharesAvailable
+    this.recipientLpSharesLocked = fields.recipientLpSharesLocked
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.publicKey("recipient"),
+        borsh.bool("recipientLpPositionCreated"),
+        borsh.u64("lpSharesTransferred"),
+        borsh.u64("lpSharesVestingTransferred"),
+        borsh.u64("quoteFeesTransferred"),
+        borsh.u64("recipientLpSharesAvailable"),
+        borsh.u64("recipientLpSharesLocked"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new TransferLpPositionEvent({
+      recipient: obj.recipient,
+      recipientLpPositionCreated: obj.recipientLpPositionCreated,
+      lpSharesTransferred: obj.lpSharesTransferred,
+      lpSharesVestingTransferred: obj.lpSharesVestingTransferred,
+      quoteFeesTransferred: obj.quoteFeesTransferred,
+      recipientLpSharesAvailable: obj.recipientLpSharesAvailable,
+      recipientLpSharesLocked: obj.recipientLpSharesLocked,
+    })
+  }
+
+  static toEncodable(fields: TransferLpPositionEventFields) {
+    return {
+      recipient: fields.recipient,
+      recipientLpPositionCreated: fields.recipientLpPositionCreated,
+      lpSharesTransferred: fields.lpSharesTransferred,
+      lpSharesVestingTransferred: fields.lpSharesVestingTransferred,
+      quoteFeesTransferred: fields.quoteFeesTransferred,
+      recipientLpSharesAvailable: fields.recipientLpSharesAvailable,
+      recipientLpSharesLocked: fields.recipientLpSharesLocked,
+    }
+  }
+
+  toJSON(): TransferLpPositionEventJSON {
+    return {
+      recipient: this.recipient.toString(),
+      recipientLpPositionCreated: this.recipientLpPositionCreated,
+      lpSharesTransferred: this.lpSharesTransferred.toString(),
+      lpSharesVestingTransferred: this.lpSharesVestingTransferred.toString(),
+      quoteFeesTransferred: this.quoteFeesTransferred.toString(),
+      recipientLpSharesAvailable: this.recipientLpSharesAvailable.toString(),
+      recipientLpSharesLocked: this.recipientLpSharesLocked.toString(),
+    }
+  }
+
+  static fromJSON(obj: TransferLpPositionEventJSON): TransferLpPositionEvent {
+    return new TransferLpPositionEvent({
+      recipient: new PublicKey(obj.recipient),
+      recipientLpPositionCreated: obj.recipientLpPositionCreated,
+      lpSharesTransferred: new BN(obj.lpSharesTransferred),
+      lpSharesVestingTransferred: new BN(obj.lpSharesVestingTransferred),
+      quoteFeesTransferred: new BN(obj.quoteFeesTransferred),
+      recipientLpSharesAvailable: new BN(obj.recipientLpSharesAvailable),
+      recipientLpSharesLocked: new BN(obj.recipientLpSharesLocked),
+    })
+  }
+
+  toEncodable() {
+    return TransferLpPositionEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,87 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface TransferProtocolFeeRecipientEventFields {
+  previousRecipient: PublicKey
+  newRecipient: PublicKey
+  shares: BN
+  uncollectedQuoteFees: BN
+}
+
+export interface TransferProtocolFeeRecipientEventJSON {
+  previousRecipient: string
+  newRecipient: string
+  shares: string
+  uncollectedQuoteFees: string
+}
+
+export class TransferProtocolFeeRecipientEvent {
+  readonly previousRecipient: PublicKey
+  readonly newRecipient: PublicKey
+  readonly shares: BN
+  readonly uncollectedQuoteFees: BN
+
+  constructor(fields: TransferProtocolFeeRecipientEventFields) {
+    this.previousRecipient = fields.previousRecipient
+    this.newRecipient = fields.newRecipient
+    this.shares = fields.shares
+    this.uncollectedQuoteFees = fields.uncollectedQuoteFees
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.publicKey("previousRecipient"),
+        borsh.publicKey("newRecipient"),
+        borsh.u64("shares"),
+        borsh.u64("uncollectedQuoteFees"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new TransferProtocolFeeRecipientEvent({
+      previousRecipient: obj.previousRecipient,
+      newRecipient: obj.newRecipient,
+      shares: obj.shares,
+      uncollectedQuoteFees: obj.uncollectedQuoteFees,
+    })
+  }
+
+  static toEncodable(fields: TransferProtocolFeeRecipientEventFields) {
+    return {
+      previousRecipient: fields.previousRecipient,
+      newRecipient: fields.newRecipient,
+      shares: fields.shares,
+      uncollectedQuoteFees: fields.uncollectedQuoteFees,
+    }
+  }
+
+  toJSON(): TransferProtocolFeeRecipientEventJSON {
+    return {
+      previousRecipient: this.previousRecipient.toString(),
+      newRecipient: this.newRecipient.toString(),
+      shares: this.shares.toString(),
+      uncollectedQuoteFees: this.uncollectedQuoteFees.toString(),
+    }
+  }
+
+  static fromJSON(
+    obj: TransferProtocolFeeRecipientEventJSON
+  ): TransferProtocolFeeRecipientEvent {
+    return new TransferProtocolFeeRecipientEvent({
+      previousRecipient: new PublicKey(obj.previousRecipient),
+      newRecipient: new PublicKey(obj.newRecipient),
+      shares: new BN(obj.shares),
+      uncollectedQuoteFees: new BN(obj.uncollectedQuoteFees),
+    })
+  }
+
+  toEncodable() {
+    return TransferProtocolFeeRecipientEvent.toEncodable(this)
+  }
+}
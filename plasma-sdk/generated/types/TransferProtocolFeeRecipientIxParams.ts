@@ -0,0 +1,55 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface TransferProtocolFeeRecipientIxParamsFields {
+  newRecipient: PublicKey
+}
+
+export interface TransferProtocolFeeRecipientIxParamsJSON {
+  newRecipient: string
+}
+
+export class TransferProtocolFeeRecipientIxParams {
+  readonly newRecipient: PublicKey
+
+  constructor(fields: TransferProtocolFeeRecipientIxParamsFields) {
+    this.newRecipient = fields.newRecipient
+  }
+
+  static layout(property?: string) {
+    return borsh.struct([borsh.publicKey("newRecipient")], property)
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new TransferProtocolFeeRecipientIxParams({
+      newRecipient: obj.newRecipient,
+    })
+  }
+
+  static toEncodable(fields: TransferProtocolFeeRecipientIxParamsFields) {
+    return {
+      newRecipient: fields.newRecipient,
+    }
+  }
+
+  toJSON(): TransferProtocolFeeRecipientIxParamsJSON {
+    return {
+      newRecipient: this.newRecipient.toString(),
+    }
+  }
+
+  static fromJSON(
+    obj: TransferProtocolFeeRecipientIxParamsJSON
+  ): TransferProtocolFeeRecipientIxParams {
+    return new TransferProtocolFeeRecipientIxParams({
+      newRecipient: new PublicKey(obj.newRecipient),
+    })
+  }
+
+  toEncodable() {
+    return TransferProtocolFeeRecipientIxParams.toEncodable(this)
+  }
+}
@@ -0,0 +1,179 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface UpdatePoolConfigEventFields {
+  oldLpFeeInBps: BN
+  newLpFeeInBps: BN
+  oldProtocolFeeInPct: BN
+  newProtocolFeeInPct: BN
+  oldLpVestingWindow: BN
+  newLpVestingWindow: BN
+  oldFeeRecipientParams: Array<types.ProtocolFeeRecipientParamsFields>
+  newFeeRecipientParams: Array<types.ProtocolFeeRecipientParamsFields>
+  oldLpVestingSchedule: types.VestingScheduleKind
+  newLpVestingSchedule: types.VestingScheduleKind
+}
+
+export interface UpdatePoolConfigEventJSON {
+  oldLpFeeInBps: string
+  newLpFeeInBps: string
+  oldProtocolFeeInPct: string
+  newProtocolFeeInPct: string
+  oldLpVestingWindow: string
+  newLpVestingWindow: string
+  oldFeeRecipientParams: Array<types.ProtocolFeeRecipientParamsJSON>
+  newFeeRecipientParams: Array<types.ProtocolFeeRecipientParamsJSON>
+  oldLpVestingSchedule: types.VestingScheduleJSON
+  newLpVestingSchedule: types.VestingScheduleJSON
+}
+
+export class UpdatePoolConfigEvent {
+  readonly oldLpFeeInBps: BN
+  readonly newLpFeeInBps: BN
+  readonly oldProtocolFeeInPct: BN
+  readonly newProtocolFeeInPct: BN
+  readonly oldLpVestingWindow: BN
+  readonly newLpVestingWindow: BN
+  readonly oldFeeRecipientParams: Array<types.ProtocolFeeRecipientParams>
+  readonly newFeeRecipientParams: Array<types.ProtocolFeeRecipientParams>
+  readonly oldLpVestingSchedule: types.VestingScheduleKind
+  readonly newLpVestingSchedule: types.VestingScheduleKind
+
+  constructor(fields: UpdatePoolConfigEventFields) {
+    this.oldLpFeeInBps = fields.oldLpFeeInBps
+    this.newLpFeeInBps = fields.newLpFeeInBps
+    this.oldProtocolFeeInPct = fields.oldProtocolFeeInPct
+    this.newProtocolFeeInPct = fields.newProtocolFeeInPct
+    this.oldLpVestingWindow = fields.oldLpVestingWindow
+    this.newLpVestingWindow = fields.newLpVestingWindow
+    this.oldFeeRecipientParams = fields.oldFeeRecipientParams.map(
+      (item) => new types.ProtocolFeeRecipientParams({ ...item })
+    )
+    this.newFeeRecipientParams = fields.newFeeRecipientParams.map(
+      (item) => new types.ProtocolFeeRecipientParams({ ...item })
+    )
+    this.oldLpVestingSchedule = fields.oldLpVestingSchedule
+    this.newLpVestingSchedule = fields.newLpVestingSchedule
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u64("oldLpFeeInBps"),
+        borsh.u64("newLpFeeInBps"),
+        borsh.u64("oldProtocolFeeInPct"),
+        borsh.u64("newProtocolFeeInPct"),
+        borsh.u64("oldLpVestingWindow"),
+        borsh.u64("newLpVestingWindow"),
+        borsh.array(
+          types.ProtocolFeeRecipientParams.layout(),
+          3,
+          "oldFeeRecipientParams"
+        ),
+        borsh.array(
+          types.ProtocolFeeRecipientParams.layout(),
+          3,
+          "newFeeRecipientParams"
+        ),
+        types.VestingSchedule.layout("oldLpVestingSchedule"),
+        types.VestingSchedule.layout("newLpVestingSchedule"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new UpdatePoolConfigEvent({
+      oldLpFeeInBps: obj.oldLpFeeInBps,
+      newLpFeeInBps: obj.newLpFeeInBps,
+      oldProtocolFeeInPct: obj.oldProtocolFeeInPct,
+      newProtocolFeeInPct: obj.newProtocolFeeInPct,
+      oldLpVestingWindow: obj.oldLpVestingWindow,
+      newLpVestingWindow: obj.newLpVestingWindow,
+      oldFeeRecipientParams: obj.oldFeeRecipientParams.map(
+        (
+          item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
+        ) => types.ProtocolFeeRecipientParams.fromDecoded(item)
+      ),
+      newFeeRecipientParams: obj.newFeeRecipientParams.map(
+        (
+          item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
+        ) => types.ProtocolFeeRecipientParams.fromDecoded(item)
+      ),
+      oldLpVestingSchedule: types.VestingSchedule.fromDecoded(
+        obj.oldLpVestingSchedule
+      ),
+      newLpVestingSchedule: types.VestingSchedule.fromDecoded(
+        obj.newLpVestingSchedule
+      ),
+    })
+  }
+
+  static toEncodable(fields: UpdatePoolConfigEventFields) {
+    return {
+      oldLpFeeInBps: fields.oldLpFeeInBps,
+      newLpFeeInBps: fields.newLpFeeInBps,
+      oldProtocolFeeInPct: fields.oldProtocolFeeInPct,
+      newProtocolFeeInPct: fields.newProtocolFeeInPct,
+      oldLpVestingWindow: fields.oldLpVestingWindow,
+      newLpVestingWindow: fields.newLpVestingWindow,
+      oldFeeRecipientParams: fields.oldFeeRecipientParams.map((item) =>
+        types.ProtocolFeeRecipientParams.toEncodable(item)
+      ),
+      newFeeRecipientParams: fields.newFeeRecipientParams.map((item) =>
+        types.ProtocolFeeRecipientParams.toEncodable(item)
+      ),
+      oldLpVestingSchedule: fields.oldLpVestingSchedule.toEncodable(),
+      newLpVestingSchedule: fields.newLpVestingSchedule.toEncodable(),
+    }
+  }
+
+  toJSON(): UpdatePoolConfigEventJSON {
+    return {
+      oldLpFeeInBps: this.oldLpFeeInBps.toString(),
+      newLpFeeInBps: this.newLpFeeInBps.toString(),
+      oldProtocolFeeInPct: this.oldProtocolFeeInPct.toString(),
+      newProtocolFeeInPct: this.newProtocolFeeInPct.toString(),
+      oldLpVestingWindow: this.oldLpVestingWindow.toString(),
+      newLpVestingWindow: this.newLpVestingWindow.toString(),
+      oldFeeRecipientParams: this.oldFeeRecipientParams.map((item) =>
+        item.toJSON()
+      ),
+      newFeeRecipientParams: this.newFeeRecipientParams.map((item) =>
+        item.toJSON()
+      ),
+      oldLpVestingSchedule: this.oldLpVestingSchedule.toJSON(),
+      newLpVestingSchedule: this.newLpVestingSchedule.toJSON(),
+    }
+  }
+
+  static fromJSON(obj: UpdatePoolConfigEventJSON): UpdatePoolConfigEvent {
+    return new UpdatePoolConfigEvent({
+      oldLpFeeInBps: new BN(obj.oldLpFeeInBps),
+      newLpFeeInBps: new BN(obj.newLpFeeInBps),
+      oldProtocolFeeInPct: new BN(obj.oldProtocolFeeInPct),
+      newProtocolFeeInPct: new BN(obj.newProtocolFeeInPct),
+      oldLpVestingWindow: new BN(obj.oldLpVestingWindow),
+      newLpVestingWindow: new BN(obj.newLpVestingWindow),
+      oldFeeRecipientParams: obj.oldFeeRecipientParams.map((item) =>
+        types.ProtocolFeeRecipientParams.fromJSON(item)
+      ),
+      newFeeRecipientParams: obj.newFeeRecipientParams.map((item) =>
+        types.ProtocolFeeRecipientParams.fromJSON(item)
+      ),
+      oldLpVestingSchedule: types.VestingSchedule.fromJSON(
+        obj.oldLpVestingSchedule
+      ),
+      newLpVestingSchedule: types.VestingSchedule.fromJSON(
+        obj.newLpVestingSchedule
+      ),
+    })
+  }
+
+  toEncodable() {
+    return UpdatePoolConfigEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,142 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface UpdatePoolConfigIxParamsFields {
+  lpFeeInBps: BN | null
+  protocolLpFeeAllocationInPct: BN | null
+  numSlotsToVestLpShares: BN | null
+  feeRecipientsParams: Array<types.ProtocolFeeRecipientParamsFields> | null
+  lpVestingSchedule: types.VestingScheduleKind | null
+}
+
+export interface UpdatePoolConfigIxParamsJSON {
+  lpFeeInBps: string | null
+  protocolLpFeeAllocationInPct: string | null
+  numSlotsToVestLpShares: string | null
+  feeRecipientsParams: Array<types.ProtocolFeeRecipientParamsJSON> | null
+  lpVestingSchedule: types.VestingScheduleJSON | null
+}
+
+export class UpdatePoolConfigIxParams {
+  readonly lpFeeInBps: BN | null
+  readonly protocolLpFeeAllocationInPct: BN | null
+  readonly numSlotsToVestLpShares: BN | null
+  readonly feeRecipientsParams: Array<types.ProtocolFeeRecipientParams> | null
+  readonly lpVestingSchedule: types.VestingScheduleKind | null
+
+  constructor(fields: UpdatePoolConfigIxParamsFields) {
+    this.lpFeeInBps = fields.lpFeeInBps
+    this.protocolLpFeeAllocationInPct = fields.protocolLpFeeAllocationInPct
+    this.numSlotsToVestLpShares = fields.numSlotsToVestLpShares
+    this.feeRecipientsParams =
+      (fields.feeRecipientsParams &&
+        fields.feeRecipientsParams.map(
+          (item) => new types.ProtocolFeeRecipientParams({ ...item })
+        )) ||
+      null
+    this.lpVestingSchedule = fields.lpVestingSchedule
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.option(borsh.u64(), "lpFeeInBps"),
+        borsh.option(borsh.u64(), "protocolLpFeeAllocationInPct"),
+        borsh.option(borsh.u64(), "numSlotsToVestLpShares"),
+        borsh.option(
+          borsh.array(types.ProtocolFeeRecipientParams.layout(), 3),
+          "feeRecipientsParams"
+        ),
+        borsh.option(types.VestingSchedule.layout(), "lpVestingSchedule"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new UpdatePoolConfigIxParams({
+      lpFeeInBps: obj.lpFeeInBps,
+      protocolLpFeeAllocationInPct: obj.protocolLpFeeAllocationInPct,
+      numSlotsToVestLpShares: obj.numSlotsToVestLpShares,
+      feeRecipientsParams:
+        (obj.feeRecipientsParams &&
+          obj.feeRecipientsParams.map(
+            (
+              item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
+            ) => types.ProtocolFeeRecipientParams.fromDecoded(item)
+          )) ||
+        null,
+      lpVestingSchedule:
+        (obj.lpVestingSchedule &&
+          types.VestingSchedule.fromDecoded(obj.lpVestingSchedule)) ||
+        null,
+    })
+  }
+
+  static toEncodable(fields: UpdatePoolConfigIxParamsFields) {
+    return {
+      lpFeeInBps: fields.lpFeeInBps,
+      protocolLpFeeAllocationInPct: fields.protocolLpFeeAllocationInPct,
+      numSlotsToVestLpShares: fields.numSlotsToVestLpShares,
+      feeRecipientsParams:
+        (fields.feeRecipientsParams &&
+          fields.feeRecipientsParams.map((item) =>
+            types.ProtocolFeeRecipientParams.toEncodable(item)
+          )) ||
+        null,
+      lpVestingSchedule:
+        (fields.lpVestingSchedule && fields.lpVestingSchedule.toEncodable()) ||
+        null,
+    }
+  }
+
+  toJSON(): UpdatePoolConfigIxParamsJSON {
+    return {
+      lpFeeInBps: (this.lpFeeInBps && this.lpFeeInBps.toString()) || null,
+      protocolLpFeeAllocationInPct:
+        (this.protocolLpFeeAllocationInPct &&
+          this.protocolLpFeeAllocationInPct.toString()) ||
+        null,
+      numSlotsToVestLpShares:
+        (this.numSlotsToVestLpShares &&
+          this.numSlotsToVestLpShares.toString()) ||
+        null,
+      feeRecipientsParams:
+        (this.feeRecipientsParams &&
+          this.feeRecipientsParams.map((item) => item.toJSON())) ||
+        null,
+      lpVestingSchedule:
+        (this.lpVestingSchedule && this.lpVestingSchedule.toJSON()) || null,
+    }
+  }
+
+  static fromJSON(obj: UpdatePoolConfigIxParamsJSON): UpdatePoolConfigIxParams {
+    return new UpdatePoolConfigIxParams({
+      lpFeeInBps: (obj.lpFeeInBps && new BN(obj.lpFeeInBps)) || null,
+      protocolLpFeeAllocationInPct:
+        (obj.protocolLpFeeAllocationInPct &&
+          new BN(obj.protocolLpFeeAllocationInPct)) ||
+        null,
+      numSlotsToVestLpShares:
+        (obj.numSlotsToVestLpShares && new BN(obj.numSlotsToVestLpShares)) ||
+        null,
+      feeRecipientsParams:
+        (obj.feeRecipientsParams &&
+          obj.feeRecipientsParams.map((item) =>
+            types.ProtocolFeeRecipientParams.fromJSON(item)
+          )) ||
+        null,
+      lpVestingSchedule:
+        (obj.lpVestingSchedule &&
+          types.VestingSchedule.fromJSON(obj.lpVestingSchedule)) ||
+        null,
+    })
+  }
+
+  toEncodable() {
+    return UpdatePoolConfigIxParams.toEncodable(this)
+  }
+}
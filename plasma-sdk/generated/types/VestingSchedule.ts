@@ -0,0 +1,90 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface CliffJSON {
+  kind: "Cliff"
+}
+
+export class Cliff {
+  static readonly discriminator = 0
+  static readonly kind = "Cliff"
+  readonly discriminator = 0
+  readonly kind = "Cliff"
+
+  toJSON(): CliffJSON {
+    return {
+      kind: "Cliff",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Cliff: {},
+    }
+  }
+}
+
+export interface LinearJSON {
+  kind: "Linear"
+}
+
+export class Linear {
+  static readonly discriminator = 1
+  static readonly kind = "Linear"
+  readonly discriminator = 1
+  readonly kind = "Linear"
+
+  toJSON(): LinearJSON {
+    return {
+      kind: "Linear",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Linear: {},
+    }
+  }
+}
+
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+export function fromDecoded(obj: any): types.VestingScheduleKind {
+  if (typeof obj !== "object") {
+    throw new Error("Invalid enum object")
+  }
+
+  if ("Cliff" in obj) {
+    return new Cliff()
+  }
+  if ("Linear" in obj) {
+    return new Linear()
+  }
+
+  throw new Error("Invalid enum object")
+}
+
+export function fromJSON(
+  obj: types.VestingScheduleJSON
+): types.VestingScheduleKind {
+  switch (obj.kind) {
+    case "Cliff": {
+      return new Cliff()
+    }
+    case "Linear": {
+      return new Linear()
+    }
+  }
+}
+
+export function layout(property?: string) {
+  const ret = borsh.rustEnum([
+    borsh.struct([], "Cliff"),
+    borsh.struct([], "Linear"),
+  ])
+  if (property !== undefined) {
+    return ret.replicate(property)
+  }
+  return ret
+}
@@ -1,5 +1,15 @@
+import * as AddLiquidityParamsExtension from "./AddLiquidityParamsExtension"
+import * as RemoveLiquidityParamsExtension from "./RemoveLiquidityParamsExtension"
+import * as SwapParamsExtension from "./SwapParamsExtension"
+import * as SwapRouteParamsExtension from "./SwapRouteParamsExtension"
+import * as AddLiquiditySingleSidedParamsExtension from "./AddLiquiditySingleSidedParamsExtension"
+import * as RemoveLiquiditySingleSidedParamsExtension from "./RemoveLiquiditySingleSidedParamsExtension"
 import * as Side from "./Side"
+import * as TokenType from "./TokenType"
 import * as SwapType from "./SwapType"
+import * as PoolStatus from "./PoolStatus"
+import * as VestingSchedule from "./VestingSchedule"
+import * as RemoveLiquidityMode from "./RemoveLiquidityMode"
 import * as PlasmaEvent from "./PlasmaEvent"
 
 export { SwapResult } from "./SwapResult"
@@ -20,6 +30,13 @@ export { PoolHeader } from "./PoolHeader"
 export type { PoolHeaderFields, PoolHeaderJSON } from "./PoolHeader"
 export { Amm } from "./Amm"
 export type { AmmFields, AmmJSON } from "./Amm"
+export { Observation } from "./Observation"
+export type { ObservationFields, ObservationJSON } from "./Observation"
+export { ObservationBufferHeader } from "./ObservationBufferHeader"
+export type {
+  ObservationBufferHeaderFields,
+  ObservationBufferHeaderJSON,
+} from "./ObservationBufferHeader"
 export { LpPosition } from "./LpPosition"
 export type { LpPositionFields, LpPositionJSON } from "./LpPosition"
 export { PendingSharesToVest } from "./PendingSharesToVest"
@@ -27,6 +44,8 @@ export type {
   PendingSharesToVestFields,
   PendingSharesToVestJSON,
 } from "./PendingSharesToVest"
+export { FeeTier } from "./FeeTier"
+export type { FeeTierFields, FeeTierJSON } from "./FeeTier"
 export { InitializePoolIxParams } from "./InitializePoolIxParams"
 export type {
   InitializePoolIxParamsFields,
@@ -37,18 +56,140 @@ export type {
   AddLiquidityIxParamsFields,
   AddLiquidityIxParamsJSON,
 } from "./AddLiquidityIxParams"
+export { AddLiquidityParamsExtension }
+
+export type AddLiquidityParamsExtensionKind =
+  | AddLiquidityParamsExtension.V0
+  | AddLiquidityParamsExtension.V1
+export type AddLiquidityParamsExtensionJSON =
+  | AddLiquidityParamsExtension.V0JSON
+  | AddLiquidityParamsExtension.V1JSON
+
+
 export { RemoveLiquidityIxParams } from "./RemoveLiquidityIxParams"
 export type {
   RemoveLiquidityIxParamsFields,
   RemoveLiquidityIxParamsJSON,
 } from "./RemoveLiquidityIxParams"
+export { RemoveLiquidityParamsExtension }
+
+export type RemoveLiquidityParamsExtensionKind =
+  | RemoveLiquidityParamsExtension.V0
+  | RemoveLiquidityParamsExtension.V1
+export type RemoveLiquidityParamsExtensionJSON =
+  | RemoveLiquidityParamsExtension.V0JSON
+  | RemoveLiquidityParamsExtension.V1JSON
+
+
 export { SwapIxParams } from "./SwapIxParams"
 export type { SwapIxParamsFields, SwapIxParamsJSON } from "./SwapIxParams"
+export { SwapParamsExtension }
+
+export type SwapParamsExtensionKind =
+  | SwapParamsExtension.V0
+  | SwapParamsExtension.V1
+export type SwapParamsExtensionJSON =
+  | SwapParamsExtension.V0JSON
+  | SwapParamsExtension.V1JSON
+
+
+export { NativeSolParams } from "./NativeSolParams"
+export type {
+  NativeSolParamsFields,
+  NativeSolParamsJSON,
+} from "./NativeSolParams"
+export { SlotDeadline } from "./SlotDeadline"
+export type { SlotDeadlineFields, SlotDeadlineJSON } from "./SlotDeadline"
+export { SwapRouteIxParams } from "./SwapRouteIxParams"
+export type {
+  SwapRouteIxParamsFields,
+  SwapRouteIxParamsJSON,
+} from "./SwapRouteIxParams"
+export { SwapRouteParamsExtension }
+
+export type SwapRouteParamsExtensionKind =
+  | SwapRouteParamsExtension.V0
+  | SwapRouteParamsExtension.V1
+export type SwapRouteParamsExtensionJSON =
+  | SwapRouteParamsExtension.V0JSON
+  | SwapRouteParamsExtension.V1JSON
+
+
 export { RenounceLiquidityIxParams } from "./RenounceLiquidityIxParams"
 export type {
   RenounceLiquidityIxParamsFields,
   RenounceLiquidityIxParamsJSON,
 } from "./RenounceLiquidityIxParams"
+export { GrowObservationsIxParams } from "./GrowObservationsIxParams"
+export type {
+  GrowObservationsIxParamsFields,
+  GrowObservationsIxParamsJSON,
+} from "./GrowObservationsIxParams"
+export { UpdatePoolConfigIxParams } from "./UpdatePoolConfigIxParams"
+export type {
+  UpdatePoolConfigIxParamsFields,
+  UpdatePoolConfigIxParamsJSON,
+} from "./UpdatePoolConfigIxParams"
+export { TransferProtocolFeeRecipientIxParams } from "./TransferProtocolFeeRecipientIxParams"
+export type {
+  TransferProtocolFeeRecipientIxParamsFields,
+  TransferProtocolFeeRecipientIxParamsJSON,
+} from "./TransferProtocolFeeRecipientIxParams"
+export { SetPoolStatusIxParams } from "./SetPoolStatusIxParams"
+export type {
+  SetPoolStatusIxParamsFields,
+  SetPoolStatusIxParamsJSON,
+} from "./SetPoolStatusIxParams"
+export { SetFeeTierIxParams } from "./SetFeeTierIxParams"
+export type {
+  SetFeeTierIxParamsFields,
+  SetFeeTierIxParamsJSON,
+} from "./SetFeeTierIxParams"
+export { TokenizeLpSharesIxParams } from "./TokenizeLpSharesIxParams"
+export type {
+  TokenizeLpSharesIxParamsFields,
+  TokenizeLpSharesIxParamsJSON,
+} from "./TokenizeLpSharesIxParams"
+export { RedeemLpTokensIxParams } from "./RedeemLpTokensIxParams"
+export type {
+  RedeemLpTokensIxParamsFields,
+  RedeemLpTokensIxParamsJSON,
+} from "./RedeemLpTokensIxParams"
+export { CompoundLpFeesIxParams } from "./CompoundLpFeesIxParams"
+export type {
+  CompoundLpFeesIxParamsFields,
+  CompoundLpFeesIxParamsJSON,
+} from "./CompoundLpFeesIxParams"
+export { AddLiquiditySingleSidedIxParams } from "./AddLiquiditySingleSidedIxParams"
+export type {
+  AddLiquiditySingleSidedIxParamsFields,
+  AddLiquiditySingleSidedIxParamsJSON,
+} from "./AddLiquiditySingleSidedIxParams"
+export { AddLiquiditySingleSidedParamsExtension }
+
+export type AddLiquiditySingleSidedParamsExtensionKind =
+  | AddLiquiditySingleSidedParamsExtension.V0
+  | AddLiquiditySingleSidedParamsExtension.V1
+export type AddLiquiditySingleSidedParamsExtensionJSON =
+  | AddLiquiditySingleSidedParamsExtension.V0JSON
+  | AddLiquiditySingleSidedParamsExtension.V1JSON
+
+
+export { RemoveLiquiditySingleSidedIxParams } from "./RemoveLiquiditySingleSidedIxParams"
+export type {
+  RemoveLiquiditySingleSidedIxParamsFields,
+  RemoveLiquiditySingleSidedIxParamsJSON,
+} from "./RemoveLiquiditySingleSidedIxParams"
+export { RemoveLiquiditySingleSidedParamsExtension }
+
+export type RemoveLiquiditySingleSidedParamsExtensionKind =
+  | RemoveLiquiditySingleSidedParamsExtension.V0
+  | RemoveLiquiditySingleSidedParamsExtension.V1
+export type RemoveLiquiditySingleSidedParamsExtensionJSON =
+  | RemoveLiquiditySingleSidedParamsExtension.V0JSON
+  | RemoveLiquiditySingleSidedParamsExtension.V1JSON
+
+
 export { PlasmaEventHeader } from "./PlasmaEventHeader"
 export type {
   PlasmaEventHeaderFields,
@@ -96,16 +237,130 @@ export type {
   WithdrawProtocolFeesEventFields,
   WithdrawProtocolFeesEventJSON,
 } from "./WithdrawProtocolFeesEvent"
+export { GrowObservationsEvent } from "./GrowObservationsEvent"
+export type {
+  GrowObservationsEventFields,
+  GrowObservationsEventJSON,
+} from "./GrowObservationsEvent"
+export { UpdatePoolConfigEvent } from "./UpdatePoolConfigEvent"
+export type {
+  UpdatePoolConfigEventFields,
+  UpdatePoolConfigEventJSON,
+} from "./UpdatePoolConfigEvent"
+export { TransferProtocolFeeRecipientEvent } from "./TransferProtocolFeeRecipientEvent"
+export type {
+  TransferProtocolFeeRecipientEventFields,
+  TransferProtocolFeeRecipientEventJSON,
+} from "./TransferProtocolFeeRecipientEvent"
+export { SetPoolStatusEvent } from "./SetPoolStatusEvent"
+export type {
+  SetPoolStatusEventFields,
+  SetPoolStatusEventJSON,
+} from "./SetPoolStatusEvent"
+export { InitializeFeeTierConfigEvent } from "./InitializeFeeTierConfigEvent"
+export type {
+  InitializeFeeTierConfigEventFields,
+  InitializeFeeTierConfigEventJSON,
+} from "./InitializeFeeTierConfigEvent"
+export { SetFeeTierEvent } from "./SetFeeTierEvent"
+export type {
+  SetFeeTierEventFields,
+  SetFeeTierEventJSON,
+} from "./SetFeeTierEvent"
+export { CloseLpPositionEvent } from "./CloseLpPositionEvent"
+export type {
+  CloseLpPositionEventFields,
+  CloseLpPositionEventJSON,
+} from "./CloseLpPositionEvent"
+export { ClosePoolEvent } from "./ClosePoolEvent"
+export type { ClosePoolEventFields, ClosePoolEventJSON } from "./ClosePoolEvent"
+export { InitializeLpMintEvent } from "./InitializeLpMintEvent"
+export type {
+  InitializeLpMintEventFields,
+  InitializeLpMintEventJSON,
+} from "./InitializeLpMintEvent"
+export { TokenizeLpSharesEvent } from "./TokenizeLpSharesEvent"
+export type {
+  TokenizeLpSharesEventFields,
+  TokenizeLpSharesEventJSON,
+} from "./TokenizeLpSharesEvent"
+export { RedeemLpTokensEvent } from "./RedeemLpTokensEvent"
+export type {
+  RedeemLpTokensEventFields,
+  RedeemLpTokensEventJSON,
+} from "./RedeemLpTokensEvent"
+export { TransferLpPositionEvent } from "./TransferLpPositionEvent"
+export type {
+  TransferLpPositionEventFields,
+  TransferLpPositionEventJSON,
+} from "./TransferLpPositionEvent"
+export { MigrateLpPositionEvent } from "./MigrateLpPositionEvent"
+export type {
+  MigrateLpPositionEventFields,
+  MigrateLpPositionEventJSON,
+} from "./MigrateLpPositionEvent"
+export { CompoundLpFeesEvent } from "./CompoundLpFeesEvent"
+export type {
+  CompoundLpFeesEventFields,
+  CompoundLpFeesEventJSON,
+} from "./CompoundLpFeesEvent"
+export { AddLiquiditySingleSidedEvent } from "./AddLiquiditySingleSidedEvent"
+export type {
+  AddLiquiditySingleSidedEventFields,
+  AddLiquiditySingleSidedEventJSON,
+} from "./AddLiquiditySingleSidedEvent"
+export { RemoveLiquiditySingleSidedEvent } from "./RemoveLiquiditySingleSidedEvent"
+export type {
+  RemoveLiquiditySingleSidedEventFields,
+  RemoveLiquiditySingleSidedEventJSON,
+} from "./RemoveLiquiditySingleSidedEvent"
+export { MigratePoolEvent } from "./MigratePoolEvent"
+export type {
+  MigratePoolEventFields,
+  MigratePoolEventJSON,
+} from "./MigratePoolEvent"
 export { Side }
 
 export type SideKind = Side.Buy | Side.Sell
 export type SideJSON = Side.BuyJSON | Side.SellJSON
 
+export { TokenType }
+
+export type TokenTypeKind = TokenType.Base | TokenType.Quote
+export type TokenTypeJSON = TokenType.BaseJSON | TokenType.QuoteJSON
+
 export { SwapType }
 
 export type SwapTypeKind = SwapType.ExactIn | SwapType.ExactOut
 export type SwapTypeJSON = SwapType.ExactInJSON | SwapType.ExactOutJSON
 
+export { PoolStatus }
+
+export type PoolStatusKind =
+  | PoolStatus.Active
+  | PoolStatus.SwapsPaused
+  | PoolStatus.FullyPaused
+export type PoolStatusJSON =
+  | PoolStatus.ActiveJSON
+  | PoolStatus.SwapsPausedJSON
+  | PoolStatus.FullyPausedJSON
+
+export { VestingSchedule }
+
+export type VestingScheduleKind = VestingSchedule.Cliff | VestingSchedule.Linear
+export type VestingScheduleJSON =
+  | VestingSchedule.CliffJSON
+  | VestingSchedule.LinearJSON
+
+export { RemoveLiquidityMode }
+
+export type RemoveLiquidityModeKind =
+  | RemoveLiquidityMode.Strict
+  | RemoveLiquidityMode.UpTo
+export type RemoveLiquidityModeJSON =
+  | RemoveLiquidityMode.StrictJSON
+  | RemoveLiquidityMode.UpToJSON
+
 export { PlasmaEvent }
 
 export type PlasmaEventKind =
@@ -117,6 +372,25 @@ export type PlasmaEventKind =
   | PlasmaEvent.InitializeLpPosition
   | PlasmaEvent.InitializePool
   | PlasmaEvent.WithdrawProtocolFees
+  | PlasmaEvent.Log
+  | PlasmaEvent.SwapRoute
+  | PlasmaEvent.GrowObservations
+  | PlasmaEvent.UpdatePoolConfig
+  | PlasmaEvent.TransferProtocolFeeRecipient
+  | PlasmaEvent.SetPoolStatus
+  | PlasmaEvent.InitializeFeeTierConfig
+  | PlasmaEvent.SetFeeTier
+  | PlasmaEvent.CloseLpPosition
+  | PlasmaEvent.ClosePool
+  | PlasmaEvent.InitializeLpMint
+  | PlasmaEvent.TokenizeLpShares
+  | PlasmaEvent.RedeemLpTokens
+  | PlasmaEvent.TransferLpPosition
+  | PlasmaEvent.MigrateLpPosition
+  | PlasmaEvent.CompoundLpFees
+  | PlasmaEvent.AddLiquiditySingleSided
+  | PlasmaEvent.RemoveLiquiditySingleSided
+  | PlasmaEvent.MigratePool
 export type PlasmaEventJSON =
   | PlasmaEvent.SwapJSON
   | PlasmaEvent.AddLiquidityJSON
@@ -126,3 +400,22 @@ export type PlasmaEventJSON =
   | PlasmaEvent.InitializeLpPositionJSON
   | PlasmaEvent.InitializePoolJSON
   | PlasmaEvent.WithdrawProtocolFeesJSON
+  | PlasmaEvent.LogJSON
+  | PlasmaEvent.SwapRouteJSON
+  | PlasmaEvent.GrowObservationsJSON
+  | PlasmaEvent.UpdatePoolConfigJSON
+  | PlasmaEvent.TransferProtocolFeeRecipientJSON
+  | PlasmaEvent.SetPoolStatusJSON
+  | PlasmaEvent.InitializeFeeTierConfigJSON
+  | PlasmaEvent.SetFeeTierJSON
+  | PlasmaEvent.CloseLpPositionJSON
+  | PlasmaEvent.ClosePoolJSON
+  | PlasmaEvent.InitializeLpMintJSON
+  | PlasmaEvent.TokenizeLpSharesJSON
+  | PlasmaEvent.RedeemLpTokensJSON
+  | PlasmaEvent.TransferLpPositionJSON
+  | PlasmaEvent.MigrateLpPositionJSON
+  | PlasmaEvent.CompoundLpFeesJSON
+  | PlasmaEvent.AddLiquiditySingleSidedJSON
+  | PlasmaEvent.RemoveLiquiditySingleSidedJSON
+  | PlasmaEvent.MigratePoolJSON
@@ -19,17 +19,25 @@ import {
 } from "@solana/spl-token";
 import {
   AddLiquidity,
+  InitializeFeeTierConfig,
   InitializeLpPosition,
   InitializePool,
   RemoveLiquidity,
   RenounceLiquidity,
+  SetFeeTier,
   Swap,
   WithdrawProtocolFees,
   WithdrawLpFees,
 } from "../generated/instructions";
 import { LpPositionAccount, PoolAccount } from "../generated/accounts";
 import BN from "bn.js";
-import { ProtocolFeeRecipientParams, Side } from "../generated/types";
+import {
+  AddLiquidityParamsExtension,
+  ProtocolFeeRecipientParams,
+  RemoveLiquidityParamsExtension,
+  Side,
+  SwapParamsExtension,
+} from "../generated/types";
 import assert from "assert";
 import { ExactIn } from "../generated/types/SwapType";
 import { FixedPoint } from "../util/FixedPoint";
@@ -37,17 +45,39 @@ import { FixedPoint } from "../util/FixedPoint";
 // We'll assume the following constants for the the initial liquidity deposit
 const GRADUATION_AMOUNT = 279_900_000_000;
 const GRADUATION_SOL_AMOUNT = 100_000_000;
-const AMM_SIZE = 8 + 8 + 16 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8; // fee, protocol allocation, reward factor, total lp shares, slot snapshot, base reserves snapshot, quote reserves snapshot, base reserves, quote reserves, cumulative quote lp fees, cumulative quote protocol fees
-const TOKEN_PARAMS_SIZE = 4 + 4 + 32 + 32; // decimals, vault bump, mint key, vault key
-const PROTOCOL_FEE_RECIPIENTS_SIZE = (32 + 8 + 8 + 8) * 3 + 12 * 8; // Recipient, shares, total fees, collected fees times 3 plus 12 u64s padding
-const POOL_HEADER_SIZE =
-  8 + 8 + 2 * TOKEN_PARAMS_SIZE + PROTOCOL_FEE_RECIPIENTS_SIZE + 13 * 8; // Discriminator, sequence number, base params, quote params, fee recipients, padding
+// Fee tiers of the pool and of the copy used to test sandwich attacks
+const FEE_TIER = new BN(25);
+const FEE_TIER_COPY = new BN(30);
 
 const LOG_AUTHORITY = PublicKey.findProgramAddressSync(
   [Buffer.from("log")],
   PROGRAM_ID
 )[0];
 
+const BPF_LOADER_UPGRADEABLE_PROGRAM_ID = new PublicKey(
+  "BPFLoaderUpgradeab1e11111111111111111111111"
+);
+
+const FEE_TIER_CONFIG = PublicKey.findProgramAddressSync(
+  [Buffer.from("fee_tier_config")],
+  PROGRAM_ID
+)[0];
+
+const getPoolAddress = (
+  baseMint: PublicKey,
+  quoteMint: PublicKey,
+  feeTier: BN
+): PublicKey =>
+  PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("pool"),
+      // The mints are sorted in the seeds
+      ...[baseMint.toBuffer(), quoteMint.toBuffer()].sort(Buffer.compare),
+      feeTier.toArrayLike(Buffer, "le", 8),
+    ],
+    PROGRAM_ID
+  )[0];
+
 function loadWalletKey(keypairFile: string): Keypair {
   const fs = require("fs");
   return Keypair.fromSecretKey(
@@ -73,9 +103,52 @@ export const sqrt = (num: BN): BN => {
   }
 };
 
+const initFeeTiers = async (c: Connection, admin: Keypair) => {
+  const initTx = new Transaction();
+  // The fee tier config is created once per program deployment
+  if ((await c.getAccountInfo(FEE_TIER_CONFIG, "confirmed")) === null) {
+    initTx.add(
+      InitializeFeeTierConfig({
+        plasmaProgram: PROGRAM_ID,
+        logAuthority: LOG_AUTHORITY,
+        feeTierConfig: FEE_TIER_CONFIG,
+        admin: admin.publicKey,
+        programData: PublicKey.findProgramAddressSync(
+          [PROGRAM_ID.toBuffer()],
+          BPF_LOADER_UPGRADEABLE_PROGRAM_ID
+        )[0],
+        systemProgram: SystemProgram.programId,
+      })
+    );
+  }
+  for (const feeTier of [FEE_TIER, FEE_TIER_COPY]) {
+    initTx.add(
+      SetFeeTier(
+        {
+          params: {
+            lpFeeInBps: feeTier,
+            protocolLpFeeAllocationInPct: new BN(10),
+            numSlotsToVestLpShares: new BN(1),
+            enabled: true,
+          },
+        },
+        {
+          plasmaProgram: PROGRAM_ID,
+          logAuthority: LOG_AUTHORITY,
+          feeTierConfig: FEE_TIER_CONFIG,
+          admin: admin.publicKey,
+        }
+      )
+    );
+  }
+  await sendAndConfirmTransaction(c, initTx, [admin], {
+    commitment: "confirmed",
+  });
+};
+
 const initPool = async (
   c: Connection,
-  poolKeypair: Keypair,
+  feeTier: BN,
   payer: Keypair,
   mintPubkey: PublicKey,
   mintAtaPayer: PublicKey,
@@ -83,53 +156,36 @@ const initPool = async (
   initialLpShares: BN,
   feeRecipients: ProtocolFeeRecipientParams[]
 ) => {
+  const pool = getPoolAddress(mintPubkey, NATIVE_MINT, feeTier);
   const initTx = new Transaction()
-    .add(
-      SystemProgram.createAccount({
-        fromPubkey: payer.publicKey,
-        newAccountPubkey: poolKeypair.publicKey,
-        lamports: await c.getMinimumBalanceForRentExemption(
-          POOL_HEADER_SIZE + AMM_SIZE
-        ),
-        space: POOL_HEADER_SIZE + AMM_SIZE,
-        programId: PROGRAM_ID,
-      })
-    )
     .add(
       InitializePool(
         {
           params: {
-            lpFeeInBps: new BN(25),
-            protocolLpFeeAllocationInPct: new BN(10),
+            feeTier,
             feeRecipientsParams: feeRecipients,
-            numSlotsToVestLpShares: new BN(1),
+            poolAuthority: null,
           },
         },
         {
           plasmaProgram: PROGRAM_ID,
           logAuthority: LOG_AUTHORITY,
-          pool: poolKeypair.publicKey,
+          pool,
           poolCreator: payer.publicKey,
           baseMint: mintPubkey,
           quoteMint: NATIVE_MINT,
           baseVault: PublicKey.findProgramAddressSync(
-            [
-              Buffer.from("vault"),
-              poolKeypair.publicKey.toBuffer(),
-              mintPubkey.toBuffer(),
-            ],
+            [Buffer.from("vault"), pool.toBuffer(), mintPubkey.toBuffer()],
             PROGRAM_ID
           )[0],
           quoteVault: PublicKey.findProgramAddressSync(
-            [
-              Buffer.from("vault"),
-              poolKeypair.publicKey.toBuffer(),
-              NATIVE_MINT.toBuffer(),
-            ],
+            [Buffer.from("vault"), pool.toBuffer(), NATIVE_MINT.toBuffer()],
             PROGRAM_ID
           )[0],
           systemProgram: SystemProgram.programId,
-          tokenProgram: TOKEN_PROGRAM_ID,
+          baseTokenProgram: TOKEN_PROGRAM_ID,
+          quoteTokenProgram: TOKEN_PROGRAM_ID,
+          feeTierConfig: FEE_TIER_CONFIG,
         }
       )
     )
@@ -137,13 +193,13 @@ const initPool = async (
       InitializeLpPosition({
         plasmaProgram: PROGRAM_ID,
         logAuthority: LOG_AUTHORITY,
-        pool: poolKeypair.publicKey,
+        pool,
         payer: payer.publicKey,
         lpPositionOwner: payer.publicKey,
         lpPosition: PublicKey.findProgramAddressSync(
           [
             Buffer.from("lp_position"),
-            poolKeypair.publicKey.toBuffer(),
+            pool.toBuffer(),
             payer.publicKey.toBuffer(),
           ],
           PROGRAM_ID
@@ -158,17 +214,18 @@ const initPool = async (
             desiredBaseAmountIn: new BN(GRADUATION_AMOUNT),
             desiredQuoteAmountIn: new BN(GRADUATION_SOL_AMOUNT),
             initialLpShares,
+            extension: new AddLiquidityParamsExtension.V0(),
           },
         },
         {
           plasmaProgram: PROGRAM_ID,
           logAuthority: LOG_AUTHORITY,
-          pool: poolKeypair.publicKey,
+          pool,
           trader: payer.publicKey,
           lpPosition: PublicKey.findProgramAddressSync(
             [
               Buffer.from("lp_position"),
-              poolKeypair.publicKey.toBuffer(),
+              pool.toBuffer(),
               payer.publicKey.toBuffer(),
             ],
             PROGRAM_ID
@@ -176,22 +233,17 @@ const initPool = async (
           baseAccount: mintAtaPayer,
           quoteAccount: wSolAtaPayer,
           baseVault: PublicKey.findProgramAddressSync(
-            [
-              Buffer.from("vault"),
-              poolKeypair.publicKey.toBuffer(),
-              mintPubkey.toBuffer(),
-            ],
+            [Buffer.from("vault"), pool.toBuffer(), mintPubkey.toBuffer()],
             PROGRAM_ID
           )[0],
           quoteVault: PublicKey.findProgramAddressSync(
-            [
-              Buffer.from("vault"),
-              poolKeypair.publicKey.toBuffer(),
-              NATIVE_MINT.toBuffer(),
-            ],
+            [Buffer.from("vault"), pool.toBuffer(), NATIVE_MINT.toBuffer()],
             PROGRAM_ID
           )[0],
-          tokenProgram: TOKEN_PROGRAM_ID,
+          baseMint: mintPubkey,
+          quoteMint: NATIVE_MINT,
+          baseTokenProgram: TOKEN_PROGRAM_ID,
+          quoteTokenProgram: TOKEN_PROGRAM_ID,
         }
       )
     )
@@ -205,12 +257,12 @@ const initPool = async (
         {
           plasmaProgram: PROGRAM_ID,
           logAuthority: LOG_AUTHORITY,
-          pool: poolKeypair.publicKey,
+          pool,
           trader: payer.publicKey,
           lpPosition: PublicKey.findProgramAddressSync(
             [
               Buffer.from("lp_position"),
-              poolKeypair.publicKey.toBuffer(),
+              pool.toBuffer(),
               payer.publicKey.toBuffer(),
             ],
             PROGRAM_ID
@@ -218,7 +270,7 @@ const initPool = async (
         }
       )
     );
-  await sendAndConfirmTransaction(c, initTx, [payer, poolKeypair], {
+  await sendAndConfirmTransaction(c, initTx, [payer], {
     commitment: "confirmed",
     skipPreflight: true,
   });
@@ -422,6 +474,8 @@ describe("Plasma AMM", async () => {
   before(async () => {
     console.log("Setting up with wallet:", payer.publicKey.toString());
     // Remove airdrop since wallet is already funded
+    // The wallet is the upgrade authority of the deployed program
+    await initFeeTiers(c, payer);
     [mintPubkey, mintAtaPayer, wSolAtaPayer] = await setupMint(c, payer);
     
     // For traders, we can either:
@@ -457,11 +511,10 @@ describe("Plasma AMM", async () => {
         }),
       ];
 
-      const poolKeypair = Keypair.generate();
       try {
         await initPool(
           c,
-          poolKeypair,
+          FEE_TIER,
           payer,
           mintPubkey,
           mintAtaPayer,
@@ -494,10 +547,9 @@ describe("Plasma AMM", async () => {
         }),
       ];
 
-      const poolKeypair = Keypair.generate();
       await initPool(
         c,
-        poolKeypair,
+        FEE_TIER,
         payer,
         mintPubkey,
         mintAtaPayer,
@@ -506,13 +558,12 @@ describe("Plasma AMM", async () => {
         feeRecipients
       );
 
-      poolKey = poolKeypair.publicKey;
+      poolKey = getPoolAddress(mintPubkey, NATIVE_MINT, FEE_TIER);
 
       // Create a copy of the pool to test sandwich attacks
-      const poolKeypairCopy = Keypair.generate();
       await initPool(
         c,
-        poolKeypairCopy,
+        FEE_TIER_COPY,
         payer,
         mintPubkey,
         mintAtaPayer,
@@ -521,7 +572,7 @@ describe("Plasma AMM", async () => {
         feeRecipients
       );
 
-      poolKeyCopy = poolKeypairCopy.publicKey;
+      poolKeyCopy = getPoolAddress(mintPubkey, NATIVE_MINT, FEE_TIER_COPY);
 
       try {
         const removeLiqTx = new Transaction().add(
@@ -529,17 +580,18 @@ describe("Plasma AMM", async () => {
             {
               params: {
                 lpShares: initialLpShares,
+                extension: new RemoveLiquidityParamsExtension.V0(),
               },
             },
             {
               plasmaProgram: PROGRAM_ID,
               logAuthority: LOG_AUTHORITY,
-              pool: poolKeypair.publicKey,
+              pool: poolKey,
               trader: payer.publicKey,
               lpPosition: PublicKey.findProgramAddressSync(
                 [
                   Buffer.from("lp_position"),
-                  poolKeypair.publicKey.toBuffer(),
+                  poolKey.toBuffer(),
                   payer.publicKey.toBuffer(),
                 ],
                 PROGRAM_ID
@@ -549,7 +601,7 @@ describe("Plasma AMM", async () => {
               baseVault: PublicKey.findProgramAddressSync(
                 [
                   Buffer.from("vault"),
-                  poolKeypair.publicKey.toBuffer(),
+                  poolKey.toBuffer(),
                   mintPubkey.toBuffer(),
                 ],
                 PROGRAM_ID
@@ -557,12 +609,15 @@ describe("Plasma AMM", async () => {
               quoteVault: PublicKey.findProgramAddressSync(
                 [
                   Buffer.from("vault"),
-                  poolKeypair.publicKey.toBuffer(),
+                  poolKey.toBuffer(),
                   NATIVE_MINT.toBuffer(),
                 ],
                 PROGRAM_ID
               )[0],
-              tokenProgram: TOKEN_PROGRAM_ID,
+              baseMint: mintPubkey,
+              quoteMint: NATIVE_MINT,
+              baseTokenProgram: TOKEN_PROGRAM_ID,
+              quoteTokenProgram: TOKEN_PROGRAM_ID,
             }
           )
         );
@@ -605,6 +660,7 @@ describe("Plasma AMM", async () => {
                   amountIn: new BN(100_000_000),
                   minAmountOut: new BN(0),
                 }),
+                extension: new SwapParamsExtension.V0(),
               },
             },
             {
@@ -630,7 +686,10 @@ describe("Plasma AMM", async () => {
                 ],
                 PROGRAM_ID
               )[0],
-              tokenProgram: TOKEN_PROGRAM_ID,
+              baseMint: mintPubkey,
+              quoteMint: NATIVE_MINT,
+              baseTokenProgram: TOKEN_PROGRAM_ID,
+              quoteTokenProgram: TOKEN_PROGRAM_ID,
             }
           )
         )
@@ -643,6 +702,7 @@ describe("Plasma AMM", async () => {
                   amountIn: new BN(1000_000_000),
                   minAmountOut: new BN(0),
                 }),
+                extension: new SwapParamsExtension.V0(),
               },
             },
             {
@@ -668,7 +728,10 @@ describe("Plasma AMM", async () => {
                 ],
                 PROGRAM_ID
               )[0],
-              tokenProgram: TOKEN_PROGRAM_ID,
+              baseMint: mintPubkey,
+              quoteMint: NATIVE_MINT,
+              baseTokenProgram: TOKEN_PROGRAM_ID,
+              quoteTokenProgram: TOKEN_PROGRAM_ID,
             }
           )
         );
@@ -740,6 +803,7 @@ describe("Plasma AMM", async () => {
                 amountIn: attackerMintBalance,
                 minAmountOut: new BN(100_000_000),
               }),
+              extension: new SwapParamsExtension.V0(),
             },
           },
           {
@@ -761,7 +825,10 @@ describe("Plasma AMM", async () => {
               ],
               PROGRAM_ID
             )[0],
-            tokenProgram: TOKEN_PROGRAM_ID,
+            baseMint: mintPubkey,
+            quoteMint: NATIVE_MINT,
+            baseTokenProgram: TOKEN_PROGRAM_ID,
+            quoteTokenProgram: TOKEN_PROGRAM_ID,
           }
         )
       );
@@ -859,6 +926,7 @@ describe("Plasma AMM", async () => {
                   amountIn: new BN(100_000_000),
                   minAmountOut: new BN(0),
                 }),
+                extension: new SwapParamsExtension.V0(),
               },
             },
             {
@@ -884,7 +952,10 @@ describe("Plasma AMM", async () => {
                 ],
                 PROGRAM_ID
               )[0],
-              tokenProgram: TOKEN_PROGRAM_ID,
+              baseMint: mintPubkey,
+              quoteMint: NATIVE_MINT,
+              baseTokenProgram: TOKEN_PROGRAM_ID,
+              quoteTokenProgram: TOKEN_PROGRAM_ID,
             }
           )
         )
@@ -897,6 +968,7 @@ describe("Plasma AMM", async () => {
                   amountIn: new BN(1_000_000_000),
                   minAmountOut: new BN(0),
                 }),
+                extension: new SwapParamsExtension.V0(),
               },
             },
             {
@@ -922,7 +994,10 @@ describe("Plasma AMM", async () => {
                 ],
                 PROGRAM_ID
               )[0],
-              tokenProgram: TOKEN_PROGRAM_ID,
+              baseMint: mintPubkey,
+              quoteMint: NATIVE_MINT,
+              baseTokenProgram: TOKEN_PROGRAM_ID,
+              quoteTokenProgram: TOKEN_PROGRAM_ID,
             }
           )
         );
@@ -972,6 +1047,7 @@ describe("Plasma AMM", async () => {
                 amountIn: attackerMintBalance,
                 minAmountOut: new BN(0),
               }),
+              extension: new SwapParamsExtension.V0(),
             },
           },
           {
@@ -997,7 +1073,10 @@ describe("Plasma AMM", async () => {
               ],
               PROGRAM_ID
             )[0],
-            tokenProgram: TOKEN_PROGRAM_ID,
+            baseMint: mintPubkey,
+            quoteMint: NATIVE_MINT,
+            baseTokenProgram: TOKEN_PROGRAM_ID,
+            quoteTokenProgram: TOKEN_PROGRAM_ID,
           }
         )
       );
@@ -1076,6 +1155,7 @@ describe("Plasma AMM", async () => {
                   amountIn: new BN(100_000_000),
                   minAmountOut: new BN(0),
                 }),
+                extension: new SwapParamsExtension.V0(),
               },
             },
 
@@ -1102,7 +1182,10 @@ describe("Plasma AMM", async () => {
                 ],
                 PROGRAM_ID
               )[0],
-              tokenProgram: TOKEN_PROGRAM_ID,
+              baseMint: mintPubkey,
+              quoteMint: NATIVE_MINT,
+              baseTokenProgram: TOKEN_PROGRAM_ID,
+              quoteTokenProgram: TOKEN_PROGRAM_ID,
             }
           )
         );
@@ -1165,6 +1248,7 @@ describe("Plasma AMM", async () => {
               ],
               PROGRAM_ID
             )[0],
+            quoteMint: NATIVE_MINT,
             tokenProgram: TOKEN_PROGRAM_ID,
           },
           PROGRAM_ID
@@ -1268,6 +1352,7 @@ describe("Plasma AMM", async () => {
           protocolFeeRecipient: traders[0][0].publicKey,
           quoteAccount: traders[0][2],
           quoteVault,
+          quoteMint: NATIVE_MINT,
           tokenProgram: TOKEN_PROGRAM_ID,
         })
       );
@@ -1288,6 +1373,7 @@ describe("Plasma AMM", async () => {
           protocolFeeRecipient: traders[1][0].publicKey,
           quoteAccount: traders[1][2],
           quoteVault,
+          quoteMint: NATIVE_MINT,
           tokenProgram: TOKEN_PROGRAM_ID,
         })
       );
@@ -1303,6 +1389,7 @@ describe("Plasma AMM", async () => {
           protocolFeeRecipient: traders[2][0].publicKey,
           quoteAccount: traders[2][2],
           quoteVault,
+          quoteMint: NATIVE_MINT,
           tokenProgram: TOKEN_PROGRAM_ID,
         })
       );
@@ -1349,6 +1436,7 @@ describe("Plasma AMM", async () => {
             protocolFeeRecipient: traders[3][0].publicKey,
             quoteAccount: traders[3][2],
             quoteVault,
+            quoteMint: NATIVE_MINT,
             tokenProgram: TOKEN_PROGRAM_ID,
           })
         );
@@ -1484,6 +1572,7 @@ describe("Plasma AMM", async () => {
               desiredBaseAmountIn: new BN(10_000_000),
               desiredQuoteAmountIn: new BN(5_000_000),
               initialLpShares: null,
+              extension: new AddLiquidityParamsExtension.V0(),
             },
           },
           {
@@ -1506,7 +1595,10 @@ describe("Plasma AMM", async () => {
               ],
               PROGRAM_ID
             )[0],
-            tokenProgram: TOKEN_PROGRAM_ID,
+            baseMint: mintPubkey,
+            quoteMint: NATIVE_MINT,
+            baseTokenProgram: TOKEN_PROGRAM_ID,
+            quoteTokenProgram: TOKEN_PROGRAM_ID,
           }
         )
       );
@@ -1616,6 +1708,7 @@ describe("Plasma AMM", async () => {
               desiredBaseAmountIn: new BN(100_000_000_000),
               desiredQuoteAmountIn: new BN(50_000_000_000),
               initialLpShares: null,
+              extension: new AddLiquidityParamsExtension.V0(),
             },
           },
           {
@@ -1638,7 +1731,10 @@ describe("Plasma AMM", async () => {
               ],
               PROGRAM_ID
             )[0],
-            tokenProgram: TOKEN_PROGRAM_ID,
+            baseMint: mintPubkey,
+            quoteMint: NATIVE_MINT,
+            baseTokenProgram: TOKEN_PROGRAM_ID,
+            quoteTokenProgram: TOKEN_PROGRAM_ID,
           }
         )
       );
@@ -1662,6 +1758,7 @@ describe("Plasma AMM", async () => {
                   amountIn: new BN(1_000_000),
                   minAmountOut: new BN(0),
                 }),
+                extension: new SwapParamsExtension.V0(),
               },
             },
             {
@@ -1687,7 +1784,10 @@ describe("Plasma AMM", async () => {
                 ],
                 PROGRAM_ID
               )[0],
-              tokenProgram: TOKEN_PROGRAM_ID,
+              baseMint: mintPubkey,
+              quoteMint: NATIVE_MINT,
+              baseTokenProgram: TOKEN_PROGRAM_ID,
+              quoteTokenProgram: TOKEN_PROGRAM_ID,
             }
           )
         );
@@ -1709,7 +1809,10 @@ describe("Plasma AMM", async () => {
             [Buffer.from("vault"), poolKey.toBuffer(), NATIVE_MINT.toBuffer()],
             PROGRAM_ID
           )[0],
-          tokenProgram: TOKEN_PROGRAM_ID,
+          baseMint: mintPubkey,
+          quoteMint: NATIVE_MINT,
+          baseTokenProgram: TOKEN_PROGRAM_ID,
+          quoteTokenProgram: TOKEN_PROGRAM_ID,
         })
       );
 
@@ -1836,6 +1939,7 @@ describe("Plasma AMM", async () => {
                 desiredBaseAmountIn: new BN(50_000_000_000),
                 desiredQuoteAmountIn: new BN(50_000_000_000),
                 initialLpShares: null,
+                extension: new AddLiquidityParamsExtension.V0(),
               },
             },
             {
@@ -1862,7 +1966,10 @@ describe("Plasma AMM", async () => {
                 ],
                 PROGRAM_ID
               )[0],
-              tokenProgram: TOKEN_PROGRAM_ID,
+              baseMint: mintPubkey,
+              quoteMint: NATIVE_MINT,
+              baseTokenProgram: TOKEN_PROGRAM_ID,
+              quoteTokenProgram: TOKEN_PROGRAM_ID,
             }
           )
         );
@@ -1890,6 +1997,7 @@ describe("Plasma AMM", async () => {
                   amountIn: new BN(1_000_000_000),
                   minAmountOut: new BN(0),
                 }),
+                extension: new SwapParamsExtension.V0(),
               },
             },
             {
@@ -1915,7 +2023,10 @@ describe("Plasma AMM", async () => {
                 ],
                 PROGRAM_ID
               )[0],
-              tokenProgram: TOKEN_PROGRAM_ID,
+              baseMint: mintPubkey,
+              quoteMint: NATIVE_MINT,
+              baseTokenProgram: TOKEN_PROGRAM_ID,
+              quoteTokenProgram: TOKEN_PROGRAM_ID,
             }
           )
         );
@@ -1949,7 +2060,10 @@ describe("Plasma AMM", async () => {
               ],
               PROGRAM_ID
             )[0],
-            tokenProgram: TOKEN_PROGRAM_ID,
+            baseMint: mintPubkey,
+            quoteMint: NATIVE_MINT,
+            baseTokenProgram: TOKEN_PROGRAM_ID,
+            quoteTokenProgram: TOKEN_PROGRAM_ID,
           })
         );
 
@@ -2079,6 +2193,7 @@ describe("Plasma AMM", async () => {
               desiredBaseAmountIn: new BN(25_000_000_000),
               desiredQuoteAmountIn: new BN(5_000_000_000),
               initialLpShares: null,
+              extension: new AddLiquidityParamsExtension.V0(),
             },
           },
           {
@@ -2101,7 +2216,10 @@ describe("Plasma AMM", async () => {
               ],
               PROGRAM_ID
             )[0],
-            tokenProgram: TOKEN_PROGRAM_ID,
+            baseMint: mintPubkey,
+            quoteMint: NATIVE_MINT,
+            baseTokenProgram: TOKEN_PROGRAM_ID,
+            quoteTokenProgram: TOKEN_PROGRAM_ID,
           }
         )
       );
@@ -2217,6 +2335,7 @@ describe("Plasma AMM", async () => {
               desiredBaseAmountIn: new BN(50_000_000_000),
               desiredQuoteAmountIn: new BN(50_000_000_000),
               initialLpShares: null,
+              extension: new AddLiquidityParamsExtension.V0(),
             },
           },
           {
@@ -2239,7 +2358,10 @@ describe("Plasma AMM", async () => {
               ],
               PROGRAM_ID
             )[0],
-            tokenProgram: TOKEN_PROGRAM_ID,
+            baseMint: mintPubkey,
+            quoteMint: NATIVE_MINT,
+            baseTokenProgram: TOKEN_PROGRAM_ID,
+            quoteTokenProgram: TOKEN_PROGRAM_ID,
           }
         )
       );
@@ -2262,6 +2384,7 @@ describe("Plasma AMM", async () => {
           {
             params: {
               lpShares: initialLpShares.div(new BN(2)),
+              extension: new RemoveLiquidityParamsExtension.V0(),
             },
           },
           {
@@ -2284,7 +2407,10 @@ describe("Plasma AMM", async () => {
               ],
               PROGRAM_ID
             )[0],
-            tokenProgram: TOKEN_PROGRAM_ID,
+            baseMint: mintPubkey,
+            quoteMint: NATIVE_MINT,
+            baseTokenProgram: TOKEN_PROGRAM_ID,
+            quoteTokenProgram: TOKEN_PROGRAM_ID,
           }
         )
       );
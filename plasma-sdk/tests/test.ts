@@ -19,17 +19,25 @@ import {
 } from "@solana/spl-token";
 import {
   AddLiquidity,
+  InitializeFeeTierConfig,
   InitializeLpPosition,
   InitializePool,
   RemoveLiquidity,
   RenounceLiquidity,
+  SetFeeTier,
   Swap,
   WithdrawProtocolFees,
   WithdrawLpFees,
 } from "../generated/instructions";
 import { LpPositionAccount, PoolAccount } from "../generated/accounts";
 import BN from "bn.js";
-import { ProtocolFeeRecipientParams, Side } from "../generated/types";
+import {
+  AddLiquidityParamsExtension,
+  ProtocolFeeRecipientParams,
+  RemoveLiquidityParamsExtension,
+  Side,
+  SwapParamsExtension,
+} from "../generated/types";
 import assert from "assert";
 import { ExactIn } from "../generated/types/SwapType";
 import { FixedPoint } from "../util/FixedPoint";
@@ -37,17 +45,46 @@ import { FixedPoint } from "../util/FixedPoint";
 // We'll assume the following constants for the the initial liquidity deposit
 const GRADUATION_AMOUNT = 279_900_000_000_000;
 const GRADUATION_SOL_AMOUNT = 100_000_000_000;
-const AMM_SIZE = 8 + 8 + 16 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8; // fee, protocol allocation, reward factor, total lp shares, slot snapshot, base reserves snapshot, quote reserves snapshot, base reserves, quote reserves, cumulative quote lp fees, cumulative quote protocol fees
-const TOKEN_PARAMS_SIZE = 4 + 4 + 32 + 32; // decimals, vault bump, mint key, vault key
-const PROTOCOL_FEE_RECIPIENTS_SIZE = (32 + 8 + 8 + 8) * 3 + 12 * 8; // Recipient, shares, total fees, collected fees times 3 plus 12 u64s padding
-const POOL_HEADER_SIZE =
-  8 + 8 + 2 * TOKEN_PARAMS_SIZE + PROTOCOL_FEE_RECIPIENTS_SIZE + 13 * 8; // Discriminator, sequence number, base params, quote params, fee recipients, padding
+// Fee tiers of the pool and of the copy used to test sandwich attacks
+const FEE_TIER = new BN(25);
+const FEE_TIER_COPY = new BN(30);
 
 const LOG_AUTHORITY = PublicKey.findProgramAddressSync(
   [Buffer.from("log")],
   PROGRAM_ID
 )[0];
 
+const BPF_LOADER_UPGRADEABLE_PROGRAM_ID = new PublicKey(
+  "BPFLoaderUpgradeab1e11111111111111111111111"
+);
+
+const FEE_TIER_CONFIG = PublicKey.findProgramAddressSync(
+  [Buffer.from("fee_tier_config")],
+  PROGRAM_ID
+)[0];
+
+const getPoolAddress = (
+  baseMint: PublicKey,
+  quoteMint: PublicKey,
+  feeTier: BN
+): PublicKey =>
+  PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("pool"),
+      // The mints are sorted in the seeds
+      ...[baseMint.toBuffer(), quoteMint.toBuffer()].sort(Buffer.compare),
+      feeTier.toArrayLike(Buffer, "le", 8),
+    ],
+    PROGRAM_ID
+  )[0];
+
+function loadWalletKey(keypairFile: string): Keypair {
+  const fs = require("fs");
+  return Keypair.fromSecretKey(
+    new Uint8Array(JSON.parse(fs.readFileSync(keypairFile).toString()))
+  );
+}
+
 export const sqrt = (num: BN): BN => {
   if (num.lt(new BN(0))) {
     throw new Error("Sqrt only works on non-negative inputs");
@@ -114,9 +151,52 @@ const bootstrapTrader = async (
   return [trader, mintAtaTrader, wSolAtaTrader];
 };
 
+const initFeeTiers = async (c: Connection, admin: Keypair) => {
+  const initTx = new Transaction();
+  // The fee tier config is created once per program deployment
+  if ((await c.getAccountInfo(FEE_TIER_CONFIG, "confirmed")) === null) {
+    initTx.add(
+      InitializeFeeTierConfig({
+        plasmaProgram: PROGRAM_ID,
+        logAuthority: LOG_AUTHORITY,
+        feeTierConfig: FEE_TIER_CONFIG,
+        admin: admin.publicKey,
+        programData: PublicKey.findProgramAddressSync(
+          [PROGRAM_ID.toBuffer()],
+          BPF_LOADER_UPGRADEABLE_PROGRAM_ID
+        )[0],
+        systemProgram: SystemProgram.programId,
+      })
+    );
+  }
+  for (const feeTier of [FEE_TIER, FEE_TIER_COPY]) {
+    initTx.add(
+      SetFeeTier(
+        {
+          params: {
+            lpFeeInBps: feeTier,
+            protocolLpFeeAllocationInPct: new BN(10),
+            numSlotsToVestLpShares: new BN(1),
+            enabled: true,
+          },
+        },
+        {
+          plasmaProgram: PROGRAM_ID,
+          logAuthority: LOG_AUTHORITY,
+          feeTierConfig: FEE_TIER_CONFIG,
+          admin: admin.publicKey,
+        }
+      )
+    );
+  }
+  await sendAndConfirmTransaction(c, initTx, [admin], {
+    commitment: "confirmed",
+  });
+};
+
 const initPool = async (
   c: Connection,
-  poolKeypair: Keypair,
+  feeTier: BN,
   payer: Keypair,
   mintPubkey: PublicKey,
   mintAtaPayer: PublicKey,
@@ -124,53 +204,36 @@ const initPool = async (
   initialLpShares: BN,
   feeRecipients: ProtocolFeeRecipientParams[]
 ) => {
+  const pool = getPoolAddress(mintPubkey, NATIVE_MINT, feeTier);
   const initTx = new Transaction()
-    .add(
-      SystemProgram.createAccount({
-        fromPubkey: payer.publicKey,
-        newAccountPubkey: poolKeypair.publicKey,
-        lamports: await c.getMinimumBalanceForRentExemption(
-          POOL_HEADER_SIZE + AMM_SIZE
-        ),
-        space: POOL_HEADER_SIZE + AMM_SIZE,
-        programId: PROGRAM_ID,
-      })
-    )
     .add(
       InitializePool(
         {
           params: {
-            lpFeeInBps: new BN(25),
-            protocolLpFeeAllocationInPct: new BN(10),
+            feeTier,
             feeRecipientsParams: feeRecipients,
-            numSlotsToVestLpShares: new BN(1),
+            poolAuthority: null,
           },
         },
         {
           plasmaProgram: PROGRAM_ID,
           logAuthority: LOG_AUTHORITY,
-          pool: poolKeypair.publicKey,
+          pool,
           poolCreator: payer.publicKey,
           baseMint: mintPubkey,
           quoteMint: NATIVE_MINT,
           baseVault: PublicKey.findProgramAddressSync(
-            [
-              Buffer.from("vault"),
-              poolKeypair.publicKey.toBuffer(),
-              mintPubkey.toBuffer(),
-            ],
+            [Buffer.from("vault"), pool.toBuffer(), mintPubkey.toBuffer()],
             PROGRAM_ID
           )[0],
           quoteVault: PublicKey.findProgramAddressSync(
-            [
-              Buffer.from("vault"),
-              poolKeypair.publicKey.toBuffer(),
-              NATIVE_MINT.toBuffer(),
-            ],
+            [Buffer.from("vault"), pool.toBuffer(), NATIVE_MINT.toBuffer()],
             PROGRAM_ID
           )[0],
           systemProgram: SystemProgram.programId,
-          tokenProgram: TOKEN_PROGRAM_ID,
+          baseTokenProgram: TOKEN_PROGRAM_ID,
+          quoteTokenProgram: TOKEN_PROGRAM_ID,
+          feeTierConfig: FEE_TIER_CONFIG,
         }
       )
     )
@@ -178,13 +241,13 @@ const initPool = async (
       InitializeLpPosition({
         plasmaProgram: PROGRAM_ID,
         logAuthority: LOG_AUTHORITY,
-        pool: poolKeypair.publicKey,
+        pool,
         payer: payer.publicKey,
         lpPositionOwner: payer.publicKey,
         lpPosition: PublicKey.findProgramAddressSync(
           [
             Buffer.from("lp_position"),
-            poolKeypair.publicKey.toBuffer(),
+            pool.toBuffer(),
             payer.publicKey.toBuffer(),
           ],
           PROGRAM_ID
@@ -199,17 +262,18 @@ const initPool = async (
             desiredBaseAmountIn: new BN(GRADUATION_AMOUNT),
             desiredQuoteAmountIn: new BN(GRADUATION_SOL_AMOUNT),
             initialLpShares,
+            extension: new AddLiquidityParamsExtension.V0(),
           },
         },
         {
           plasmaProgram: PROGRAM_ID,
           logAuthority: LOG_AUTHORITY,
-          pool: poolKeypair.publicKey,
+          pool,
           trader: payer.publicKey,
           lpPosition: PublicKey.findProgramAddressSync(
             [
               Buffer.from("lp_position"),
-              poolKeypair.publicKey.toBuffer(),
+              pool.toBuffer(),
               payer.publicKey.toBuffer(),
             ],
             PROGRAM_ID
@@ -217,22 +281,17 @@ const initPool = async (
           baseAccount: mintAtaPayer,
           quoteAccount: wSolAtaPayer,
           baseVault: PublicKey.findProgramAddressSync(
-            [
-              Buffer.from("vault"),
-              poolKeypair.publicKey.toBuffer(),
-              mintPubkey.toBuffer(),
-            ],
+            [Buffer.from("vault"), pool.toBuffer(), mintPubkey.toBuffer()],
             PROGRAM_ID
           )[0],
           quoteVault: PublicKey.findProgramAddressSync(
-            [
-              Buffer.from("vault"),
-              poolKeypair.publicKey.toBuffer(),
-              NATIVE_MINT.toBuffer(),
-            ],
+            [Buffer.from("vault"), pool.toBuffer(), NATIVE_MINT.toBuffer()],
             PROGRAM_ID
           )[0],
-          tokenProgram: TOKEN_PROGRAM_ID,
+          baseMint: mintPubkey,
+          quoteMint: NATIVE_MINT,
+          baseTokenProgram: TOKEN_PROGRAM_ID,
+          quoteTokenProgram: TOKEN_PROGRAM_ID,
         }
       )
     )
@@ -246,12 +305,12 @@ const initPool = async (
         {
           plasmaProgram: PROGRAM_ID,
           logAuthority: LOG_AUTHORITY,
-          pool: poolKeypair.publicKey,
+          pool,
           trader: payer.publicKey,
           lpPosition: PublicKey.findProgramAddressSync(
             [
               Buffer.from("lp_position"),
-              poolKeypair.publicKey.toBuffer(),
+              pool.toBuffer(),
               payer.publicKey.toBuffer(),
             ],
             PROGRAM_ID
@@ -259,7 +318,7 @@ const initPool = async (
         }
       )
     );
-  await sendAndConfirmTransaction(c, initTx, [payer, poolKeypair], {
+  await sendAndConfirmTransaction(c, initTx, [payer], {
     commitment: "confirmed",
     skipPreflight: true,
   });
@@ -338,6 +397,10 @@ const setupMint = async (
 describe("Plasma AMM", async () => {
   const c = new Connection("http://127.0.0.1:8899", "confirmed");
   const payer = Keypair.generate();
+  // Upgrade authority of the program, see start-test-validator
+  const admin = loadWalletKey(
+    `${require("os").homedir()}/.config/solana/id.json`
+  );
   const traders: [Keypair, PublicKey, PublicKey][] = [];
   let mintPubkey: PublicKey;
   let mintAtaPayer: PublicKey;
@@ -353,6 +416,7 @@ describe("Plasma AMM", async () => {
     );
     console.log("Confirming airdrop");
     await c.confirmTransaction(airdropTx, "confirmed");
+    await initFeeTiers(c, admin);
     [mintPubkey, mintAtaPayer, wSolAtaPayer] = await setupMint(c, payer);
     for (let i = 0; i < 4; i++) {
       traders.push(await bootstrapTrader(c, mintPubkey));
@@ -379,11 +443,10 @@ describe("Plasma AMM", async () => {
         }),
       ];
 
-      const poolKeypair = Keypair.generate();
       try {
         await initPool(
           c,
-          poolKeypair,
+          FEE_TIER,
           payer,
           mintPubkey,
           mintAtaPayer,
@@ -416,10 +479,9 @@ describe("Plasma AMM", async () => {
         }),
       ];
 
-      const poolKeypair = Keypair.generate();
       await initPool(
         c,
-        poolKeypair,
+        FEE_TIER,
         payer,
         mintPubkey,
         mintAtaPayer,
@@ -428,13 +490,12 @@ describe("Plasma AMM", async () => {
         feeRecipients
       );
 
-      poolKey = poolKeypair.publicKey;
+      poolKey = getPoolAddress(mintPubkey, NATIVE_MINT, FEE_TIER);
 
       // Create a copy of the pool to test sandwich attacks
-      const poolKeypairCopy = Keypair.generate();
       await initPool(
         c,
-        poolKeypairCopy,
+        FEE_TIER_COPY,
         payer,
         mintPubkey,
         mintAtaPayer,
@@ -443,7 +504,7 @@ describe("Plasma AMM", async () => {
         feeRecipients
       );
 
-      poolKeyCopy = poolKeypairCopy.publicKey;
+      poolKeyCopy = getPoolAddress(mintPubkey, NATIVE_MINT, FEE_TIER_COPY);
 
       try {
         const removeLiqTx = new Transaction().add(
@@ -451,17 +512,18 @@ describe("Plasma AMM", async () => {
             {
               params: {
                 lpShares: initialLpShares,
+                extension: new RemoveLiquidityParamsExtension.V0(),
               },
             },
             {
               plasmaProgram: PROGRAM_ID,
               logAuthority: LOG_AUTHORITY,
-              pool: poolKeypair.publicKey,
+              pool: poolKey,
               trader: payer.publicKey,
               lpPosition: PublicKey.findProgramAddressSync(
                 [
                   Buffer.from("lp_position"),
-                  poolKeypair.publicKey.toBuffer(),
+                  poolKey.toBuffer(),
                   payer.publicKey.toBuffer(),
                 ],
                 PROGRAM_ID
@@ -471,7 +533,7 @@ describe("Plasma AMM", async () => {
               baseVault: PublicKey.findProgramAddressSync(
                 [
                   Buffer.from("vault"),
-                  poolKeypair.publicKey.toBuffer(),
+                  poolKey.toBuffer(),
                   mintPubkey.toBuffer(),
                 ],
                 PROGRAM_ID
@@ -479,12 +541,15 @@ describe("Plasma AMM", async () => {
               quoteVault: PublicKey.findProgramAddressSync(
                 [
                   Buffer.from("vault"),
-                  poolKeypair.publicKey.toBuffer(),
+                  poolKey.toBuffer(),
                   NATIVE_MINT.toBuffer(),
                 ],
                 PROGRAM_ID
               )[0],
-              tokenProgram: TOKEN_PROGRAM_ID,
+              baseMint: mintPubkey,
+              quoteMint: NATIVE_MINT,
+              baseTokenProgram: TOKEN_PROGRAM_ID,
+              quoteTokenProgram: TOKEN_PROGRAM_ID,
             }
           )
         );
@@ -527,6 +592,7 @@ describe("Plasma AMM", async () => {
                   amountIn: new BN(2_000_000_000),
                   minAmountOut: new BN(0),
                 }),
+                extension: new SwapParamsExtension.V0(),
               },
             },
             {
@@ -552,7 +618,10 @@ describe("Plasma AMM", async () => {
                 ],
                 PROGRAM_ID
               )[0],
-              tokenProgram: TOKEN_PROGRAM_ID,
+              baseMint: mintPubkey,
+              quoteMint: NATIVE_MINT,
+              baseTokenProgram: TOKEN_PROGRAM_ID,
+              quoteTokenProgram: TOKEN_PROGRAM_ID,
             }
           )
         )
@@ -565,6 +634,7 @@ describe("Plasma AMM", async () => {
                   amountIn: new BN(1000_000_000),
                   minAmountOut: new BN(0),
                 }),
+                extension: new SwapParamsExtension.V0(),
               },
             },
             {
@@ -590,7 +660,10 @@ describe("Plasma AMM", async () => {
                 ],
                 PROGRAM_ID
               )[0],
-              tokenProgram: TOKEN_PROGRAM_ID,
+              baseMint: mintPubkey,
+              quoteMint: NATIVE_MINT,
+              baseTokenProgram: TOKEN_PROGRAM_ID,
+              quoteTokenProgram: TOKEN_PROGRAM_ID,
             }
           )
         );
@@ -662,6 +735,7 @@ describe("Plasma AMM", async () => {
                 amountIn: attackerMintBalance,
                 minAmountOut: new BN(2_000_000_000),
               }),
+              extension: new SwapParamsExtension.V0(),
             },
           },
           {
@@ -683,7 +757,10 @@ describe("Plasma AMM", async () => {
               ],
               PROGRAM_ID
             )[0],
-            tokenProgram: TOKEN_PROGRAM_ID,
+            baseMint: mintPubkey,
+            quoteMint: NATIVE_MINT,
+            baseTokenProgram: TOKEN_PROGRAM_ID,
+            quoteTokenProgram: TOKEN_PROGRAM_ID,
           }
         )
       );
@@ -781,6 +858,7 @@ describe("Plasma AMM", async () => {
                   amountIn: new BN(2_000_000_000),
                   minAmountOut: new BN(0),
                 }),
+                extension: new SwapParamsExtension.V0(),
               },
             },
             {
@@ -806,7 +884,10 @@ describe("Plasma AMM", async () => {
                 ],
                 PROGRAM_ID
               )[0],
-              tokenProgram: TOKEN_PROGRAM_ID,
+              baseMint: mintPubkey,
+              quoteMint: NATIVE_MINT,
+              baseTokenProgram: TOKEN_PROGRAM_ID,
+              quoteTokenProgram: TOKEN_PROGRAM_ID,
             }
           )
         )
@@ -819,6 +900,7 @@ describe("Plasma AMM", async () => {
                   amountIn: new BN(1_000_000_000),
                   minAmountOut: new BN(0),
                 }),
+                extension: new SwapParamsExtension.V0(),
               },
             },
             {
@@ -844,7 +926,10 @@ describe("Plasma AMM", async () => {
                 ],
                 PROGRAM_ID
               )[0],
-              tokenProgram: TOKEN_PROGRAM_ID,
+              baseMint: mintPubkey,
+              quoteMint: NATIVE_MINT,
+              baseTokenProgram: TOKEN_PROGRAM_ID,
+              quoteTokenProgram: TOKEN_PROGRAM_ID,
             }
           )
         );
@@ -894,6 +979,7 @@ describe("Plasma AMM", async () => {
                 amountIn: attackerMintBalance,
                 minAmountOut: new BN(0),
               }),
+              extension: new SwapParamsExtension.V0(),
             },
           },
           {
@@ -919,7 +1005,10 @@ describe("Plasma AMM", async () => {
               ],
               PROGRAM_ID
             )[0],
-            tokenProgram: TOKEN_PROGRAM_ID,
+            baseMint: mintPubkey,
+            quoteMint: NATIVE_MINT,
+            baseTokenProgram: TOKEN_PROGRAM_ID,
+            quoteTokenProgram: TOKEN_PROGRAM_ID,
           }
         )
       );
@@ -998,6 +1087,7 @@ describe("Plasma AMM", async () => {
                   amountIn: new BN(2_000_000_000),
                   minAmountOut: new BN(0),
                 }),
+                extension: new SwapParamsExtension.V0(),
               },
             },
 
@@ -1024,7 +1114,10 @@ describe("Plasma AMM", async () => {
                 ],
                 PROGRAM_ID
               )[0],
-              tokenProgram: TOKEN_PROGRAM_ID,
+              baseMint: mintPubkey,
+              quoteMint: NATIVE_MINT,
+              baseTokenProgram: TOKEN_PROGRAM_ID,
+              quoteTokenProgram: TOKEN_PROGRAM_ID,
             }
           )
         );
@@ -1087,6 +1180,7 @@ describe("Plasma AMM", async () => {
               ],
               PROGRAM_ID
             )[0],
+            quoteMint: NATIVE_MINT,
             tokenProgram: TOKEN_PROGRAM_ID,
           },
           PROGRAM_ID
@@ -1190,6 +1284,7 @@ describe("Plasma AMM", async () => {
           protocolFeeRecipient: traders[0][0].publicKey,
           quoteAccount: traders[0][2],
           quoteVault,
+          quoteMint: NATIVE_MINT,
           tokenProgram: TOKEN_PROGRAM_ID,
         })
       );
@@ -1210,6 +1305,7 @@ describe("Plasma AMM", async () => {
           protocolFeeRecipient: traders[1][0].publicKey,
           quoteAccount: traders[1][2],
           quoteVault,
+          quoteMint: NATIVE_MINT,
           tokenProgram: TOKEN_PROGRAM_ID,
         })
       );
@@ -1225,6 +1321,7 @@ describe("Plasma AMM", async () => {
           protocolFeeRecipient: traders[2][0].publicKey,
           quoteAccount: traders[2][2],
           quoteVault,
+          quoteMint: NATIVE_MINT,
           tokenProgram: TOKEN_PROGRAM_ID,
         })
       );
@@ -1271,6 +1368,7 @@ describe("Plasma AMM", async () => {
             protocolFeeRecipient: traders[3][0].publicKey,
             quoteAccount: traders[3][2],
             quoteVault,
+            quoteMint: NATIVE_MINT,
             tokenProgram: TOKEN_PROGRAM_ID,
           })
         );
@@ -1411,6 +1509,7 @@ describe("Plasma AMM", async () => {
               desiredBaseAmountIn: new BN(10_000_000),
               desiredQuoteAmountIn: new BN(5_000_000),
               initialLpShares: null,
+              extension: new AddLiquidityParamsExtension.V0(),
             },
           },
           {
@@ -1433,7 +1532,10 @@ describe("Plasma AMM", async () => {
               ],
               PROGRAM_ID
             )[0],
-            tokenProgram: TOKEN_PROGRAM_ID,
+            baseMint: mintPubkey,
+            quoteMint: NATIVE_MINT,
+            baseTokenProgram: TOKEN_PROGRAM_ID,
+            quoteTokenProgram: TOKEN_PROGRAM_ID,
           }
         )
       );
@@ -1548,6 +1650,7 @@ describe("Plasma AMM", async () => {
               desiredBaseAmountIn: new BN(100_000_000_000),
               desiredQuoteAmountIn: new BN(50_000_000_000),
               initialLpShares: null,
+              extension: new AddLiquidityParamsExtension.V0(),
             },
           },
           {
@@ -1570,7 +1673,10 @@ describe("Plasma AMM", async () => {
               ],
               PROGRAM_ID
             )[0],
-            tokenProgram: TOKEN_PROGRAM_ID,
+            baseMint: mintPubkey,
+            quoteMint: NATIVE_MINT,
+            baseTokenProgram: TOKEN_PROGRAM_ID,
+            quoteTokenProgram: TOKEN_PROGRAM_ID,
           }
         )
       );
@@ -1594,6 +1700,7 @@ describe("Plasma AMM", async () => {
                   amountIn: new BN(1_000_000),
                   minAmountOut: new BN(0),
                 }),
+                extension: new SwapParamsExtension.V0(),
               },
             },
             {
@@ -1619,7 +1726,10 @@ describe("Plasma AMM", async () => {
                 ],
                 PROGRAM_ID
               )[0],
-              tokenProgram: TOKEN_PROGRAM_ID,
+              baseMint: mintPubkey,
+              quoteMint: NATIVE_MINT,
+              baseTokenProgram: TOKEN_PROGRAM_ID,
+              quoteTokenProgram: TOKEN_PROGRAM_ID,
             }
           )
         );
@@ -1641,7 +1751,10 @@ describe("Plasma AMM", async () => {
             [Buffer.from("vault"), poolKey.toBuffer(), NATIVE_MINT.toBuffer()],
             PROGRAM_ID
           )[0],
-          tokenProgram: TOKEN_PROGRAM_ID,
+          baseMint: mintPubkey,
+          quoteMint: NATIVE_MINT,
+          baseTokenProgram: TOKEN_PROGRAM_ID,
+          quoteTokenProgram: TOKEN_PROGRAM_ID,
         })
       );
 
@@ -1769,6 +1882,7 @@ describe("Plasma AMM", async () => {
                 desiredBaseAmountIn: new BN(50_000_000_000),
                 desiredQuoteAmountIn: new BN(50_000_000_000),
                 initialLpShares: null,
+                extension: new AddLiquidityParamsExtension.V0(),
               },
             },
             {
@@ -1795,7 +1909,10 @@ describe("Plasma AMM", async () => {
                 ],
                 PROGRAM_ID
               )[0],
-              tokenProgram: TOKEN_PROGRAM_ID,
+              baseMint: mintPubkey,
+              quoteMint: NATIVE_MINT,
+              baseTokenProgram: TOKEN_PROGRAM_ID,
+              quoteTokenProgram: TOKEN_PROGRAM_ID,
             }
           )
         );
@@ -1823,6 +1940,7 @@ describe("Plasma AMM", async () => {
                   amountIn: new BN(1_000_000_000),
                   minAmountOut: new BN(0),
                 }),
+                extension: new SwapParamsExtension.V0(),
               },
             },
             {
@@ -1848,7 +1966,10 @@ describe("Plasma AMM", async () => {
                 ],
                 PROGRAM_ID
               )[0],
-              tokenProgram: TOKEN_PROGRAM_ID,
+              baseMint: mintPubkey,
+              quoteMint: NATIVE_MINT,
+              baseTokenProgram: TOKEN_PROGRAM_ID,
+              quoteTokenProgram: TOKEN_PROGRAM_ID,
             }
           )
         );
@@ -1882,7 +2003,10 @@ describe("Plasma AMM", async () => {
               ],
               PROGRAM_ID
             )[0],
-            tokenProgram: TOKEN_PROGRAM_ID,
+            baseMint: mintPubkey,
+            quoteMint: NATIVE_MINT,
+            baseTokenProgram: TOKEN_PROGRAM_ID,
+            quoteTokenProgram: TOKEN_PROGRAM_ID,
           })
         );
 
@@ -2012,6 +2136,7 @@ describe("Plasma AMM", async () => {
               desiredBaseAmountIn: new BN(25_000_000_000),
               desiredQuoteAmountIn: new BN(5_000_000_000),
               initialLpShares: null,
+              extension: new AddLiquidityParamsExtension.V0(),
             },
           },
           {
@@ -2034,7 +2159,10 @@ describe("Plasma AMM", async () => {
               ],
               PROGRAM_ID
             )[0],
-            tokenProgram: TOKEN_PROGRAM_ID,
+            baseMint: mintPubkey,
+            quoteMint: NATIVE_MINT,
+            baseTokenProgram: TOKEN_PROGRAM_ID,
+            quoteTokenProgram: TOKEN_PROGRAM_ID,
           }
         )
       );
@@ -2150,6 +2278,7 @@ describe("Plasma AMM", async () => {
               desiredBaseAmountIn: new BN(50_000_000_000),
               desiredQuoteAmountIn: new BN(50_000_000_000),
               initialLpShares: null,
+              extension: new AddLiquidityParamsExtension.V0(),
             },
           },
           {
@@ -2172,7 +2301,10 @@ describe("Plasma AMM", async () => {
               ],
               PROGRAM_ID
             )[0],
-            tokenProgram: TOKEN_PROGRAM_ID,
+            baseMint: mintPubkey,
+            quoteMint: NATIVE_MINT,
+            baseTokenProgram: TOKEN_PROGRAM_ID,
+            quoteTokenProgram: TOKEN_PROGRAM_ID,
           }
         )
       );
@@ -2195,6 +2327,7 @@ describe("Plasma AMM", async () => {
           {
             params: {
               lpShares: initialLpShares.div(new BN(2)),
+              extension: new RemoveLiquidityParamsExtension.V0(),
             },
           },
           {
@@ -2217,7 +2350,10 @@ describe("Plasma AMM", async () => {
               ],
               PROGRAM_ID
             )[0],
-            tokenProgram: TOKEN_PROGRAM_ID,
+            baseMint: mintPubkey,
+            quoteMint: NATIVE_MINT,
+            baseTokenProgram: TOKEN_PROGRAM_ID,
+            quoteTokenProgram: TOKEN_PROGRAM_ID,
           }
         )
       );
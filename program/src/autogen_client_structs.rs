@@ -10,6 +10,13 @@ pub enum Side {
     Sell,
 }
 
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub enum TokenType {
+    Base,
+    Quote,
+}
+
 #[derive(Debug, Clone, Copy, BorshDeserialize, BorshSerialize)]
 pub struct SwapResult {
     pub side: Side,
@@ -44,7 +51,9 @@ pub struct ProtocolFeeRecipient {
 #[repr(C)]
 pub struct ProtocolFeeRecipients {
     pub recipients: [ProtocolFeeRecipient; 3],
-    _padding: [u64; 12],
+    pub cumulative_quote_fees_checkpoint: u64,
+    pub accumulated_quote_fees_checkpoints: [u64; 3],
+    _padding: [u64; 8],
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone, BorshDeserialize, BorshSerialize)]
@@ -54,7 +63,14 @@ pub struct PoolHeader {
     pub base_params: TokenParams,
     pub quote_params: TokenParams,
     pub fee_recipients: ProtocolFeeRecipients,
-    pub padding: [u64; 13],
+    pub pool_authority: Pubkey,
+    pub status: u64,
+    pub fee_tier: u64,
+    pub lp_mint: Pubkey,
+    pub lp_mint_bump: u32,
+    pub lp_vesting_schedule: u32,
+    pub quote_lp_fees_paid: u64,
+    pub last_lp_vesting_slot: u64,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone, BorshDeserialize, BorshSerialize)]
@@ -71,6 +87,24 @@ pub struct Amm {
     pub quote_reserves: u64,
     pub cumulative_quote_lp_fees: u64,
     pub cumulative_quote_protocol_fees: u64,
+    pub cumulative_price: u128,
+    pub cumulative_liquidity: u128,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, BorshDeserialize, BorshSerialize)]
+pub struct Observation {
+    pub slot: u64,
+    _padding: u64,
+    pub cumulative_price: u128,
+    pub cumulative_liquidity: u128,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, BorshDeserialize, BorshSerialize)]
+pub struct ObservationBufferHeader {
+    pub index: u64,
+    pub length: u64,
 }
 
 #[repr(C)]
@@ -81,7 +115,7 @@ pub struct LpPosition {
     pub withdrawable_lp_shares: u64,
     uncollected_fees: u64,
     collected_fees: u64,
-    pending_shares_to_vest: PendingSharesToVest,
+    pending_shares_to_vest: [PendingSharesToVest; 4],
 }
 
 #[repr(C)]
@@ -91,14 +125,22 @@ pub struct PendingSharesToVest {
     pub lp_shares_to_vest: u64,
 }
 
+#[repr(C)]
+#[derive(Debug, Copy, Clone, BorshDeserialize, BorshSerialize)]
+pub struct FeeTier {
+    pub lp_fee_in_bps: u64,
+    pub protocol_lp_fee_allocation_in_pct: u64,
+    pub num_slots_to_vest_lp_shares: u64,
+    pub enabled: u64,
+}
+
 /* Instruction params. Each struct below must be formatted as IX_NAME + "IxParams" */
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default, PartialEq, BorshDeserialize, BorshSerialize)]
 pub struct InitializePoolIxParams {
-    lp_fee_in_bps: u64,
-    protocol_lp_fee_allocation_in_pct: u64,
+    fee_tier: u64,
     fee_recipients_params: [ProtocolFeeRecipientParams; 3],
-    num_slots_to_vest_lp_shares: Option<u64>,
+    pool_authority: Option<Pubkey>,
 }
 
 #[repr(C)]
@@ -107,12 +149,40 @@ pub struct AddLiquidityIxParams {
     pub desired_base_amount_in: u64,
     pub desired_quote_amount_in: u64,
     pub initial_lp_shares: Option<u64>,
+    pub extension: AddLiquidityParamsExtension,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize)]
+pub enum AddLiquidityParamsExtension {
+    #[default]
+    V0,
+    V1 {
+        native_sol: NativeSolParams,
+        min_lp_shares_out: u64,
+        deadline: SlotDeadline,
+    },
 }
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize)]
 pub struct RemoveLiquidityIxParams {
     pub lp_shares: u64,
+    pub extension: RemoveLiquidityParamsExtension,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize)]
+pub enum RemoveLiquidityParamsExtension {
+    #[default]
+    V0,
+    V1 {
+        native_sol: NativeSolParams,
+        min_base_out: u64,
+        min_quote_out: u64,
+        deadline: SlotDeadline,
+        mode: RemoveLiquidityMode,
+    },
 }
 
 #[repr(C)]
@@ -120,6 +190,48 @@ pub struct RemoveLiquidityIxParams {
 pub struct SwapIxParams {
     pub side: Side,
     pub swap_type: SwapType,
+    pub extension: SwapParamsExtension,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, BorshDeserialize, BorshSerialize)]
+pub enum SwapParamsExtension {
+    V0,
+    V1 {
+        native_sol: NativeSolParams,
+        deadline: SlotDeadline,
+    },
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize)]
+pub struct NativeSolParams {
+    pub wrap: bool,
+    pub unwrap: bool,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize)]
+pub struct SlotDeadline {
+    pub max_slot: Option<u64>,
+    pub min_snapshot_slot: Option<u64>,
+}
+
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize)]
+pub struct SwapRouteIxParams {
+    pub sides: Vec<Side>,
+    pub swap_type: SwapType,
+    pub extension: SwapRouteParamsExtension,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, BorshDeserialize, BorshSerialize)]
+pub enum SwapRouteParamsExtension {
+    V0,
+    V1 {
+        native_sol: NativeSolParams,
+        deadline: SlotDeadline,
+    },
 }
 
 #[repr(C)]
@@ -134,6 +246,124 @@ pub struct RenounceLiquidityIxParams {
     pub allow_fee_withdrawal: bool,
 }
 
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize)]
+pub struct GrowObservationsIxParams {
+    pub new_capacity: u64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize)]
+pub struct UpdatePoolConfigIxParams {
+    lp_fee_in_bps: Option<u64>,
+    protocol_lp_fee_allocation_in_pct: Option<u64>,
+    num_slots_to_vest_lp_shares: Option<u64>,
+    fee_recipients_params: Option<[ProtocolFeeRecipientParams; 3]>,
+    lp_vesting_schedule: Option<VestingSchedule>,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, BorshDeserialize, BorshSerialize)]
+pub enum VestingSchedule {
+    Cliff,
+    Linear,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize)]
+pub enum RemoveLiquidityMode {
+    #[default]
+    Strict,
+    UpTo,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize)]
+pub struct TransferProtocolFeeRecipientIxParams {
+    pub new_recipient: Pubkey,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, BorshDeserialize, BorshSerialize)]
+pub struct SetPoolStatusIxParams {
+    pub status: PoolStatus,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, BorshDeserialize, BorshSerialize)]
+pub enum PoolStatus {
+    Active,
+    SwapsPaused,
+    FullyPaused,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize)]
+pub struct SetFeeTierIxParams {
+    pub lp_fee_in_bps: u64,
+    pub protocol_lp_fee_allocation_in_pct: u64,
+    pub num_slots_to_vest_lp_shares: u64,
+    pub enabled: bool,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize)]
+pub struct TokenizeLpSharesIxParams {
+    pub lp_shares: u64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize)]
+pub struct RedeemLpTokensIxParams {
+    pub lp_tokens: u64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize)]
+pub struct CompoundLpFeesIxParams {
+    pub min_lp_shares_out: u64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, BorshDeserialize, BorshSerialize)]
+pub struct AddLiquiditySingleSidedIxParams {
+    pub token_in: TokenType,
+    pub amount_in: u64,
+    pub min_lp_shares_out: u64,
+    pub extension: AddLiquiditySingleSidedParamsExtension,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize)]
+pub enum AddLiquiditySingleSidedParamsExtension {
+    #[default]
+    V0,
+    V1 {
+        native_sol: NativeSolParams,
+        deadline: SlotDeadline,
+    },
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, BorshDeserialize, BorshSerialize)]
+pub struct RemoveLiquiditySingleSidedIxParams {
+    pub token_out: TokenType,
+    pub lp_shares: u64,
+    pub min_amount_out: u64,
+    pub extension: RemoveLiquiditySingleSidedParamsExtension,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, BorshDeserialize, BorshSerialize)]
+pub enum RemoveLiquiditySingleSidedParamsExtension {
+    #[default]
+    V0,
+    V1 {
+        native_sol: NativeSolParams,
+        deadline: SlotDeadline,
+    },
+}
+
 /* Accounts */
 
 #[repr(C)]
@@ -153,6 +383,27 @@ pub struct LpPositionAccount {
     pub lp_position: LpPosition,
 }
 
+#[repr(C)]
+#[derive(Debug, Copy, Clone, BorshDeserialize, BorshSerialize)]
+pub struct ObservationsAccount {
+    // We omit the discriminator from the autogen_client_structs.rs file to enable Anchor to autogenerate the struct
+    // The account data is followed by an array of Observation entries
+    pub pool: Pubkey,
+    pub buffer: ObservationBufferHeader,
+    _padding: [u64; 1],
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, BorshDeserialize, BorshSerialize)]
+pub struct FeeTierConfigAccount {
+    // We omit the discriminator from the autogen_client_structs.rs file to enable Anchor to autogenerate the struct
+    pub sequence_number: u64,
+    pub admin: Pubkey,
+    pub num_fee_tiers: u64,
+    pub fee_tiers: [FeeTier; 16],
+    _padding: [u64; 8],
+}
+
 /* Events */
 #[repr(C)]
 #[derive(Debug, Copy, Clone, BorshDeserialize, BorshSerialize)]
@@ -209,6 +460,8 @@ pub struct RemoveLiquidityEvent {
     pub user_quote_withdrawn: u64,
     pub user_total_withdrawable_base: u64,
     pub user_total_withdrawable_quote: u64,
+    pub user_lp_shares_requested: u64,
+    pub mode: RemoveLiquidityMode,
 }
 
 #[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
@@ -233,6 +486,7 @@ pub struct InitializePoolEvent {
     pub lp_fee_in_bps: u64,
     pub protocol_fee_in_pct: u64,
     pub fee_recipient_params: [ProtocolFeeRecipientParams; 3],
+    pub pool_authority: Pubkey,
 }
 
 #[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
@@ -246,6 +500,159 @@ pub struct WithdrawProtocolFeesEvent {
     pub fees_withdrawn: u64,
 }
 
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct GrowObservationsEvent {
+    pub previous_capacity: u64,
+    pub new_capacity: u64,
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct UpdatePoolConfigEvent {
+    pub old_lp_fee_in_bps: u64,
+    pub new_lp_fee_in_bps: u64,
+    pub old_protocol_fee_in_pct: u64,
+    pub new_protocol_fee_in_pct: u64,
+    pub old_lp_vesting_window: u64,
+    pub new_lp_vesting_window: u64,
+    pub old_fee_recipient_params: [ProtocolFeeRecipientParams; 3],
+    pub new_fee_recipient_params: [ProtocolFeeRecipientParams; 3],
+    pub old_lp_vesting_schedule: VestingSchedule,
+    pub new_lp_vesting_schedule: VestingSchedule,
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct TransferProtocolFeeRecipientEvent {
+    pub previous_recipient: Pubkey,
+    pub new_recipient: Pubkey,
+    pub shares: u64,
+    pub uncollected_quote_fees: u64,
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct SetPoolStatusEvent {
+    pub previous_status: PoolStatus,
+    pub new_status: PoolStatus,
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct InitializeFeeTierConfigEvent {
+    pub admin: Pubkey,
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct SetFeeTierEvent {
+    pub lp_fee_in_bps: u64,
+    pub protocol_lp_fee_allocation_in_pct: u64,
+    pub num_slots_to_vest_lp_shares: u64,
+    pub enabled: bool,
+    pub is_new_fee_tier: bool,
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct CloseLpPositionEvent {
+    pub lp_position: Pubkey,
+    pub lamports_reclaimed: u64,
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct ClosePoolEvent {
+    pub lamports_reclaimed: u64,
+    pub base_amount_swept: u64,
+    pub quote_amount_swept: u64,
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct InitializeLpMintEvent {
+    pub lp_mint: Pubkey,
+    pub decimals: u8,
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct TokenizeLpSharesEvent {
+    pub lp_shares_tokenized: u64,
+    pub lp_token_fees_redistributed: u64,
+    pub total_lp_shares_tokenized: u64,
+    pub user_lp_shares_available: u64,
+    pub user_lp_shares_locked: u64,
+    pub user_lp_shares_unlocked_for_withdrawal: u64,
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct RedeemLpTokensEvent {
+    pub lp_tokens_redeemed: u64,
+    pub lp_token_fees_redistributed: u64,
+    pub total_lp_shares_tokenized: u64,
+    pub user_lp_shares_available: u64,
+    pub user_lp_shares_locked: u64,
+    pub user_lp_shares_unlocked_for_withdrawal: u64,
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct TransferLpPositionEvent {
+    pub recipient: Pubkey,
+    pub recipient_lp_position_created: bool,
+    pub lp_shares_transferred: u64,
+    pub lp_shares_vesting_transferred: u64,
+    pub quote_fees_transferred: u64,
+    pub recipient_lp_shares_available: u64,
+    pub recipient_lp_shares_locked: u64,
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct MigrateLpPositionEvent {
+    pub lp_position: Pubkey,
+    pub owner: Pubkey,
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct CompoundLpFeesEvent {
+    pub quote_fees_compounded: u64,
+    pub quote_fees_swapped: u64,
+    pub base_amount_bought: u64,
+    pub user_base_deposited: u64,
+    pub user_quote_deposited: u64,
+    pub user_lp_shares_received: u64,
+    pub user_lp_shares_available: u64,
+    pub user_lp_shares_locked: u64,
+    pub user_lp_shares_unlocked_for_withdrawal: u64,
+    pub pool_total_lp_shares: u64,
+    pub pool_total_base_liquidity: u64,
+    pub pool_total_quote_liquidity: u64,
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct AddLiquiditySingleSidedEvent {
+    pub token_in: TokenType,
+    pub user_amount_deposited: u64,
+    pub swap_result: SwapResult,
+    pub pool_total_lp_shares: u64,
+    pub pool_total_base_liquidity: u64,
+    pub pool_total_quote_liquidity: u64,
+    pub user_lp_shares_received: u64,
+    pub user_lp_shares_available: u64,
+    pub user_lp_shares_locked: u64,
+    pub user_lp_shares_unlocked_for_withdrawal: u64,
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct RemoveLiquiditySingleSidedEvent {
+    pub token_out: TokenType,
+    pub user_amount_withdrawn: u64,
+    pub swap_result: SwapResult,
+    pub pool_total_lp_shares: u64,
+    pub pool_total_base_liquidity: u64,
+    pub pool_total_quote_liquidity: u64,
+    pub user_lp_shares_burned: u64,
+    pub user_lp_shares_available: u64,
+    pub user_lp_shares_locked: u64,
+    pub user_lp_shares_unlocked_for_withdrawal: u64,
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct MigratePoolEvent {
+    pub lamports_paid: u64,
+}
+
 #[repr(C)]
 #[derive(Debug, Clone, BorshDeserialize, BorshSerialize)]
 pub enum PlasmaEvent {
@@ -281,4 +688,78 @@ pub enum PlasmaEvent {
         header: PlasmaEventHeader,
         event: WithdrawProtocolFeesEvent,
     },
+    // The Log instruction does not emit an event, this variant keeps the tags aligned with the instructions
+    Log,
+    SwapRoute {
+        header: PlasmaEventHeader,
+        event: SwapEvent,
+    },
+    GrowObservations {
+        header: PlasmaEventHeader,
+        event: GrowObservationsEvent,
+    },
+    UpdatePoolConfig {
+        header: PlasmaEventHeader,
+        event: UpdatePoolConfigEvent,
+    },
+    TransferProtocolFeeRecipient {
+        header: PlasmaEventHeader,
+        event: TransferProtocolFeeRecipientEvent,
+    },
+    SetPoolStatus {
+        header: PlasmaEventHeader,
+        event: SetPoolStatusEvent,
+    },
+    InitializeFeeTierConfig {
+        header: PlasmaEventHeader,
+        event: InitializeFeeTierConfigEvent,
+    },
+    SetFeeTier {
+        header: PlasmaEventHeader,
+        event: SetFeeTierEvent,
+    },
+    CloseLpPosition {
+        header: PlasmaEventHeader,
+        event: CloseLpPositionEvent,
+    },
+    ClosePool {
+        header: PlasmaEventHeader,
+        event: ClosePoolEvent,
+    },
+    InitializeLpMint {
+        header: PlasmaEventHeader,
+        event: InitializeLpMintEvent,
+    },
+    TokenizeLpShares {
+        header: PlasmaEventHeader,
+        event: TokenizeLpSharesEvent,
+    },
+    RedeemLpTokens {
+        header: PlasmaEventHeader,
+        event: RedeemLpTokensEvent,
+    },
+    TransferLpPosition {
+        header: PlasmaEventHeader,
+        event: TransferLpPositionEvent,
+    },
+    MigrateLpPosition {
+        header: PlasmaEventHeader,
+        event: MigrateLpPositionEvent,
+    },
+    CompoundLpFees {
+        header: PlasmaEventHeader,
+        event: CompoundLpFeesEvent,
+    },
+    AddLiquiditySingleSided {
+        header: PlasmaEventHeader,
+        event: AddLiquiditySingleSidedEvent,
+    },
+    RemoveLiquiditySingleSided {
+        header: PlasmaEventHeader,
+        event: RemoveLiquiditySingleSidedEvent,
+    },
+    MigratePool {
+        header: PlasmaEventHeader,
+        event: MigratePoolEvent,
+    },
 }
@@ -3,8 +3,10 @@ pub mod program;
 #[cfg(feature = "autogen-client-structs")]
 mod autogen_client_structs;
 
+use crate::program::error::PlasmaError;
 use crate::program::instruction::PlasmaInstruction;
-use crate::program::validation::loaders::PlasmaLogContext;
+use crate::program::system_utils;
+use crate::program::validation::loaders::{FeeTierConfigContext, PlasmaLogContext};
 pub use program::processor::*;
 use program::validation::loaders::PlasmaPoolContext;
 use solana_program::{
@@ -80,7 +82,7 @@ pub fn process_instruction(
 ) -> ProgramResult {
     assert_with_msg(
         *program_id == crate::id(),
-        ProgramError::IncorrectProgramId,
+        PlasmaError::IncorrectProgramId,
         "Incorrect program ID",
     )?;
 
@@ -102,12 +104,12 @@ pub fn process_instruction(
         let authority = next_account_info(&mut accounts.iter())?;
         assert_with_msg(
             authority.is_signer,
-            ProgramError::MissingRequiredSignature,
+            PlasmaError::InvalidLogAuthority,
             "Log authority must sign through CPI",
         )?;
         assert_with_msg(
             authority.key == &plasma_log_authority::id(),
-            ProgramError::InvalidArgument,
+            PlasmaError::InvalidLogAuthority,
             "Invalid log authority",
         )?;
         return Ok(());
@@ -116,10 +118,35 @@ pub fn process_instruction(
     let (program_accounts, accounts) = accounts.split_at(4);
     let accounts_iter = &mut program_accounts.iter();
     let plasma_log_context = PlasmaLogContext::load(accounts_iter)?;
-    let pool_context = if instruction == PlasmaInstruction::InitializePool {
-        PlasmaPoolContext::load_init(accounts_iter)?
-    } else {
-        PlasmaPoolContext::load(accounts_iter)?
+
+    // Fee tier config instructions act on the global config account instead of a pool
+    match instruction {
+        PlasmaInstruction::InitializeFeeTierConfig => {
+            msg!("InitializeFeeTierConfig");
+            let config_context = FeeTierConfigContext::load_init(accounts_iter)?;
+            return fee_tiers::process_initialize_fee_tier_config(&config_context, accounts)
+                .and_then(|event| {
+                    plasma_log_context.record_fee_tier_config_event(
+                        instruction,
+                        &config_context,
+                        event,
+                    )
+                });
+        }
+        PlasmaInstruction::SetFeeTier => {
+            msg!("SetFeeTier");
+            let config_context = FeeTierConfigContext::load(accounts_iter)?;
+            return fee_tiers::process_set_fee_tier(&config_context, data).and_then(|event| {
+                plasma_log_context.record_fee_tier_config_event(instruction, &config_context, event)
+            });
+        }
+        _ => {}
+    }
+
+    let pool_context = match instruction {
+        PlasmaInstruction::InitializePool => PlasmaPoolContext::load_init(accounts_iter)?,
+        PlasmaInstruction::MigratePool => PlasmaPoolContext::load_legacy(accounts_iter)?,
+        _ => PlasmaPoolContext::load(accounts_iter)?,
     };
 
     match instruction {
@@ -171,8 +198,116 @@ pub fn process_instruction(
                 record_event!(event, plasma_log_context, instruction, pool_context)
             })?
         }
-        PlasmaInstruction::Log => {
-            // The log instruction is handled at the beginning of this function
+        PlasmaInstruction::SwapRoute => {
+            msg!("SwapRoute");
+            swap::process_swap_route(&pool_context, accounts, data).and_then(|hop_events| {
+                // Each hop is recorded as an event of the pool it was executed against
+                for (hop_pool_context, event) in hop_events {
+                    record_event!(event, plasma_log_context, instruction, hop_pool_context)?;
+                }
+                Ok(())
+            })?
+        }
+        PlasmaInstruction::GrowObservations => {
+            msg!("GrowObservations");
+            observations::process_grow_observations(&pool_context, accounts, data).and_then(
+                |event| record_event!(event, plasma_log_context, instruction, pool_context),
+            )?
+        }
+        PlasmaInstruction::UpdatePoolConfig => {
+            msg!("UpdatePoolConfig");
+            admin::process_update_pool_config(&pool_context, accounts, data).and_then(|event| {
+                record_event!(event, plasma_log_context, instruction, pool_context)
+            })?
+        }
+        PlasmaInstruction::TransferProtocolFeeRecipient => {
+            msg!("TransferProtocolFeeRecipient");
+            fees::process_transfer_protocol_fee_recipient(&pool_context, data).and_then(
+                |event| record_event!(event, plasma_log_context, instruction, pool_context),
+            )?
+        }
+        PlasmaInstruction::SetPoolStatus => {
+            msg!("SetPoolStatus");
+            admin::process_set_pool_status(&pool_context, accounts, data).and_then(|event| {
+                record_event!(event, plasma_log_context, instruction, pool_context)
+            })?
+        }
+        PlasmaInstruction::CloseLpPosition => {
+            msg!("CloseLpPosition");
+            liquidity::process_close_lp_position(&pool_context, accounts).and_then(|event| {
+                record_event!(event, plasma_log_context, instruction, pool_context)
+            })?
+        }
+        PlasmaInstruction::ClosePool => {
+            msg!("ClosePool");
+            admin::process_close_pool(&pool_context, accounts).and_then(|event| {
+                record_event!(event, plasma_log_context, instruction, pool_context)
+            })?;
+            // The pool account is closed last because recording the event reads the pool header
+            system_utils::close_account(
+                pool_context.pool_info.as_ref(),
+                pool_context.signer.as_ref(),
+            )?
+        }
+        PlasmaInstruction::InitializeLpMint => {
+            msg!("InitializeLpMint");
+            lp_tokens::process_initialize_lp_mint(&pool_context, accounts).and_then(|event| {
+                record_event!(event, plasma_log_context, instruction, pool_context)
+            })?
+        }
+        PlasmaInstruction::TokenizeLpShares => {
+            msg!("TokenizeLpShares");
+            lp_tokens::process_tokenize_lp_shares(&pool_context, accounts, data).and_then(
+                |event| record_event!(event, plasma_log_context, instruction, pool_context),
+            )?
+        }
+        PlasmaInstruction::RedeemLpTokens => {
+            msg!("RedeemLpTokens");
+            lp_tokens::process_redeem_lp_tokens(&pool_context, accounts, data).and_then(
+                |event| record_event!(event, plasma_log_context, instruction, pool_context),
+            )?
+        }
+        PlasmaInstruction::TransferLpPosition => {
+            msg!("TransferLpPosition");
+            liquidity::process_transfer_lp_position(&pool_context, accounts).and_then(|event| {
+                record_event!(event, plasma_log_context, instruction, pool_context)
+            })?
+        }
+        PlasmaInstruction::MigrateLpPosition => {
+            msg!("MigrateLpPosition");
+            liquidity::process_migrate_lp_position(&pool_context, accounts).and_then(|event| {
+                record_event!(event, plasma_log_context, instruction, pool_context)
+            })?
+        }
+        PlasmaInstruction::CompoundLpFees => {
+            msg!("CompoundLpFees");
+            fees::process_compound_lp_fees(&pool_context, accounts, data).and_then(|event| {
+                record_event!(event, plasma_log_context, instruction, pool_context)
+            })?
+        }
+        PlasmaInstruction::AddLiquiditySingleSided => {
+            msg!("AddLiquiditySingleSided");
+            liquidity::process_add_liquidity_single_sided(&pool_context, accounts, data).and_then(
+                |event| record_event!(event, plasma_log_context, instruction, pool_context),
+            )?
+        }
+        PlasmaInstruction::RemoveLiquiditySingleSided => {
+            msg!("RemoveLiquiditySingleSided");
+            liquidity::process_remove_liquidity_single_sided(&pool_context, accounts, data)
+                .and_then(|event| {
+                    record_event!(event, plasma_log_context, instruction, pool_context)
+                })?
+        }
+        PlasmaInstruction::MigratePool => {
+            msg!("MigratePool");
+            admin::process_migrate_pool(&pool_context, accounts).and_then(|event| {
+                record_event!(event, plasma_log_context, instruction, pool_context)
+            })?
+        }
+        PlasmaInstruction::Log
+        | PlasmaInstruction::InitializeFeeTierConfig
+        | PlasmaInstruction::SetFeeTier => {
+            // These instructions are handled at the beginning of this function
             unreachable!()
         }
     }
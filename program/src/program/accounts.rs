@@ -1,18 +1,32 @@
 use std::{
     fmt::Display,
+    mem::size_of,
     ops::{Deref, DerefMut},
     slice::{Iter, IterMut},
 };
 
-use bytemuck::{Pod, Zeroable};
+use borsh::{BorshDeserialize as Deserialize, BorshSerialize as Serialize};
+use bytemuck::{try_cast_slice_mut, try_from_bytes_mut, Pod, Zeroable};
+use num_enum::TryFromPrimitive;
 use solana_program::{msg, program_error::ProgramError, pubkey::Pubkey};
+use static_assertions::const_assert_eq;
 
-use plasma_state::{amm::Amm, lp::LpPosition};
+use plasma_state::{
+    amm::Amm,
+    fixed::I80F48,
+    lp::{LpPosition, PendingSharesToVest, VestingSchedule, MAX_VESTING_TRANCHES},
+    oracle::{Observation, ObservationBufferHeader},
+};
 
 use crate::assert_with_msg;
+use crate::program::error::PlasmaError;
 
 pub const LP_POSITION_ACCOUNT_DISCRIMINATOR: [u8; 8] = [101, 177, 26, 44, 161, 242, 87, 136];
 pub const POOL_ACCOUNT_DISCRIMINATOR: [u8; 8] = [116, 210, 187, 119, 196, 196, 52, 137];
+pub const OBSERVATIONS_ACCOUNT_DISCRIMINATOR: [u8; 8] = [235, 12, 79, 82, 47, 161, 49, 29];
+pub const FEE_TIER_CONFIG_ACCOUNT_DISCRIMINATOR: [u8; 8] = [188, 5, 219, 206, 243, 133, 123, 15];
+
+pub const MAX_FEE_TIERS: usize = 16;
 
 #[derive(Debug, Copy, Clone, Zeroable, Pod)]
 #[repr(C)]
@@ -26,7 +40,7 @@ pub struct TokenParams {
     /// Pubkey of the token mint.
     pub mint_key: Pubkey,
 
-    /// Pubkey of the token vault.
+    /// Pubkey of the token vault. The token program of the pool is the owner of the vault.
     pub vault_key: Pubkey,
 }
 
@@ -43,14 +57,20 @@ pub struct ProtocolFeeRecipient {
 #[repr(C)]
 pub struct ProtocolFeeRecipients {
     pub recipients: [ProtocolFeeRecipient; 3],
-    _padding: [u64; 12],
+    /// Value of `cumulative_quote_protocol_fees` when the recipients were last updated.
+    pub cumulative_quote_fees_checkpoint: u64,
+    /// Fees accumulated by each recipient before the recipients were last updated.
+    pub accumulated_quote_fees_checkpoints: [u64; 3],
+    _padding: [u64; 8],
 }
 
 impl ProtocolFeeRecipients {
     pub fn new(recipients: [ProtocolFeeRecipient; 3]) -> Self {
         Self {
             recipients,
-            _padding: [0; 12],
+            cumulative_quote_fees_checkpoint: 0,
+            accumulated_quote_fees_checkpoints: [0; 3],
+            _padding: [0; 8],
         }
     }
 
@@ -70,7 +90,77 @@ pub struct PoolHeader {
     pub base_params: TokenParams,
     pub quote_params: TokenParams,
     pub fee_recipients: ProtocolFeeRecipients,
-    pub padding: [u64; 13],
+    /// Authority allowed to update the pool configuration. The pool is immutable if this is
+    /// the default pubkey.
+    pub pool_authority: Pubkey,
+    /// See `PoolStatus`
+    pub status: u64,
+    /// LP fee in basis points that the pool was created with, used to derive the pool address.
+    /// `UpdatePoolConfig` can change the fees of the AMM, this field keeps the original tier.
+    pub fee_tier: u64,
+    /// Mint of the LP tokens of the pool, the default pubkey until `InitializeLpMint` is called
+    pub lp_mint: Pubkey,
+    pub lp_mint_bump: u32,
+    /// See `VestingSchedule`
+    pub lp_vesting_schedule: u32,
+    /// LP fees withdrawn from the quote vault or compounded into the reserves. Fees paid before
+    /// this was tracked are not included.
+    pub quote_lp_fees_paid: u64,
+    /// Last slot at which LP shares started vesting in a position of the pool. Every tranche is
+    /// fully vested once the vesting window has elapsed since this slot.
+    pub last_lp_vesting_slot: u64,
+}
+
+// Existing pools share this layout, all of the padding of the header is in use
+const_assert_eq!(size_of::<PoolHeader>(), 528);
+
+impl PoolHeader {
+    pub fn assert_pool_authority(&self, signer: &Pubkey) -> Result<(), ProgramError> {
+        assert_with_msg(
+            self.pool_authority != Pubkey::default(),
+            PlasmaError::MissingPoolAuthority,
+            "Pool has no authority",
+        )?;
+        assert_with_msg(
+            self.pool_authority == *signer,
+            PlasmaError::InvalidPoolAuthority,
+            "Signer is not the pool authority",
+        )
+    }
+
+    pub fn get_lp_vesting_schedule(&self) -> Result<VestingSchedule, ProgramError> {
+        match self.lp_vesting_schedule {
+            0 => Ok(VestingSchedule::Cliff),
+            1 => Ok(VestingSchedule::Linear),
+            schedule => {
+                msg!(
+                    "[ERROR] Invalid VestingSchedule {}. Data corruption detected.",
+                    schedule
+                );
+                Err(PlasmaError::InvalidPoolAccount.into())
+            }
+        }
+    }
+
+    /// Swaps and deposits are only allowed while the pool is active
+    pub fn assert_active(&self) -> Result<(), ProgramError> {
+        let status = PoolStatus::parse(self.status)?;
+        assert_with_msg(
+            status == PoolStatus::Active,
+            PlasmaError::PoolPaused,
+            &format!("Pool is paused ({})", status),
+        )
+    }
+
+    /// Withdrawals are allowed unless the pool is fully paused
+    pub fn assert_withdrawals_enabled(&self) -> Result<(), ProgramError> {
+        let status = PoolStatus::parse(self.status)?;
+        assert_with_msg(
+            status != PoolStatus::FullyPaused,
+            PlasmaError::PoolPaused,
+            &format!("Pool is paused ({})", status),
+        )
+    }
 }
 
 #[derive(Debug, Copy, Clone, Zeroable, Pod)]
@@ -80,6 +170,10 @@ pub struct PoolAccount {
     pub amm: Amm,
 }
 
+/// Size of pool accounts created before the AMM tracked the cumulative price and liquidity. The
+/// legacy layout is a prefix of the current one, see `MigratePool`.
+pub const LEGACY_POOL_ACCOUNT_SIZE: usize = size_of::<PoolAccount>() - 2 * size_of::<I80F48>();
+
 impl PoolAccount {
     pub fn increment_sequence_number(&mut self) {
         self.header.sequence_number += 1;
@@ -95,34 +189,145 @@ impl PoolAccount {
 
         if total_shares > u64::MAX as u128 {
             msg!("Total shares exceeds u64::MAX");
-            return Err(ProgramError::InvalidArgument);
+            return Err(PlasmaError::ProtocolFeeAccountingError.into());
         }
 
+        // Only the fees accumulated since the last recipient update are split by shares
+        let new_protocol_fees = self
+            .amm
+            .cumulative_quote_protocol_fees
+            .checked_sub(self.header.fee_recipients.cumulative_quote_fees_checkpoint)
+            .ok_or_else(|| {
+                msg!("Cumulative protocol fees are below the recipient checkpoint");
+                PlasmaError::ProtocolFeeAccountingError
+            })?;
+
         let mut accumulated_fees_all_recpients = 0;
 
-        for recipient in self.header.fee_recipients.iter_mut() {
-            recipient.total_accumulated_quote_fees = self
-                .amm
-                .cumulative_quote_protocol_fees
+        let fee_recipients = &mut self.header.fee_recipients;
+        for (recipient, checkpoint) in fee_recipients
+            .recipients
+            .iter_mut()
+            .zip(fee_recipients.accumulated_quote_fees_checkpoints.iter())
+        {
+            recipient.total_accumulated_quote_fees = new_protocol_fees
                 .checked_mul(recipient.shares)
                 .and_then(|total_unnormalized_fee| {
                     total_unnormalized_fee.checked_div(total_shares as u64)
                 })
+                .and_then(|fee| fee.checked_add(*checkpoint))
                 .ok_or_else(|| {
                     msg!("Overflow while calculating total_accumulated_quote_fees");
-                    ProgramError::InvalidArgument
+                    PlasmaError::ProtocolFeeAccountingError
                 })?;
             accumulated_fees_all_recpients += recipient.total_accumulated_quote_fees;
         }
 
         assert_with_msg(
             self.amm.cumulative_quote_protocol_fees >= accumulated_fees_all_recpients,
-            ProgramError::InvalidAccountData,
+            PlasmaError::ProtocolFeeAccountingError,
             "Cumulative protocol fees does not match the sum of all recipients",
         )?;
         Ok(())
     }
 
+    /// Replaces the protocol fee recipients and their shares.
+    ///
+    /// The fees accumulated so far are checkpointed, so the new shares only apply to fees
+    /// collected after this update. A recipient that remains in the new set keeps its
+    /// uncollected fees, while a removed recipient must have withdrawn all of its fees.
+    pub fn set_protocol_fee_recipients(
+        &mut self,
+        recipients: [(Pubkey, u64); 3],
+    ) -> Result<(), ProgramError> {
+        self.update_protocol_fee_recipients_post_swap()?;
+
+        assert_with_msg(
+            recipients
+                .iter()
+                .map(|(_, shares)| *shares as u128)
+                .sum::<u128>()
+                < 10000,
+            PlasmaError::TooManyProtocolFeeShares,
+            "The total shares must sum to less than 10000",
+        )?;
+        assert_with_msg(
+            recipients[0].0 != recipients[1].0
+                && recipients[0].0 != recipients[2].0
+                && recipients[1].0 != recipients[2].0,
+            PlasmaError::DuplicateProtocolFeeRecipients,
+            "Protocol fee recipients must be different",
+        )?;
+
+        for recipient in self.header.fee_recipients.iter() {
+            if !recipients
+                .iter()
+                .any(|(key, _)| *key == recipient.recipient)
+            {
+                assert_with_msg(
+                    recipient.collected_quote_fees >= recipient.total_accumulated_quote_fees,
+                    PlasmaError::UncollectedProtocolFees,
+                    &format!(
+                        "Protocol fee recipient {} must withdraw its fees before being removed",
+                        recipient.recipient
+                    ),
+                )?;
+            }
+        }
+
+        let previous_recipients = self.header.fee_recipients.recipients;
+        let mut fee_recipients = ProtocolFeeRecipients::new(recipients.map(|(key, shares)| {
+            previous_recipients
+                .iter()
+                .find(|r| r.recipient == key)
+                .map(|r| ProtocolFeeRecipient { shares, ..*r })
+                .unwrap_or(ProtocolFeeRecipient {
+                    recipient: key,
+                    shares,
+                    total_accumulated_quote_fees: 0,
+                    collected_quote_fees: 0,
+                })
+        }));
+        fee_recipients.cumulative_quote_fees_checkpoint = self.amm.cumulative_quote_protocol_fees;
+        for (checkpoint, recipient) in fee_recipients
+            .accumulated_quote_fees_checkpoints
+            .iter_mut()
+            .zip(fee_recipients.recipients.iter())
+        {
+            *checkpoint = recipient.total_accumulated_quote_fees;
+        }
+        self.header.fee_recipients = fee_recipients;
+        Ok(())
+    }
+
+    /// Moves the slot of a protocol fee recipient to a new pubkey. The shares and the fees
+    /// accumulated by the slot, collected or not, are transferred with it.
+    pub fn transfer_protocol_fee_recipient(
+        &mut self,
+        recipient: &Pubkey,
+        new_recipient: &Pubkey,
+    ) -> Result<ProtocolFeeRecipient, ProgramError> {
+        self.update_protocol_fee_recipients_post_swap()?;
+
+        assert_with_msg(
+            self.header
+                .fee_recipients
+                .iter()
+                .all(|r| r.recipient != *new_recipient),
+            PlasmaError::DuplicateProtocolFeeRecipients,
+            "New recipient is already a protocol fee recipient",
+        )?;
+        let recipient = self
+            .header
+            .fee_recipients
+            .iter_mut()
+            .find(|r| r.recipient == *recipient)
+            .ok_or(PlasmaError::IncorrectProtocolFeeRecipient)?;
+        recipient.recipient = *new_recipient;
+
+        Ok(*recipient)
+    }
+
     /// Withdraws protocol fees for a given recipient. Error if the recipient is not one of the protocol fee recipients.
     pub fn withdraw_protocol_fee(&mut self, recipient: &Pubkey) -> Result<u64, ProgramError> {
         let recipient_index = self
@@ -130,7 +335,7 @@ impl PoolAccount {
             .fee_recipients
             .iter()
             .position(|r: &ProtocolFeeRecipient| r.recipient == *recipient)
-            .ok_or(ProgramError::InvalidArgument)?;
+            .ok_or(PlasmaError::IncorrectProtocolFeeRecipient)?;
         let recipient = &mut self.header.fee_recipients.recipients[recipient_index];
 
         let withdrawable_amount = recipient
@@ -156,6 +361,39 @@ impl DerefMut for PoolAccount {
     }
 }
 
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, TryFromPrimitive, Serialize, Deserialize)]
+#[repr(u64)]
+pub enum PoolStatus {
+    #[default]
+    Active,
+    /// Swaps and deposits are disabled, withdrawals are still allowed
+    SwapsPaused,
+    /// Swaps, deposits and withdrawals are disabled
+    FullyPaused,
+}
+
+impl PoolStatus {
+    pub fn parse(status: u64) -> Result<Self, ProgramError> {
+        PoolStatus::try_from(status).map_err(|_| {
+            msg!(
+                "[ERROR] Invalid PoolStatus {}. Data corruption detected.",
+                status
+            );
+            PlasmaError::InvalidPoolAccount.into()
+        })
+    }
+}
+
+impl Display for PoolStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PoolStatus::Active => write!(f, "Active"),
+            PoolStatus::SwapsPaused => write!(f, "SwapsPaused"),
+            PoolStatus::FullyPaused => write!(f, "FullyPaused"),
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 #[repr(u64)]
 pub enum LpPositionStatus {
@@ -172,7 +410,7 @@ impl LpPositionStatus {
                 "[ERROR] Invalid LpPositionStatus {}. Data corruption detected.",
                 status
             );
-            ProgramError::InvalidAccountData
+            PlasmaError::InvalidLpPositionAccount.into()
         })
     }
 }
@@ -216,6 +454,21 @@ pub struct LpPositionAccount {
     pub lp_position: LpPosition,
 }
 
+/// Size of LP position accounts created before positions had multiple vesting tranches. The
+/// legacy layout is a prefix of the current one, see `MigrateLpPosition`.
+pub const LEGACY_LP_POSITION_ACCOUNT_SIZE: usize =
+    size_of::<LpPositionAccount>() - (MAX_VESTING_TRANCHES - 1) * size_of::<PendingSharesToVest>();
+
+impl LpPositionAccount {
+    /// Renounced positions can never add, remove or move liquidity again
+    pub fn is_renounced(&self) -> Result<bool, ProgramError> {
+        Ok(matches!(
+            LpPositionStatus::parse(self.status)?,
+            LpPositionStatus::RenouncedWithBurnedFees | LpPositionStatus::RenouncedWithFeeWithdawal
+        ))
+    }
+}
+
 impl Deref for LpPositionAccount {
     type Target = LpPosition;
 
@@ -229,3 +482,208 @@ impl DerefMut for LpPositionAccount {
         &mut self.lp_position
     }
 }
+
+/// Header of the observations account of a pool. The header is followed by the entries of the
+/// ring buffer, so the capacity of the buffer is derived from the size of the account.
+#[derive(Debug, Copy, Clone, Zeroable, Pod)]
+#[repr(C)]
+pub struct ObservationsAccount {
+    pub discriminator: [u8; 8],
+    pub pool: Pubkey,
+    pub buffer: ObservationBufferHeader,
+    _padding: [u64; 1],
+}
+
+impl ObservationsAccount {
+    pub fn new(pool: Pubkey) -> Self {
+        Self {
+            discriminator: OBSERVATIONS_ACCOUNT_DISCRIMINATOR,
+            pool,
+            buffer: ObservationBufferHeader::default(),
+            _padding: [0; 1],
+        }
+    }
+
+    pub fn get_space(capacity: u64) -> usize {
+        size_of::<Self>() + capacity as usize * size_of::<Observation>()
+    }
+
+    pub fn get_capacity(data_len: usize) -> u64 {
+        (data_len.saturating_sub(size_of::<Self>()) / size_of::<Observation>()) as u64
+    }
+
+    /// Splits the data of an observations account into its header and its entries
+    pub fn split_mut(data: &mut [u8]) -> Result<(&mut Self, &mut [Observation]), ProgramError> {
+        if data.len() < size_of::<Self>() {
+            return Err(PlasmaError::InvalidObservationsAccount.into());
+        }
+        let capacity = Self::get_capacity(data.len()) as usize;
+        let (header_bytes, entry_bytes) = data.split_at_mut(size_of::<Self>());
+        let header = try_from_bytes_mut::<Self>(header_bytes)
+            .map_err(|_| PlasmaError::InvalidObservationsAccount)?;
+        let entries = try_cast_slice_mut::<u8, Observation>(
+            &mut entry_bytes[..capacity * size_of::<Observation>()],
+        )
+        .map_err(|_| PlasmaError::InvalidObservationsAccount)?;
+        Ok((header, entries))
+    }
+}
+
+/// Fee configuration that new pools must pick from. A tier is identified by its LP fee.
+#[derive(Debug, Default, Copy, Clone, Zeroable, Pod)]
+#[repr(C)]
+pub struct FeeTier {
+    pub lp_fee_in_bps: u64,
+    pub protocol_lp_fee_allocation_in_pct: u64,
+    /// Number of slots that the LP shares of new pools are vested over
+    pub num_slots_to_vest_lp_shares: u64,
+    /// Disabled tiers cannot be used to create new pools, existing pools are not affected
+    pub enabled: u64,
+}
+
+impl FeeTier {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled != 0
+    }
+}
+
+/// Global allow-list of fee tiers, stored at the PDA with seeds [b"fee_tier_config"]
+#[derive(Debug, Copy, Clone, Zeroable, Pod)]
+#[repr(C)]
+pub struct FeeTierConfigAccount {
+    pub discriminator: [u8; 8],
+    pub sequence_number: u64,
+    /// Authority allowed to add, update and disable fee tiers
+    pub admin: Pubkey,
+    pub num_fee_tiers: u64,
+    pub fee_tiers: [FeeTier; MAX_FEE_TIERS],
+    _padding: [u64; 8],
+}
+
+impl FeeTierConfigAccount {
+    pub fn new(admin: Pubkey) -> Self {
+        Self {
+            discriminator: FEE_TIER_CONFIG_ACCOUNT_DISCRIMINATOR,
+            sequence_number: 0,
+            admin,
+            num_fee_tiers: 0,
+            fee_tiers: [FeeTier::default(); MAX_FEE_TIERS],
+            _padding: [0; 8],
+        }
+    }
+
+    pub fn assert_admin(&self, signer: &Pubkey) -> Result<(), ProgramError> {
+        assert_with_msg(
+            self.admin == *signer,
+            PlasmaError::InvalidFeeTierConfigAdmin,
+            "Signer is not the fee tier config admin",
+        )
+    }
+
+    pub fn fee_tiers(&self) -> &[FeeTier] {
+        &self.fee_tiers[..self.num_fee_tiers as usize]
+    }
+
+    pub fn get_fee_tier(&self, lp_fee_in_bps: u64) -> Option<&FeeTier> {
+        self.fee_tiers()
+            .iter()
+            .find(|tier| tier.lp_fee_in_bps == lp_fee_in_bps)
+    }
+
+    /// Returns the tier that caps the fees of a pool created on `pool_fee_tier` when its LP fee
+    /// is updated to `lp_fee_in_bps`. A pool can lower its fees within its own tier, or move to
+    /// the fee of another enabled tier.
+    pub fn get_fee_cap(
+        &self,
+        pool_fee_tier: u64,
+        lp_fee_in_bps: u64,
+    ) -> Result<FeeTier, ProgramError> {
+        match self.get_fee_tier(pool_fee_tier) {
+            Some(fee_tier) if lp_fee_in_bps <= fee_tier.lp_fee_in_bps => Ok(*fee_tier),
+            _ => self.get_enabled_fee_tier(lp_fee_in_bps),
+        }
+    }
+
+    /// Returns the enabled tier with the given LP fee
+    pub fn get_enabled_fee_tier(&self, lp_fee_in_bps: u64) -> Result<FeeTier, ProgramError> {
+        let fee_tier = self.get_fee_tier(lp_fee_in_bps).ok_or_else(|| {
+            msg!("Fee tier {} bps does not exist", lp_fee_in_bps);
+            ProgramError::from(PlasmaError::FeeTierNotFound)
+        })?;
+        assert_with_msg(
+            fee_tier.is_enabled(),
+            PlasmaError::FeeTierDisabled,
+            &format!("Fee tier {} bps is disabled", lp_fee_in_bps),
+        )?;
+        Ok(*fee_tier)
+    }
+
+    /// Adds the tier, or overwrites the tier with the same LP fee. Returns the previous tier.
+    pub fn set_fee_tier(&mut self, fee_tier: FeeTier) -> Result<Option<FeeTier>, ProgramError> {
+        let num_fee_tiers = self.num_fee_tiers as usize;
+        if let Some(existing) = self.fee_tiers[..num_fee_tiers]
+            .iter_mut()
+            .find(|tier| tier.lp_fee_in_bps == fee_tier.lp_fee_in_bps)
+        {
+            let previous = *existing;
+            *existing = fee_tier;
+            return Ok(Some(previous));
+        }
+        assert_with_msg(
+            num_fee_tiers < MAX_FEE_TIERS,
+            PlasmaError::TooManyFeeTiers,
+            &format!("There can be at most {} fee tiers", MAX_FEE_TIERS),
+        )?;
+        self.fee_tiers[num_fee_tiers] = fee_tier;
+        self.num_fee_tiers += 1;
+        Ok(None)
+    }
+}
+
+#[test]
+fn test_account_discriminators() {
+    // Discriminators follow the Anchor convention so that generated clients can decode accounts
+    for (discriminator, name) in [
+        (POOL_ACCOUNT_DISCRIMINATOR, "PoolAccount"),
+        (LP_POSITION_ACCOUNT_DISCRIMINATOR, "LpPositionAccount"),
+        (OBSERVATIONS_ACCOUNT_DISCRIMINATOR, "ObservationsAccount"),
+        (
+            FEE_TIER_CONFIG_ACCOUNT_DISCRIMINATOR,
+            "FeeTierConfigAccount",
+        ),
+    ] {
+        let hash = solana_program::hash::hash(format!("account:{}", name).as_bytes());
+        assert_eq!(discriminator, hash.to_bytes()[..8]);
+    }
+}
+
+#[test]
+fn test_fee_cap() {
+    let mut config = FeeTierConfigAccount::new(Pubkey::default());
+    for (lp_fee_in_bps, protocol_lp_fee_allocation_in_pct, enabled) in
+        [(30, 20, 1), (100, 10, 1), (300, 0, 0)]
+    {
+        config
+            .set_fee_tier(FeeTier {
+                lp_fee_in_bps,
+                protocol_lp_fee_allocation_in_pct,
+                num_slots_to_vest_lp_shares: 0,
+                enabled,
+            })
+            .unwrap();
+    }
+
+    // Lower fees are capped by the tier of the pool
+    let cap = config.get_fee_cap(30, 5).unwrap();
+    assert_eq!(cap.lp_fee_in_bps, 30);
+    assert_eq!(cap.protocol_lp_fee_allocation_in_pct, 20);
+
+    // Higher fees must be the fee of another enabled tier
+    let cap = config.get_fee_cap(30, 100).unwrap();
+    assert_eq!(cap.protocol_lp_fee_allocation_in_pct, 10);
+    assert!(config.get_fee_cap(30, 50).is_err());
+    assert!(config.get_fee_cap(30, 300).is_err());
+
+    // The tier of the pool caps its fees even when it is disabled
+    assert_eq!(config.get_fee_cap(300, 200).unwrap().lp_fee_in_bps, 300);
+}
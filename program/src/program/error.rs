@@ -0,0 +1,270 @@
+use num_enum::TryFromPrimitive;
+use plasma_state::errors::PlasmaStateError;
+use solana_program::program_error::ProgramError;
+use thiserror::Error;
+
+/// Errors returned by the Plasma program as `ProgramError::Custom(code)`.
+///
+/// The discriminant of each variant is its on-chain error code. Codes are part of the public
+/// interface of the program, so variants must never be reordered or removed.
+#[repr(u32)]
+#[derive(Error, TryFromPrimitive, Debug, Copy, Clone, PartialEq, Eq)]
+#[rustfmt::skip]
+pub enum PlasmaError {
+    // Errors returned by the AMM, see `PlasmaStateError`
+    #[error("Swap violated the constant product invariant")]
+    InvariantViolation = 0,
+    #[error("Fees do not match the expected amount")]
+    MismatchedFees = 1,
+    #[error("Pool is uninitialized")]
+    UninitializedPool = 2,
+    #[error("Swap amounts do not match")]
+    SwapAmountMismatch = 3,
+    #[error("Calculation overflowed")]
+    Overflow = 4,
+    #[error("Calculation underflowed")]
+    Underflow = 5,
+    #[error("Unexpected argument")]
+    UnexpectedArgument = 6,
+    #[error("Missing expected argument")]
+    MissingExpectedArgument = 7,
+    #[error("Must mint at least 1 LP share")]
+    BelowMinimumLpSharesRequired = 8,
+    #[error("Must withdraw at least 1 base token and 1 quote token")]
+    BelowMinimumWithdrawalRequired = 9,
+    #[error("Previous vesting period not over")]
+    VestingPeriodNotOver = 10,
+    #[error("Signer is not one of the protocol fee recipients")]
+    IncorrectProtocolFeeRecipient = 11,
+    #[error("Too many LP shares supplied")]
+    TooManyShares = 12,
+    #[error("SwapExactOut amount too large")]
+    SwapExactOutTooLarge = 13,
+    #[error("SwapExactIn amount too large")]
+    SwapExactInTooLarge = 14,
+    #[error("Swap output is greater than or equal to the pool reserves")]
+    SwapOutputGreaterThanOrEqualToReserves = 15,
+    #[error("Slippage limit exceeded")]
+    SlippageExceeded = 16,
+    #[error("Requested slot is before the oldest observation")]
+    ObservationTooOld = 17,
+
+    // Account validation errors
+    #[error("Incorrect program id")]
+    IncorrectProgramId = 18,
+    #[error("Invalid log authority")]
+    InvalidLogAuthority = 19,
+    #[error("Incorrect account key")]
+    IncorrectAccountKey = 20,
+    #[error("Missing required signature")]
+    MissingRequiredSignature = 21,
+    #[error("Payer must be writable")]
+    PayerNotWritable = 22,
+    #[error("Account must be uninitialized")]
+    AccountAlreadyInitialized = 23,
+    #[error("Empty accounts must be owned by the system program")]
+    EmptyAccountNotOwnedBySystemProgram = 24,
+    #[error("Token account or mint must be owned by the Token program")]
+    NotOwnedByTokenProgram = 25,
+    #[error("Invalid token account data")]
+    InvalidTokenAccountData = 26,
+    #[error("Token account mint mismatch")]
+    TokenAccountMintMismatch = 27,
+    #[error("Token account owner mismatch")]
+    TokenAccountOwnerMismatch = 28,
+    #[error("Incorrect token account key")]
+    IncorrectTokenAccountKey = 29,
+    #[error("Account must be owned by the Plasma program")]
+    NotOwnedByPlasmaProgram = 30,
+    #[error("Invalid pool account")]
+    InvalidPoolAccount = 31,
+    #[error("Pool is already initialized")]
+    PoolAlreadyInitialized = 32,
+    #[error("Invalid LP position address")]
+    InvalidLpPositionAddress = 33,
+    #[error("Invalid LP position account")]
+    InvalidLpPositionAccount = 34,
+    #[error("LP position authority mismatch")]
+    LpPositionAuthorityMismatch = 35,
+    #[error("LP position pool mismatch")]
+    LpPositionPoolMismatch = 36,
+    #[error("Invalid observations account address")]
+    InvalidObservationsAddress = 37,
+    #[error("Invalid observations account")]
+    InvalidObservationsAccount = 38,
+    #[error("Observations account pool mismatch")]
+    ObservationsPoolMismatch = 39,
+    #[error("Vault address does not match the expected PDA")]
+    InvalidVaultAddress = 40,
+
+    // Pool configuration errors
+    #[error("LP fee is capped at 5%")]
+    LpFeeTooHigh = 41,
+    #[error("Protocol fee allocation is capped at 50% of the LP fee")]
+    ProtocolFeeAllocationTooHigh = 42,
+    #[error("Protocol fee recipient shares must sum to less than 10000")]
+    TooManyProtocolFeeShares = 43,
+    #[error("Protocol fee recipients must be different")]
+    DuplicateProtocolFeeRecipients = 44,
+    #[error("Base mint and quote mint must be different")]
+    IdenticalBaseAndQuoteMints = 45,
+    #[error("Protocol fee accounting is inconsistent")]
+    ProtocolFeeAccountingError = 46,
+    #[error("Cumulative fees decreased after swap")]
+    CumulativeFeesDecreased = 47,
+
+    // Liquidity errors
+    #[error("Initial LP shares must be provided for the first deposit only")]
+    InvalidInitialLpShares = 48,
+    #[error("LP position has been renounced")]
+    LpPositionRenounced = 49,
+    #[error("LP position fees have been burned")]
+    LpPositionFeesBurned = 50,
+
+    // Swap errors
+    #[error("Insufficient token balance")]
+    InsufficientBalance = 51,
+    #[error("Swap route must contain at least one hop")]
+    EmptySwapRoute = 52,
+    #[error("A pool can only appear once in a swap route")]
+    DuplicatePoolInSwapRoute = 53,
+    #[error("The output mint of each hop must be the input mint of the next hop")]
+    SwapRouteMintMismatch = 54,
+
+    // Observation errors
+    #[error("New observations capacity must be greater than the current capacity")]
+    InvalidObservationsCapacity = 55,
+    #[error("Observations account can only grow by 10KB per instruction")]
+    ObservationsGrowthTooLarge = 56,
+
+    // Pool authority errors
+    #[error("Pool has no authority and cannot be updated")]
+    MissingPoolAuthority = 57,
+    #[error("Signer is not the pool authority")]
+    InvalidPoolAuthority = 58,
+    #[error("Protocol fee recipient must withdraw its fees before being removed")]
+    UncollectedProtocolFees = 59,
+    #[error("Pool is paused")]
+    PoolPaused = 60,
+
+    // Token errors
+    #[error("Mint has a Token-2022 extension that is not supported by pools")]
+    UnsupportedMintExtension = 61,
+
+    // Pool address errors
+    #[error("Pool address does not match the expected PDA")]
+    InvalidPoolAddress = 62,
+
+    // Fee tier errors
+    #[error("Invalid fee tier config account")]
+    InvalidFeeTierConfigAccount = 63,
+    #[error("Signer is not the fee tier config admin")]
+    InvalidFeeTierConfigAdmin = 64,
+    #[error("Fee tier does not exist")]
+    FeeTierNotFound = 65,
+    #[error("Fee tier is disabled")]
+    FeeTierDisabled = 66,
+    #[error("Fee tier config is full")]
+    TooManyFeeTiers = 67,
+
+    // Close errors
+    #[error("LP position still holds shares or uncollected fees")]
+    LpPositionNotEmpty = 68,
+    #[error("Pool still holds liquidity or uncollected fees")]
+    PoolNotEmpty = 69,
+
+    // LP token errors
+    #[error("LP tokens are not enabled for this pool")]
+    LpTokensNotEnabled = 70,
+    #[error("Invalid LP mint address")]
+    InvalidLpMintAddress = 71,
+
+    // LP position transfer errors
+    #[error("LP positions cannot be transferred to their owner")]
+    InvalidLpPositionRecipient = 72,
+
+    // Migration errors
+    #[error("LP position must be migrated to the current layout")]
+    LpPositionMigrationRequired = 73,
+    #[error("LP position does not need to be migrated")]
+    LpPositionAlreadyMigrated = 74,
+
+    // Deadline errors
+    #[error("Instruction landed outside of its slot deadline")]
+    SlotDeadlineExceeded = 75,
+
+    // Withdrawal errors
+    #[error("Not enough withdrawable LP shares")]
+    InsufficientWithdrawableShares = 76,
+
+    // Pool migration errors
+    #[error("Pool must be migrated to the current layout")]
+    PoolMigrationRequired = 77,
+    #[error("Pool does not need to be migrated")]
+    PoolAlreadyMigrated = 78,
+
+    // Native SOL errors
+    #[error("System program is required to wrap or unwrap native SOL")]
+    MissingSystemProgram = 79,
+
+    // Pool config errors
+    #[error("Fee tier config account is required")]
+    MissingFeeTierConfig = 80,
+    #[error("LP shares are still vesting")]
+    LpSharesStillVesting = 81,
+}
+
+impl From<PlasmaError> for ProgramError {
+    fn from(e: PlasmaError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
+
+impl From<PlasmaStateError> for PlasmaError {
+    fn from(e: PlasmaStateError) -> Self {
+        match e {
+            PlasmaStateError::InvariantViolation(..) => PlasmaError::InvariantViolation,
+            PlasmaStateError::MismatchedFees(..) => PlasmaError::MismatchedFees,
+            PlasmaStateError::UninitializedPool => PlasmaError::UninitializedPool,
+            PlasmaStateError::SwapAmountMismatch => PlasmaError::SwapAmountMismatch,
+            PlasmaStateError::Overflow => PlasmaError::Overflow,
+            PlasmaStateError::Underflow => PlasmaError::Underflow,
+            PlasmaStateError::UnexpectedArgument => PlasmaError::UnexpectedArgument,
+            PlasmaStateError::MissingExpectedArgument => PlasmaError::MissingExpectedArgument,
+            PlasmaStateError::BelowMinimumLpSharesRequired => {
+                PlasmaError::BelowMinimumLpSharesRequired
+            }
+            PlasmaStateError::BelowMinimumWithdrawaRequired => {
+                PlasmaError::BelowMinimumWithdrawalRequired
+            }
+            PlasmaStateError::VestingPeriodNotOver => PlasmaError::VestingPeriodNotOver,
+            PlasmaStateError::IncorrectProtocolFeeRecipient => {
+                PlasmaError::IncorrectProtocolFeeRecipient
+            }
+            PlasmaStateError::TooManyShares => PlasmaError::TooManyShares,
+            PlasmaStateError::SwapExactOutTooLarge => PlasmaError::SwapExactOutTooLarge,
+            PlasmaStateError::SwapExactInTooLarge => PlasmaError::SwapExactInTooLarge,
+            PlasmaStateError::SwapOutputGreaterThanOrEqualToReserves(..) => {
+                PlasmaError::SwapOutputGreaterThanOrEqualToReserves
+            }
+            PlasmaStateError::SlippageExceeded(..) => PlasmaError::SlippageExceeded,
+            PlasmaStateError::ObservationTooOld(..) => PlasmaError::ObservationTooOld,
+            PlasmaStateError::InsufficientWithdrawableShares(..) => {
+                PlasmaError::InsufficientWithdrawableShares
+            }
+        }
+    }
+}
+
+#[test]
+fn test_error_codes_are_stable() {
+    // Codes are contiguous, so a removed or reordered variant breaks this test
+    let mut code = 0;
+    while let Ok(error) = PlasmaError::try_from(code) {
+        assert_eq!(ProgramError::from(error), ProgramError::Custom(code));
+        code += 1;
+    }
+    assert_eq!(code, PlasmaError::LpSharesStillVesting as u32 + 1);
+    assert_eq!(PlasmaError::SlippageExceeded as u32, 16);
+    assert_eq!(PlasmaError::InsufficientBalance as u32, 51);
+}
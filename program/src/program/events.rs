@@ -1,9 +1,13 @@
 use borsh::{BorshDeserialize as Deserialize, BorshSerialize as Serialize};
 use solana_program::pubkey::Pubkey;
 
-use plasma_state::amm::SwapResult;
+use plasma_state::{
+    amm::{SwapResult, TokenType},
+    lp::{RemoveLiquidityMode, VestingSchedule},
+};
 
 use crate::initialize::ProtocolFeeRecipientParams;
+use crate::program::accounts::PoolStatus;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlasmaEvent<T: Clone + Deserialize + Serialize> {
@@ -61,6 +65,9 @@ pub struct RemoveLiquidityEvent {
     pub user_quote_withdrawn: u64,
     pub user_total_withdrawable_base: u64,
     pub user_total_withdrawable_quote: u64,
+    pub user_lp_shares_requested: u64,
+    /// Behaviour applied when more shares were requested than were withdrawable
+    pub mode: RemoveLiquidityMode,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -78,6 +85,7 @@ pub struct InitializePoolEvent {
     pub lp_fee_in_bps: u64,
     pub protocol_fee_in_pct: u64,
     pub fee_recipient_params: [ProtocolFeeRecipientParams; 3],
+    pub pool_authority: Pubkey,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -90,3 +98,165 @@ pub struct WithdrawProtocolFeesEvent {
     pub protocol_fee_recipient: Pubkey,
     pub fees_withdrawn: u64,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GrowObservationsEvent {
+    pub previous_capacity: u64,
+    pub new_capacity: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdatePoolConfigEvent {
+    pub old_lp_fee_in_bps: u64,
+    pub new_lp_fee_in_bps: u64,
+    pub old_protocol_fee_in_pct: u64,
+    pub new_protocol_fee_in_pct: u64,
+    pub old_lp_vesting_window: u64,
+    pub new_lp_vesting_window: u64,
+    pub old_fee_recipient_params: [ProtocolFeeRecipientParams; 3],
+    pub new_fee_recipient_params: [ProtocolFeeRecipientParams; 3],
+    pub old_lp_vesting_schedule: VestingSchedule,
+    pub new_lp_vesting_schedule: VestingSchedule,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransferProtocolFeeRecipientEvent {
+    pub previous_recipient: Pubkey,
+    pub new_recipient: Pubkey,
+    pub shares: u64,
+    pub uncollected_quote_fees: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetPoolStatusEvent {
+    pub previous_status: PoolStatus,
+    pub new_status: PoolStatus,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InitializeFeeTierConfigEvent {
+    pub admin: Pubkey,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetFeeTierEvent {
+    pub lp_fee_in_bps: u64,
+    pub protocol_lp_fee_allocation_in_pct: u64,
+    pub num_slots_to_vest_lp_shares: u64,
+    pub enabled: bool,
+    /// False if an existing tier was updated
+    pub is_new_fee_tier: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CloseLpPositionEvent {
+    pub lp_position: Pubkey,
+    pub lamports_reclaimed: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClosePoolEvent {
+    /// Rent of the pool account and both vaults
+    pub lamports_reclaimed: u64,
+    /// Balances left in the vaults, sent to the pool authority
+    pub base_amount_swept: u64,
+    pub quote_amount_swept: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InitializeLpMintEvent {
+    pub lp_mint: Pubkey,
+    pub decimals: u8,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenizeLpSharesEvent {
+    pub lp_shares_tokenized: u64,
+    /// Fees earned by the LP tokens since the last conversion, handed to the other LP shares
+    pub lp_token_fees_redistributed: u64,
+    pub total_lp_shares_tokenized: u64,
+    pub user_lp_shares_available: u64,
+    pub user_lp_shares_locked: u64,
+    pub user_lp_shares_unlocked_for_withdrawal: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RedeemLpTokensEvent {
+    pub lp_tokens_redeemed: u64,
+    /// Fees earned by the LP tokens since the last conversion, handed to the other LP shares
+    pub lp_token_fees_redistributed: u64,
+    pub total_lp_shares_tokenized: u64,
+    pub user_lp_shares_available: u64,
+    pub user_lp_shares_locked: u64,
+    pub user_lp_shares_unlocked_for_withdrawal: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransferLpPositionEvent {
+    pub recipient: Pubkey,
+    pub recipient_lp_position_created: bool,
+    /// Includes the shares that are still vesting
+    pub lp_shares_transferred: u64,
+    pub lp_shares_vesting_transferred: u64,
+    pub quote_fees_transferred: u64,
+    pub recipient_lp_shares_available: u64,
+    pub recipient_lp_shares_locked: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MigrateLpPositionEvent {
+    pub lp_position: Pubkey,
+    pub owner: Pubkey,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompoundLpFeesEvent {
+    /// Fees spent on the swap and the deposit
+    pub quote_fees_compounded: u64,
+    pub quote_fees_swapped: u64,
+    pub base_amount_bought: u64,
+    pub user_base_deposited: u64,
+    pub user_quote_deposited: u64,
+    pub user_lp_shares_received: u64,
+    pub user_lp_shares_available: u64,
+    pub user_lp_shares_locked: u64,
+    pub user_lp_shares_unlocked_for_withdrawal: u64,
+    pub pool_total_lp_shares: u64,
+    pub pool_total_base_liquidity: u64,
+    pub pool_total_quote_liquidity: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddLiquiditySingleSidedEvent {
+    pub token_in: TokenType,
+    /// Includes the amount swapped for the other token
+    pub user_amount_deposited: u64,
+    pub swap_result: SwapResult,
+    pub pool_total_lp_shares: u64,
+    pub pool_total_base_liquidity: u64,
+    pub pool_total_quote_liquidity: u64,
+    pub user_lp_shares_received: u64,
+    pub user_lp_shares_available: u64,
+    pub user_lp_shares_locked: u64,
+    pub user_lp_shares_unlocked_for_withdrawal: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoveLiquiditySingleSidedEvent {
+    pub token_out: TokenType,
+    /// Includes the output of the swap, before transfer fees
+    pub user_amount_withdrawn: u64,
+    pub swap_result: SwapResult,
+    pub pool_total_lp_shares: u64,
+    pub pool_total_base_liquidity: u64,
+    pub pool_total_quote_liquidity: u64,
+    pub user_lp_shares_burned: u64,
+    pub user_lp_shares_available: u64,
+    pub user_lp_shares_locked: u64,
+    pub user_lp_shares_unlocked_for_withdrawal: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MigratePoolEvent {
+    pub lamports_paid: u64,
+}
@@ -6,16 +6,22 @@ use shank::ShankInstruction;
 #[rustfmt::skip]
 pub enum PlasmaInstruction {
     // Pool instructions
-    /// Send a swap order
+    /// Send a swap order. The min_amount_out of an ExactIn swap is an amount of the token received,
+    /// for a Sell it is a quote amount and not the base amount sold.
     #[account(0, name = "plasma_program", desc = "Plasma program")]
     #[account(1, name = "log_authority", desc = "Plasma log authority")]
     #[account(2, writable, name = "pool", desc = "This account holds the pool state")]
     #[account(3, signer, name = "trader")]
-    #[account(4, writable, name = "base_account", desc = "Trader base token account")]
-    #[account(5, writable, name = "quote_account", desc = "Trader quote token account")]
+    #[account(4, writable, name = "base_account", desc = "Trader base token account. If the base mint is the native mint, it can be the temporary wSOL PDA, seeds are [b'wsol', trader_address]")]
+    #[account(5, writable, name = "quote_account", desc = "Trader quote token account. If the quote mint is the native mint, it can be the temporary wSOL PDA, seeds are [b'wsol', trader_address]")]
     #[account(6, writable, name = "base_vault", desc = "Base vault PDA, seeds are [b'vault', pool_address, base_mint_address]")]
     #[account(7, writable, name = "quote_vault", desc = "Quote vault PDA, seeds are [b'vault', pool_address, quote_mint_address]")]
-    #[account(8, name = "token_program", desc = "Token program")]
+    #[account(8, name = "base_mint", desc = "Base mint account")]
+    #[account(9, name = "quote_mint", desc = "Quote mint account")]
+    #[account(10, name = "base_token_program", desc = "Token program of the base mint")]
+    #[account(11, name = "quote_token_program", desc = "Token program of the quote mint")]
+    #[account(12, optional, writable, name = "observations", desc = "Optional observations PDA, seeds are [b'observations', pool_address]")]
+    #[account(13, optional, name = "system_program", desc = "System program, only required to wrap native SOL or to create the temporary wSOL account. It can be passed without the observations account")]
     Swap = 0,
 
     /// Adds liquidity to the pool 
@@ -24,11 +30,15 @@ pub enum PlasmaInstruction {
     #[account(2, writable, name = "pool", desc = "This account holds the pool state")]
     #[account(3, signer, name = "trader")]
     #[account(4, writable, name = "lp_position")]
-    #[account(5, writable, name = "base_account", desc = "Trader base token account")]
-    #[account(6, writable, name = "quote_account", desc = "Trader quote token account")]
+    #[account(5, writable, name = "base_account", desc = "Trader base token account. If the base mint is the native mint, it can be the temporary wSOL PDA, seeds are [b'wsol', trader_address]")]
+    #[account(6, writable, name = "quote_account", desc = "Trader quote token account. If the quote mint is the native mint, it can be the temporary wSOL PDA, seeds are [b'wsol', trader_address]")]
     #[account(7, writable, name = "base_vault", desc = "Base vault PDA, seeds are [b'vault', pool_address, base_mint_address]")]
     #[account(8, writable, name = "quote_vault", desc = "Quote vault PDA, seeds are [b'vault', pool_address, quote_mint_address]")]
-    #[account(9, name = "token_program", desc = "Token program")]
+    #[account(9, name = "base_mint", desc = "Base mint account")]
+    #[account(10, name = "quote_mint", desc = "Quote mint account")]
+    #[account(11, name = "base_token_program", desc = "Token program of the base mint")]
+    #[account(12, name = "quote_token_program", desc = "Token program of the quote mint")]
+    #[account(13, optional, name = "system_program", desc = "System program, only required to wrap native SOL or to create the temporary wSOL account")]
     AddLiquidity = 1,
 
     /// Removes Liquidity from the pool 
@@ -37,11 +47,15 @@ pub enum PlasmaInstruction {
     #[account(2, writable, name = "pool", desc = "This account holds the pool state")]
     #[account(3, signer, name = "trader")]
     #[account(4, writable, name = "lp_position")]
-    #[account(5, writable, name = "base_account", desc = "Trader base token account")]
-    #[account(6, writable, name = "quote_account", desc = "Trader quote token account")]
+    #[account(5, writable, name = "base_account", desc = "Trader base token account. If the base mint is the native mint, it can be the temporary wSOL PDA, seeds are [b'wsol', trader_address]")]
+    #[account(6, writable, name = "quote_account", desc = "Trader quote token account. If the quote mint is the native mint, it can be the temporary wSOL PDA, seeds are [b'wsol', trader_address]")]
     #[account(7, writable, name = "base_vault", desc = "Base vault PDA, seeds are [b'vault', pool_address, base_mint_address]")]
     #[account(8, writable, name = "quote_vault", desc = "Quote vault PDA, seeds are [b'vault', pool_address, quote_mint_address]")]
-    #[account(9, name = "token_program", desc = "Token program")]
+    #[account(9, name = "base_mint", desc = "Base mint account")]
+    #[account(10, name = "quote_mint", desc = "Quote mint account")]
+    #[account(11, name = "base_token_program", desc = "Token program of the base mint")]
+    #[account(12, name = "quote_token_program", desc = "Token program of the quote mint")]
+    #[account(13, optional, name = "system_program", desc = "System program, only required to create the temporary wSOL account")]
     RemoveLiquidity = 2,
 
     /// Renounce ownership of LP position 
@@ -61,7 +75,8 @@ pub enum PlasmaInstruction {
     #[account(5, writable, name = "lp_position")]
     #[account(6, writable, name = "quote_account", desc = "Trader quote token account")]
     #[account(7, writable, name = "quote_vault", desc = "Quote vault PDA, seeds are [b'vault', pool_address, quote_mint_address]")]
-    #[account(8, name = "token_program", desc = "Token program")]
+    #[account(8, name = "quote_mint", desc = "Quote mint account")]
+    #[account(9, name = "token_program", desc = "Token program of the quote mint")]
     WithdrawLpFees = 4,
 
     #[account(0, name = "plasma_program", desc = "Plasma program")]
@@ -76,14 +91,16 @@ pub enum PlasmaInstruction {
     /// Create a pool 
     #[account(0, name = "plasma_program", desc = "Plasma program")]
     #[account(1, name = "log_authority", desc = "Plasma log authority")]
-    #[account(2, writable, name = "pool", desc = "This account holds the pool state")]
+    #[account(2, writable, name = "pool", desc = "Pool PDA, seeds are [b'pool', lower mint address, higher mint address, lp_fee_in_bps as u64 le bytes]")]
     #[account(3, writable, signer, name = "pool_creator", desc = "The pool_creator account must sign for the creation of new vaults")]
     #[account(4, name = "base_mint", desc = "Base mint account")]
     #[account(5, name = "quote_mint", desc = "Quote mint account")]
     #[account(6, writable, name = "base_vault", desc = "Base vault PDA, seeds are [b'vault', pool_address, base_mint_address]")]
     #[account(7, writable, name = "quote_vault", desc = "Quote vault PDA, seeds are [b'vault', pool_address, quote_mint_address]")]
     #[account(8, name = "system_program", desc = "System program")]
-    #[account(9, name = "token_program", desc = "Token program")]
+    #[account(9, name = "base_token_program", desc = "Token program of the base mint")]
+    #[account(10, name = "quote_token_program", desc = "Token program of the quote mint")]
+    #[account(11, name = "fee_tier_config", desc = "Fee tier config PDA, seeds are [b'fee_tier_config']")]
     InitializePool = 6,
 
     /// Withdraw Protocol Fees
@@ -93,11 +110,221 @@ pub enum PlasmaInstruction {
     #[account(3, signer, name = "protocol_fee_recipient", desc = "Recipient of protocol fees")]
     #[account(4, writable, name = "quote_account", desc = "Trader quote token account")]
     #[account(5, writable, name = "quote_vault", desc = "Quote vault PDA, seeds are [b'vault', pool_address, quote_mint_address]")]
-    #[account(6, name = "token_program", desc = "Token program")]
+    #[account(6, name = "quote_mint", desc = "Quote mint account")]
+    #[account(7, name = "token_program", desc = "Token program of the quote mint")]
     WithdrawProtocolFees = 7,
 
     #[account(0, signer, name = "log_authority", desc = "Log authority")]
     Log = 8,
+
+    /// Send a swap order through a route of pools, the output of each hop is the input of the next hop.
+    /// Each additional hop appends the accounts [pool, base_vault, quote_vault, base_mint, quote_mint, base_token_program, quote_token_program]
+    /// after the accounts below. The system program can follow the accounts of the last hop, it is only required
+    /// to wrap native SOL or to create the temporary wSOL account.
+    #[account(0, name = "plasma_program", desc = "Plasma program")]
+    #[account(1, name = "log_authority", desc = "Plasma log authority")]
+    #[account(2, writable, name = "pool", desc = "This account holds the state of the first pool of the route")]
+    #[account(3, signer, name = "trader")]
+    #[account(4, writable, name = "input_account", desc = "Trader token account of the input mint of the route. If it is the native mint, it can be the temporary wSOL PDA, seeds are [b'wsol', trader_address]")]
+    #[account(5, writable, name = "output_account", desc = "Trader token account of the output mint of the route. If it is the native mint, it can be the temporary wSOL PDA, seeds are [b'wsol', trader_address]")]
+    #[account(6, writable, name = "base_vault", desc = "Base vault PDA of the first pool, seeds are [b'vault', pool_address, base_mint_address]")]
+    #[account(7, writable, name = "quote_vault", desc = "Quote vault PDA of the first pool, seeds are [b'vault', pool_address, quote_mint_address]")]
+    #[account(8, name = "base_mint", desc = "Base mint of the first pool")]
+    #[account(9, name = "quote_mint", desc = "Quote mint of the first pool")]
+    #[account(10, name = "base_token_program", desc = "Token program of the base mint of the first pool")]
+    #[account(11, name = "quote_token_program", desc = "Token program of the quote mint of the first pool")]
+    SwapRoute = 9,
+
+    /// Create the observations account of a pool or grow its capacity
+    #[account(0, name = "plasma_program", desc = "Plasma program")]
+    #[account(1, name = "log_authority", desc = "Plasma log authority")]
+    #[account(2, writable, name = "pool", desc = "This account holds the pool state")]
+    #[account(3, writable, signer, name = "payer")]
+    #[account(4, writable, name = "observations", desc = "Observations PDA, seeds are [b'observations', pool_address]")]
+    #[account(5, name = "system_program", desc = "System program")]
+    GrowObservations = 10,
+
+    /// Update the fees, LP vesting window and schedule, and protocol fee recipients of a pool.
+    /// The fees can be lowered within the fee tier of the pool or set to another enabled tier.
+    #[account(0, name = "plasma_program", desc = "Plasma program")]
+    #[account(1, name = "log_authority", desc = "Plasma log authority")]
+    #[account(2, writable, name = "pool", desc = "This account holds the pool state")]
+    #[account(3, signer, name = "pool_authority", desc = "Authority of the pool")]
+    #[account(4, optional, name = "fee_tier_config", desc = "Fee tier config PDA, seeds are [b'fee_tier_config'], only required to update the fees")]
+    UpdatePoolConfig = 11,
+
+    /// Transfer the slot of a protocol fee recipient, including its uncollected fees, to a new pubkey.
+    /// The pool authority can rebalance the shares of each slot with UpdatePoolConfig.
+    #[account(0, name = "plasma_program", desc = "Plasma program")]
+    #[account(1, name = "log_authority", desc = "Plasma log authority")]
+    #[account(2, writable, name = "pool", desc = "This account holds the pool state")]
+    #[account(3, signer, name = "protocol_fee_recipient", desc = "Current recipient of the protocol fee slot")]
+    TransferProtocolFeeRecipient = 12,
+
+    /// Pause or resume swaps, deposits and withdrawals on a pool. The signer is the pool authority,
+    /// or the fee tier config admin when the fee tier config is passed.
+    #[account(0, name = "plasma_program", desc = "Plasma program")]
+    #[account(1, name = "log_authority", desc = "Plasma log authority")]
+    #[account(2, writable, name = "pool", desc = "This account holds the pool state")]
+    #[account(3, signer, name = "pool_authority", desc = "Authority of the pool, or the fee tier config admin")]
+    #[account(4, optional, name = "fee_tier_config", desc = "Fee tier config PDA, seeds are [b'fee_tier_config'], only required when the signer is the fee tier config admin")]
+    SetPoolStatus = 13,
+
+    /// Create the fee tier config, the signer must be the upgrade authority of the program and
+    /// becomes the admin of the config
+    #[account(0, name = "plasma_program", desc = "Plasma program")]
+    #[account(1, name = "log_authority", desc = "Plasma log authority")]
+    #[account(2, writable, name = "fee_tier_config", desc = "Fee tier config PDA, seeds are [b'fee_tier_config']")]
+    #[account(3, writable, signer, name = "admin", desc = "Upgrade authority of the program")]
+    #[account(4, name = "program_data", desc = "Program data account of the Plasma program")]
+    #[account(5, name = "system_program", desc = "System program")]
+    InitializeFeeTierConfig = 14,
+
+    /// Add a fee tier, or update or disable an existing one. Pools can only be created with an enabled tier.
+    #[account(0, name = "plasma_program", desc = "Plasma program")]
+    #[account(1, name = "log_authority", desc = "Plasma log authority")]
+    #[account(2, writable, name = "fee_tier_config", desc = "Fee tier config PDA, seeds are [b'fee_tier_config']")]
+    #[account(3, signer, name = "admin", desc = "Admin of the fee tier config")]
+    SetFeeTier = 15,
+
+    /// Close an LP position with no shares, no vesting shares and no uncollected fees.
+    /// The rent is returned to the owner.
+    #[account(0, name = "plasma_program", desc = "Plasma program")]
+    #[account(1, name = "log_authority", desc = "Plasma log authority")]
+    #[account(2, writable, name = "pool", desc = "This account holds the pool state")]
+    #[account(3, writable, signer, name = "owner", desc = "Owner of the LP position, receives the rent")]
+    #[account(4, writable, name = "lp_position", desc = "LP position to close")]
+    CloseLpPosition = 16,
+
+    /// Close a pool with no LP shares and no unclaimed protocol or LP fees. The tokens left in the
+    /// vaults are swept to the pool authority, and the vault and pool rent is returned to it.
+    #[account(0, name = "plasma_program", desc = "Plasma program")]
+    #[account(1, name = "log_authority", desc = "Plasma log authority")]
+    #[account(2, writable, name = "pool", desc = "This account holds the pool state")]
+    #[account(3, writable, signer, name = "pool_authority", desc = "Authority of the pool, receives the rent")]
+    #[account(4, writable, name = "base_account", desc = "Base token account of the pool authority")]
+    #[account(5, writable, name = "quote_account", desc = "Quote token account of the pool authority")]
+    #[account(6, writable, name = "base_vault", desc = "Base vault PDA, seeds are [b'vault', pool_address, base_mint_address]")]
+    #[account(7, writable, name = "quote_vault", desc = "Quote vault PDA, seeds are [b'vault', pool_address, quote_mint_address]")]
+    #[account(8, writable, name = "base_mint", desc = "Base mint account, receives the withheld transfer fees of the vault")]
+    #[account(9, writable, name = "quote_mint", desc = "Quote mint account, receives the withheld transfer fees of the vault")]
+    #[account(10, name = "base_token_program", desc = "Token program of the base mint")]
+    #[account(11, name = "quote_token_program", desc = "Token program of the quote mint")]
+    ClosePool = 17,
+
+    /// Opt the pool in to LP tokens by creating its LP mint and the escrow LP position that holds
+    /// the tokenized shares. Anyone can pay for the accounts.
+    #[account(0, name = "plasma_program", desc = "Plasma program")]
+    #[account(1, name = "log_authority", desc = "Plasma log authority")]
+    #[account(2, writable, name = "pool", desc = "This account holds the pool state")]
+    #[account(3, writable, signer, name = "payer", desc = "Pays for the LP mint and the escrow")]
+    #[account(4, writable, name = "lp_mint", desc = "LP mint PDA, seeds are [b'lp_mint', pool_address]")]
+    #[account(5, writable, name = "lp_escrow", desc = "Escrow LP position PDA, seeds are [b'lp_escrow', pool_address]")]
+    #[account(6, name = "token_program", desc = "Token program")]
+    #[account(7, name = "system_program", desc = "System program")]
+    InitializeLpMint = 18,
+
+    /// Convert vested LP shares into LP tokens. Fees accrued by the shares stay in the LP position,
+    /// LP tokens do not earn fees.
+    #[account(0, name = "plasma_program", desc = "Plasma program")]
+    #[account(1, name = "log_authority", desc = "Plasma log authority")]
+    #[account(2, writable, name = "pool", desc = "This account holds the pool state")]
+    #[account(3, signer, name = "trader", desc = "Owner of the LP position")]
+    #[account(4, writable, name = "lp_position", desc = "LP position of the trader")]
+    #[account(5, writable, name = "lp_escrow", desc = "Escrow LP position PDA, seeds are [b'lp_escrow', pool_address]")]
+    #[account(6, writable, name = "lp_mint", desc = "LP mint PDA, seeds are [b'lp_mint', pool_address]")]
+    #[account(7, writable, name = "lp_token_account", desc = "Trader LP token account")]
+    #[account(8, name = "token_program", desc = "Token program")]
+    TokenizeLpShares = 19,
+
+    /// Burn LP tokens and credit the same number of vested LP shares to the LP position
+    #[account(0, name = "plasma_program", desc = "Plasma program")]
+    #[account(1, name = "log_authority", desc = "Plasma log authority")]
+    #[account(2, writable, name = "pool", desc = "This account holds the pool state")]
+    #[account(3, signer, name = "trader", desc = "Owner of the LP position and the LP tokens")]
+    #[account(4, writable, name = "lp_position", desc = "LP position of the trader")]
+    #[account(5, writable, name = "lp_escrow", desc = "Escrow LP position PDA, seeds are [b'lp_escrow', pool_address]")]
+    #[account(6, writable, name = "lp_mint", desc = "LP mint PDA, seeds are [b'lp_mint', pool_address]")]
+    #[account(7, writable, name = "lp_token_account", desc = "Trader LP token account")]
+    #[account(8, name = "token_program", desc = "Token program")]
+    RedeemLpTokens = 20,
+
+    /// Move the shares, pending vesting and uncollected fees of an LP position into the position of
+    /// the recipient for the same pool, creating it if needed. Pending shares keep vesting from
+    /// their deposit slot.
+    #[account(0, name = "plasma_program", desc = "Plasma program")]
+    #[account(1, name = "log_authority", desc = "Plasma log authority")]
+    #[account(2, writable, name = "pool", desc = "This account holds the pool state")]
+    #[account(3, writable, signer, name = "trader", desc = "Owner of the LP position, pays for the recipient position if it is created")]
+    #[account(4, writable, name = "lp_position", desc = "LP position of the trader")]
+    #[account(5, name = "recipient", desc = "New owner of the liquidity")]
+    #[account(6, writable, name = "recipient_lp_position", desc = "LP position of the recipient, seeds are [b'lp_position', pool_address, recipient_address]")]
+    #[account(7, name = "system_program", desc = "System program")]
+    TransferLpPosition = 21,
+
+    /// Extend an LP position created before positions had multiple vesting tranches to the current
+    /// layout. Legacy positions must be migrated before they can be used.
+    #[account(0, name = "plasma_program", desc = "Plasma program")]
+    #[account(1, name = "log_authority", desc = "Plasma log authority")]
+    #[account(2, writable, name = "pool", desc = "This account holds the pool state")]
+    #[account(3, writable, signer, name = "payer", desc = "Pays for the additional rent")]
+    #[account(4, writable, name = "lp_position", desc = "Legacy LP position")]
+    #[account(5, name = "system_program", desc = "System program")]
+    MigrateLpPosition = 22,
+
+    /// Reinvest the uncollected LP fees of a position. Half of the fees buy base through the pool
+    /// and both halves are deposited for LP shares, which are withdrawable right away.
+    #[account(0, name = "plasma_program", desc = "Plasma program")]
+    #[account(1, name = "log_authority", desc = "Plasma log authority")]
+    #[account(2, writable, name = "pool", desc = "This account holds the pool state")]
+    #[account(3, signer, name = "trader", desc = "Owner of the LP position")]
+    #[account(4, writable, name = "lp_position", desc = "LP position of the trader")]
+    CompoundLpFees = 23,
+
+    /// Add liquidity with a single token. Part of the deposit is swapped through the pool for
+    /// the other token, so the swap fee is charged on that part.
+    #[account(0, name = "plasma_program", desc = "Plasma program")]
+    #[account(1, name = "log_authority", desc = "Plasma log authority")]
+    #[account(2, writable, name = "pool", desc = "This account holds the pool state")]
+    #[account(3, signer, name = "trader")]
+    #[account(4, writable, name = "lp_position")]
+    #[account(5, writable, name = "base_account", desc = "Trader base token account. If the base mint is the native mint, it can be the temporary wSOL PDA, seeds are [b'wsol', trader_address]")]
+    #[account(6, writable, name = "quote_account", desc = "Trader quote token account. If the quote mint is the native mint, it can be the temporary wSOL PDA, seeds are [b'wsol', trader_address]")]
+    #[account(7, writable, name = "base_vault", desc = "Base vault PDA, seeds are [b'vault', pool_address, base_mint_address]")]
+    #[account(8, writable, name = "quote_vault", desc = "Quote vault PDA, seeds are [b'vault', pool_address, quote_mint_address]")]
+    #[account(9, name = "base_mint", desc = "Base mint account")]
+    #[account(10, name = "quote_mint", desc = "Quote mint account")]
+    #[account(11, name = "base_token_program", desc = "Token program of the base mint")]
+    #[account(12, name = "quote_token_program", desc = "Token program of the quote mint")]
+    #[account(13, optional, name = "system_program", desc = "System program, only required to wrap native SOL or to create the temporary wSOL account")]
+    AddLiquiditySingleSided = 24,
+
+    /// Remove liquidity and swap the withdrawn amount of the other token through the pool, so the
+    /// trader only receives a single token
+    #[account(0, name = "plasma_program", desc = "Plasma program")]
+    #[account(1, name = "log_authority", desc = "Plasma log authority")]
+    #[account(2, writable, name = "pool", desc = "This account holds the pool state")]
+    #[account(3, signer, name = "trader")]
+    #[account(4, writable, name = "lp_position")]
+    #[account(5, writable, name = "base_account", desc = "Trader base token account. If the base mint is the native mint, it can be the temporary wSOL PDA, seeds are [b'wsol', trader_address]")]
+    #[account(6, writable, name = "quote_account", desc = "Trader quote token account. If the quote mint is the native mint, it can be the temporary wSOL PDA, seeds are [b'wsol', trader_address]")]
+    #[account(7, writable, name = "base_vault", desc = "Base vault PDA, seeds are [b'vault', pool_address, base_mint_address]")]
+    #[account(8, writable, name = "quote_vault", desc = "Quote vault PDA, seeds are [b'vault', pool_address, quote_mint_address]")]
+    #[account(9, name = "base_mint", desc = "Base mint account")]
+    #[account(10, name = "quote_mint", desc = "Quote mint account")]
+    #[account(11, name = "base_token_program", desc = "Token program of the base mint")]
+    #[account(12, name = "quote_token_program", desc = "Token program of the quote mint")]
+    #[account(13, optional, name = "system_program", desc = "System program, only required to create the temporary wSOL account")]
+    RemoveLiquiditySingleSided = 25,
+
+    /// Extend a pool created before the AMM tracked the cumulative price and liquidity to the
+    /// current layout. The accumulators start at zero.
+    #[account(0, name = "plasma_program", desc = "Plasma program")]
+    #[account(1, name = "log_authority", desc = "Plasma log authority")]
+    #[account(2, writable, name = "pool", desc = "Legacy pool")]
+    #[account(3, writable, signer, name = "payer", desc = "Pays for the additional rent")]
+    #[account(4, name = "system_program", desc = "System program")]
+    MigratePool = 26,
 }
 
 impl PlasmaInstruction {
@@ -113,7 +340,7 @@ fn test_instruction_serialization() {
             Ok(j) => j,
             Err(_) => {
                 // This needs to be changed if new instructions are added
-                assert!(i > 7);
+                assert!(i > 26);
                 continue;
             }
         };
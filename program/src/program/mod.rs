@@ -1,4 +1,5 @@
 pub mod accounts;
+pub mod error;
 pub mod events;
 pub mod instruction;
 pub mod processor;
@@ -0,0 +1,348 @@
+use borsh::{BorshDeserialize as Deserialize, BorshSerialize as Serialize};
+use bytemuck::try_from_bytes_mut;
+use plasma_state::lp::VestingSchedule;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, msg, program::invoke, program_error::ProgramError,
+    rent::Rent, system_instruction, sysvar::Sysvar,
+};
+use std::mem::size_of;
+
+use crate::program::error::PlasmaError;
+use crate::{
+    assert_with_msg,
+    program::{
+        accounts::{FeeTier, PoolAccount, PoolStatus},
+        events::{ClosePoolEvent, MigratePoolEvent, SetPoolStatusEvent, UpdatePoolConfigEvent},
+        processor::initialize::ProtocolFeeRecipientParams,
+        token_utils::{
+            close_vault, harvest_withheld_fees, maybe_invoke_withdraw, MaybeInvokeWithdrawParams,
+        },
+        validation::loaders::{
+            ClosePoolContext, MigratePoolContext, PlasmaPoolContext, SetPoolStatusContext,
+            UpdatePoolConfigContext,
+        },
+    },
+    LEADER_SLOT_WINDOW,
+};
+
+/// The fees of a position are rounded down each time they are settled, so the LP fees paid out
+/// of a pool fall slightly short of the fees it accumulated. At most this many quote atoms of
+/// unpaid LP fees are swept when the pool is closed.
+const MAX_LP_FEE_REMAINDER: u64 = 1_000;
+
+/// Fields that are not set are left unchanged
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+pub struct UpdatePoolConfigParams {
+    /// Capped by the fee tier of the pool, unless it is the LP fee of another enabled tier
+    lp_fee_in_bps: Option<u64>,
+    /// Capped by the tier that caps the LP fee
+    protocol_lp_fee_allocation_in_pct: Option<u64>,
+    /// This is the number of slots that the LP shares will be vested over
+    /// If this value is not a multiple of the leader slot window, it will be rounded down
+    num_slots_to_vest_lp_shares: Option<u64>,
+    fee_recipients_params: Option<[ProtocolFeeRecipientParams; 3]>,
+    /// How LP shares unlock over the vesting window. Changing it also applies to the shares
+    /// that are already vesting, so it cannot change from linear to cliff until they all
+    /// vested, since that would lock again the shares that already unlocked.
+    lp_vesting_schedule: Option<VestingSchedule>,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+pub struct SetPoolStatusParams {
+    status: PoolStatus,
+}
+
+pub(crate) fn process_update_pool_config<'a, 'info>(
+    pool_context: &PlasmaPoolContext<'a, 'info>,
+    accounts: &'a [AccountInfo<'info>],
+    data: &[u8],
+) -> Result<UpdatePoolConfigEvent, ProgramError> {
+    let UpdatePoolConfigContext { fee_tier_config } = UpdatePoolConfigContext::load(accounts)?;
+    let UpdatePoolConfigParams {
+        lp_fee_in_bps,
+        protocol_lp_fee_allocation_in_pct,
+        num_slots_to_vest_lp_shares,
+        fee_recipients_params,
+        lp_vesting_schedule,
+    } = UpdatePoolConfigParams::try_from_slice(data)?;
+
+    let mut pool_bytes = pool_context.pool_info.try_borrow_mut_data()?;
+    let pool = try_from_bytes_mut::<PoolAccount>(&mut *pool_bytes)
+        .map_err(|_| PlasmaError::InvalidPoolAccount)?;
+
+    pool.header.assert_pool_authority(pool_context.signer.key)?;
+
+    let old_lp_fee_in_bps = pool.fee_in_bps as u64;
+    let old_protocol_fee_in_pct = pool.get_protocol_allocation_in_pct() as u64;
+    let old_lp_vesting_window = pool.lp_vesting_window;
+    let old_lp_vesting_schedule = pool.header.get_lp_vesting_schedule()?;
+    let old_fee_recipient_params =
+        pool.header
+            .fee_recipients
+            .recipients
+            .map(|r| ProtocolFeeRecipientParams {
+                recipient: r.recipient,
+                shares: r.shares,
+            });
+
+    let new_lp_fee_in_bps = lp_fee_in_bps.unwrap_or(old_lp_fee_in_bps);
+    let new_protocol_fee_in_pct =
+        protocol_lp_fee_allocation_in_pct.unwrap_or(old_protocol_fee_in_pct);
+
+    if lp_fee_in_bps.is_some() || protocol_lp_fee_allocation_in_pct.is_some() {
+        let fee_tier_config = fee_tier_config.ok_or_else(|| {
+            msg!("The fee tier config is required to update the fees");
+            PlasmaError::MissingFeeTierConfig
+        })?;
+        // The fee caps of 5% and 50% are enforced when the tiers are set
+        let FeeTier {
+            lp_fee_in_bps: max_lp_fee_in_bps,
+            protocol_lp_fee_allocation_in_pct: max_protocol_fee_in_pct,
+            ..
+        } = fee_tier_config
+            .get_config()?
+            .get_fee_cap(pool.header.fee_tier, new_lp_fee_in_bps)?;
+
+        assert_with_msg(
+            new_lp_fee_in_bps <= max_lp_fee_in_bps,
+            PlasmaError::LpFeeTooHigh,
+            &format!("LP fee is capped at {} bps", max_lp_fee_in_bps),
+        )?;
+        assert_with_msg(
+            new_protocol_fee_in_pct <= max_protocol_fee_in_pct,
+            PlasmaError::ProtocolFeeAllocationTooHigh,
+            &format!(
+                "The protocol fee allocation is capped at {}% of the LP fee",
+                max_protocol_fee_in_pct
+            ),
+        )?;
+
+        pool.set_fees(new_lp_fee_in_bps as u32, new_protocol_fee_in_pct as u32);
+    }
+
+    if let Some(num_slots_to_vest_lp_shares) = num_slots_to_vest_lp_shares {
+        pool.lp_vesting_window = num_slots_to_vest_lp_shares / LEADER_SLOT_WINDOW;
+    }
+
+    if let Some(lp_vesting_schedule) = lp_vesting_schedule {
+        if old_lp_vesting_schedule == VestingSchedule::Linear
+            && lp_vesting_schedule == VestingSchedule::Cliff
+        {
+            let slot = (Clock::get()?.slot / LEADER_SLOT_WINDOW) * LEADER_SLOT_WINDOW;
+            assert_with_msg(
+                pool.header.last_lp_vesting_slot + pool.lp_vesting_window <= slot,
+                PlasmaError::LpSharesStillVesting,
+                "Cannot switch from linear to cliff vesting while LP shares are vesting",
+            )?;
+        }
+        pool.header.lp_vesting_schedule = lp_vesting_schedule as u32;
+    }
+
+    if let Some(fee_recipients_params) = fee_recipients_params {
+        pool.set_protocol_fee_recipients(
+            fee_recipients_params.map(|params| (params.recipient, params.shares)),
+        )?;
+    }
+
+    msg!(
+        "Updated pool config: fee {} bps, protocol allocation {}%, vesting window {} ({:?})",
+        new_lp_fee_in_bps,
+        new_protocol_fee_in_pct,
+        pool.lp_vesting_window,
+        pool.header.get_lp_vesting_schedule()?
+    );
+
+    Ok(UpdatePoolConfigEvent {
+        old_lp_fee_in_bps,
+        new_lp_fee_in_bps,
+        old_protocol_fee_in_pct,
+        new_protocol_fee_in_pct,
+        old_lp_vesting_window,
+        new_lp_vesting_window: pool.lp_vesting_window,
+        old_fee_recipient_params,
+        new_fee_recipient_params: fee_recipients_params.unwrap_or(old_fee_recipient_params),
+        old_lp_vesting_schedule,
+        new_lp_vesting_schedule: lp_vesting_schedule.unwrap_or(old_lp_vesting_schedule),
+    })
+}
+
+pub(crate) fn process_set_pool_status<'a, 'info>(
+    pool_context: &PlasmaPoolContext<'a, 'info>,
+    accounts: &'a [AccountInfo<'info>],
+    data: &[u8],
+) -> Result<SetPoolStatusEvent, ProgramError> {
+    let SetPoolStatusContext { fee_tier_config } = SetPoolStatusContext::load(accounts)?;
+    let SetPoolStatusParams { status } = SetPoolStatusParams::try_from_slice(data)?;
+
+    let mut pool_bytes = pool_context.pool_info.try_borrow_mut_data()?;
+    let pool = try_from_bytes_mut::<PoolAccount>(&mut *pool_bytes)
+        .map_err(|_| PlasmaError::InvalidPoolAccount)?;
+
+    // The fee tier config admin can also set the status, so that pools without an authority can
+    // be paused
+    match fee_tier_config {
+        Some(fee_tier_config) => fee_tier_config
+            .get_config()?
+            .assert_admin(pool_context.signer.key)?,
+        None => pool.header.assert_pool_authority(pool_context.signer.key)?,
+    }
+
+    let previous_status = PoolStatus::parse(pool.header.status)?;
+    pool.header.status = status as u64;
+
+    msg!("Pool status changed from {} to {}", previous_status, status);
+
+    Ok(SetPoolStatusEvent {
+        previous_status,
+        new_status: status,
+    })
+}
+
+/// Closes the vaults of an empty pool. The pool account itself is closed by the entrypoint
+/// after the event is recorded, since recording the event reads the pool header.
+pub(crate) fn process_close_pool<'a, 'info>(
+    pool_context: &PlasmaPoolContext<'a, 'info>,
+    accounts: &'a [AccountInfo<'info>],
+) -> Result<ClosePoolEvent, ProgramError> {
+    let ClosePoolContext {
+        base_account,
+        quote_account,
+        base_vault,
+        quote_vault,
+        base_mint,
+        quote_mint,
+        base_token_program,
+        quote_token_program,
+    } = ClosePoolContext::load(pool_context, accounts)?;
+
+    let (base_params, quote_params) = {
+        let mut pool_bytes = pool_context.pool_info.try_borrow_mut_data()?;
+        let pool = try_from_bytes_mut::<PoolAccount>(&mut *pool_bytes)
+            .map_err(|_| PlasmaError::InvalidPoolAccount)?;
+
+        pool.header.assert_pool_authority(pool_context.signer.key)?;
+
+        pool.update_protocol_fee_recipients_post_swap()?;
+        assert_with_msg(
+            pool.total_lp_shares == 0,
+            PlasmaError::PoolNotEmpty,
+            "Pool still has LP shares",
+        )?;
+        assert_with_msg(
+            pool.header
+                .fee_recipients
+                .recipients
+                .iter()
+                .all(|r| r.collected_quote_fees >= r.total_accumulated_quote_fees),
+            PlasmaError::PoolNotEmpty,
+            "Protocol fee recipients must withdraw their fees before the pool is closed",
+        )?;
+        let unpaid_lp_fees = pool
+            .amm
+            .cumulative_quote_lp_fees
+            .saturating_sub(pool.header.quote_lp_fees_paid);
+        assert_with_msg(
+            unpaid_lp_fees <= MAX_LP_FEE_REMAINDER,
+            PlasmaError::PoolNotEmpty,
+            &format!(
+                "LP positions must withdraw their fees before the pool is closed, {} are unpaid",
+                unpaid_lp_fees
+            ),
+        )?;
+        (pool.header.base_params, pool.header.quote_params)
+    };
+
+    // The rounding remainder of the LP fees and any tokens sent to the vaults directly are swept
+    // to the pool authority. Transfer fees withheld in the vaults are harvested to the mints, the
+    // vaults cannot be closed otherwise.
+    let mut lamports_reclaimed = pool_context.pool_info.lamports();
+    let mut amounts_swept = [0; 2];
+    for ((vault, account, mint, params, token_program), amount_swept) in [
+        (
+            &base_vault,
+            &base_account,
+            &base_mint,
+            &base_params,
+            &base_token_program,
+        ),
+        (
+            &quote_vault,
+            &quote_account,
+            &quote_mint,
+            &quote_params,
+            &quote_token_program,
+        ),
+    ]
+    .into_iter()
+    .zip(amounts_swept.iter_mut())
+    {
+        if vault.withheld_amount()? > 0 {
+            harvest_withheld_fees(token_program.as_ref(), mint, vault)?;
+        }
+        *amount_swept = vault.amount()?;
+        maybe_invoke_withdraw(MaybeInvokeWithdrawParams {
+            pool_key: pool_context.pool_info.key,
+            mint,
+            bump: params.vault_bump as u8,
+            withdraw_amount: *amount_swept,
+            token_program: token_program.as_ref(),
+            withdraw_account: account.clone(),
+            withdraw_vault: vault.clone(),
+            unwrap_to: None,
+        })?;
+        lamports_reclaimed += vault.lamports();
+        close_vault(
+            pool_context.pool_info.key,
+            params,
+            token_program.as_ref(),
+            vault,
+            pool_context.signer.as_ref(),
+        )?;
+    }
+    let [base_amount_swept, quote_amount_swept] = amounts_swept;
+
+    msg!(
+        "Closed pool {}, swept {} base and {} quote to the pool authority",
+        pool_context.pool_info.key,
+        base_amount_swept,
+        quote_amount_swept
+    );
+
+    Ok(ClosePoolEvent {
+        lamports_reclaimed,
+        base_amount_swept,
+        quote_amount_swept,
+    })
+}
+
+/// Appends the cumulative price and liquidity of the AMM to a legacy pool. The legacy layout is
+/// a prefix of the current one, so the accumulators are zero until the next snapshot.
+pub(crate) fn process_migrate_pool<'a, 'info>(
+    pool_context: &PlasmaPoolContext<'a, 'info>,
+    accounts: &'a [AccountInfo<'info>],
+) -> Result<MigratePoolEvent, ProgramError> {
+    let MigratePoolContext { system_program } = MigratePoolContext::load(accounts)?;
+    let PlasmaPoolContext { pool_info, signer } = pool_context;
+
+    let space = size_of::<PoolAccount>();
+    let rent_shortfall = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(pool_info.lamports());
+    if rent_shortfall > 0 {
+        invoke(
+            &system_instruction::transfer(signer.key, pool_info.key, rent_shortfall),
+            &[
+                signer.as_ref().clone(),
+                pool_info.as_ref().clone(),
+                system_program.as_ref().clone(),
+            ],
+        )?;
+    }
+    pool_info.realloc(space, true)?;
+
+    Ok(MigratePoolEvent {
+        lamports_paid: rent_shortfall,
+    })
+}
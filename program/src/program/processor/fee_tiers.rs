@@ -0,0 +1,114 @@
+use std::mem::size_of;
+
+use borsh::{BorshDeserialize as Deserialize, BorshSerialize as Serialize};
+use bytemuck::try_from_bytes_mut;
+use solana_program::{
+    account_info::AccountInfo, msg, program_error::ProgramError, rent::Rent, sysvar::Sysvar,
+};
+
+use crate::program::error::PlasmaError;
+use crate::{
+    assert_with_msg,
+    program::{
+        accounts::{FeeTier, FeeTierConfigAccount},
+        events::{InitializeFeeTierConfigEvent, SetFeeTierEvent},
+        system_utils::create_account,
+        validation::loaders::{
+            get_fee_tier_config_address, FeeTierConfigContext, InitializeFeeTierConfigContext,
+        },
+    },
+};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+pub struct SetFeeTierParams {
+    /// Identifies the tier, an existing tier with the same LP fee is overwritten
+    pub lp_fee_in_bps: u64,
+    pub protocol_lp_fee_allocation_in_pct: u64,
+    /// This is the number of slots that the LP shares of new pools will be vested over
+    /// If this value is not a multiple of the leader slot window, it will be rounded down
+    pub num_slots_to_vest_lp_shares: u64,
+    pub enabled: bool,
+}
+
+pub(crate) fn process_initialize_fee_tier_config<'a, 'info>(
+    config_context: &FeeTierConfigContext<'a, 'info>,
+    accounts: &'a [AccountInfo<'info>],
+) -> Result<InitializeFeeTierConfigEvent, ProgramError> {
+    let InitializeFeeTierConfigContext {
+        config,
+        system_program,
+    } = InitializeFeeTierConfigContext::load(config_context, accounts)?;
+    let admin = *config_context.signer.key;
+
+    let (_, bump) = get_fee_tier_config_address();
+    create_account(
+        &config_context.signer,
+        config.as_ref(),
+        system_program.as_ref(),
+        &crate::id(),
+        &Rent::get()?,
+        size_of::<FeeTierConfigAccount>() as u64,
+        vec![b"fee_tier_config".to_vec(), vec![bump]],
+    )?;
+    let mut config_bytes = config.try_borrow_mut_data()?;
+    *try_from_bytes_mut::<FeeTierConfigAccount>(&mut config_bytes)
+        .map_err(|_| PlasmaError::InvalidFeeTierConfigAccount)? = FeeTierConfigAccount::new(admin);
+
+    msg!("Initialized fee tier config with admin {}", admin);
+
+    Ok(InitializeFeeTierConfigEvent { admin })
+}
+
+pub(crate) fn process_set_fee_tier<'a, 'info>(
+    config_context: &FeeTierConfigContext<'a, 'info>,
+    data: &[u8],
+) -> Result<SetFeeTierEvent, ProgramError> {
+    let SetFeeTierParams {
+        lp_fee_in_bps,
+        protocol_lp_fee_allocation_in_pct,
+        num_slots_to_vest_lp_shares,
+        enabled,
+    } = SetFeeTierParams::try_from_slice(data)?;
+
+    assert_with_msg(
+        lp_fee_in_bps < 500,
+        PlasmaError::LpFeeTooHigh,
+        "LP fee is capped at 5%",
+    )?;
+
+    assert_with_msg(
+        protocol_lp_fee_allocation_in_pct < 50,
+        PlasmaError::ProtocolFeeAllocationTooHigh,
+        "The protocol fee allocation is capped at 50% of the LP fee",
+    )?;
+
+    let mut config_bytes = config_context.config_info.try_borrow_mut_data()?;
+    let config = try_from_bytes_mut::<FeeTierConfigAccount>(&mut config_bytes)
+        .map_err(|_| PlasmaError::InvalidFeeTierConfigAccount)?;
+
+    config.assert_admin(config_context.signer.key)?;
+
+    let previous_fee_tier = config.set_fee_tier(FeeTier {
+        lp_fee_in_bps,
+        protocol_lp_fee_allocation_in_pct,
+        num_slots_to_vest_lp_shares,
+        enabled: enabled as u64,
+    })?;
+
+    msg!(
+        "Set fee tier {} bps: protocol allocation {}%, vesting over {} slots, enabled: {}",
+        lp_fee_in_bps,
+        protocol_lp_fee_allocation_in_pct,
+        num_slots_to_vest_lp_shares,
+        enabled
+    );
+
+    Ok(SetFeeTierEvent {
+        lp_fee_in_bps,
+        protocol_lp_fee_allocation_in_pct,
+        num_slots_to_vest_lp_shares,
+        enabled,
+        is_new_fee_tier: previous_fee_tier.is_none(),
+    })
+}
@@ -1,14 +1,39 @@
+use borsh::{BorshDeserialize as Deserialize, BorshSerialize as Serialize};
 use bytemuck::try_from_bytes_mut;
+use plasma_state::lp::CompoundFeesResult;
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, msg, program_error::ProgramError, sysvar::Sysvar,
+    account_info::AccountInfo, clock::Clock, msg, program_error::ProgramError, pubkey::Pubkey,
+    sysvar::Sysvar,
 };
 
+use crate::assert_with_msg;
+use crate::program::error::PlasmaError;
 use crate::program::{
     accounts::{LpPositionAccount, LpPositionStatus, PoolAccount},
-    events::{WithdrawLpFeesEvent, WithdrawProtocolFeesEvent},
+    events::{
+        CompoundLpFeesEvent, TransferProtocolFeeRecipientEvent, WithdrawLpFeesEvent,
+        WithdrawProtocolFeesEvent,
+    },
     token_utils::{maybe_invoke_withdraw, MaybeInvokeWithdrawParams},
-    validation::loaders::{PlasmaPoolContext, WithdrawLpFeesContext, WithdrawProtocolFeesContext},
+    validation::loaders::{
+        CompoundLpFeesContext, PlasmaPoolContext, WithdrawLpFeesContext,
+        WithdrawProtocolFeesContext,
+    },
 };
+use crate::LEADER_SLOT_WINDOW;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+pub struct TransferProtocolFeeRecipientParams {
+    pub new_recipient: Pubkey,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+pub struct CompoundLpFeesParams {
+    /// The instruction fails if fewer LP shares are minted from the fees
+    pub min_lp_shares_out: u64,
+}
 
 pub(crate) fn process_withdraw_lp_fees<'a, 'info>(
     pool_context: &PlasmaPoolContext<'a, 'info>,
@@ -18,17 +43,18 @@ pub(crate) fn process_withdraw_lp_fees<'a, 'info>(
         lp_position: lp_position_account,
         quote_account,
         quote_vault,
+        quote_mint,
         token_program,
     } = WithdrawLpFeesContext::load(&pool_context, accounts)?;
 
     let slot = Clock::get()?.slot;
     let mut pool_bytes = pool_context.pool_info.try_borrow_mut_data()?;
     let pool = try_from_bytes_mut::<PoolAccount>(&mut *pool_bytes)
-        .map_err(|_| ProgramError::InvalidAccountData)?;
+        .map_err(|_| PlasmaError::InvalidPoolAccount)?;
 
     let mut lp_position_bytes = lp_position_account.info.try_borrow_mut_data()?;
     let lp_position = try_from_bytes_mut::<LpPositionAccount>(&mut *lp_position_bytes)
-        .map_err(|_| ProgramError::InvalidAccountData)?;
+        .map_err(|_| PlasmaError::InvalidLpPositionAccount)?;
 
     if matches!(
         LpPositionStatus::parse(lp_position.status)?,
@@ -38,24 +64,29 @@ pub(crate) fn process_withdraw_lp_fees<'a, 'info>(
             "LP position has been burned ({}), cannot withdraw fees",
             lp_position.status
         );
-        return Err(ProgramError::InvalidArgument);
+        return Err(PlasmaError::LpPositionFeesBurned.into());
     }
 
-    let quote_fees_collected = lp_position.collect_fees(slot, &pool).map_err(|e| {
-        msg!("Error collecting fees: {:?}", e);
-        ProgramError::InvalidArgument
-    })?;
+    let quote_fees_collected = lp_position
+        .collect_fees(slot, &pool, pool.header.get_lp_vesting_schedule()?)
+        .map_err(|e| {
+            msg!("Error collecting fees: {:?}", e);
+            PlasmaError::from(e)
+        })?;
+
+    pool.header.quote_lp_fees_paid += quote_fees_collected;
 
     msg!("Collected fees: {}", quote_fees_collected);
 
     maybe_invoke_withdraw(MaybeInvokeWithdrawParams {
         pool_key: pool_context.pool_info.key,
-        mint_key: &pool.header.quote_params.mint_key,
+        mint: &quote_mint,
         bump: pool.header.quote_params.vault_bump as u8,
         withdraw_account: quote_account,
         withdraw_amount: quote_fees_collected,
         withdraw_vault: quote_vault,
         token_program: token_program.as_ref(),
+        unwrap_to: None,
     })?;
 
     Ok(WithdrawLpFeesEvent {
@@ -63,6 +94,84 @@ pub(crate) fn process_withdraw_lp_fees<'a, 'info>(
     })
 }
 
+/// Swaps half of the uncollected LP fees of the trader for base and deposits both halves back
+/// into the pool. No tokens are transferred since the fees are already in the quote vault.
+pub(crate) fn process_compound_lp_fees<'a, 'info>(
+    pool_context: &PlasmaPoolContext<'a, 'info>,
+    accounts: &'a [AccountInfo<'info>],
+    data: &[u8],
+) -> Result<CompoundLpFeesEvent, ProgramError> {
+    let CompoundLpFeesContext {
+        lp_position: lp_position_account,
+    } = CompoundLpFeesContext::load(pool_context, accounts)?;
+
+    let CompoundLpFeesParams { min_lp_shares_out } = CompoundLpFeesParams::try_from_slice(data)?;
+
+    // Get the active leader slot
+    let slot = (Clock::get()?.slot / LEADER_SLOT_WINDOW) * LEADER_SLOT_WINDOW;
+
+    let mut pool_bytes = pool_context.pool_info.try_borrow_mut_data()?;
+    let pool = try_from_bytes_mut::<PoolAccount>(&mut *pool_bytes)
+        .map_err(|_| PlasmaError::InvalidPoolAccount)?;
+    pool.header.assert_active()?;
+    let lp_vesting_schedule = pool.header.get_lp_vesting_schedule()?;
+
+    let mut lp_position_bytes = lp_position_account.info.try_borrow_mut_data()?;
+    let lp_position = try_from_bytes_mut::<LpPositionAccount>(&mut *lp_position_bytes)
+        .map_err(|_| PlasmaError::InvalidLpPositionAccount)?;
+
+    // Renounced positions cannot add liquidity
+    if lp_position.is_renounced()? {
+        msg!("Liquidity position has been renounced, cannot compound fees");
+        return Err(PlasmaError::LpPositionRenounced.into());
+    }
+
+    let pre_lp_fees = pool.amm.cumulative_quote_lp_fees;
+    let CompoundFeesResult {
+        quote_fees_compounded,
+        swap_result,
+        base_amount_deposited,
+        quote_amount_deposited,
+        lp_shares_received,
+        lp_shares_vested,
+        ..
+    } = lp_position
+        .compound_fees(slot, pool, lp_vesting_schedule, min_lp_shares_out)
+        .map_err(|e| {
+            msg!("Error compounding fees: {:?}", e);
+            PlasmaError::from(e)
+        })?;
+
+    pool.update_protocol_fee_recipients_post_swap()?;
+    assert_with_msg(
+        pool.amm.cumulative_quote_lp_fees >= pre_lp_fees,
+        PlasmaError::CumulativeFeesDecreased,
+        "Cumulative LP fees did not increase after swap",
+    )?;
+    pool.header.quote_lp_fees_paid += quote_fees_compounded;
+
+    msg!(
+        "Compounded {} quote fees into {} LP shares",
+        quote_fees_compounded,
+        lp_shares_received
+    );
+
+    Ok(CompoundLpFeesEvent {
+        quote_fees_compounded,
+        quote_fees_swapped: swap_result.quote_amount_to_transfer,
+        base_amount_bought: swap_result.base_amount_to_transfer,
+        user_base_deposited: base_amount_deposited,
+        user_quote_deposited: quote_amount_deposited,
+        user_lp_shares_received: lp_shares_received,
+        user_lp_shares_available: lp_position.lp_shares,
+        user_lp_shares_locked: lp_position.pending_lp_shares(),
+        user_lp_shares_unlocked_for_withdrawal: lp_shares_vested,
+        pool_total_lp_shares: pool.total_lp_shares,
+        pool_total_base_liquidity: pool.base_reserves,
+        pool_total_quote_liquidity: pool.quote_reserves,
+    })
+}
+
 pub(crate) fn process_withdraw_protocol_fees<'a, 'info>(
     pool_context: &PlasmaPoolContext<'a, 'info>,
     accounts: &'a [AccountInfo<'info>],
@@ -70,12 +179,13 @@ pub(crate) fn process_withdraw_protocol_fees<'a, 'info>(
     let WithdrawProtocolFeesContext {
         quote_account,
         quote_vault,
+        quote_mint,
         token_program,
     } = WithdrawProtocolFeesContext::load(&pool_context, accounts)?;
 
     let mut pool_bytes = pool_context.pool_info.try_borrow_mut_data()?;
     let pool = try_from_bytes_mut::<PoolAccount>(&mut *pool_bytes)
-        .map_err(|_| ProgramError::InvalidAccountData)?;
+        .map_err(|_| PlasmaError::InvalidPoolAccount)?;
 
     let recipient = pool_context.signer.key;
     let withdrawable_fees = pool.withdraw_protocol_fee(recipient)?;
@@ -88,12 +198,13 @@ pub(crate) fn process_withdraw_protocol_fees<'a, 'info>(
 
     maybe_invoke_withdraw(MaybeInvokeWithdrawParams {
         pool_key: pool_context.pool_info.key,
-        mint_key: &pool.header.quote_params.mint_key,
+        mint: &quote_mint,
         bump: pool.header.quote_params.vault_bump as u8,
         withdraw_account: quote_account,
         withdraw_amount: withdrawable_fees,
         withdraw_vault: quote_vault,
         token_program: token_program.as_ref(),
+        unwrap_to: None,
     })?;
 
     Ok(WithdrawProtocolFeesEvent {
@@ -101,3 +212,33 @@ pub(crate) fn process_withdraw_protocol_fees<'a, 'info>(
         protocol_fee_recipient: *recipient,
     })
 }
+
+pub(crate) fn process_transfer_protocol_fee_recipient<'a, 'info>(
+    pool_context: &PlasmaPoolContext<'a, 'info>,
+    data: &[u8],
+) -> Result<TransferProtocolFeeRecipientEvent, ProgramError> {
+    let TransferProtocolFeeRecipientParams { new_recipient } =
+        TransferProtocolFeeRecipientParams::try_from_slice(data)?;
+
+    let mut pool_bytes = pool_context.pool_info.try_borrow_mut_data()?;
+    let pool = try_from_bytes_mut::<PoolAccount>(&mut *pool_bytes)
+        .map_err(|_| PlasmaError::InvalidPoolAccount)?;
+
+    let recipient = pool_context.signer.key;
+    let fee_recipient = pool.transfer_protocol_fee_recipient(recipient, &new_recipient)?;
+
+    msg!(
+        "Transferred protocol fee recipient {} to {}",
+        recipient,
+        new_recipient
+    );
+
+    Ok(TransferProtocolFeeRecipientEvent {
+        previous_recipient: *recipient,
+        new_recipient,
+        shares: fee_recipient.shares,
+        uncollected_quote_fees: fee_recipient
+            .total_accumulated_quote_fees
+            .saturating_sub(fee_recipient.collected_quote_fees),
+    })
+}
@@ -1,21 +1,28 @@
+use std::mem::size_of;
+
 use borsh::{BorshDeserialize as Deserialize, BorshSerialize as Serialize};
 use bytemuck::try_from_bytes_mut;
-use plasma_state::amm::Amm;
+use plasma_state::{amm::Amm, lp::VestingSchedule};
 use solana_program::{
     account_info::AccountInfo, clock::Clock, msg, program::invoke, program_error::ProgramError,
-    program_pack::Pack, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+    pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
 };
+use spl_token_2022::{extension::ExtensionType, state::Account};
 
+use crate::program::error::PlasmaError;
 use crate::{
     assert_with_msg,
     program::{
         accounts::{
-            PoolAccount, PoolHeader, ProtocolFeeRecipient, ProtocolFeeRecipients, TokenParams,
-            POOL_ACCOUNT_DISCRIMINATOR,
+            FeeTier, PoolAccount, PoolHeader, PoolStatus, ProtocolFeeRecipient,
+            ProtocolFeeRecipients, TokenParams, POOL_ACCOUNT_DISCRIMINATOR,
         },
         events::InitializePoolEvent,
         system_utils::create_account,
-        validation::loaders::{get_vault_address, InitializePoolContext, PlasmaPoolContext},
+        validation::loaders::{
+            get_pool_address, get_vault_address, sort_mints, InitializePoolContext,
+            PlasmaPoolContext,
+        },
     },
     LEADER_SLOT_WINDOW,
 };
@@ -30,12 +37,13 @@ pub struct ProtocolFeeRecipientParams {
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
 pub struct InitializePoolParams {
-    lp_fee_in_bps: u64,
-    protocol_lp_fee_allocation_in_pct: u64,
+    /// LP fee in basis points of an enabled tier of the fee tier config. The protocol fee
+    /// allocation and LP vesting window of the pool are taken from the tier.
+    fee_tier: u64,
     fee_recipients_params: [ProtocolFeeRecipientParams; 3],
-    /// This is the number of slots that the LP shares will be vested over
-    /// If this value is not a multiple of the leader slot window, it will be rounded down
-    num_slots_to_vest_lp_shares: Option<u64>,
+    /// Authority allowed to update the pool configuration. If this is not set, the pool
+    /// configuration can never be changed.
+    pool_authority: Option<Pubkey>,
 }
 
 pub(crate) fn process_initialize_pool<'a, 'info>(
@@ -53,27 +61,26 @@ pub(crate) fn process_initialize_pool<'a, 'info>(
         base_vault,
         quote_vault,
         system_program,
-        token_program,
+        base_token_program,
+        quote_token_program,
+        fee_tier_config,
     } = InitializePoolContext::load(accounts)?;
 
     let InitializePoolParams {
-        lp_fee_in_bps,
-        protocol_lp_fee_allocation_in_pct: protocol_fee_allocation_in_pct,
+        fee_tier,
         fee_recipients_params,
-        num_slots_to_vest_lp_shares: vesting_slot_window,
+        pool_authority,
     } = InitializePoolParams::try_from_slice(data)?;
 
-    assert_with_msg(
-        lp_fee_in_bps < 500,
-        ProgramError::InvalidArgument,
-        "LP fee is capped at 5%",
-    )?;
-
-    assert_with_msg(
-        protocol_fee_allocation_in_pct < 50,
-        ProgramError::InvalidArgument,
-        "The protocol fee allocation is capped at 50% of the LP fee",
-    )?;
+    // The fee caps are enforced when the tier is set
+    let FeeTier {
+        lp_fee_in_bps,
+        protocol_lp_fee_allocation_in_pct: protocol_fee_allocation_in_pct,
+        num_slots_to_vest_lp_shares,
+        ..
+    } = fee_tier_config
+        .get_config()?
+        .get_enabled_fee_tier(fee_tier)?;
 
     assert_with_msg(
         fee_recipients_params
@@ -81,27 +88,73 @@ pub(crate) fn process_initialize_pool<'a, 'info>(
             .map(|params| params.shares as u128)
             .sum::<u128>()
             < 10000,
-        ProgramError::InvalidArgument,
+        PlasmaError::TooManyProtocolFeeShares,
         "The total shares must sum to less than 10000",
     )?;
 
-    // Create the base and quote vaults of this pool
+    // The pool account is created at the PDA of its mint pair and fee tier
     let rent = Rent::get()?;
+    let (pool_key, pool_bump) = get_pool_address(base_mint.info.key, quote_mint.info.key, fee_tier);
+    assert_with_msg(
+        pool_key == *pool_info.key,
+        PlasmaError::InvalidPoolAddress,
+        &format!(
+            "Supplied pool ({}) does not match computed key ({})",
+            pool_info.key, pool_key
+        ),
+    )?;
+    let (mint_a, mint_b) = sort_mints(base_mint.info.key, quote_mint.info.key);
+    create_account(
+        pool_creator.as_ref(),
+        pool_info.as_ref(),
+        system_program.as_ref(),
+        &crate::id(),
+        &rent,
+        size_of::<PoolAccount>() as u64,
+        vec![
+            b"pool".to_vec(),
+            mint_a.as_ref().to_vec(),
+            mint_b.as_ref().to_vec(),
+            fee_tier.to_le_bytes().to_vec(),
+            vec![pool_bump],
+        ],
+    )?;
+
+    // Create the base and quote vaults of this pool
     let mut bumps = vec![];
-    for (token_account, mint) in [
-        (base_vault.as_ref(), base_mint.as_ref()),
-        (quote_vault.as_ref(), quote_mint.as_ref()),
+    for (token_account, mint_info, token_program) in [
+        (base_vault.as_ref(), &base_mint, &base_token_program),
+        (quote_vault.as_ref(), &quote_mint, &quote_token_program),
     ] {
+        let mint = mint_info.as_ref();
+        let mint_extensions = mint_info.get_extension_types()?;
+        // Extensions are allowed explicitly, any other extension could block or claw back the
+        // transfers in and out of the vaults
+        assert_with_msg(
+            mint_extensions.iter().all(|extension| {
+                matches!(
+                    extension,
+                    ExtensionType::TransferFeeConfig
+                        | ExtensionType::MetadataPointer
+                        | ExtensionType::TokenMetadata
+                )
+            }),
+            PlasmaError::UnsupportedMintExtension,
+            &format!("Mint {} has an unsupported extension", mint.key),
+        )?;
         let (vault_key, bump) = get_vault_address(pool_info.key, mint.key);
         assert_with_msg(
             vault_key == *token_account.key,
-            ProgramError::InvalidSeeds,
+            PlasmaError::InvalidVaultAddress,
             &format!(
                 "Supplied vault ({}) does not match computed key ({})",
                 token_account.key, vault_key
             ),
         )?;
-        let space = spl_token::state::Account::LEN;
+        // Token-2022 vaults must have room for the account extensions required by the mint
+        let space = ExtensionType::try_calculate_account_len::<Account>(
+            &ExtensionType::get_required_init_account_extensions(&mint_extensions),
+        )?;
         let seeds = vec![
             b"vault".to_vec(),
             pool_info.key.as_ref().to_vec(),
@@ -112,14 +165,14 @@ pub(crate) fn process_initialize_pool<'a, 'info>(
             pool_creator.as_ref(),
             token_account,
             system_program.as_ref(),
-            &spl_token::id(),
+            token_program.key,
             &rent,
             space as u64,
             seeds,
         )?;
         invoke(
-            &spl_token::instruction::initialize_account3(
-                &spl_token::id(),
+            &spl_token_2022::instruction::initialize_account3(
+                token_program.key,
                 token_account.key,
                 mint.key,
                 token_account.key,
@@ -136,11 +189,11 @@ pub(crate) fn process_initialize_pool<'a, 'info>(
 
     let mut pool_bytes = pool_context.pool_info.try_borrow_mut_data()?;
     let pool = try_from_bytes_mut::<PoolAccount>(&mut *pool_bytes)
-        .map_err(|_| ProgramError::InvalidAccountData)?;
+        .map_err(|_| PlasmaError::InvalidPoolAccount)?;
 
     if pool.header.sequence_number != 0 {
         msg!("Pool account already initialized");
-        return Err(ProgramError::InvalidAccountData);
+        return Err(PlasmaError::PoolAlreadyInitialized.into());
     }
     let fee_recipients = {
         let fee_recipients = fee_recipients_params.map(|params| ProtocolFeeRecipient {
@@ -154,7 +207,7 @@ pub(crate) fn process_initialize_pool<'a, 'info>(
             fee_recipients[0].recipient != fee_recipients[1].recipient
                 && fee_recipients[0].recipient != fee_recipients[2].recipient
                 && fee_recipients[1].recipient != fee_recipients[2].recipient,
-            ProgramError::InvalidArgument,
+            PlasmaError::DuplicateProtocolFeeRecipients,
             "Protocol fee recipients must be different",
         )?;
 
@@ -178,16 +231,21 @@ pub(crate) fn process_initialize_pool<'a, 'info>(
             vault_key: *quote_vault.key,
         },
         fee_recipients,
-        padding: [0; 13],
+        pool_authority: pool_authority.unwrap_or_default(),
+        status: PoolStatus::Active as u64,
+        fee_tier,
+        lp_mint: Pubkey::default(),
+        lp_mint_bump: 0,
+        lp_vesting_schedule: VestingSchedule::Cliff as u32,
+        quote_lp_fees_paid: 0,
+        last_lp_vesting_slot: 0,
     };
 
     let slot = (Clock::get()?.slot / LEADER_SLOT_WINDOW) * LEADER_SLOT_WINDOW;
     pool.amm = Amm::new(
         lp_fee_in_bps as u32,
         protocol_fee_allocation_in_pct as u32,
-        vesting_slot_window
-            .map(|v| v / LEADER_SLOT_WINDOW)
-            .unwrap_or(2),
+        num_slots_to_vest_lp_shares / LEADER_SLOT_WINDOW,
         slot,
     );
 
@@ -195,5 +253,19 @@ pub(crate) fn process_initialize_pool<'a, 'info>(
         lp_fee_in_bps,
         protocol_fee_in_pct: protocol_fee_allocation_in_pct,
         fee_recipient_params: fee_recipients_params,
+        pool_authority: pool_authority.unwrap_or_default(),
     })
 }
+
+#[test]
+fn test_pool_address_is_independent_of_mint_order() {
+    let (mint_a, mint_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+    assert_eq!(
+        get_pool_address(&mint_a, &mint_b, 30),
+        get_pool_address(&mint_b, &mint_a, 30)
+    );
+    assert_ne!(
+        get_pool_address(&mint_a, &mint_b, 30),
+        get_pool_address(&mint_a, &mint_b, 100)
+    );
+}
@@ -2,33 +2,52 @@ use std::mem::size_of;
 
 use borsh::{BorshDeserialize as Deserialize, BorshSerialize as Serialize};
 use bytemuck::{try_from_bytes, try_from_bytes_mut};
-use plasma_state::lp::{AddLiquidityResult, LpPosition, RemoveLiquidityResult};
+use plasma_state::{
+    amm::TokenType,
+    fixed::I80F48,
+    lp::{
+        AddLiquidityResult, LpPosition, LpPositionTransferResult, RemoveLiquidityMode,
+        RemoveLiquidityResult,
+    },
+    zap::{AddLiquiditySingleSidedResult, RemoveLiquiditySingleSidedResult},
+};
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     clock::Clock,
+    entrypoint::ProgramResult,
     msg,
+    program::invoke,
     program_error::ProgramError,
+    pubkey::Pubkey,
     rent::Rent,
+    system_instruction,
     sysvar::Sysvar,
 };
 
+use crate::program::error::PlasmaError;
 use crate::{
     assert_with_msg,
     program::{
         accounts::{
-            LpPositionAccount, LpPositionStatus, PoolAccount, LP_POSITION_ACCOUNT_DISCRIMINATOR,
+            LpPositionAccount, LpPositionStatus, PoolAccount, LEGACY_LP_POSITION_ACCOUNT_SIZE,
+            LP_POSITION_ACCOUNT_DISCRIMINATOR,
         },
         events::{
-            AddLiquidityEvent, InitializeLpPositionEvent, RemoveLiquidityEvent,
-            RenounceLiquidityEvent,
+            AddLiquidityEvent, AddLiquiditySingleSidedEvent, CloseLpPositionEvent,
+            InitializeLpPositionEvent, MigrateLpPositionEvent, RemoveLiquidityEvent,
+            RemoveLiquiditySingleSidedEvent, RenounceLiquidityEvent, TransferLpPositionEvent,
+        },
+        processor::swap::SlotDeadline,
+        system_utils::{close_account, create_account},
+        token_utils::{
+            try_deposit, try_withdraw, NativeSolParams, TryDepositParams, TryWithdrawParams,
         },
-        system_utils::create_account,
-        token_utils::{try_deposit, try_withdraw, TryDepositParams, TryWithdrawParams},
         validation::{
             checkers::plasma_checkers::LpPositionAccountInfo,
             loaders::{
                 get_lp_position_address, InitializeLpPositionContext, LiquidityActionContext,
-                PlasmaPoolContext, PlasmaVaultContext,
+                MigrateLpPositionContext, PlasmaPoolContext, PlasmaVaultContext,
+                TransferLpPositionContext,
             },
         },
     },
@@ -51,52 +70,77 @@ pub(crate) fn process_initialize_lp_position<'a, 'info>(
 
     assert_with_msg(
         &lp_position_address == lp_position.key,
-        ProgramError::InvalidAccountData,
+        PlasmaError::InvalidLpPositionAddress,
         "Invalid lp_position address",
     )?;
     assert_with_msg(
         lp_position.data_is_empty(),
-        ProgramError::InvalidAccountData,
+        PlasmaError::AccountAlreadyInitialized,
         "LpPosition account is already initialized",
     )?;
 
+    let reward_factor = {
+        let pool_bytes = pool_context.pool_info.try_borrow_data()?;
+        let pool = try_from_bytes::<PoolAccount>(&pool_bytes).map_err(|_| {
+            msg!("Invalid pool account data");
+            PlasmaError::InvalidPoolAccount
+        })?;
+        pool.reward_factor
+    };
+    create_lp_position(
+        pool_context.signer.as_ref(),
+        pool_key,
+        lp_position_owner.key,
+        lp_position.as_ref(),
+        bump,
+        system_program.as_ref(),
+        reward_factor,
+    )?;
+
+    Ok(InitializeLpPositionEvent {
+        owner: *lp_position_owner.key,
+    })
+}
+
+/// Creates the LP position account of `owner` at its PDA, the position starts earning fees at
+/// `reward_factor`
+fn create_lp_position<'a, 'info>(
+    payer: &'a AccountInfo<'info>,
+    pool_key: &Pubkey,
+    owner: &Pubkey,
+    lp_position: &'a AccountInfo<'info>,
+    bump: u8,
+    system_program: &'a AccountInfo<'info>,
+    reward_factor: I80F48,
+) -> ProgramResult {
     let space = size_of::<LpPositionAccount>();
     let seeds = vec![
         b"lp_position".to_vec(),
         pool_key.as_ref().to_vec(),
-        lp_position_owner.key.as_ref().to_vec(),
+        owner.as_ref().to_vec(),
         vec![bump],
     ];
 
     create_account(
-        &pool_context.signer,
-        lp_position.as_ref(),
-        system_program.as_ref(),
+        payer,
+        lp_position,
+        system_program,
         &crate::id(),
         &Rent::get()?,
         space as u64,
         seeds,
     )?;
 
-    let pool_bytes = pool_context.pool_info.try_borrow_data()?;
-    let pool = try_from_bytes::<PoolAccount>(&pool_bytes).map_err(|_| {
-        msg!("Invalid pool account data");
-        ProgramError::InvalidAccountData
-    })?;
-
     let mut lp_position_bytes = lp_position.try_borrow_mut_data()?;
     *try_from_bytes_mut::<LpPositionAccount>(&mut lp_position_bytes)
-        .map_err(|_| ProgramError::InvalidAccountData)? = LpPositionAccount {
+        .map_err(|_| PlasmaError::InvalidLpPositionAccount)? = LpPositionAccount {
         discriminator: LP_POSITION_ACCOUNT_DISCRIMINATOR,
-        authority: *lp_position_owner.key,
+        authority: *owner,
         pool: *pool_key,
         status: LpPositionStatus::Active as u64,
-        lp_position: LpPosition::new_with_reward_factor_snapshot(pool.reward_factor),
+        lp_position: LpPosition::new_with_reward_factor_snapshot(reward_factor),
     };
-
-    Ok(InitializeLpPositionEvent {
-        owner: *lp_position_owner.key,
-    })
+    Ok(())
 }
 
 #[repr(C)]
@@ -107,6 +151,111 @@ pub struct AddLiquidityParams {
     pub initial_lp_shares: Option<u64>,
 }
 
+/// Versioned data that follows `AddLiquidityParams`. Instructions without trailing data are
+/// parsed as `V0`, new fields are added in new versions.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum AddLiquidityParamsExtension {
+    V0,
+    V1 {
+        native_sol: NativeSolParams,
+        /// Fails the deposit if the trader receives fewer LP shares
+        min_lp_shares_out: u64,
+        deadline: SlotDeadline,
+    },
+}
+
+impl AddLiquidityParamsExtension {
+    /// Parses the data that follows the instruction params, an empty slice is treated as `V0`
+    pub(crate) fn load_trailing(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.is_empty() {
+            return Ok(Self::V0);
+        }
+        Ok(Self::try_from_slice(data)?)
+    }
+
+    pub fn native_sol(&self) -> NativeSolParams {
+        match *self {
+            Self::V0 => NativeSolParams::default(),
+            Self::V1 { native_sol, .. } => native_sol,
+        }
+    }
+
+    pub fn min_lp_shares_out(&self) -> u64 {
+        match *self {
+            Self::V0 => 0,
+            Self::V1 {
+                min_lp_shares_out, ..
+            } => min_lp_shares_out,
+        }
+    }
+
+    pub fn deadline(&self) -> SlotDeadline {
+        match *self {
+            Self::V0 => SlotDeadline::default(),
+            Self::V1 { deadline, .. } => deadline,
+        }
+    }
+}
+
+/// Versioned data that follows the LP shares of `RemoveLiquidity`, see
+/// `AddLiquidityParamsExtension`
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum RemoveLiquidityParamsExtension {
+    V0,
+    V1 {
+        native_sol: NativeSolParams,
+        /// Minimum amounts received by the trader, after transfer fees
+        min_base_out: u64,
+        min_quote_out: u64,
+        deadline: SlotDeadline,
+        mode: RemoveLiquidityMode,
+    },
+}
+
+impl RemoveLiquidityParamsExtension {
+    /// Parses the data that follows the instruction params, an empty slice is treated as `V0`
+    pub(crate) fn load_trailing(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.is_empty() {
+            return Ok(Self::V0);
+        }
+        Ok(Self::try_from_slice(data)?)
+    }
+
+    pub fn native_sol(&self) -> NativeSolParams {
+        match *self {
+            Self::V0 => NativeSolParams::default(),
+            Self::V1 { native_sol, .. } => native_sol,
+        }
+    }
+
+    /// Returns the minimum base and quote amounts out
+    pub fn min_amounts_out(&self) -> (u64, u64) {
+        match *self {
+            Self::V0 => (0, 0),
+            Self::V1 {
+                min_base_out,
+                min_quote_out,
+                ..
+            } => (min_base_out, min_quote_out),
+        }
+    }
+
+    pub fn deadline(&self) -> SlotDeadline {
+        match *self {
+            Self::V0 => SlotDeadline::default(),
+            Self::V1 { deadline, .. } => deadline,
+        }
+    }
+
+    /// Instructions without an extension fail when the requested shares are not withdrawable
+    pub fn mode(&self) -> RemoveLiquidityMode {
+        match *self {
+            Self::V0 => RemoveLiquidityMode::Strict,
+            Self::V1 { mode, .. } => mode,
+        }
+    }
+}
+
 pub(crate) fn process_add_liquidity<'a, 'info>(
     pool_context: &PlasmaPoolContext<'a, 'info>,
     accounts: &[AccountInfo<'info>],
@@ -115,46 +264,66 @@ pub(crate) fn process_add_liquidity<'a, 'info>(
     let LiquidityActionContext {
         lp_position: lp_position_account,
         vault_context,
+        system_program,
     } = LiquidityActionContext::load(&pool_context, accounts)?;
+    let system_program = system_program.as_ref().map(|program| program.as_ref());
+    let temporary_wsol_account = vault_context
+        .maybe_create_temporary_wsol_account(pool_context.signer.as_ref(), system_program)?;
 
     let PlasmaVaultContext {
         base_account,
         quote_account,
         base_vault,
         quote_vault,
-        token_program,
+        base_mint,
+        quote_mint,
+        base_token_program,
+        quote_token_program,
     } = vault_context;
 
+    let mut data = data;
     let AddLiquidityParams {
         desired_base_amount_in,
         desired_quote_amount_in,
         initial_lp_shares,
-    } = AddLiquidityParams::try_from_slice(data)?;
+    } = AddLiquidityParams::deserialize(&mut data)?;
+    let extension = AddLiquidityParamsExtension::load_trailing(data)?;
+    let native_sol = extension.native_sol();
+    if native_sol.wrap && system_program.is_none() {
+        msg!("The system program is required to wrap native SOL");
+        return Err(PlasmaError::MissingSystemProgram.into());
+    }
 
     // Get the active leader slot
-    let slot = (Clock::get()?.slot / LEADER_SLOT_WINDOW) * LEADER_SLOT_WINDOW;
+    let current_slot = Clock::get()?.slot;
+    extension.deadline().check_slot(current_slot)?;
+    let slot = (current_slot / LEADER_SLOT_WINDOW) * LEADER_SLOT_WINDOW;
 
     let mut pool_bytes = pool_context.pool_info.try_borrow_mut_data()?;
     let pool = try_from_bytes_mut::<PoolAccount>(&mut *pool_bytes)
-        .map_err(|_| ProgramError::InvalidAccountData)?;
+        .map_err(|_| PlasmaError::InvalidPoolAccount)?;
+    pool.header.assert_active()?;
+    pool.maybe_update_snapshot(slot);
+    extension.deadline().check_snapshot(pool)?;
+    let lp_vesting_schedule = pool.header.get_lp_vesting_schedule()?;
 
     if pool.total_lp_shares == 0 {
         assert_with_msg(
             initial_lp_shares.is_some(),
-            ProgramError::InvalidArgument,
+            PlasmaError::InvalidInitialLpShares,
             "Initial LP shares must be provided for the first liquidity deposit",
         )?;
     } else {
         assert_with_msg(
             initial_lp_shares.is_none(),
-            ProgramError::InvalidArgument,
+            PlasmaError::InvalidInitialLpShares,
             "Initial LP shares must be None for subsequent liquidity deposits",
         )?;
     }
 
     let mut lp_position_bytes = lp_position_account.info.try_borrow_mut_data()?;
     let lp_position = try_from_bytes_mut::<LpPositionAccount>(&mut *lp_position_bytes)
-        .map_err(|_| ProgramError::InvalidAccountData)?;
+        .map_err(|_| PlasmaError::InvalidLpPositionAccount)?;
 
     // One consequence of renouncing a liquidity position is that the associated trader pubkey can no longer add or remove liquidity
     // for this pool ever again.
@@ -163,7 +332,7 @@ pub(crate) fn process_add_liquidity<'a, 'info>(
         LpPositionStatus::RenouncedWithBurnedFees | LpPositionStatus::RenouncedWithFeeWithdawal
     ) {
         msg!("Liquidity position has been renounced, cannot remove liquidity");
-        return Err(ProgramError::InvalidArgument);
+        return Err(PlasmaError::LpPositionRenounced.into());
     }
 
     let AddLiquidityResult {
@@ -176,20 +345,35 @@ pub(crate) fn process_add_liquidity<'a, 'info>(
         .add_liquidity(
             slot,
             pool,
+            lp_vesting_schedule,
             desired_base_amount_in,
             desired_quote_amount_in,
             initial_lp_shares,
         )
         .map_err(|e| {
             msg!("Error adding liquidity: {:?}", e);
-            ProgramError::InvalidArgument
+            PlasmaError::from(e)
         })?;
+    pool.header.last_lp_vesting_slot = slot;
+    assert_with_msg(
+        lp_shares >= extension.min_lp_shares_out(),
+        PlasmaError::SlippageExceeded,
+        &format!(
+            "Received {} LP shares but the minimum is {}",
+            lp_shares,
+            extension.min_lp_shares_out()
+        ),
+    )?;
 
     let (user_total_withdrawable_base, user_total_withdrawable_quote) =
         lp_position.get_withdrawable_base_and_quote_amounts(pool);
 
+    // Deposits are grossed up by the transfer fee so that the vaults receive the deposited amounts
     try_deposit(TryDepositParams {
-        token_program: &token_program,
+        base_mint: &base_mint,
+        quote_mint: &quote_mint,
+        base_token_program: &base_token_program,
+        quote_token_program: &quote_token_program,
         quote_account,
         quote_vault,
         base_account,
@@ -197,7 +381,11 @@ pub(crate) fn process_add_liquidity<'a, 'info>(
         quote_amount: quote_amount_deposited,
         base_amount: base_amount_deposited,
         trader: &pool_context.signer,
+        system_program: system_program.filter(|_| native_sol.wrap),
     })?;
+    if let Some(temporary_wsol_account) = temporary_wsol_account {
+        temporary_wsol_account.close(pool_context.signer.as_ref())?;
+    }
 
     Ok(AddLiquidityEvent {
         pool_total_lp_shares: pool.total_lp_shares,
@@ -229,35 +417,51 @@ pub(crate) fn process_remove_liqidity<'a, 'info>(
     let LiquidityActionContext {
         lp_position: lp_position_account,
         vault_context,
+        system_program,
     } = LiquidityActionContext::load(&pool_context, accounts)?;
+    let system_program = system_program.as_ref().map(|program| program.as_ref());
+    let temporary_wsol_account = vault_context
+        .maybe_create_temporary_wsol_account(pool_context.signer.as_ref(), system_program)?;
 
     let PlasmaVaultContext {
         base_account,
         quote_account,
         base_vault,
         quote_vault,
-        token_program,
+        base_mint,
+        quote_mint,
+        base_token_program,
+        quote_token_program,
     } = vault_context;
 
-    let lp_shares = u64::try_from_slice(data)?;
+    let mut data = data;
+    let lp_shares = u64::deserialize(&mut data)?;
+    let extension = RemoveLiquidityParamsExtension::load_trailing(data)?;
+    let native_sol = extension.native_sol();
 
     // Get the active leader slot
-    let slot = (Clock::get()?.slot / LEADER_SLOT_WINDOW) * LEADER_SLOT_WINDOW;
+    let current_slot = Clock::get()?.slot;
+    extension.deadline().check_slot(current_slot)?;
+    let slot = (current_slot / LEADER_SLOT_WINDOW) * LEADER_SLOT_WINDOW;
 
     let mut pool_bytes = pool_context.pool_info.try_borrow_mut_data()?;
     let pool = try_from_bytes_mut::<PoolAccount>(&mut *pool_bytes)
-        .map_err(|_| ProgramError::InvalidAccountData)?;
+        .map_err(|_| PlasmaError::InvalidPoolAccount)?;
+    pool.header.assert_withdrawals_enabled()?;
+    pool.maybe_update_snapshot(slot);
+    extension.deadline().check_snapshot(pool)?;
+    let lp_vesting_schedule = pool.header.get_lp_vesting_schedule()?;
 
     let mut lp_position_bytes = lp_position_account.info.try_borrow_mut_data()?;
     let lp_position = try_from_bytes_mut::<LpPositionAccount>(&mut *lp_position_bytes)
-        .map_err(|_| ProgramError::InvalidAccountData)?;
+        .map_err(|_| PlasmaError::InvalidLpPositionAccount)?;
 
     if matches!(
         LpPositionStatus::parse(lp_position.status)?,
         LpPositionStatus::RenouncedWithBurnedFees | LpPositionStatus::RenouncedWithFeeWithdawal
     ) {
         msg!("Liquidity position has been renounced, cannot remove liquidity");
-        return Err(ProgramError::InvalidArgument);
+        return Err(PlasmaError::LpPositionRenounced.into());
     }
 
     let RemoveLiquidityResult {
@@ -267,12 +471,24 @@ pub(crate) fn process_remove_liqidity<'a, 'info>(
         lp_shares_vested,
         ..
     } = lp_position
-        .remove_liquidity(slot, pool, lp_shares)
+        .remove_liquidity(slot, pool, lp_vesting_schedule, lp_shares, extension.mode())
         .map_err(|e| {
             msg!("Error removing liquidity: {:?}", e);
-            ProgramError::InvalidArgument
+            PlasmaError::from(e)
         })?;
 
+    let (min_base_out, min_quote_out) = extension.min_amounts_out();
+    let trader_base_out = base_mint.get_post_fee_amount(base_amount_withdrawn)?;
+    let trader_quote_out = quote_mint.get_post_fee_amount(quote_amount_withdrawn)?;
+    assert_with_msg(
+        trader_base_out >= min_base_out && trader_quote_out >= min_quote_out,
+        PlasmaError::SlippageExceeded,
+        &format!(
+            "Received {} base and {} quote but the minimum is {} base and {} quote",
+            trader_base_out, trader_quote_out, min_base_out, min_quote_out
+        ),
+    )?;
+
     let (user_total_withdrawable_base, user_total_withdrawable_quote) =
         lp_position.get_withdrawable_base_and_quote_amounts(pool);
 
@@ -280,14 +496,21 @@ pub(crate) fn process_remove_liqidity<'a, 'info>(
         pool_key: &pool_context.pool_info.key,
         base_params: &base_params,
         quote_params: &quote_params,
-        token_program: &token_program,
+        base_mint: &base_mint,
+        quote_mint: &quote_mint,
+        base_token_program: &base_token_program,
+        quote_token_program: &quote_token_program,
         quote_account,
         quote_vault,
         base_account,
         base_vault,
         quote_amount: quote_amount_withdrawn,
         base_amount: base_amount_withdrawn,
+        unwrap_to: native_sol.unwrap.then_some(pool_context.signer.as_ref()),
     })?;
+    if let Some(temporary_wsol_account) = temporary_wsol_account {
+        temporary_wsol_account.close(pool_context.signer.as_ref())?;
+    }
 
     Ok(RemoveLiquidityEvent {
         pool_total_lp_shares: pool.total_lp_shares,
@@ -303,6 +526,347 @@ pub(crate) fn process_remove_liqidity<'a, 'info>(
         user_quote_withdrawn: quote_amount_withdrawn,
         user_total_withdrawable_base,
         user_total_withdrawable_quote,
+        user_lp_shares_requested: lp_shares,
+        mode: extension.mode(),
+    })
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct AddLiquiditySingleSidedParams {
+    pub token_in: TokenType,
+    /// Maximum amount of `token_in` to deposit, part of it is swapped for the other token
+    pub amount_in: u64,
+    pub min_lp_shares_out: u64,
+}
+
+/// Versioned data that follows `AddLiquiditySingleSidedParams`, see `AddLiquidityParamsExtension`
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum AddLiquiditySingleSidedParamsExtension {
+    V0,
+    V1 {
+        native_sol: NativeSolParams,
+        deadline: SlotDeadline,
+    },
+}
+
+impl AddLiquiditySingleSidedParamsExtension {
+    /// Parses the data that follows the instruction params, an empty slice is treated as `V0`
+    pub(crate) fn load_trailing(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.is_empty() {
+            return Ok(Self::V0);
+        }
+        Ok(Self::try_from_slice(data)?)
+    }
+
+    pub fn native_sol(&self) -> NativeSolParams {
+        match *self {
+            Self::V0 => NativeSolParams::default(),
+            Self::V1 { native_sol, .. } => native_sol,
+        }
+    }
+
+    pub fn deadline(&self) -> SlotDeadline {
+        match *self {
+            Self::V0 => SlotDeadline::default(),
+            Self::V1 { deadline, .. } => deadline,
+        }
+    }
+}
+
+/// Deposits a single token. Part of the deposit is swapped through the pool for the other token
+/// and the rest is deposited with the swap output, so only `token_in` is transferred.
+pub(crate) fn process_add_liquidity_single_sided<'a, 'info>(
+    pool_context: &PlasmaPoolContext<'a, 'info>,
+    accounts: &[AccountInfo<'info>],
+    data: &[u8],
+) -> Result<AddLiquiditySingleSidedEvent, ProgramError> {
+    let LiquidityActionContext {
+        lp_position: lp_position_account,
+        vault_context,
+        system_program,
+    } = LiquidityActionContext::load(&pool_context, accounts)?;
+    let system_program = system_program.as_ref().map(|program| program.as_ref());
+    let temporary_wsol_account = vault_context
+        .maybe_create_temporary_wsol_account(pool_context.signer.as_ref(), system_program)?;
+
+    let PlasmaVaultContext {
+        base_account,
+        quote_account,
+        base_vault,
+        quote_vault,
+        base_mint,
+        quote_mint,
+        base_token_program,
+        quote_token_program,
+    } = vault_context;
+
+    let mut data = data;
+    let AddLiquiditySingleSidedParams {
+        token_in,
+        amount_in,
+        min_lp_shares_out,
+    } = AddLiquiditySingleSidedParams::deserialize(&mut data)?;
+    let extension = AddLiquiditySingleSidedParamsExtension::load_trailing(data)?;
+    let native_sol = extension.native_sol();
+    if native_sol.wrap && system_program.is_none() {
+        msg!("The system program is required to wrap native SOL");
+        return Err(PlasmaError::MissingSystemProgram.into());
+    }
+
+    // Get the active leader slot
+    let current_slot = Clock::get()?.slot;
+    extension.deadline().check_slot(current_slot)?;
+    let slot = (current_slot / LEADER_SLOT_WINDOW) * LEADER_SLOT_WINDOW;
+
+    let mut pool_bytes = pool_context.pool_info.try_borrow_mut_data()?;
+    let pool = try_from_bytes_mut::<PoolAccount>(&mut *pool_bytes)
+        .map_err(|_| PlasmaError::InvalidPoolAccount)?;
+    pool.header.assert_active()?;
+    pool.maybe_update_snapshot(slot);
+    extension.deadline().check_snapshot(pool)?;
+    let lp_vesting_schedule = pool.header.get_lp_vesting_schedule()?;
+
+    let mut lp_position_bytes = lp_position_account.info.try_borrow_mut_data()?;
+    let lp_position = try_from_bytes_mut::<LpPositionAccount>(&mut *lp_position_bytes)
+        .map_err(|_| PlasmaError::InvalidLpPositionAccount)?;
+    if lp_position.is_renounced()? {
+        msg!("Liquidity position has been renounced, cannot add liquidity");
+        return Err(PlasmaError::LpPositionRenounced.into());
+    }
+
+    let AddLiquiditySingleSidedResult {
+        amount_in: amount_deposited,
+        swap_result,
+        add_liquidity_result:
+            AddLiquidityResult {
+                lp_shares_received,
+                lp_shares_vested,
+                ..
+            },
+    } = lp_position
+        .add_liquidity_single_sided(
+            slot,
+            pool,
+            lp_vesting_schedule,
+            token_in,
+            amount_in,
+            min_lp_shares_out,
+        )
+        .map_err(|e| {
+            msg!("Error adding single sided liquidity: {:?}", e);
+            PlasmaError::from(e)
+        })?;
+    pool.header.last_lp_vesting_slot = slot;
+    pool.update_protocol_fee_recipients_post_swap()?;
+
+    let (base_amount, quote_amount) = match token_in {
+        TokenType::Base => (amount_deposited, 0),
+        TokenType::Quote => (0, amount_deposited),
+    };
+    try_deposit(TryDepositParams {
+        base_mint: &base_mint,
+        quote_mint: &quote_mint,
+        base_token_program: &base_token_program,
+        quote_token_program: &quote_token_program,
+        quote_account,
+        quote_vault,
+        base_account,
+        base_vault,
+        quote_amount,
+        base_amount,
+        trader: &pool_context.signer,
+        system_program: system_program.filter(|_| native_sol.wrap),
+    })?;
+    if let Some(temporary_wsol_account) = temporary_wsol_account {
+        temporary_wsol_account.close(pool_context.signer.as_ref())?;
+    }
+
+    Ok(AddLiquiditySingleSidedEvent {
+        token_in,
+        user_amount_deposited: amount_deposited,
+        swap_result,
+        pool_total_lp_shares: pool.total_lp_shares,
+        pool_total_base_liquidity: pool.base_reserves,
+        pool_total_quote_liquidity: pool.quote_reserves,
+        user_lp_shares_received: lp_shares_received,
+        user_lp_shares_available: lp_position.lp_shares,
+        user_lp_shares_locked: lp_position.lp_shares - lp_position.withdrawable_lp_shares,
+        user_lp_shares_unlocked_for_withdrawal: lp_shares_vested,
+    })
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct RemoveLiquiditySingleSidedParams {
+    pub token_out: TokenType,
+    pub lp_shares: u64,
+    /// Minimum amount of `token_out` received by the trader, after transfer fees
+    pub min_amount_out: u64,
+}
+
+/// Versioned data that follows `RemoveLiquiditySingleSidedParams`, see `AddLiquidityParamsExtension`
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum RemoveLiquiditySingleSidedParamsExtension {
+    V0,
+    V1 {
+        native_sol: NativeSolParams,
+        deadline: SlotDeadline,
+    },
+}
+
+impl RemoveLiquiditySingleSidedParamsExtension {
+    /// Parses the data that follows the instruction params, an empty slice is treated as `V0`
+    pub(crate) fn load_trailing(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.is_empty() {
+            return Ok(Self::V0);
+        }
+        Ok(Self::try_from_slice(data)?)
+    }
+
+    pub fn native_sol(&self) -> NativeSolParams {
+        match *self {
+            Self::V0 => NativeSolParams::default(),
+            Self::V1 { native_sol, .. } => native_sol,
+        }
+    }
+
+    pub fn deadline(&self) -> SlotDeadline {
+        match *self {
+            Self::V0 => SlotDeadline::default(),
+            Self::V1 { deadline, .. } => deadline,
+        }
+    }
+}
+
+/// Burns LP shares and swaps the withdrawn amount of the other token through the pool, so only
+/// `token_out` is transferred
+pub(crate) fn process_remove_liquidity_single_sided<'a, 'info>(
+    pool_context: &PlasmaPoolContext<'a, 'info>,
+    accounts: &[AccountInfo<'info>],
+    data: &[u8],
+) -> Result<RemoveLiquiditySingleSidedEvent, ProgramError> {
+    let (base_params, quote_params) = {
+        let header = pool_context.pool_info.get_header()?;
+        (header.base_params, header.quote_params)
+    };
+
+    let LiquidityActionContext {
+        lp_position: lp_position_account,
+        vault_context,
+        system_program,
+    } = LiquidityActionContext::load(&pool_context, accounts)?;
+    let system_program = system_program.as_ref().map(|program| program.as_ref());
+    let temporary_wsol_account = vault_context
+        .maybe_create_temporary_wsol_account(pool_context.signer.as_ref(), system_program)?;
+
+    let PlasmaVaultContext {
+        base_account,
+        quote_account,
+        base_vault,
+        quote_vault,
+        base_mint,
+        quote_mint,
+        base_token_program,
+        quote_token_program,
+    } = vault_context;
+
+    let mut data = data;
+    let RemoveLiquiditySingleSidedParams {
+        token_out,
+        lp_shares,
+        min_amount_out,
+    } = RemoveLiquiditySingleSidedParams::deserialize(&mut data)?;
+    let extension = RemoveLiquiditySingleSidedParamsExtension::load_trailing(data)?;
+    let native_sol = extension.native_sol();
+
+    // Get the active leader slot
+    let current_slot = Clock::get()?.slot;
+    extension.deadline().check_slot(current_slot)?;
+    let slot = (current_slot / LEADER_SLOT_WINDOW) * LEADER_SLOT_WINDOW;
+
+    let mut pool_bytes = pool_context.pool_info.try_borrow_mut_data()?;
+    let pool = try_from_bytes_mut::<PoolAccount>(&mut *pool_bytes)
+        .map_err(|_| PlasmaError::InvalidPoolAccount)?;
+    // The withdrawal includes a swap, so it is not allowed while swaps are paused
+    pool.header.assert_active()?;
+    pool.maybe_update_snapshot(slot);
+    extension.deadline().check_snapshot(pool)?;
+    let lp_vesting_schedule = pool.header.get_lp_vesting_schedule()?;
+
+    let mut lp_position_bytes = lp_position_account.info.try_borrow_mut_data()?;
+    let lp_position = try_from_bytes_mut::<LpPositionAccount>(&mut *lp_position_bytes)
+        .map_err(|_| PlasmaError::InvalidLpPositionAccount)?;
+    if lp_position.is_renounced()? {
+        msg!("Liquidity position has been renounced, cannot remove liquidity");
+        return Err(PlasmaError::LpPositionRenounced.into());
+    }
+
+    let RemoveLiquiditySingleSidedResult {
+        amount_out,
+        swap_result,
+        remove_liquidity_result:
+            RemoveLiquidityResult {
+                lp_shares_burned,
+                lp_shares_vested,
+                ..
+            },
+    } = lp_position
+        .remove_liquidity_single_sided(slot, pool, lp_vesting_schedule, token_out, lp_shares)
+        .map_err(|e| {
+            msg!("Error removing single sided liquidity: {:?}", e);
+            PlasmaError::from(e)
+        })?;
+    pool.update_protocol_fee_recipients_post_swap()?;
+
+    let trader_amount_out = match token_out {
+        TokenType::Base => base_mint.get_post_fee_amount(amount_out)?,
+        TokenType::Quote => quote_mint.get_post_fee_amount(amount_out)?,
+    };
+    assert_with_msg(
+        trader_amount_out >= min_amount_out,
+        PlasmaError::SlippageExceeded,
+        &format!(
+            "Received {} but the minimum amount out is {}",
+            trader_amount_out, min_amount_out
+        ),
+    )?;
+
+    let (base_amount, quote_amount) = match token_out {
+        TokenType::Base => (amount_out, 0),
+        TokenType::Quote => (0, amount_out),
+    };
+    try_withdraw(TryWithdrawParams {
+        pool_key: &pool_context.pool_info.key,
+        base_params: &base_params,
+        quote_params: &quote_params,
+        base_mint: &base_mint,
+        quote_mint: &quote_mint,
+        base_token_program: &base_token_program,
+        quote_token_program: &quote_token_program,
+        quote_account,
+        quote_vault,
+        base_account,
+        base_vault,
+        quote_amount,
+        base_amount,
+        unwrap_to: native_sol.unwrap.then_some(pool_context.signer.as_ref()),
+    })?;
+    if let Some(temporary_wsol_account) = temporary_wsol_account {
+        temporary_wsol_account.close(pool_context.signer.as_ref())?;
+    }
+
+    Ok(RemoveLiquiditySingleSidedEvent {
+        token_out,
+        user_amount_withdrawn: amount_out,
+        swap_result,
+        pool_total_lp_shares: pool.total_lp_shares,
+        pool_total_base_liquidity: pool.base_reserves,
+        pool_total_quote_liquidity: pool.quote_reserves,
+        user_lp_shares_burned: lp_shares_burned,
+        user_lp_shares_available: lp_position.lp_shares,
+        user_lp_shares_locked: lp_position.lp_shares - lp_position.withdrawable_lp_shares,
+        user_lp_shares_unlocked_for_withdrawal: lp_shares_vested,
     })
 }
 
@@ -320,14 +884,14 @@ pub(crate) fn process_renounce_liqidity<'a, 'info>(
 
     let mut lp_position_bytes = lp_position_account.info.try_borrow_mut_data()?;
     let lp_position = try_from_bytes_mut::<LpPositionAccount>(&mut *lp_position_bytes)
-        .map_err(|_| ProgramError::InvalidAccountData)?;
+        .map_err(|_| PlasmaError::InvalidLpPositionAccount)?;
 
     if matches!(
         LpPositionStatus::parse(lp_position.status)?,
         LpPositionStatus::RenouncedWithBurnedFees | LpPositionStatus::RenouncedWithFeeWithdawal
     ) {
         msg!("Liquidity position has been renounced, cannot renounce again");
-        return Err(ProgramError::InvalidArgument);
+        return Err(PlasmaError::LpPositionRenounced.into());
     }
 
     let allow_fee_withdrawal = bool::try_from_slice(data)?;
@@ -342,3 +906,244 @@ pub(crate) fn process_renounce_liqidity<'a, 'info>(
         allow_fee_withdrawal,
     })
 }
+
+/// Closes an LP position that holds nothing, its rent is returned to the owner
+pub(crate) fn process_close_lp_position<'a, 'info>(
+    pool_context: &PlasmaPoolContext<'a, 'info>,
+    accounts: &'a [AccountInfo<'info>],
+) -> Result<CloseLpPositionEvent, ProgramError> {
+    let account_iter = &mut accounts.iter();
+    let lp_position_account = LpPositionAccountInfo::new(
+        next_account_info(account_iter)?,
+        pool_context.pool_info.key,
+        pool_context.signer.key,
+    )?;
+
+    {
+        let lp_position_bytes = lp_position_account.info.try_borrow_data()?;
+        let lp_position = try_from_bytes::<LpPositionAccount>(&*lp_position_bytes)
+            .map_err(|_| PlasmaError::InvalidLpPositionAccount)?;
+        assert_with_msg(
+            lp_position.is_empty(),
+            PlasmaError::LpPositionNotEmpty,
+            "LP position still holds shares or uncollected fees",
+        )?;
+    }
+
+    let lamports_reclaimed = lp_position_account.info.lamports();
+    close_account(lp_position_account.info, pool_context.signer.as_ref())?;
+
+    Ok(CloseLpPositionEvent {
+        lp_position: *lp_position_account.info.key,
+        lamports_reclaimed,
+    })
+}
+
+/// Moves everything held by the signer's LP position into the position of the recipient,
+/// which is created if it does not exist yet. The vesting of pending shares is not restarted.
+pub(crate) fn process_transfer_lp_position<'a, 'info>(
+    pool_context: &PlasmaPoolContext<'a, 'info>,
+    accounts: &'a [AccountInfo<'info>],
+) -> Result<TransferLpPositionEvent, ProgramError> {
+    let pool_key = pool_context.pool_info.key;
+    let TransferLpPositionContext {
+        lp_position: lp_position_account,
+        recipient,
+        recipient_lp_position,
+        system_program,
+    } = TransferLpPositionContext::load(pool_context, accounts)?;
+
+    // Get the active leader slot
+    let slot = (Clock::get()?.slot / LEADER_SLOT_WINDOW) * LEADER_SLOT_WINDOW;
+
+    let reward_factor = {
+        let pool_bytes = pool_context.pool_info.try_borrow_data()?;
+        let pool = try_from_bytes::<PoolAccount>(&pool_bytes)
+            .map_err(|_| PlasmaError::InvalidPoolAccount)?;
+        pool.header.assert_withdrawals_enabled()?;
+        pool.reward_factor
+    };
+
+    let recipient_lp_position_created = recipient_lp_position.data_is_empty();
+    if recipient_lp_position_created {
+        let (recipient_lp_position_address, bump) =
+            get_lp_position_address(pool_key, recipient.key);
+        assert_with_msg(
+            &recipient_lp_position_address == recipient_lp_position.key,
+            PlasmaError::InvalidLpPositionAddress,
+            "Invalid recipient lp_position address",
+        )?;
+        create_lp_position(
+            pool_context.signer.as_ref(),
+            pool_key,
+            recipient.key,
+            recipient_lp_position,
+            bump,
+            system_program.as_ref(),
+            reward_factor,
+        )?;
+    }
+    let recipient_lp_position =
+        LpPositionAccountInfo::new(recipient_lp_position, pool_key, recipient.key)?;
+
+    let mut pool_bytes = pool_context.pool_info.try_borrow_mut_data()?;
+    let pool = try_from_bytes_mut::<PoolAccount>(&mut *pool_bytes)
+        .map_err(|_| PlasmaError::InvalidPoolAccount)?;
+
+    let mut lp_position_bytes = lp_position_account.info.try_borrow_mut_data()?;
+    let lp_position = try_from_bytes_mut::<LpPositionAccount>(&mut *lp_position_bytes)
+        .map_err(|_| PlasmaError::InvalidLpPositionAccount)?;
+    let mut recipient_lp_position_bytes = recipient_lp_position.info.try_borrow_mut_data()?;
+    let recipient_lp_position =
+        try_from_bytes_mut::<LpPositionAccount>(&mut *recipient_lp_position_bytes)
+            .map_err(|_| PlasmaError::InvalidLpPositionAccount)?;
+
+    if lp_position.is_renounced()? || recipient_lp_position.is_renounced()? {
+        msg!("Liquidity position has been renounced, cannot transfer liquidity");
+        return Err(PlasmaError::LpPositionRenounced.into());
+    }
+
+    let LpPositionTransferResult {
+        lp_shares_transferred,
+        lp_shares_vesting_transferred,
+        quote_fees_transferred,
+    } = lp_position
+        .transfer_to(
+            slot,
+            pool,
+            pool.header.get_lp_vesting_schedule()?,
+            recipient_lp_position,
+        )
+        .map_err(|e| {
+            msg!("Error transferring LP position: {:?}", e);
+            PlasmaError::from(e)
+        })?;
+    // With a linear schedule, tranches merged into the latest one restart vesting
+    pool.header.last_lp_vesting_slot = slot;
+
+    Ok(TransferLpPositionEvent {
+        recipient: *recipient.key,
+        recipient_lp_position_created,
+        lp_shares_transferred,
+        lp_shares_vesting_transferred,
+        quote_fees_transferred,
+        recipient_lp_shares_available: recipient_lp_position.lp_shares,
+        recipient_lp_shares_locked: recipient_lp_position.lp_shares
+            - recipient_lp_position.withdrawable_lp_shares,
+    })
+}
+
+/// Extends an LP position created with the legacy single tranche layout to the current layout.
+/// The legacy tranche becomes the first tranche and the added tranches are zeroed, which marks
+/// them as unused. Anyone can pay for the additional rent.
+pub(crate) fn process_migrate_lp_position<'a, 'info>(
+    pool_context: &PlasmaPoolContext<'a, 'info>,
+    accounts: &'a [AccountInfo<'info>],
+) -> Result<MigrateLpPositionEvent, ProgramError> {
+    let MigrateLpPositionContext {
+        lp_position,
+        system_program,
+    } = MigrateLpPositionContext::load(accounts)?;
+
+    assert_with_msg(
+        lp_position.owner == &crate::ID,
+        PlasmaError::NotOwnedByPlasmaProgram,
+        "LP position account must be owned by the Plasma program",
+    )?;
+    assert_with_msg(
+        lp_position.data_len() == LEGACY_LP_POSITION_ACCOUNT_SIZE,
+        PlasmaError::LpPositionAlreadyMigrated,
+        "LP position does not have the legacy layout",
+    )?;
+
+    let space = size_of::<LpPositionAccount>();
+    let rent_shortfall = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(lp_position.lamports());
+    if rent_shortfall > 0 {
+        invoke(
+            &system_instruction::transfer(pool_context.signer.key, lp_position.key, rent_shortfall),
+            &[
+                pool_context.signer.as_ref().clone(),
+                lp_position.clone(),
+                system_program.as_ref().clone(),
+            ],
+        )?;
+    }
+    lp_position.realloc(space, true)?;
+
+    let owner = {
+        let lp_position_bytes = lp_position.try_borrow_data()?;
+        try_from_bytes::<LpPositionAccount>(&lp_position_bytes)
+            .map_err(|_| PlasmaError::InvalidLpPositionAccount)?
+            .authority
+    };
+    // The header is unchanged by the migration, so the account is validated after it is extended
+    LpPositionAccountInfo::new(lp_position, pool_context.pool_info.key, &owner)?;
+
+    Ok(MigrateLpPositionEvent {
+        lp_position: *lp_position.key,
+        owner,
+    })
+}
+
+#[test]
+fn test_liquidity_params_extensions() {
+    let extension = AddLiquidityParamsExtension::load_trailing(&[]).unwrap();
+    assert!(!extension.native_sol().wrap);
+    assert_eq!(extension.min_lp_shares_out(), 0);
+    assert!(extension.deadline().max_slot.is_none());
+
+    let extension = RemoveLiquidityParamsExtension::load_trailing(&[]).unwrap();
+    assert!(!extension.native_sol().unwrap);
+    assert_eq!(extension.min_amounts_out(), (0, 0));
+    assert_eq!(extension.mode(), RemoveLiquidityMode::Strict);
+
+    let native_sol = NativeSolParams {
+        wrap: true,
+        unwrap: false,
+    };
+    let deadline = SlotDeadline {
+        max_slot: Some(100),
+        min_snapshot_slot: None,
+    };
+    let data = AddLiquidityParamsExtension::V1 {
+        native_sol,
+        min_lp_shares_out: 1,
+        deadline,
+    }
+    .try_to_vec()
+    .unwrap();
+    let extension = AddLiquidityParamsExtension::load_trailing(&data).unwrap();
+    assert!(extension.native_sol().wrap);
+    assert_eq!(extension.min_lp_shares_out(), 1);
+    assert!(extension.deadline().check_slot(100).is_ok());
+    assert!(extension.deadline().check_slot(101).is_err());
+
+    let data = RemoveLiquidityParamsExtension::V1 {
+        native_sol,
+        min_base_out: 1,
+        min_quote_out: 2,
+        deadline,
+        mode: RemoveLiquidityMode::UpTo,
+    }
+    .try_to_vec()
+    .unwrap();
+    let extension = RemoveLiquidityParamsExtension::load_trailing(&data).unwrap();
+    assert_eq!(extension.min_amounts_out(), (1, 2));
+    assert_eq!(extension.mode(), RemoveLiquidityMode::UpTo);
+
+    let extension = AddLiquiditySingleSidedParamsExtension::load_trailing(&[]).unwrap();
+    assert!(!extension.native_sol().wrap);
+    assert!(extension.deadline().max_slot.is_none());
+
+    let data = RemoveLiquiditySingleSidedParamsExtension::V1 {
+        native_sol,
+        deadline,
+    }
+    .try_to_vec()
+    .unwrap();
+    let extension = RemoveLiquiditySingleSidedParamsExtension::load_trailing(&data).unwrap();
+    assert!(extension.native_sol().wrap);
+    assert!(extension.deadline().check_slot(101).is_err());
+}
@@ -0,0 +1,287 @@
+//! LP tokens are an opt-in, transferable form of vested LP shares. Tokenized shares are held by
+//! an escrow LP position of the pool, so they stay in the pool and can be redeemed 1:1.
+//!
+//! LP tokens do not earn swap fees. The fees earned by the escrow are handed to the other LP
+//! shares whenever shares are tokenized or redeemed.
+
+use std::mem::size_of;
+
+use borsh::{BorshDeserialize as Deserialize, BorshSerialize as Serialize};
+use bytemuck::try_from_bytes_mut;
+use plasma_state::lp::{LpPosition, LpSharesTransferResult};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, msg, program::invoke, program_error::ProgramError,
+    program_pack::Pack, rent::Rent, sysvar::Sysvar,
+};
+
+use crate::program::error::PlasmaError;
+use crate::{
+    assert_with_msg,
+    program::{
+        accounts::{
+            LpPositionAccount, LpPositionStatus, PoolAccount, LP_POSITION_ACCOUNT_DISCRIMINATOR,
+        },
+        events::{InitializeLpMintEvent, RedeemLpTokensEvent, TokenizeLpSharesEvent},
+        system_utils::create_account,
+        token_utils::{burn_lp_tokens, mint_lp_tokens},
+        validation::loaders::{
+            get_lp_escrow_address, get_lp_mint_address, InitializeLpMintContext, LpTokenContext,
+            PlasmaPoolContext,
+        },
+    },
+    LEADER_SLOT_WINDOW,
+};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub struct TokenizeLpSharesParams {
+    /// Vested LP shares to convert into LP tokens
+    pub lp_shares: u64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub struct RedeemLpTokensParams {
+    /// LP tokens to burn, each token is redeemed for one vested LP share
+    pub lp_tokens: u64,
+}
+
+pub(crate) fn process_initialize_lp_mint<'a, 'info>(
+    pool_context: &PlasmaPoolContext<'a, 'info>,
+    accounts: &'a [AccountInfo<'info>],
+) -> Result<InitializeLpMintEvent, ProgramError> {
+    let pool_key = pool_context.pool_info.key;
+    let InitializeLpMintContext {
+        lp_mint,
+        lp_escrow,
+        token_program,
+        system_program,
+    } = InitializeLpMintContext::load(accounts)?;
+
+    let (lp_mint_key, lp_mint_bump) = get_lp_mint_address(pool_key);
+    assert_with_msg(
+        lp_mint_key == *lp_mint.key,
+        PlasmaError::InvalidLpMintAddress,
+        &format!(
+            "Supplied LP mint ({}) does not match computed key ({})",
+            lp_mint.key, lp_mint_key
+        ),
+    )?;
+    let (lp_escrow_key, lp_escrow_bump) = get_lp_escrow_address(pool_key);
+    assert_with_msg(
+        lp_escrow_key == *lp_escrow.key,
+        PlasmaError::InvalidLpPositionAddress,
+        &format!(
+            "Supplied LP escrow ({}) does not match computed key ({})",
+            lp_escrow.key, lp_escrow_key
+        ),
+    )?;
+
+    // The initial LP shares are the square root of the initial product of the reserves
+    let decimals = {
+        let header = pool_context.pool_info.get_header()?;
+        ((header.base_params.decimals + header.quote_params.decimals) / 2) as u8
+    };
+
+    let rent = Rent::get()?;
+    create_account(
+        pool_context.signer.as_ref(),
+        lp_mint.as_ref(),
+        system_program.as_ref(),
+        &spl_token::id(),
+        &rent,
+        spl_token::state::Mint::LEN as u64,
+        vec![
+            b"lp_mint".to_vec(),
+            pool_key.as_ref().to_vec(),
+            vec![lp_mint_bump],
+        ],
+    )?;
+    invoke(
+        &spl_token::instruction::initialize_mint2(
+            token_program.key,
+            lp_mint.key,
+            lp_mint.key,
+            None,
+            decimals,
+        )?,
+        &[lp_mint.as_ref().clone(), token_program.as_ref().clone()],
+    )?;
+    create_account(
+        pool_context.signer.as_ref(),
+        lp_escrow.as_ref(),
+        system_program.as_ref(),
+        &crate::id(),
+        &rent,
+        size_of::<LpPositionAccount>() as u64,
+        vec![
+            b"lp_escrow".to_vec(),
+            pool_key.as_ref().to_vec(),
+            vec![lp_escrow_bump],
+        ],
+    )?;
+
+    let mut pool_bytes = pool_context.pool_info.try_borrow_mut_data()?;
+    let pool = try_from_bytes_mut::<PoolAccount>(&mut *pool_bytes)
+        .map_err(|_| PlasmaError::InvalidPoolAccount)?;
+
+    let mut lp_escrow_bytes = lp_escrow.try_borrow_mut_data()?;
+    *try_from_bytes_mut::<LpPositionAccount>(&mut lp_escrow_bytes)
+        .map_err(|_| PlasmaError::InvalidLpPositionAccount)? = LpPositionAccount {
+        discriminator: LP_POSITION_ACCOUNT_DISCRIMINATOR,
+        authority: lp_mint_key,
+        pool: *pool_key,
+        status: LpPositionStatus::Active as u64,
+        lp_position: LpPosition::new_with_reward_factor_snapshot(pool.reward_factor),
+    };
+
+    pool.header.lp_mint = lp_mint_key;
+    pool.header.lp_mint_bump = lp_mint_bump as u32;
+
+    Ok(InitializeLpMintEvent {
+        lp_mint: lp_mint_key,
+        decimals,
+    })
+}
+
+pub(crate) fn process_tokenize_lp_shares<'a, 'info>(
+    pool_context: &PlasmaPoolContext<'a, 'info>,
+    accounts: &'a [AccountInfo<'info>],
+    data: &[u8],
+) -> Result<TokenizeLpSharesEvent, ProgramError> {
+    let LpTokenContext {
+        lp_position: lp_position_account,
+        lp_escrow,
+        lp_mint,
+        lp_token_account,
+        token_program,
+    } = LpTokenContext::load(pool_context, accounts)?;
+
+    let TokenizeLpSharesParams { lp_shares } = TokenizeLpSharesParams::try_from_slice(data)?;
+
+    // Get the active leader slot
+    let slot = (Clock::get()?.slot / LEADER_SLOT_WINDOW) * LEADER_SLOT_WINDOW;
+
+    let mut pool_bytes = pool_context.pool_info.try_borrow_mut_data()?;
+    let pool = try_from_bytes_mut::<PoolAccount>(&mut *pool_bytes)
+        .map_err(|_| PlasmaError::InvalidPoolAccount)?;
+    pool.header.assert_withdrawals_enabled()?;
+    let lp_vesting_schedule = pool.header.get_lp_vesting_schedule()?;
+
+    let mut lp_position_bytes = lp_position_account.info.try_borrow_mut_data()?;
+    let lp_position = try_from_bytes_mut::<LpPositionAccount>(&mut *lp_position_bytes)
+        .map_err(|_| PlasmaError::InvalidLpPositionAccount)?;
+    if lp_position.is_renounced()? {
+        msg!("Liquidity position has been renounced, cannot convert LP shares");
+        return Err(PlasmaError::LpPositionRenounced.into());
+    }
+
+    let mut lp_escrow_bytes = lp_escrow.info.try_borrow_mut_data()?;
+    let escrow = try_from_bytes_mut::<LpPositionAccount>(&mut *lp_escrow_bytes)
+        .map_err(|_| PlasmaError::InvalidLpPositionAccount)?;
+
+    // The escrow fees are redistributed first so that the trader receives their part of them
+    let lp_token_fees_redistributed = escrow
+        .redistribute_fees(slot, &mut pool.amm, lp_vesting_schedule)
+        .map_err(PlasmaError::from)?;
+    let LpSharesTransferResult {
+        lp_shares_vested, ..
+    } = lp_position
+        .withdraw_vested_lp_shares(slot, pool, lp_vesting_schedule, lp_shares)
+        .map_err(|e| {
+            msg!("Error tokenizing LP shares: {:?}", e);
+            PlasmaError::from(e)
+        })?;
+    escrow
+        .deposit_vested_lp_shares(slot, pool, lp_vesting_schedule, lp_shares)
+        .map_err(PlasmaError::from)?;
+
+    mint_lp_tokens(
+        pool_context.pool_info.key,
+        pool.header.lp_mint_bump as u8,
+        token_program.as_ref(),
+        &lp_mint,
+        &lp_token_account,
+        lp_shares,
+    )?;
+
+    Ok(TokenizeLpSharesEvent {
+        lp_shares_tokenized: lp_shares,
+        lp_token_fees_redistributed,
+        total_lp_shares_tokenized: escrow.lp_shares,
+        user_lp_shares_available: lp_position.lp_shares,
+        user_lp_shares_locked: lp_position.lp_shares - lp_position.withdrawable_lp_shares,
+        user_lp_shares_unlocked_for_withdrawal: lp_shares_vested,
+    })
+}
+
+pub(crate) fn process_redeem_lp_tokens<'a, 'info>(
+    pool_context: &PlasmaPoolContext<'a, 'info>,
+    accounts: &'a [AccountInfo<'info>],
+    data: &[u8],
+) -> Result<RedeemLpTokensEvent, ProgramError> {
+    let LpTokenContext {
+        lp_position: lp_position_account,
+        lp_escrow,
+        lp_mint,
+        lp_token_account,
+        token_program,
+    } = LpTokenContext::load(pool_context, accounts)?;
+
+    let RedeemLpTokensParams { lp_tokens } = RedeemLpTokensParams::try_from_slice(data)?;
+
+    // Get the active leader slot
+    let slot = (Clock::get()?.slot / LEADER_SLOT_WINDOW) * LEADER_SLOT_WINDOW;
+
+    // The token program fails if the trader does not hold enough LP tokens
+    burn_lp_tokens(
+        token_program.as_ref(),
+        &lp_mint,
+        &lp_token_account,
+        pool_context.signer.as_ref(),
+        lp_tokens,
+    )?;
+
+    let mut pool_bytes = pool_context.pool_info.try_borrow_mut_data()?;
+    let pool = try_from_bytes_mut::<PoolAccount>(&mut *pool_bytes)
+        .map_err(|_| PlasmaError::InvalidPoolAccount)?;
+    pool.header.assert_withdrawals_enabled()?;
+    let lp_vesting_schedule = pool.header.get_lp_vesting_schedule()?;
+
+    let mut lp_position_bytes = lp_position_account.info.try_borrow_mut_data()?;
+    let lp_position = try_from_bytes_mut::<LpPositionAccount>(&mut *lp_position_bytes)
+        .map_err(|_| PlasmaError::InvalidLpPositionAccount)?;
+    if lp_position.is_renounced()? {
+        msg!("Liquidity position has been renounced, cannot convert LP shares");
+        return Err(PlasmaError::LpPositionRenounced.into());
+    }
+
+    let mut lp_escrow_bytes = lp_escrow.info.try_borrow_mut_data()?;
+    let escrow = try_from_bytes_mut::<LpPositionAccount>(&mut *lp_escrow_bytes)
+        .map_err(|_| PlasmaError::InvalidLpPositionAccount)?;
+
+    let lp_token_fees_redistributed = escrow
+        .redistribute_fees(slot, &mut pool.amm, lp_vesting_schedule)
+        .map_err(PlasmaError::from)?;
+    escrow
+        .withdraw_vested_lp_shares(slot, pool, lp_vesting_schedule, lp_tokens)
+        .map_err(|e| {
+            msg!("Error redeeming LP tokens: {:?}", e);
+            PlasmaError::from(e)
+        })?;
+    // The redeemed shares only earn the fees accrued after this point
+    let LpSharesTransferResult {
+        lp_shares_vested, ..
+    } = lp_position
+        .deposit_vested_lp_shares(slot, pool, lp_vesting_schedule, lp_tokens)
+        .map_err(PlasmaError::from)?;
+
+    Ok(RedeemLpTokensEvent {
+        lp_tokens_redeemed: lp_tokens,
+        lp_token_fees_redistributed,
+        total_lp_shares_tokenized: escrow.lp_shares,
+        user_lp_shares_available: lp_position.lp_shares,
+        user_lp_shares_locked: lp_position.lp_shares - lp_position.withdrawable_lp_shares,
+        user_lp_shares_unlocked_for_withdrawal: lp_shares_vested,
+    })
+}
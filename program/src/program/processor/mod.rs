@@ -1,4 +1,8 @@
+pub mod admin;
+pub mod fee_tiers;
 pub mod fees;
 pub mod initialize;
 pub mod liquidity;
+pub mod lp_tokens;
+pub mod observations;
 pub mod swap;
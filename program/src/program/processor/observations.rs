@@ -0,0 +1,120 @@
+use borsh::{BorshDeserialize as Deserialize, BorshSerialize as Serialize};
+use bytemuck::try_from_bytes;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::MAX_PERMITTED_DATA_INCREASE, msg, program::invoke,
+    program_error::ProgramError, rent::Rent, system_instruction, sysvar::Sysvar,
+};
+
+use crate::program::error::PlasmaError;
+use crate::{
+    assert_with_msg,
+    program::{
+        accounts::{ObservationsAccount, PoolAccount},
+        events::GrowObservationsEvent,
+        system_utils::create_account,
+        validation::{
+            checkers::plasma_checkers::ObservationsAccountInfo,
+            loaders::{get_observations_address, GrowObservationsContext, PlasmaPoolContext},
+        },
+    },
+};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub struct GrowObservationsParams {
+    pub new_capacity: u64,
+}
+
+/// Creates the observations account of a pool if it does not exist yet, or grows its capacity.
+pub(crate) fn process_grow_observations<'a, 'info>(
+    pool_context: &PlasmaPoolContext<'a, 'info>,
+    accounts: &'a [AccountInfo<'info>],
+    data: &[u8],
+) -> Result<GrowObservationsEvent, ProgramError> {
+    let GrowObservationsParams { new_capacity } = GrowObservationsParams::try_from_slice(data)?;
+    let GrowObservationsContext {
+        observations,
+        system_program,
+    } = GrowObservationsContext::load(pool_context, accounts)?;
+    let pool_key = pool_context.pool_info.key;
+    let new_space = ObservationsAccount::get_space(new_capacity);
+    let rent = Rent::get()?;
+
+    let previous_capacity = if observations.data_is_empty() {
+        assert_with_msg(
+            new_capacity > 0,
+            PlasmaError::InvalidObservationsCapacity,
+            "Observations capacity must be greater than 0",
+        )?;
+        let (_, bump) = get_observations_address(pool_key);
+        let seeds = vec![
+            b"observations".to_vec(),
+            pool_key.as_ref().to_vec(),
+            vec![bump],
+        ];
+        create_account(
+            &pool_context.signer,
+            observations,
+            system_program.as_ref(),
+            &crate::id(),
+            &rent,
+            new_space as u64,
+            seeds,
+        )?;
+        let mut observations_bytes = observations.try_borrow_mut_data()?;
+        let (header, _) = ObservationsAccount::split_mut(&mut observations_bytes)?;
+        *header = ObservationsAccount::new(*pool_key);
+        0
+    } else {
+        let previous_capacity =
+            ObservationsAccountInfo::new(observations, pool_key)?.get_capacity();
+        assert_with_msg(
+            new_capacity > previous_capacity,
+            PlasmaError::InvalidObservationsCapacity,
+            "New observations capacity must be greater than the current capacity",
+        )?;
+        assert_with_msg(
+            new_space - observations.data_len() <= MAX_PERMITTED_DATA_INCREASE,
+            PlasmaError::ObservationsGrowthTooLarge,
+            "Observations account can only grow by 10KB per instruction",
+        )?;
+        let required_lamports = rent
+            .minimum_balance(new_space)
+            .saturating_sub(observations.lamports());
+        if required_lamports > 0 {
+            invoke(
+                &system_instruction::transfer(
+                    pool_context.signer.key,
+                    observations.key,
+                    required_lamports,
+                ),
+                &[
+                    pool_context.signer.as_ref().clone(),
+                    observations.clone(),
+                    system_program.as_ref().clone(),
+                ],
+            )?;
+        }
+        observations.realloc(new_space, true)?;
+        previous_capacity
+    };
+
+    // Record the current state of the pool so that the buffer is never empty
+    let pool_bytes = pool_context.pool_info.try_borrow_data()?;
+    let pool = try_from_bytes::<PoolAccount>(&pool_bytes).map_err(|_| {
+        msg!("Invalid pool account data");
+        PlasmaError::InvalidPoolAccount
+    })?;
+    ObservationsAccountInfo::new(observations, pool_key)?.record(&pool.amm)?;
+
+    msg!(
+        "Observations capacity grown from {} to {}",
+        previous_capacity,
+        new_capacity
+    );
+
+    Ok(GrowObservationsEvent {
+        previous_capacity,
+        new_capacity,
+    })
+}
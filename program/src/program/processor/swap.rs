@@ -1,21 +1,27 @@
 use borsh::{BorshDeserialize as Deserialize, BorshSerialize as Serialize};
 use bytemuck::try_from_bytes_mut;
-use plasma_state::amm::Side;
+pub use plasma_state::amm::SwapType;
+use plasma_state::amm::{Amm, Side};
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, msg, program::set_return_data,
-    program_error::ProgramError, sysvar::Sysvar,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program::set_return_data, program_error::ProgramError, system_program, sysvar::Sysvar,
 };
 
+use crate::program::error::PlasmaError;
 use crate::{
     assert_with_msg,
     program::{
         accounts::PoolAccount,
         events::SwapEvent,
         token_utils::{
-            maybe_invoke_deposit, maybe_invoke_withdraw, MaybeInvokeDepositParams,
-            MaybeInvokeWithdrawParams,
+            is_native_mint, maybe_create_temporary_wsol_account, maybe_invoke_deposit,
+            maybe_invoke_withdraw, MaybeInvokeDepositParams, MaybeInvokeWithdrawParams,
+            NativeSolParams,
+        },
+        validation::{
+            checkers::{plasma_checkers::ObservationsAccountInfo, Program},
+            loaders::{PlasmaPoolContext, PlasmaVaultContext, SwapRouteContext},
         },
-        validation::loaders::{PlasmaPoolContext, PlasmaVaultContext},
     },
     LEADER_SLOT_WINDOW,
 };
@@ -27,10 +33,84 @@ pub struct SwapParams {
     pub swap_type: SwapType,
 }
 
-#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
-pub enum SwapType {
-    ExactIn { amount_in: u64, min_amount_out: u64 },
-    ExactOut { amount_out: u64, max_amount_in: u64 },
+/// Optional slot bounds of a trading instruction, so that a transaction that waited too long to
+/// land fails instead of executing against a stale quote
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+pub struct SlotDeadline {
+    /// Last slot at which the instruction can execute
+    pub max_slot: Option<u64>,
+    /// The instruction fails if it trades against a reserves snapshot taken before this slot
+    pub min_snapshot_slot: Option<u64>,
+}
+
+impl SlotDeadline {
+    /// Checks the maximum slot against the current slot
+    pub(crate) fn check_slot(&self, slot: u64) -> ProgramResult {
+        if let Some(max_slot) = self.max_slot {
+            assert_with_msg(
+                slot <= max_slot,
+                PlasmaError::SlotDeadlineExceeded,
+                &format!(
+                    "Current slot {} is past the maximum slot {}",
+                    slot, max_slot
+                ),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Checks the minimum snapshot slot against the reserves snapshot of the pool. The snapshot
+    /// must already be updated for the current slot window.
+    pub(crate) fn check_snapshot(&self, amm: &Amm) -> ProgramResult {
+        if let Some(min_snapshot_slot) = self.min_snapshot_slot {
+            let snapshot_slot = amm.get_slot();
+            assert_with_msg(
+                snapshot_slot >= min_snapshot_slot,
+                PlasmaError::SlotDeadlineExceeded,
+                &format!(
+                    "Snapshot slot {} is before the minimum snapshot slot {}",
+                    snapshot_slot, min_snapshot_slot
+                ),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Versioned data that follows `SwapParams`. Instructions without trailing data are parsed as
+/// `V0`, new fields are added in new versions.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum SwapParamsExtension {
+    V0,
+    V1 {
+        native_sol: NativeSolParams,
+        deadline: SlotDeadline,
+    },
+}
+
+impl SwapParamsExtension {
+    /// Parses the data that follows the instruction params, an empty slice is treated as `V0`
+    pub(crate) fn load_trailing(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.is_empty() {
+            return Ok(Self::V0);
+        }
+        Ok(Self::try_from_slice(data)?)
+    }
+
+    pub fn native_sol(&self) -> NativeSolParams {
+        match *self {
+            Self::V0 => NativeSolParams::default(),
+            Self::V1 { native_sol, .. } => native_sol,
+        }
+    }
+
+    pub fn deadline(&self) -> SlotDeadline {
+        match *self {
+            Self::V0 => SlotDeadline::default(),
+            Self::V1 { deadline, .. } => deadline,
+        }
+    }
 }
 
 pub(crate) fn process_swap<'a, 'info>(
@@ -42,180 +122,190 @@ pub(crate) fn process_swap<'a, 'info>(
         let header = pool_context.pool_info.get_header()?;
         (header.base_params, header.quote_params)
     };
-    let PlasmaVaultContext {
-        base_account,
-        quote_account,
-        base_vault,
-        quote_vault,
-        token_program,
-    } = PlasmaVaultContext::load_from_iter(
-        &mut accounts.iter(),
+    let account_iter = &mut accounts.iter();
+    let vault_context = PlasmaVaultContext::load_from_iter(
+        account_iter,
         &base_params,
         &quote_params,
         &pool_context.signer.key,
     )?;
+    // The trailing accounts are optional. An observation is recorded whenever the snapshot rolls
+    // if the observations account is passed, and the system program is only used to wrap native SOL.
+    let mut observations = None;
+    let mut system_program = None;
+    for info in account_iter {
+        if info.key == &system_program::id() {
+            system_program = Some(Program::new(info, &system_program::id())?);
+        } else {
+            observations = Some(ObservationsAccountInfo::new(
+                info,
+                pool_context.pool_info.key,
+            )?);
+        }
+    }
 
-    let SwapParams { side, swap_type } = SwapParams::try_from_slice(data)?;
+    let mut data = data;
+    let SwapParams { side, swap_type } = SwapParams::deserialize(&mut data)?;
+    let extension = SwapParamsExtension::load_trailing(data)?;
+    let native_sol = extension.native_sol();
+    if native_sol.wrap && system_program.is_none() {
+        msg!("The system program is required to wrap native SOL");
+        return Err(PlasmaError::MissingSystemProgram.into());
+    }
     msg!("{:?} {:?}", side, swap_type);
     // Get the active leader slot
     let slot = Clock::get()?.slot;
+    extension.deadline().check_slot(slot)?;
+
+    let system_program = system_program.as_ref().map(|program| program.as_ref());
+    let temporary_wsol_account = vault_context
+        .maybe_create_temporary_wsol_account(pool_context.signer.as_ref(), system_program)?;
+    let PlasmaVaultContext {
+        base_account,
+        quote_account,
+        base_vault,
+        quote_vault,
+        base_mint,
+        quote_mint,
+        base_token_program,
+        quote_token_program,
+    } = vault_context;
     let snapshot_slot = (slot / LEADER_SLOT_WINDOW) * LEADER_SLOT_WINDOW;
 
     let mut pool_bytes = pool_context.pool_info.try_borrow_mut_data()?;
     let pool = try_from_bytes_mut::<PoolAccount>(&mut *pool_bytes)
-        .map_err(|_| ProgramError::InvalidAccountData)?;
+        .map_err(|_| PlasmaError::InvalidPoolAccount)?;
+    pool.header.assert_active()?;
+    pool.maybe_update_snapshot(snapshot_slot);
+    extension.deadline().check_snapshot(pool)?;
 
     let pre_base_liquidity = pool.base_reserves;
     let pre_quote_liquidity = pool.quote_reserves;
     let pre_protocol_fees = pool.amm.cumulative_quote_protocol_fees;
     let pre_lp_fees = pool.amm.cumulative_quote_lp_fees;
 
-    let (swap_result, deposit_params, withdraw_params) = match side {
-        Side::Buy => {
-            let result = match swap_type {
-                SwapType::ExactIn {
-                    amount_in,
-                    min_amount_out,
-                } => {
-                    if amount_in > quote_account.amount()? {
-                        msg!("Insufficient quote balance, failing");
-                        return Err(ProgramError::InvalidArgument);
-                    }
-                    let result = pool.buy_exact_in(snapshot_slot, amount_in).map_err(|e| {
-                        msg!("Swap failed: {:?}", e);
-                        ProgramError::InvalidAccountData
-                    })?;
-                    if result.base_amount_to_transfer < min_amount_out {
-                        msg!("Buy failed: slippage exceeded");
-                        return Err(ProgramError::InvalidArgument);
-                    }
-                    result
-                }
-                SwapType::ExactOut {
-                    amount_out,
-                    max_amount_in,
-                } => {
-                    let result = pool.buy_exact_out(snapshot_slot, amount_out).map_err(|e| {
-                        msg!("Swap failed: {:?}", e);
-                        ProgramError::InvalidAccountData
-                    })?;
-                    if result.quote_amount_to_transfer > max_amount_in {
-                        msg!("Buy failed: slippage exceeded");
-                        return Err(ProgramError::InvalidArgument);
-                    }
-                    if result.quote_amount_to_transfer + result.fee_in_quote
-                        > quote_account.amount()?
-                    {
-                        msg!("Insufficient quote balance, failing");
-                        return Err(ProgramError::InvalidArgument);
-                    }
-                    result
-                }
-            };
-            let deposit_params = MaybeInvokeDepositParams {
-                deposit_amount: result.quote_amount_to_transfer,
-                token_program: &token_program,
-                deposit_account: quote_account,
-                deposit_vault: quote_vault,
-                trader: &pool_context.signer,
-            };
-
-            let withdraw_params = MaybeInvokeWithdrawParams {
-                pool_key: pool_context.pool_info.key,
-                mint_key: &base_params.mint_key,
-                bump: base_params.vault_bump as u8,
-                withdraw_amount: result.base_amount_to_transfer,
-                token_program: &token_program,
-                withdraw_account: base_account,
-                withdraw_vault: base_vault,
-            };
-            (result, deposit_params, withdraw_params)
-        }
+    let (
+        (input_account, input_vault, input_mint, input_token_program),
+        (output_account, output_vault, output_mint, output_token_program, output_params),
+    ) = match side {
+        Side::Buy => (
+            (
+                quote_account,
+                quote_vault,
+                &quote_mint,
+                &quote_token_program,
+            ),
+            (
+                base_account,
+                base_vault,
+                &base_mint,
+                &base_token_program,
+                &base_params,
+            ),
+        ),
+        Side::Sell => (
+            (base_account, base_vault, &base_mint, &base_token_program),
+            (
+                quote_account,
+                quote_vault,
+                &quote_mint,
+                &quote_token_program,
+                &quote_params,
+            ),
+        ),
+    };
 
-        Side::Sell => {
-            let result = match swap_type {
-                SwapType::ExactIn {
-                    amount_in,
-                    min_amount_out,
-                } => {
-                    if amount_in > base_account.amount()? {
-                        msg!("Insufficient base balance, failing");
-                        return Err(ProgramError::InvalidArgument);
-                    }
-                    let result = pool.sell_exact_in(snapshot_slot, amount_in).map_err(|e| {
-                        msg!("Swap failed: {:?}", e);
-                        ProgramError::InvalidAccountData
-                    })?;
-                    if result.base_amount_to_transfer < min_amount_out {
-                        msg!("Sell failed: slippage exceeded");
-                        return Err(ProgramError::InvalidArgument);
-                    }
-                    result
-                }
-                SwapType::ExactOut {
-                    amount_out,
-                    max_amount_in,
-                } => {
-                    let result = pool
-                        .sell_exact_out(snapshot_slot, amount_out)
-                        .map_err(|e| {
-                            msg!("Swap failed: {:?}", e);
-                            ProgramError::InvalidAccountData
-                        })?;
-                    if result.base_amount_to_transfer > max_amount_in {
-                        msg!("Sell failed: slippage exceeded");
-                        return Err(ProgramError::InvalidArgument);
-                    }
-                    if result.base_amount_to_transfer > base_account.amount()? {
-                        msg!("Insufficient base balance, failing");
-                        return Err(ProgramError::InvalidArgument);
-                    }
-                    result
-                }
-            };
-
-            let deposit_params = MaybeInvokeDepositParams {
-                deposit_amount: result.base_amount_to_transfer,
-                token_program: &token_program,
-                deposit_account: base_account,
-                deposit_vault: base_vault,
-                trader: &pool_context.signer,
-            };
-
-            let withdraw_params = MaybeInvokeWithdrawParams {
-                pool_key: pool_context.pool_info.key,
-                mint_key: &quote_params.mint_key,
-                bump: quote_params.vault_bump as u8,
-                withdraw_amount: result.quote_amount_to_transfer,
-                token_program: &token_program,
-                withdraw_account: quote_account,
-                withdraw_vault: quote_vault,
-            };
-            (result, deposit_params, withdraw_params)
+    // Lamports are wrapped at deposit time, so the input balance is not checked upfront
+    let wrap_input = native_sol.wrap && is_native_mint(input_mint.info.key);
+
+    // The pool only trades the amounts that actually arrive in (or leave) its vaults, so the
+    // amounts requested by the trader are adjusted for Token-2022 transfer fees
+    let pool_swap_type = match swap_type {
+        SwapType::ExactIn { amount_in, .. } => {
+            if !wrap_input && amount_in > input_account.amount()? {
+                msg!("Insufficient input balance, failing");
+                return Err(PlasmaError::InsufficientBalance.into());
+            }
+            SwapType::ExactIn {
+                amount_in: input_mint.get_post_fee_amount(amount_in)?,
+                min_amount_out: 0,
+            }
         }
+        SwapType::ExactOut { amount_out, .. } => SwapType::ExactOut {
+            amount_out: output_mint.get_pre_fee_amount(amount_out)?,
+            max_amount_in: u64::MAX,
+        },
+    };
+    let swap_result = pool
+        .swap(snapshot_slot, side, pool_swap_type)
+        .map_err(|e| {
+            msg!("Swap failed: {:?}", e);
+            PlasmaError::from(e)
+        })?;
+
+    // Amounts sent and received by the trader
+    let trader_amount_in = input_mint.get_pre_fee_amount(swap_result.amount_in())?;
+    let trader_amount_out = output_mint.get_post_fee_amount(swap_result.amount_out())?;
+    swap_type
+        .check_slippage(trader_amount_in, trader_amount_out)
+        .map_err(|e| {
+            msg!("Swap failed: {}", e);
+            PlasmaError::from(e)
+        })?;
+    if !wrap_input && trader_amount_in > input_account.amount()? {
+        msg!("Insufficient input balance, failing");
+        return Err(PlasmaError::InsufficientBalance.into());
+    }
+
+    let deposit_params = MaybeInvokeDepositParams {
+        deposit_amount: swap_result.amount_in(),
+        mint: input_mint,
+        token_program: input_token_program,
+        deposit_account: input_account,
+        deposit_vault: input_vault,
+        trader: &pool_context.signer,
+        system_program: system_program.filter(|_| wrap_input),
+    };
+
+    let withdraw_params = MaybeInvokeWithdrawParams {
+        pool_key: pool_context.pool_info.key,
+        mint: output_mint,
+        bump: output_params.vault_bump as u8,
+        withdraw_amount: swap_result.amount_out(),
+        token_program: output_token_program,
+        withdraw_account: output_account,
+        withdraw_vault: output_vault,
+        unwrap_to: native_sol.unwrap.then_some(pool_context.signer.as_ref()),
     };
 
     let post_base_liquidity = pool.base_reserves;
     let post_quote_liquidity = pool.quote_reserves;
 
-    let deposit_amount = deposit_params.deposit_amount;
-    let withdraw_amount = withdraw_params.withdraw_amount;
+    let deposit_amount = trader_amount_in;
+    let withdraw_amount = trader_amount_out;
 
     // Handle protocol fees accounting
     pool.update_protocol_fee_recipients_post_swap()?;
     assert_with_msg(
         pool.amm.cumulative_quote_protocol_fees >= pre_protocol_fees,
-        ProgramError::InvalidArgument,
+        PlasmaError::CumulativeFeesDecreased,
         "Cumulative protocol fees did not increase after swap",
     )?;
     assert_with_msg(
         pool.amm.cumulative_quote_lp_fees >= pre_lp_fees,
-        ProgramError::InvalidArgument,
+        PlasmaError::CumulativeFeesDecreased,
         "Cumulative LP fees did not increase after swap",
     )?;
 
+    if let Some(observations) = observations {
+        observations.record(&pool.amm)?;
+    }
+
     maybe_invoke_deposit(deposit_params)?;
     maybe_invoke_withdraw(withdraw_params)?;
+    if let Some(temporary_wsol_account) = temporary_wsol_account {
+        temporary_wsol_account.close(pool_context.signer.as_ref())?;
+    }
 
     // Set the return data to the in and out amounts so upstream callers can quickly process the swap results
     set_return_data(&[deposit_amount.to_le_bytes(), withdraw_amount.to_le_bytes()].concat());
@@ -247,3 +337,309 @@ pub(crate) fn process_swap<'a, 'info>(
         swap_result,
     }))
 }
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SwapRouteParams {
+    /// Side of each hop, in the order of the route
+    pub sides: Vec<Side>,
+    /// Limits of the route as a whole. There is no slippage check on intermediate hops.
+    pub swap_type: SwapType,
+}
+
+/// Versioned data that follows `SwapRouteParams`, see `SwapParamsExtension`
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum SwapRouteParamsExtension {
+    V0,
+    V1 {
+        /// Native SOL can be wrapped into the input of the first hop and unwrapped from the
+        /// output of the last hop
+        native_sol: NativeSolParams,
+        deadline: SlotDeadline,
+    },
+}
+
+impl SwapRouteParamsExtension {
+    /// Parses the data that follows the instruction params, an empty slice is treated as `V0`
+    pub(crate) fn load_trailing(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.is_empty() {
+            return Ok(Self::V0);
+        }
+        Ok(Self::try_from_slice(data)?)
+    }
+
+    pub fn native_sol(&self) -> NativeSolParams {
+        match *self {
+            Self::V0 => NativeSolParams::default(),
+            Self::V1 { native_sol, .. } => native_sol,
+        }
+    }
+
+    pub fn deadline(&self) -> SlotDeadline {
+        match *self {
+            Self::V0 => SlotDeadline::default(),
+            Self::V1 { deadline, .. } => deadline,
+        }
+    }
+}
+
+pub(crate) fn process_swap_route<'a, 'info>(
+    pool_context: &PlasmaPoolContext<'a, 'info>,
+    accounts: &'a [AccountInfo<'info>],
+    data: &[u8],
+) -> Result<Vec<(PlasmaPoolContext<'a, 'info>, SwapEvent)>, ProgramError> {
+    let mut data = data;
+    let SwapRouteParams { sides, swap_type } = SwapRouteParams::deserialize(&mut data)?;
+    let extension = SwapRouteParamsExtension::load_trailing(data)?;
+    msg!("{:?} {:?}", sides, swap_type);
+    let SwapRouteContext {
+        input_account,
+        output_account,
+        hops,
+        system_program,
+    } = SwapRouteContext::load(pool_context, accounts, &sides)?;
+    let native_sol = extension.native_sol();
+    let system_program = system_program.as_ref().map(|program| program.as_ref());
+    if native_sol.wrap && system_program.is_none() {
+        msg!("The system program is required to wrap native SOL");
+        return Err(PlasmaError::MissingSystemProgram.into());
+    }
+
+    // Get the active leader slot
+    let slot = Clock::get()?.slot;
+    let deadline = extension.deadline();
+    deadline.check_slot(slot)?;
+    let snapshot_slot = (slot / LEADER_SLOT_WINDOW) * LEADER_SLOT_WINDOW;
+
+    let first_hop = &hops[0];
+    let last_hop = &hops[hops.len() - 1];
+    let temporary_wsol_account = maybe_create_temporary_wsol_account(
+        [
+            (
+                input_account.info,
+                first_hop.input_accounts().1.info,
+                first_hop.input_accounts().2.as_ref(),
+            ),
+            (
+                output_account.info,
+                last_hop.output_accounts().2.info,
+                last_hop.output_accounts().3.as_ref(),
+            ),
+        ],
+        pool_context.signer.as_ref(),
+        system_program,
+    )?;
+    // Lamports are wrapped at deposit time, so the input balance is not checked upfront
+    let wrap_input = native_sol.wrap && is_native_mint(first_hop.input_mint());
+
+    // Exact in routes are executed front to back, each hop swapping the output of the previous one.
+    // Exact out routes are solved back to front so that each hop outputs exactly the input of the next one.
+    // Every transfer between vaults is adjusted for the Token-2022 transfer fee of the transferred mint.
+    let mut events = Vec::with_capacity(hops.len());
+    match swap_type {
+        SwapType::ExactIn { amount_in, .. } => {
+            if !wrap_input && amount_in > input_account.amount()? {
+                msg!("Insufficient input balance, failing");
+                return Err(PlasmaError::InsufficientBalance.into());
+            }
+            let mut hop_amount_in = hops[0].input_accounts().1.get_post_fee_amount(amount_in)?;
+            for hop in hops.iter() {
+                let event = execute_route_hop(
+                    &hop.pool_context,
+                    snapshot_slot,
+                    &deadline,
+                    hop.side,
+                    SwapType::ExactIn {
+                        amount_in: hop_amount_in,
+                        min_amount_out: 0,
+                    },
+                )?;
+                hop_amount_in = hop
+                    .output_accounts()
+                    .2
+                    .get_post_fee_amount(event.swap_result.amount_out())?;
+                events.push(event);
+            }
+        }
+        SwapType::ExactOut { amount_out, .. } => {
+            let mut hop_amount_out = hops[hops.len() - 1]
+                .output_accounts()
+                .2
+                .get_pre_fee_amount(amount_out)?;
+            for hop in hops.iter().rev() {
+                let event = execute_route_hop(
+                    &hop.pool_context,
+                    snapshot_slot,
+                    &deadline,
+                    hop.side,
+                    SwapType::ExactOut {
+                        amount_out: hop_amount_out,
+                        max_amount_in: u64::MAX,
+                    },
+                )?;
+                hop_amount_out = hop
+                    .input_accounts()
+                    .1
+                    .get_pre_fee_amount(event.swap_result.amount_in())?;
+                events.push(event);
+            }
+            events.reverse();
+        }
+    }
+
+    let deposit_amount = first_hop
+        .input_accounts()
+        .1
+        .get_pre_fee_amount(events[0].swap_result.amount_in())?;
+    let withdraw_amount = last_hop
+        .output_accounts()
+        .2
+        .get_post_fee_amount(events[events.len() - 1].swap_result.amount_out())?;
+    if !wrap_input && deposit_amount > input_account.amount()? {
+        msg!("Insufficient input balance, failing");
+        return Err(PlasmaError::InsufficientBalance.into());
+    }
+    swap_type
+        .check_slippage(deposit_amount, withdraw_amount)
+        .map_err(|e| {
+            msg!("Swap route failed: {}", e);
+            PlasmaError::from(e)
+        })?;
+
+    let (input_vault, input_mint, input_token_program) = first_hop.input_accounts();
+    maybe_invoke_deposit(MaybeInvokeDepositParams {
+        deposit_amount: events[0].swap_result.amount_in(),
+        mint: input_mint,
+        token_program: input_token_program,
+        deposit_account: input_account,
+        deposit_vault: input_vault.clone(),
+        trader: &pool_context.signer,
+        system_program: system_program.filter(|_| wrap_input),
+    })?;
+
+    // The output of each hop is sent directly to the vault of the next pool
+    for (i, (hop, event)) in hops.iter().zip(events.iter()).enumerate() {
+        let (output_params, output_vault, output_mint, output_token_program) =
+            hop.output_accounts();
+        let (withdraw_account, unwrap_to) = match hops.get(i + 1) {
+            Some(next_hop) => (next_hop.input_accounts().0.clone(), None),
+            None => (
+                output_account.clone(),
+                native_sol.unwrap.then_some(pool_context.signer.as_ref()),
+            ),
+        };
+        maybe_invoke_withdraw(MaybeInvokeWithdrawParams {
+            pool_key: hop.pool_context.pool_info.key,
+            mint: output_mint,
+            bump: output_params.vault_bump as u8,
+            withdraw_amount: event.swap_result.amount_out(),
+            token_program: output_token_program,
+            withdraw_account,
+            withdraw_vault: output_vault.clone(),
+            unwrap_to,
+        })?;
+    }
+    if let Some(temporary_wsol_account) = temporary_wsol_account {
+        temporary_wsol_account.close(pool_context.signer.as_ref())?;
+    }
+
+    // Set the return data to the in and out amounts so upstream callers can quickly process the swap results
+    set_return_data(&[deposit_amount.to_le_bytes(), withdraw_amount.to_le_bytes()].concat());
+    msg!(
+        "[Route] Swapped {} for {} through {} pools",
+        deposit_amount,
+        withdraw_amount,
+        hops.len()
+    );
+
+    Ok(hops
+        .into_iter()
+        .map(|hop| hop.pool_context)
+        .zip(events)
+        .collect())
+}
+
+/// Swaps against a single pool of a route. Token transfers are handled by the caller.
+fn execute_route_hop(
+    pool_context: &PlasmaPoolContext,
+    snapshot_slot: u64,
+    deadline: &SlotDeadline,
+    side: Side,
+    swap_type: SwapType,
+) -> Result<SwapEvent, ProgramError> {
+    let mut pool_bytes = pool_context.pool_info.try_borrow_mut_data()?;
+    let pool = try_from_bytes_mut::<PoolAccount>(&mut *pool_bytes)
+        .map_err(|_| PlasmaError::InvalidPoolAccount)?;
+    pool.header.assert_active()?;
+    pool.maybe_update_snapshot(snapshot_slot);
+    deadline.check_snapshot(pool)?;
+
+    let pre_base_liquidity = pool.base_reserves;
+    let pre_quote_liquidity = pool.quote_reserves;
+    let pre_protocol_fees = pool.amm.cumulative_quote_protocol_fees;
+    let pre_lp_fees = pool.amm.cumulative_quote_lp_fees;
+
+    let swap_result = pool.swap(snapshot_slot, side, swap_type).map_err(|e| {
+        msg!("Swap failed: {:?}", e);
+        PlasmaError::from(e)
+    })?;
+
+    // Handle protocol fees accounting
+    pool.update_protocol_fee_recipients_post_swap()?;
+    assert_with_msg(
+        pool.amm.cumulative_quote_protocol_fees >= pre_protocol_fees,
+        PlasmaError::CumulativeFeesDecreased,
+        "Cumulative protocol fees did not increase after swap",
+    )?;
+    assert_with_msg(
+        pool.amm.cumulative_quote_lp_fees >= pre_lp_fees,
+        PlasmaError::CumulativeFeesDecreased,
+        "Cumulative LP fees did not increase after swap",
+    )?;
+
+    Ok(SwapEvent {
+        pre_base_liquidity,
+        pre_quote_liquidity,
+        post_base_liquidity: pool.base_reserves,
+        post_quote_liquidity: pool.quote_reserves,
+        snapshot_base_liquidity: pool.base_reserves_snapshot,
+        snapshot_quote_liquidity: pool.quote_reserves_snapshot,
+        swap_result,
+    })
+}
+
+#[test]
+fn test_swap_params_extensions() {
+    let extension = SwapParamsExtension::load_trailing(&[]).unwrap();
+    assert!(!extension.native_sol().wrap);
+    assert!(extension.deadline().max_slot.is_none());
+
+    let extension = SwapRouteParamsExtension::load_trailing(&[]).unwrap();
+    assert!(!extension.native_sol().unwrap);
+    assert!(extension.deadline().max_slot.is_none());
+
+    let data = SwapRouteParamsExtension::V1 {
+        native_sol: NativeSolParams {
+            wrap: true,
+            unwrap: true,
+        },
+        deadline: SlotDeadline {
+            max_slot: Some(100),
+            min_snapshot_slot: None,
+        },
+    }
+    .try_to_vec()
+    .unwrap();
+    let extension = SwapRouteParamsExtension::load_trailing(&data).unwrap();
+    assert!(extension.native_sol().wrap && extension.native_sol().unwrap);
+    assert!(extension.deadline().check_slot(100).is_ok());
+    assert!(extension.deadline().check_slot(101).is_err());
+
+    let deadline = SlotDeadline {
+        max_slot: None,
+        min_snapshot_slot: Some(104),
+    };
+    let mut amm = Amm::new(30, 0, 0, 100);
+    assert!(deadline.check_snapshot(&amm).is_err());
+    amm.maybe_update_snapshot(104);
+    assert!(deadline.check_snapshot(&amm).is_ok());
+}
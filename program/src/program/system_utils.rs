@@ -2,9 +2,10 @@ use solana_program::{
     account_info::AccountInfo,
     entrypoint::ProgramResult,
     program::{invoke, invoke_signed},
+    program_error::ProgramError,
     pubkey::Pubkey,
     rent::Rent,
-    system_instruction,
+    system_instruction, system_program,
 };
 
 pub fn create_account<'a, 'info>(
@@ -68,3 +69,19 @@ pub fn create_account<'a, 'info>(
         )
     }
 }
+
+/// Closes an account owned by this program, moving all of its lamports to `destination`
+pub fn close_account<'a, 'info>(
+    account: &'a AccountInfo<'info>,
+    destination: &'a AccountInfo<'info>,
+) -> ProgramResult {
+    let lamports = account.lamports();
+    **destination.try_borrow_mut_lamports()? = destination
+        .lamports()
+        .checked_add(lamports)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    **account.try_borrow_mut_lamports()? = 0;
+    account.realloc(0, false)?;
+    account.assign(&system_program::id());
+    Ok(())
+}
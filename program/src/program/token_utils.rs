@@ -1,23 +1,65 @@
+use borsh::{BorshDeserialize as Deserialize, BorshSerialize as Serialize};
 use solana_program::{
     account_info::AccountInfo,
     entrypoint::ProgramResult,
+    msg,
     program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    program_pack::Pack,
     pubkey::Pubkey,
+    rent::Rent,
+    system_instruction,
+    sysvar::Sysvar,
 };
 
-use super::{accounts::TokenParams, validation::checkers::TokenAccountInfo};
+use super::{
+    accounts::TokenParams,
+    error::PlasmaError,
+    system_utils::create_account,
+    validation::{
+        checkers::{MintAccountInfo, TokenAccountInfo},
+        loaders::get_temporary_wsol_address,
+    },
+};
+use crate::assert_with_msg;
+
+/// Optional trailing instruction data of the trading instructions that lets the trader pay and
+/// receive native SOL. Both flags only apply to the side of the pool whose mint is the native mint.
+///
+/// The trader's token account of the native mint can be an existing wSOL account, or the
+/// trader's temporary wSOL account (see `get_temporary_wsol_address`). The temporary account is
+/// created by the instruction and always closed before it ends, so the trader does not need to
+/// hold a wSOL account at all.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+pub struct NativeSolParams {
+    /// Wrap lamports from the trader into the wSOL account before the deposit. Only the amount
+    /// missing from the wSOL account is wrapped. Requires the system program account.
+    pub wrap: bool,
+    /// Close the wSOL account after the withdrawal, the proceeds and the rent of the account are
+    /// returned to the trader as lamports
+    pub unwrap: bool,
+}
+
+pub(crate) fn is_native_mint(mint: &Pubkey) -> bool {
+    mint == &spl_token::native_mint::id() || mint == &spl_token_2022::native_mint::id()
+}
 
 pub(crate) struct TryWithdrawParams<'a, 'info> {
     pub(crate) pool_key: &'a Pubkey,
     pub(crate) base_params: &'a TokenParams,
     pub(crate) quote_params: &'a TokenParams,
-    pub(crate) token_program: &'a AccountInfo<'info>,
+    pub(crate) base_mint: &'a MintAccountInfo<'a, 'info>,
+    pub(crate) quote_mint: &'a MintAccountInfo<'a, 'info>,
+    pub(crate) base_token_program: &'a AccountInfo<'info>,
+    pub(crate) quote_token_program: &'a AccountInfo<'info>,
     pub(crate) quote_account: TokenAccountInfo<'a, 'info>,
     pub(crate) quote_vault: TokenAccountInfo<'a, 'info>,
     pub(crate) base_account: TokenAccountInfo<'a, 'info>,
     pub(crate) base_vault: TokenAccountInfo<'a, 'info>,
     pub(crate) quote_amount: u64,
     pub(crate) base_amount: u64,
+    pub(crate) unwrap_to: Option<&'a AccountInfo<'info>>,
 }
 
 pub(crate) fn try_withdraw<'a, 'info>(params: TryWithdrawParams<'a, 'info>) -> ProgramResult {
@@ -25,39 +67,62 @@ pub(crate) fn try_withdraw<'a, 'info>(params: TryWithdrawParams<'a, 'info>) -> P
         pool_key,
         base_params,
         quote_params,
-        token_program,
+        base_mint,
+        quote_mint,
+        base_token_program,
+        quote_token_program,
         quote_account,
         quote_vault,
         base_account,
         base_vault,
         quote_amount,
         base_amount,
+        unwrap_to,
     } = params;
-    for (withdraw_vault, withdraw_account, withdraw_amount, params) in [
-        (quote_vault, quote_account, quote_amount, quote_params),
-        (base_vault, base_account, base_amount, base_params),
+    for (withdraw_vault, withdraw_account, withdraw_amount, params, mint, token_program) in [
+        (
+            quote_vault,
+            quote_account,
+            quote_amount,
+            quote_params,
+            quote_mint,
+            quote_token_program,
+        ),
+        (
+            base_vault,
+            base_account,
+            base_amount,
+            base_params,
+            base_mint,
+            base_token_program,
+        ),
     ] {
         maybe_invoke_withdraw(MaybeInvokeWithdrawParams {
             pool_key,
-            mint_key: &params.mint_key,
+            mint,
             bump: params.vault_bump as u8,
             withdraw_amount,
             token_program,
             withdraw_account,
             withdraw_vault,
+            unwrap_to,
         })?;
     }
     Ok(())
 }
 
+/// Transfers `withdraw_amount` out of a vault. If the mint has a transfer fee, the recipient
+/// receives less than `withdraw_amount`. If `unwrap_to` is set and the mint is the native mint,
+/// the recipient wSOL account is then closed to `unwrap_to`, which must be its owner.
 pub(crate) struct MaybeInvokeWithdrawParams<'a, 'info> {
     pub(crate) pool_key: &'a Pubkey,
-    pub(crate) mint_key: &'a Pubkey,
+    pub(crate) mint: &'a MintAccountInfo<'a, 'info>,
     pub(crate) bump: u8,
     pub(crate) withdraw_amount: u64,
     pub(crate) token_program: &'a AccountInfo<'info>,
     pub(crate) withdraw_account: TokenAccountInfo<'a, 'info>,
     pub(crate) withdraw_vault: TokenAccountInfo<'a, 'info>,
+    pub(crate) unwrap_to: Option<&'a AccountInfo<'info>>,
 }
 
 pub(crate) fn maybe_invoke_withdraw<'a, 'info>(
@@ -65,40 +130,163 @@ pub(crate) fn maybe_invoke_withdraw<'a, 'info>(
 ) -> ProgramResult {
     let MaybeInvokeWithdrawParams {
         pool_key,
-        mint_key,
+        mint,
         bump,
         withdraw_amount,
         token_program,
         withdraw_account,
         withdraw_vault,
+        unwrap_to,
     } = params;
     if withdraw_amount != 0 {
         invoke_signed(
-            &spl_token::instruction::transfer(
+            &spl_token_2022::instruction::transfer_checked(
                 token_program.key,
                 withdraw_vault.key,
+                mint.info.key,
                 withdraw_account.key,
                 withdraw_vault.key,
                 &[],
                 withdraw_amount,
+                mint.decimals,
             )?,
             &[
                 token_program.clone(),
                 withdraw_vault.as_ref().clone(),
+                mint.as_ref().clone(),
                 withdraw_account.as_ref().clone(),
             ],
-            &[&[b"vault", pool_key.as_ref(), mint_key.as_ref(), &[bump]]],
+            &[&[b"vault", pool_key.as_ref(), mint.info.key.as_ref(), &[bump]]],
         )?;
     }
+    if let Some(trader) = unwrap_to.filter(|_| is_native_mint(mint.info.key)) {
+        close_wsol_account(token_program, &withdraw_account, trader)?;
+    }
     Ok(())
 }
 
+/// Closes a wSOL account owned by the trader, its balance and rent are returned as lamports
+fn close_wsol_account<'info>(
+    token_program: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    trader: &AccountInfo<'info>,
+) -> ProgramResult {
+    invoke(
+        &spl_token_2022::instruction::close_account(
+            token_program.key,
+            account.key,
+            trader.key,
+            trader.key,
+            &[],
+        )?,
+        &[token_program.clone(), account.clone(), trader.clone()],
+    )
+}
+
+/// wSOL account created by an instruction in place of the trader's token account of the native
+/// mint. It is owned by the trader and must be closed before the instruction ends.
+pub(crate) struct TemporaryWsolAccount<'info> {
+    account: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+}
+
+impl<'info> TemporaryWsolAccount<'info> {
+    /// Creates the temporary wSOL account of the trader if it was passed in place of the trader's
+    /// token account, which the loaders only allow for the native mint. The account is created
+    /// empty, lamports are wrapped into it when tokens are deposited from it.
+    pub(crate) fn maybe_create(
+        account: &AccountInfo<'info>,
+        mint: &AccountInfo<'info>,
+        token_program: &AccountInfo<'info>,
+        trader: &AccountInfo<'info>,
+        system_program: Option<&AccountInfo<'info>>,
+    ) -> Result<Option<Self>, ProgramError> {
+        if !account.data_is_empty() {
+            return Ok(None);
+        }
+        let system_program = system_program.ok_or_else(|| {
+            msg!("The system program is required to create a temporary wSOL account");
+            PlasmaError::MissingSystemProgram
+        })?;
+        let (wsol_key, bump) = get_temporary_wsol_address(trader.key);
+        assert_with_msg(
+            account.key == &wsol_key,
+            PlasmaError::IncorrectTokenAccountKey,
+            &format!(
+                "Supplied wSOL account ({}) does not match computed key ({})",
+                account.key, wsol_key
+            ),
+        )?;
+        create_account(
+            trader,
+            account,
+            system_program,
+            token_program.key,
+            &Rent::get()?,
+            spl_token_2022::state::Account::LEN as u64,
+            vec![b"wsol".to_vec(), trader.key.as_ref().to_vec(), vec![bump]],
+        )?;
+        invoke(
+            &spl_token_2022::instruction::initialize_account3(
+                token_program.key,
+                account.key,
+                mint.key,
+                trader.key,
+            )?,
+            &[account.clone(), mint.clone(), token_program.clone()],
+        )?;
+        Ok(Some(Self {
+            account: account.clone(),
+            token_program: token_program.clone(),
+        }))
+    }
+
+    /// Closes the account to the trader, unless it was already unwrapped by a withdrawal
+    pub(crate) fn close(self, trader: &AccountInfo<'info>) -> ProgramResult {
+        if self.account.lamports() == 0 {
+            return Ok(());
+        }
+        close_wsol_account(&self.token_program, &self.account, trader)
+    }
+}
+
+/// Creates the temporary wSOL account passed in place of one of the trader's token accounts, if any.
+/// Each entry is a token account of the trader with its mint and token program.
+pub(crate) fn maybe_create_temporary_wsol_account<'info>(
+    accounts: [(
+        &AccountInfo<'info>,
+        &AccountInfo<'info>,
+        &AccountInfo<'info>,
+    ); 2],
+    trader: &AccountInfo<'info>,
+    system_program: Option<&AccountInfo<'info>>,
+) -> Result<Option<TemporaryWsolAccount<'info>>, ProgramError> {
+    for (account, mint, token_program) in accounts {
+        if let Some(temporary_account) = TemporaryWsolAccount::maybe_create(
+            account,
+            mint,
+            token_program,
+            trader,
+            system_program,
+        )? {
+            return Ok(Some(temporary_account));
+        }
+    }
+    Ok(None)
+}
+
+/// Transfers tokens into a vault so that the vault is credited with exactly `deposit_amount`.
+/// If the mint has a transfer fee, the fee is added on top of `deposit_amount`. If `system_program`
+/// is set and the mint is the native mint, the lamports missing from the deposit account are first
+/// wrapped from the trader.
 pub(crate) struct MaybeInvokeDepositParams<'a, 'info> {
     pub(crate) deposit_amount: u64,
+    pub(crate) mint: &'a MintAccountInfo<'a, 'info>,
     pub(crate) token_program: &'a AccountInfo<'info>,
     pub(crate) deposit_account: TokenAccountInfo<'a, 'info>,
     pub(crate) deposit_vault: TokenAccountInfo<'a, 'info>,
     pub(crate) trader: &'a AccountInfo<'info>,
+    pub(crate) system_program: Option<&'a AccountInfo<'info>>,
 }
 
 pub(crate) fn maybe_invoke_deposit<'a, 'info>(
@@ -106,24 +294,54 @@ pub(crate) fn maybe_invoke_deposit<'a, 'info>(
 ) -> ProgramResult {
     let MaybeInvokeDepositParams {
         deposit_amount,
+        mint,
         token_program,
         deposit_account,
         deposit_vault,
         trader,
+        system_program,
     } = params;
     if deposit_amount > 0 {
+        let transfer_amount = mint.get_pre_fee_amount(deposit_amount)?;
+        if let Some(system_program) = system_program.filter(|_| is_native_mint(mint.info.key)) {
+            let lamports_to_wrap = transfer_amount.saturating_sub(deposit_account.amount()?);
+            if lamports_to_wrap > 0 {
+                invoke(
+                    &system_instruction::transfer(
+                        trader.key,
+                        deposit_account.key,
+                        lamports_to_wrap,
+                    ),
+                    &[
+                        trader.clone(),
+                        deposit_account.as_ref().clone(),
+                        system_program.clone(),
+                    ],
+                )?;
+                invoke(
+                    &spl_token_2022::instruction::sync_native(
+                        token_program.key,
+                        deposit_account.key,
+                    )?,
+                    &[token_program.clone(), deposit_account.as_ref().clone()],
+                )?;
+            }
+        }
         invoke(
-            &spl_token::instruction::transfer(
+            &spl_token_2022::instruction::transfer_checked(
                 token_program.key,
                 deposit_account.key,
+                mint.info.key,
                 deposit_vault.key,
                 trader.key,
                 &[],
-                deposit_amount,
+                transfer_amount,
+                mint.decimals,
             )?,
             &[
                 token_program.as_ref().clone(),
                 deposit_account.as_ref().clone(),
+                mint.as_ref().clone(),
                 deposit_vault.as_ref().clone(),
                 trader.as_ref().clone(),
             ],
@@ -133,7 +351,10 @@ pub(crate) fn maybe_invoke_deposit<'a, 'info>(
 }
 
 pub(crate) struct TryDepositParams<'a, 'info> {
-    pub(crate) token_program: &'a AccountInfo<'info>,
+    pub(crate) base_mint: &'a MintAccountInfo<'a, 'info>,
+    pub(crate) quote_mint: &'a MintAccountInfo<'a, 'info>,
+    pub(crate) base_token_program: &'a AccountInfo<'info>,
+    pub(crate) quote_token_program: &'a AccountInfo<'info>,
     pub(crate) quote_account: TokenAccountInfo<'a, 'info>,
     pub(crate) quote_vault: TokenAccountInfo<'a, 'info>,
     pub(crate) base_account: TokenAccountInfo<'a, 'info>,
@@ -141,12 +362,16 @@ pub(crate) struct TryDepositParams<'a, 'info> {
     pub(crate) quote_amount: u64,
     pub(crate) base_amount: u64,
     pub(crate) trader: &'a AccountInfo<'info>,
+    pub(crate) system_program: Option<&'a AccountInfo<'info>>,
 }
 
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn try_deposit<'a, 'info>(params: TryDepositParams<'a, 'info>) -> ProgramResult {
     let TryDepositParams {
-        token_program,
+        base_mint,
+        quote_mint,
+        base_token_program,
+        quote_token_program,
         quote_account,
         quote_vault,
         base_account,
@@ -154,18 +379,137 @@ pub(crate) fn try_deposit<'a, 'info>(params: TryDepositParams<'a, 'info>) -> Pro
         quote_amount,
         base_amount,
         trader,
+        system_program,
     } = params;
-    for (deposit_vault, deposit_account, deposit_amount) in [
-        (quote_vault, quote_account, quote_amount),
-        (base_vault, base_account, base_amount),
+    for (deposit_vault, deposit_account, deposit_amount, mint, token_program) in [
+        (
+            quote_vault,
+            quote_account,
+            quote_amount,
+            quote_mint,
+            quote_token_program,
+        ),
+        (
+            base_vault,
+            base_account,
+            base_amount,
+            base_mint,
+            base_token_program,
+        ),
     ] {
         maybe_invoke_deposit(MaybeInvokeDepositParams {
             deposit_amount,
+            mint,
             token_program,
             deposit_account,
             deposit_vault,
             trader,
+            system_program,
         })?;
     }
     Ok(())
 }
+
+/// Closes an empty vault, its rent is sent to `destination`
+pub(crate) fn close_vault<'a, 'info>(
+    pool_key: &Pubkey,
+    params: &TokenParams,
+    token_program: &'a AccountInfo<'info>,
+    vault: &TokenAccountInfo<'a, 'info>,
+    destination: &'a AccountInfo<'info>,
+) -> ProgramResult {
+    invoke_signed(
+        &spl_token_2022::instruction::close_account(
+            token_program.key,
+            vault.key,
+            destination.key,
+            vault.key,
+            &[],
+        )?,
+        &[
+            token_program.clone(),
+            vault.as_ref().clone(),
+            destination.clone(),
+        ],
+        &[&[
+            b"vault",
+            pool_key.as_ref(),
+            params.mint_key.as_ref(),
+            &[params.vault_bump as u8],
+        ]],
+    )
+}
+
+/// Moves the transfer fees withheld in a vault to its mint. Token-2022 does not close accounts that
+/// still hold withheld fees, harvesting them is permissionless.
+pub(crate) fn harvest_withheld_fees<'a, 'info>(
+    token_program: &'a AccountInfo<'info>,
+    mint: &MintAccountInfo<'a, 'info>,
+    vault: &TokenAccountInfo<'a, 'info>,
+) -> ProgramResult {
+    invoke(
+        &spl_token_2022::extension::transfer_fee::instruction::harvest_withheld_tokens_to_mint(
+            token_program.key,
+            mint.info.key,
+            &[vault.key],
+        )?,
+        &[
+            token_program.clone(),
+            mint.info.clone(),
+            vault.as_ref().clone(),
+        ],
+    )
+}
+
+/// Mints LP tokens of a pool, the LP mint signs as its own authority
+pub(crate) fn mint_lp_tokens<'a, 'info>(
+    pool_key: &Pubkey,
+    lp_mint_bump: u8,
+    token_program: &'a AccountInfo<'info>,
+    lp_mint: &MintAccountInfo<'a, 'info>,
+    destination: &TokenAccountInfo<'a, 'info>,
+    amount: u64,
+) -> ProgramResult {
+    invoke_signed(
+        &spl_token::instruction::mint_to(
+            token_program.key,
+            lp_mint.info.key,
+            destination.key,
+            lp_mint.info.key,
+            &[],
+            amount,
+        )?,
+        &[
+            token_program.clone(),
+            lp_mint.info.clone(),
+            destination.as_ref().clone(),
+        ],
+        &[&[b"lp_mint", pool_key.as_ref(), &[lp_mint_bump]]],
+    )
+}
+
+/// Burns LP tokens from an account owned by the trader
+pub(crate) fn burn_lp_tokens<'a, 'info>(
+    token_program: &'a AccountInfo<'info>,
+    lp_mint: &MintAccountInfo<'a, 'info>,
+    source: &TokenAccountInfo<'a, 'info>,
+    trader: &'a AccountInfo<'info>,
+    amount: u64,
+) -> ProgramResult {
+    invoke(
+        &spl_token::instruction::burn(
+            token_program.key,
+            source.key,
+            lp_mint.info.key,
+            trader.key,
+            &[],
+            amount,
+        )?,
+        &[
+            token_program.clone(),
+            source.as_ref().clone(),
+            lp_mint.info.clone(),
+            trader.clone(),
+        ],
+    )
+}
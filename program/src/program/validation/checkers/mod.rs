@@ -1,4 +1,5 @@
 use crate::assert_with_msg;
+use crate::program::error::PlasmaError;
 use solana_program::{
     account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, system_program,
 };
@@ -21,7 +22,7 @@ impl<'a, 'info> PDA<'a, 'info> {
     ) -> Result<PDA<'a, 'info>, ProgramError> {
         assert_with_msg(
             info.key == known_address,
-            ProgramError::InvalidInstructionData,
+            PlasmaError::IncorrectAccountKey,
             "Incorrect account key",
         )?;
         Ok(Self { info })
@@ -54,7 +55,7 @@ impl<'a, 'info> Program<'a, 'info> {
     ) -> Result<Program<'a, 'info>, ProgramError> {
         assert_with_msg(
             info.key == expected_program_id,
-            ProgramError::IncorrectProgramId,
+            PlasmaError::IncorrectProgramId,
             "Incorrect program id",
         )?;
         Ok(Self { info })
@@ -84,7 +85,7 @@ impl<'a, 'info> Signer<'a, 'info> {
     pub fn new(info: &'a AccountInfo<'info>) -> Result<Signer<'a, 'info>, ProgramError> {
         assert_with_msg(
             info.is_signer,
-            ProgramError::MissingRequiredSignature,
+            PlasmaError::MissingRequiredSignature,
             "Missing required signature",
         )?;
         Ok(Self { info })
@@ -97,7 +98,7 @@ impl<'a, 'info> Signer<'a, 'info> {
         let signer = Self::new(info)?;
         assert_with_msg(
             signer.key == key,
-            ProgramError::MissingRequiredSignature,
+            PlasmaError::IncorrectAccountKey,
             "Incorrect key for signer",
         )?;
         Ok(signer)
@@ -106,12 +107,12 @@ impl<'a, 'info> Signer<'a, 'info> {
     pub fn new_payer(info: &'a AccountInfo<'info>) -> Result<Signer<'a, 'info>, ProgramError> {
         assert_with_msg(
             info.is_writable,
-            ProgramError::InvalidInstructionData,
+            PlasmaError::PayerNotWritable,
             "Payer is not writable",
         )?;
         assert_with_msg(
             info.is_signer,
-            ProgramError::MissingRequiredSignature,
+            PlasmaError::MissingRequiredSignature,
             "Missing required signature for payer",
         )?;
         Ok(Self { info })
@@ -141,12 +142,12 @@ impl<'a, 'info> EmptyAccount<'a, 'info> {
     pub fn new(info: &'a AccountInfo<'info>) -> Result<EmptyAccount<'a, 'info>, ProgramError> {
         assert_with_msg(
             info.data_is_empty(),
-            ProgramError::InvalidAccountData,
+            PlasmaError::AccountAlreadyInitialized,
             "Account must be uninitialized",
         )?;
         assert_with_msg(
             info.owner == &system_program::id(),
-            ProgramError::IllegalOwner,
+            PlasmaError::EmptyAccountNotOwnedBySystemProgram,
             "Empty accounts must be owned by the system program",
         )?;
         Ok(Self { info })
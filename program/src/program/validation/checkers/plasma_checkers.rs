@@ -1,15 +1,24 @@
+use crate::program::error::PlasmaError;
 use crate::{
     assert_with_msg,
     program::{
         accounts::{
-            LpPositionAccount, PoolHeader, LP_POSITION_ACCOUNT_DISCRIMINATOR,
-            POOL_ACCOUNT_DISCRIMINATOR,
+            FeeTierConfigAccount, LpPositionAccount, ObservationsAccount, PoolHeader,
+            FEE_TIER_CONFIG_ACCOUNT_DISCRIMINATOR, LEGACY_LP_POSITION_ACCOUNT_SIZE,
+            LEGACY_POOL_ACCOUNT_SIZE, LP_POSITION_ACCOUNT_DISCRIMINATOR,
+            OBSERVATIONS_ACCOUNT_DISCRIMINATOR, POOL_ACCOUNT_DISCRIMINATOR,
+        },
+        validation::loaders::{
+            get_fee_tier_config_address, get_lp_escrow_address, get_lp_position_address,
+            get_observations_address,
         },
-        validation::loaders::get_lp_position_address,
     },
 };
 use bytemuck::try_from_bytes;
-use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+use plasma_state::amm::Amm;
+use solana_program::{
+    account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey, system_program,
+};
 use std::{cell::Ref, mem::size_of, ops::Deref};
 
 #[derive(Clone)]
@@ -24,7 +33,7 @@ impl<'a, 'info> PoolAccountInfo<'a, 'info> {
     ) -> Result<PoolAccountInfo<'a, 'info>, ProgramError> {
         assert_with_msg(
             info.owner == &crate::ID,
-            ProgramError::IllegalOwner,
+            PlasmaError::NotOwnedByPlasmaProgram,
             "Pool must be owned by the Plasma program",
         )?;
         Ok(Self { info })
@@ -32,41 +41,57 @@ impl<'a, 'info> PoolAccountInfo<'a, 'info> {
 
     pub(crate) fn new(
         info: &'a AccountInfo<'info>,
+    ) -> Result<PoolAccountInfo<'a, 'info>, ProgramError> {
+        let pool_info = Self::_new_checked_discriminator(info)?;
+        assert_with_msg(
+            info.data_len() != LEGACY_POOL_ACCOUNT_SIZE,
+            PlasmaError::PoolMigrationRequired,
+            "Pool must be migrated with MigratePool",
+        )?;
+        Ok(pool_info)
+    }
+
+    /// Pools with the legacy layout are only loaded to be migrated
+    pub(crate) fn new_legacy(
+        info: &'a AccountInfo<'info>,
+    ) -> Result<PoolAccountInfo<'a, 'info>, ProgramError> {
+        let pool_info = Self::_new_checked_discriminator(info)?;
+        assert_with_msg(
+            info.data_len() == LEGACY_POOL_ACCOUNT_SIZE,
+            PlasmaError::PoolAlreadyMigrated,
+            "Pool does not have the legacy layout",
+        )?;
+        Ok(pool_info)
+    }
+
+    fn _new_checked_discriminator(
+        info: &'a AccountInfo<'info>,
     ) -> Result<PoolAccountInfo<'a, 'info>, ProgramError> {
         let pool_info = Self::_new_unchecked(info)?;
         {
             let header = pool_info.get_header()?;
             assert_with_msg(
                 header.discriminator == POOL_ACCOUNT_DISCRIMINATOR,
-                ProgramError::InvalidAccountData,
+                PlasmaError::InvalidPoolAccount,
                 "Invalid pool discriminant",
             )?;
         }
         Ok(pool_info)
     }
 
+    /// The pool account is created by `InitializePool` at its PDA, so it must not exist yet
     pub(crate) fn new_init(
         info: &'a AccountInfo<'info>,
     ) -> Result<PoolAccountInfo<'a, 'info>, ProgramError> {
-        let pool_bytes = info.try_borrow_data()?;
-        let (header_bytes, _) = pool_bytes.split_at(size_of::<PoolHeader>());
-        let header = try_from_bytes::<PoolHeader>(header_bytes)
-            .map_err(|_| ProgramError::InvalidAccountData)?;
         assert_with_msg(
-            info.owner == &crate::ID,
-            ProgramError::IllegalOwner,
-            "Pool must be owned by the Plasma program",
+            info.data_is_empty(),
+            PlasmaError::PoolAlreadyInitialized,
+            "Pool account must be uninitialized",
         )?;
-        // On initialization, the discriminant is not set yet.
         assert_with_msg(
-            u64::from_le_bytes(header.discriminator) == 0,
-            ProgramError::InvalidAccountData,
-            "Expected uninitialized pool with discriminant 0",
-        )?;
-        assert_with_msg(
-            header.sequence_number == 0,
-            ProgramError::InvalidAccountData,
-            "PoolStatus must be uninitialized (sequence_number = 0)",
+            info.owner == &system_program::id(),
+            PlasmaError::EmptyAccountNotOwnedBySystemProgram,
+            "Empty accounts must be owned by the system program",
         )?;
         Ok(Self { info })
     }
@@ -105,32 +130,56 @@ impl<'a, 'info> LpPositionAccountInfo<'a, 'info> {
         trader: &Pubkey,
     ) -> Result<LpPositionAccountInfo<'a, 'info>, ProgramError> {
         let (lp_position_address, _) = get_lp_position_address(pool, trader);
+        Self::new_with_address(info, &lp_position_address, pool, trader)
+    }
+
+    /// The escrow of the tokenized LP shares of a pool is an LP position owned by the LP mint
+    pub(crate) fn new_lp_escrow(
+        info: &'a AccountInfo<'info>,
+        pool: &Pubkey,
+        lp_mint: &Pubkey,
+    ) -> Result<LpPositionAccountInfo<'a, 'info>, ProgramError> {
+        let (lp_escrow_address, _) = get_lp_escrow_address(pool);
+        Self::new_with_address(info, &lp_escrow_address, pool, lp_mint)
+    }
+
+    fn new_with_address(
+        info: &'a AccountInfo<'info>,
+        lp_position_address: &Pubkey,
+        pool: &Pubkey,
+        trader: &Pubkey,
+    ) -> Result<LpPositionAccountInfo<'a, 'info>, ProgramError> {
         assert_with_msg(
             info.owner == &crate::ID,
-            ProgramError::IllegalOwner,
+            PlasmaError::NotOwnedByPlasmaProgram,
             "LP position account must be owned by the Plasma program",
         )?;
         assert_with_msg(
-            &lp_position_address == info.key,
-            ProgramError::InvalidInstructionData,
+            lp_position_address == info.key,
+            PlasmaError::InvalidLpPositionAddress,
             "Invalid address for LP position",
         )?;
+        assert_with_msg(
+            info.data_len() != LEGACY_LP_POSITION_ACCOUNT_SIZE,
+            PlasmaError::LpPositionMigrationRequired,
+            "LP position must be migrated with MigrateLpPosition",
+        )?;
         let lp_position_bytes = info.try_borrow_data()?;
         let lp_position = try_from_bytes::<LpPositionAccount>(&lp_position_bytes)
-            .map_err(|_| ProgramError::InvalidAccountData)?;
+            .map_err(|_| PlasmaError::InvalidLpPositionAccount)?;
         assert_with_msg(
             lp_position.discriminator == LP_POSITION_ACCOUNT_DISCRIMINATOR,
-            ProgramError::InvalidAccountData,
+            PlasmaError::InvalidLpPositionAccount,
             "Invalid discriminant for seat",
         )?;
         assert_with_msg(
             &lp_position.authority == trader,
-            ProgramError::InvalidAccountData,
+            PlasmaError::LpPositionAuthorityMismatch,
             "Invalid authority for LP position",
         )?;
         assert_with_msg(
             &lp_position.pool == pool,
-            ProgramError::InvalidAccountData,
+            PlasmaError::LpPositionPoolMismatch,
             "Invalid pool for LP position",
         )?;
         Ok(Self { info })
@@ -150,3 +199,137 @@ impl<'a, 'info> Deref for LpPositionAccountInfo<'a, 'info> {
         self.info
     }
 }
+
+#[derive(Clone)]
+pub(crate) struct ObservationsAccountInfo<'a, 'info> {
+    pub(crate) info: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> ObservationsAccountInfo<'a, 'info> {
+    pub(crate) fn new(
+        info: &'a AccountInfo<'info>,
+        pool: &Pubkey,
+    ) -> Result<ObservationsAccountInfo<'a, 'info>, ProgramError> {
+        let (observations_address, _) = get_observations_address(pool);
+        assert_with_msg(
+            info.owner == &crate::ID,
+            PlasmaError::NotOwnedByPlasmaProgram,
+            "Observations account must be owned by the Plasma program",
+        )?;
+        assert_with_msg(
+            &observations_address == info.key,
+            PlasmaError::InvalidObservationsAddress,
+            "Invalid address for observations account",
+        )?;
+        let observations_bytes = info.try_borrow_data()?;
+        let observations = try_from_bytes::<ObservationsAccount>(
+            &observations_bytes[..size_of::<ObservationsAccount>().min(observations_bytes.len())],
+        )
+        .map_err(|_| PlasmaError::InvalidObservationsAccount)?;
+        assert_with_msg(
+            observations.discriminator == OBSERVATIONS_ACCOUNT_DISCRIMINATOR,
+            PlasmaError::InvalidObservationsAccount,
+            "Invalid discriminant for observations account",
+        )?;
+        assert_with_msg(
+            &observations.pool == pool,
+            PlasmaError::ObservationsPoolMismatch,
+            "Invalid pool for observations account",
+        )?;
+        Ok(Self { info })
+    }
+
+    pub(crate) fn get_capacity(&self) -> u64 {
+        ObservationsAccount::get_capacity(self.info.data_len())
+    }
+
+    /// Records the accumulators of the pool if the snapshot rolled since the last observation
+    pub(crate) fn record(&self, amm: &Amm) -> Result<bool, ProgramError> {
+        let mut observations_bytes = self.info.try_borrow_mut_data()?;
+        let (header, entries) = ObservationsAccount::split_mut(&mut observations_bytes)?;
+        header.buffer.write(entries, amm).map_err(|e| {
+            msg!("Failed to record observation: {}", e);
+            PlasmaError::from(e).into()
+        })
+    }
+}
+
+impl<'a, 'info> AsRef<AccountInfo<'info>> for ObservationsAccountInfo<'a, 'info> {
+    fn as_ref(&self) -> &AccountInfo<'info> {
+        self.info
+    }
+}
+
+impl<'a, 'info> Deref for ObservationsAccountInfo<'a, 'info> {
+    type Target = AccountInfo<'info>;
+
+    fn deref(&self) -> &Self::Target {
+        self.info
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct FeeTierConfigAccountInfo<'a, 'info> {
+    pub(crate) info: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> FeeTierConfigAccountInfo<'a, 'info> {
+    pub(crate) fn new(
+        info: &'a AccountInfo<'info>,
+    ) -> Result<FeeTierConfigAccountInfo<'a, 'info>, ProgramError> {
+        assert_with_msg(
+            info.owner == &crate::ID,
+            PlasmaError::NotOwnedByPlasmaProgram,
+            "Fee tier config account must be owned by the Plasma program",
+        )?;
+        let config_info = Self::new_init(info)?;
+        {
+            let config = config_info.get_config()?;
+            assert_with_msg(
+                config.discriminator == FEE_TIER_CONFIG_ACCOUNT_DISCRIMINATOR,
+                PlasmaError::InvalidFeeTierConfigAccount,
+                "Invalid discriminant for fee tier config account",
+            )?;
+        }
+        Ok(config_info)
+    }
+
+    /// Only checks the address, the account is created by `InitializeFeeTierConfig`
+    pub(crate) fn new_init(
+        info: &'a AccountInfo<'info>,
+    ) -> Result<FeeTierConfigAccountInfo<'a, 'info>, ProgramError> {
+        let (config_address, _) = get_fee_tier_config_address();
+        assert_with_msg(
+            &config_address == info.key,
+            PlasmaError::InvalidFeeTierConfigAccount,
+            "Invalid address for fee tier config account",
+        )?;
+        Ok(Self { info })
+    }
+
+    pub(crate) fn get_config(&self) -> Result<Ref<'_, FeeTierConfigAccount>, ProgramError> {
+        let data = self.info.try_borrow_data()?;
+        assert_with_msg(
+            data.len() == size_of::<FeeTierConfigAccount>(),
+            PlasmaError::InvalidFeeTierConfigAccount,
+            "Invalid size for fee tier config account",
+        )?;
+        Ok(Ref::map(data, |data| {
+            try_from_bytes::<FeeTierConfigAccount>(data).unwrap()
+        }))
+    }
+}
+
+impl<'a, 'info> AsRef<AccountInfo<'info>> for FeeTierConfigAccountInfo<'a, 'info> {
+    fn as_ref(&self) -> &AccountInfo<'info> {
+        self.info
+    }
+}
+
+impl<'a, 'info> Deref for FeeTierConfigAccountInfo<'a, 'info> {
+    type Target = AccountInfo<'info>;
+
+    fn deref(&self) -> &Self::Target {
+        self.info
+    }
+}
@@ -1,26 +1,91 @@
 use crate::assert_with_msg;
+use crate::program::error::PlasmaError;
 use solana_program::{
-    account_info::AccountInfo, program_error::ProgramError, program_pack::Pack, pubkey::Pubkey,
+    account_info::AccountInfo, clock::Clock, program_error::ProgramError,
+    program_pack::IsInitialized, pubkey::Pubkey, sysvar::Sysvar,
+};
+use spl_token_2022::{
+    extension::{
+        transfer_fee::{TransferFee, TransferFeeAmount, TransferFeeConfig},
+        BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+    },
+    state::{Account, Mint},
 };
-use spl_token::state::{Account, Mint};
 use std::ops::Deref;
 
+/// Returns true if the account is owned by either the Token program or the Token-2022 program
+pub fn is_owned_by_token_program(info: &AccountInfo) -> bool {
+    info.owner == &spl_token::id() || info.owner == &spl_token_2022::id()
+}
+
 #[derive(Clone)]
 pub struct MintAccountInfo<'a, 'info> {
     pub mint: Mint,
+    /// Transfer fee of the current epoch, only set for Token-2022 mints with the transfer fee extension
+    pub transfer_fee: Option<TransferFee>,
     pub info: &'a AccountInfo<'info>,
 }
 
 impl<'a, 'info> MintAccountInfo<'a, 'info> {
     pub fn new(info: &'a AccountInfo<'info>) -> Result<MintAccountInfo<'a, 'info>, ProgramError> {
         assert_with_msg(
-            info.owner == &spl_token::id(),
-            ProgramError::IllegalOwner,
-            "Mint account must be owned by the Token Program",
+            is_owned_by_token_program(info),
+            PlasmaError::NotOwnedByTokenProgram,
+            "Mint account must be owned by the Token Program or the Token-2022 Program",
+        )?;
+        let data = info.try_borrow_data()?;
+        let state = StateWithExtensions::<Mint>::unpack(&data)?;
+        let transfer_fee = match state.get_extension::<TransferFeeConfig>() {
+            Ok(config) => Some(*config.get_epoch_fee(Clock::get()?.epoch)),
+            Err(_) => None,
+        };
+
+        Ok(Self {
+            mint: state.base,
+            transfer_fee,
+            info,
+        })
+    }
+
+    pub fn new_with_key(
+        info: &'a AccountInfo<'info>,
+        key: &Pubkey,
+    ) -> Result<MintAccountInfo<'a, 'info>, ProgramError> {
+        assert_with_msg(
+            info.key == key,
+            PlasmaError::IncorrectAccountKey,
+            "Invalid pubkey for Mint",
         )?;
-        let mint = Mint::unpack(&info.try_borrow_data()?)?;
+        Self::new(info)
+    }
+
+    /// Returns the extensions enabled on the mint, this is empty for Token program mints
+    pub fn get_extension_types(&self) -> Result<Vec<ExtensionType>, ProgramError> {
+        if self.info.owner != &spl_token_2022::id() {
+            return Ok(vec![]);
+        }
+        let data = self.info.try_borrow_data()?;
+        StateWithExtensions::<Mint>::unpack(&data)?.get_extension_types()
+    }
 
-        Ok(Self { mint, info })
+    /// Amount received by the destination of a transfer of `amount`
+    pub fn get_post_fee_amount(&self, amount: u64) -> Result<u64, ProgramError> {
+        match &self.transfer_fee {
+            Some(transfer_fee) => transfer_fee
+                .calculate_post_fee_amount(amount)
+                .ok_or_else(|| PlasmaError::Overflow.into()),
+            None => Ok(amount),
+        }
+    }
+
+    /// Amount that must be transferred for the destination to receive `post_fee_amount`
+    pub fn get_pre_fee_amount(&self, post_fee_amount: u64) -> Result<u64, ProgramError> {
+        match &self.transfer_fee {
+            Some(transfer_fee) if post_fee_amount > 0 => transfer_fee
+                .calculate_pre_fee_amount(post_fee_amount)
+                .ok_or_else(|| PlasmaError::Overflow.into()),
+            _ => Ok(post_fee_amount),
+        }
     }
 }
 
@@ -48,23 +113,7 @@ impl<'a, 'info> TokenAccountInfo<'a, 'info> {
         info: &'a AccountInfo<'info>,
         mint: &Pubkey,
     ) -> Result<TokenAccountInfo<'a, 'info>, ProgramError> {
-        assert_with_msg(
-            info.owner == &spl_token::id(),
-            ProgramError::IllegalOwner,
-            "Token account must be owned by the Token Program",
-        )?;
-        assert_with_msg(
-            info.data_len() == Account::LEN,
-            ProgramError::InvalidAccountData,
-            "Token account data length must be 165 bytes",
-        )?;
-        // The mint key is found at offset 0 of the token account
-        assert_with_msg(
-            &info.try_borrow_data()?[0..32] == mint.as_ref(),
-            ProgramError::InvalidAccountData,
-            "Token account mint mismatch",
-        )?;
-        Ok(Self { info })
+        Self::new_with_state(info, mint).map(|(token_account_info, _)| token_account_info)
     }
 
     pub fn new_with_owner(
@@ -72,16 +121,45 @@ impl<'a, 'info> TokenAccountInfo<'a, 'info> {
         mint: &Pubkey,
         owner: &Pubkey,
     ) -> Result<TokenAccountInfo<'a, 'info>, ProgramError> {
-        let token_account_info = Self::new(info, mint)?;
-        // The owner key is found at offset 32 of the token account
+        let (token_account_info, account) = Self::new_with_state(info, mint)?;
         assert_with_msg(
-            &info.try_borrow_data()?[32..64] == owner.as_ref(),
-            ProgramError::IllegalOwner,
+            &account.owner == owner,
+            PlasmaError::TokenAccountOwnerMismatch,
             "Token account owner mismatch",
         )?;
         Ok(token_account_info)
     }
 
+    /// Checks that the account is an initialized token account of `mint` and returns its base state.
+    /// Token-2022 accounts with extensions are longer than the base account.
+    fn new_with_state(
+        info: &'a AccountInfo<'info>,
+        mint: &Pubkey,
+    ) -> Result<(TokenAccountInfo<'a, 'info>, Account), ProgramError> {
+        assert_with_msg(
+            is_owned_by_token_program(info),
+            PlasmaError::NotOwnedByTokenProgram,
+            "Token account must be owned by the Token Program or the Token-2022 Program",
+        )?;
+        let account = {
+            let data = info.try_borrow_data()?;
+            StateWithExtensions::<Account>::unpack(&data)
+                .map_err(|_| PlasmaError::InvalidTokenAccountData)?
+                .base
+        };
+        assert_with_msg(
+            account.is_initialized(),
+            PlasmaError::InvalidTokenAccountData,
+            "Token account is not initialized",
+        )?;
+        assert_with_msg(
+            &account.mint == mint,
+            PlasmaError::TokenAccountMintMismatch,
+            "Token account mint mismatch",
+        )?;
+        Ok((Self { info }, account))
+    }
+
     pub fn new_with_owner_and_key(
         info: &'a AccountInfo<'info>,
         mint: &Pubkey,
@@ -90,7 +168,7 @@ impl<'a, 'info> TokenAccountInfo<'a, 'info> {
     ) -> Result<TokenAccountInfo<'a, 'info>, ProgramError> {
         assert_with_msg(
             info.key == key,
-            ProgramError::InvalidInstructionData,
+            PlasmaError::IncorrectTokenAccountKey,
             "Invalid pubkey for Token Account",
         )?;
         Self::new_with_owner(info, mint, owner)
@@ -102,9 +180,26 @@ impl<'a, 'info> TokenAccountInfo<'a, 'info> {
     pub fn amount(&self) -> Result<u64, ProgramError> {
         let bytes = self.info.try_borrow_data()?;
         let mut amount_bytes = [0u8; 8];
-        amount_bytes.copy_from_slice(&bytes[64..72]);
+        amount_bytes.copy_from_slice(
+            bytes
+                .get(64..72)
+                .ok_or(PlasmaError::InvalidTokenAccountData)?,
+        );
         Ok(u64::from_le_bytes(amount_bytes))
     }
+
+    /// Transfer fees withheld in the account, only Token-2022 accounts of mints with a transfer
+    /// fee hold any
+    pub fn withheld_amount(&self) -> Result<u64, ProgramError> {
+        if self.info.owner != &spl_token_2022::id() {
+            return Ok(0);
+        }
+        let data = self.info.try_borrow_data()?;
+        let state = StateWithExtensions::<Account>::unpack(&data)?;
+        Ok(state
+            .get_extension::<TransferFeeAmount>()
+            .map_or(0, |extension| u64::from(extension.withheld_amount)))
+    }
 }
 
 impl<'a, 'info> AsRef<AccountInfo<'info>> for TokenAccountInfo<'a, 'info> {
@@ -8,12 +8,16 @@
 //! The loader structs are used to validate the accounts passed into the program based on the
 //! current instruction.
 
-use super::checkers::plasma_checkers::LpPositionAccountInfo;
+use super::checkers::plasma_checkers::{FeeTierConfigAccountInfo, LpPositionAccountInfo};
 use super::checkers::{plasma_checkers::PoolAccountInfo, MintAccountInfo, TokenAccountInfo, PDA};
 use crate::assert_with_msg;
-use crate::program::accounts::{PoolAccount, TokenParams};
+use crate::program::accounts::{FeeTierConfigAccount, PoolAccount, TokenParams};
+use crate::program::error::PlasmaError;
 use crate::program::events::PlasmaEvent;
 use crate::program::instruction::PlasmaInstruction;
+use crate::program::token_utils::{
+    is_native_mint, maybe_create_temporary_wsol_account, TemporaryWsolAccount,
+};
 use crate::{
     plasma_log_authority,
     program::validation::checkers::{EmptyAccount, Program, Signer},
@@ -21,6 +25,7 @@ use crate::{
 use borsh::{BorshDeserialize, BorshSerialize};
 use bytemuck::try_from_bytes_mut;
 use core::slice::Iter;
+use plasma_state::amm::Side;
 use solana_program::clock::Clock;
 use solana_program::instruction::{AccountMeta, Instruction};
 use solana_program::log::sol_log_data;
@@ -28,11 +33,41 @@ use solana_program::program::invoke_signed;
 use solana_program::sysvar::Sysvar;
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
+    bpf_loader_upgradeable,
     program_error::ProgramError,
     pubkey::Pubkey,
     system_program,
 };
 
+/// Pools are keyed by their mint pair and fee tier, the fee tier is the LP fee in basis points
+/// that the pool was created with. The mints are sorted in the seeds so that there is a single
+/// pool per pair and fee tier regardless of which mint is the base.
+pub fn get_pool_address(base_mint: &Pubkey, quote_mint: &Pubkey, fee_tier: u64) -> (Pubkey, u8) {
+    let (mint_a, mint_b) = sort_mints(base_mint, quote_mint);
+    Pubkey::find_program_address(
+        &[
+            b"pool",
+            mint_a.as_ref(),
+            mint_b.as_ref(),
+            &fee_tier.to_le_bytes(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Returns the mints of a pair in the order used by the pool seeds
+pub fn sort_mints<'a>(base_mint: &'a Pubkey, quote_mint: &'a Pubkey) -> (&'a Pubkey, &'a Pubkey) {
+    if base_mint <= quote_mint {
+        (base_mint, quote_mint)
+    } else {
+        (quote_mint, base_mint)
+    }
+}
+
+pub fn get_fee_tier_config_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"fee_tier_config"], &crate::ID)
+}
+
 pub fn get_vault_address(pool: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[b"vault", pool.as_ref(), mint.as_ref()], &crate::ID)
 }
@@ -44,6 +79,40 @@ pub fn get_lp_position_address(pool: &Pubkey, trader: &Pubkey) -> (Pubkey, u8) {
     )
 }
 
+/// The LP mint is its own mint authority
+pub fn get_lp_mint_address(pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"lp_mint", pool.as_ref()], &crate::ID)
+}
+
+/// LP position that holds the shares backing the LP tokens of a pool
+pub fn get_lp_escrow_address(pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"lp_escrow", pool.as_ref()], &crate::ID)
+}
+
+pub fn get_observations_address(pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"observations", pool.as_ref()], &crate::ID)
+}
+
+/// wSOL account that the trading instructions create and close within the instruction, so that
+/// native SOL can be traded without a wSOL account
+pub fn get_temporary_wsol_address(trader: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"wsol", trader.as_ref()], &crate::ID)
+}
+
+/// Loads a token account of the trader. For the native mint, an empty account is accepted in its
+/// place: it must be the trader's temporary wSOL account, whose key is checked when the instruction
+/// creates it. Instructions that do not create it fail when transferring from or to it.
+fn load_trader_token_account<'a, 'info>(
+    info: &'a AccountInfo<'info>,
+    mint: &Pubkey,
+    trader_key: &Pubkey,
+) -> Result<TokenAccountInfo<'a, 'info>, ProgramError> {
+    if is_native_mint(mint) && info.data_is_empty() {
+        return Ok(TokenAccountInfo { info });
+    }
+    TokenAccountInfo::new_with_owner(info, mint, trader_key)
+}
+
 pub(crate) struct PlasmaLogContext<'a, 'info> {
     pub(crate) plasma_program: Program<'a, 'info>,
     pub(crate) log_authority: PDA<'a, 'info>,
@@ -74,7 +143,7 @@ impl<'a, 'info> PlasmaLogContext<'a, 'info> {
         let (sequence_number, base_decimals, quote_decimals) = {
             let mut pool_bytes = pool_context.pool_info.try_borrow_mut_data()?;
             let pool = try_from_bytes_mut::<PoolAccount>(&mut pool_bytes)
-                .map_err(|_| ProgramError::InvalidAccountData)?;
+                .map_err(|_| PlasmaError::InvalidPoolAccount)?;
             let sequence_number = pool.header.sequence_number;
             pool.increment_sequence_number();
             (
@@ -83,7 +152,7 @@ impl<'a, 'info> PlasmaLogContext<'a, 'info> {
                 pool.header.quote_params.decimals as u8,
             )
         };
-        let plasma_event = PlasmaEvent {
+        self.log_event(PlasmaEvent {
             instruction: instruction as u8,
             sequence_number,
             slot: clock.slot,
@@ -93,8 +162,42 @@ impl<'a, 'info> PlasmaLogContext<'a, 'info> {
             base_decimals,
             quote_decimals,
             event,
+        })
+    }
+
+    /// Fee tier config events are recorded with the config account in place of the pool
+    pub(crate) fn record_fee_tier_config_event<T: BorshSerialize + BorshDeserialize + Clone>(
+        &self,
+        instruction: PlasmaInstruction,
+        config_context: &FeeTierConfigContext<'a, 'info>,
+        event: T,
+    ) -> Result<(), ProgramError> {
+        let clock = Clock::get()?;
+        let sequence_number = {
+            let mut config_bytes = config_context.config_info.try_borrow_mut_data()?;
+            let config = try_from_bytes_mut::<FeeTierConfigAccount>(&mut config_bytes)
+                .map_err(|_| PlasmaError::InvalidFeeTierConfigAccount)?;
+            let sequence_number = config.sequence_number;
+            config.sequence_number += 1;
+            sequence_number
         };
+        self.log_event(PlasmaEvent {
+            instruction: instruction as u8,
+            sequence_number,
+            slot: clock.slot,
+            timestamp: clock.unix_timestamp,
+            signer: *config_context.signer.key,
+            pool: *config_context.config_info.key,
+            base_decimals: 0,
+            quote_decimals: 0,
+            event,
+        })
+    }
 
+    fn log_event<T: BorshSerialize + BorshDeserialize + Clone>(
+        &self,
+        plasma_event: PlasmaEvent<T>,
+    ) -> Result<(), ProgramError> {
         let event_vec = plasma_event.try_to_vec()?;
         sol_log_data(&[&event_vec]);
 
@@ -138,9 +241,91 @@ impl<'a, 'info> PlasmaPoolContext<'a, 'info> {
     ) -> Result<Self, ProgramError> {
         Ok(Self {
             pool_info: PoolAccountInfo::new_init(next_account_info(account_iter)?)?,
+            // The pool creator pays for the pool account and its vaults
+            signer: Signer::new_payer(next_account_info(account_iter)?)?,
+        })
+    }
+
+    /// The signer pays the rent of the accumulators that are appended to the pool
+    pub(crate) fn load_legacy(
+        account_iter: &mut Iter<'a, AccountInfo<'info>>,
+    ) -> Result<Self, ProgramError> {
+        Ok(Self {
+            pool_info: PoolAccountInfo::new_legacy(next_account_info(account_iter)?)?,
+            signer: Signer::new_payer(next_account_info(account_iter)?)?,
+        })
+    }
+}
+
+/// Accounts of the instructions that manage the fee tier config, which take the place of the
+/// pool and signer accounts of pool instructions
+pub(crate) struct FeeTierConfigContext<'a, 'info> {
+    pub(crate) config_info: FeeTierConfigAccountInfo<'a, 'info>,
+    pub(crate) signer: Signer<'a, 'info>,
+}
+
+impl<'a, 'info> FeeTierConfigContext<'a, 'info> {
+    pub(crate) fn load(
+        account_iter: &mut Iter<'a, AccountInfo<'info>>,
+    ) -> Result<Self, ProgramError> {
+        Ok(Self {
+            config_info: FeeTierConfigAccountInfo::new(next_account_info(account_iter)?)?,
             signer: Signer::new(next_account_info(account_iter)?)?,
         })
     }
+
+    pub(crate) fn load_init(
+        account_iter: &mut Iter<'a, AccountInfo<'info>>,
+    ) -> Result<Self, ProgramError> {
+        Ok(Self {
+            config_info: FeeTierConfigAccountInfo::new_init(next_account_info(account_iter)?)?,
+            signer: Signer::new_payer(next_account_info(account_iter)?)?,
+        })
+    }
+}
+
+pub(crate) struct InitializeFeeTierConfigContext<'a, 'info> {
+    pub(crate) config: EmptyAccount<'a, 'info>,
+    pub(crate) system_program: Program<'a, 'info>,
+}
+
+impl<'a, 'info> InitializeFeeTierConfigContext<'a, 'info> {
+    /// The fee tier config can only be created by the upgrade authority of the program
+    pub(crate) fn load(
+        config_context: &FeeTierConfigContext<'a, 'info>,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let account_iter = &mut accounts.iter();
+        let program_data = next_account_info(account_iter)?;
+        let (program_data_address, _) =
+            Pubkey::find_program_address(&[crate::id().as_ref()], &bpf_loader_upgradeable::id());
+        assert_with_msg(
+            program_data.key == &program_data_address,
+            PlasmaError::IncorrectAccountKey,
+            "Invalid program data account",
+        )?;
+        // Program data accounts start with the u32 state tag (3), the deployment slot and the
+        // optional upgrade authority
+        let upgrade_authority = {
+            let data = program_data.try_borrow_data()?;
+            match (data.get(..4), data.get(12), data.get(13..45)) {
+                (Some(tag), Some(&1), Some(authority)) if tag == 3u32.to_le_bytes() => Some(
+                    Pubkey::try_from(authority)
+                        .map_err(|_| PlasmaError::InvalidFeeTierConfigAdmin)?,
+                ),
+                _ => None,
+            }
+        };
+        assert_with_msg(
+            upgrade_authority.as_ref() == Some(config_context.signer.key),
+            PlasmaError::InvalidFeeTierConfigAdmin,
+            "The fee tier config must be initialized by the program upgrade authority",
+        )?;
+        Ok(Self {
+            config: EmptyAccount::new(config_context.config_info.info)?,
+            system_program: Program::new(next_account_info(account_iter)?, &system_program::id())?,
+        })
+    }
 }
 
 /// These accounts that are required for all pool actions that interact with a token vault
@@ -149,7 +334,10 @@ pub(crate) struct PlasmaVaultContext<'a, 'info> {
     pub(crate) quote_account: TokenAccountInfo<'a, 'info>,
     pub(crate) base_vault: TokenAccountInfo<'a, 'info>,
     pub(crate) quote_vault: TokenAccountInfo<'a, 'info>,
-    pub(crate) token_program: Program<'a, 'info>,
+    pub(crate) base_mint: MintAccountInfo<'a, 'info>,
+    pub(crate) quote_mint: MintAccountInfo<'a, 'info>,
+    pub(crate) base_token_program: Program<'a, 'info>,
+    pub(crate) quote_token_program: Program<'a, 'info>,
 }
 
 impl<'a, 'info> PlasmaVaultContext<'a, 'info> {
@@ -159,32 +347,74 @@ impl<'a, 'info> PlasmaVaultContext<'a, 'info> {
         quote_params: &TokenParams,
         trader_key: &Pubkey,
     ) -> Result<Self, ProgramError> {
+        let base_account = load_trader_token_account(
+            next_account_info(account_iter)?,
+            &base_params.mint_key,
+            trader_key,
+        )?;
+        let quote_account = load_trader_token_account(
+            next_account_info(account_iter)?,
+            &quote_params.mint_key,
+            trader_key,
+        )?;
+        let base_vault = TokenAccountInfo::new_with_owner_and_key(
+            next_account_info(account_iter)?,
+            &base_params.mint_key,
+            &base_params.vault_key,
+            &base_params.vault_key,
+        )?;
+        let quote_vault = TokenAccountInfo::new_with_owner_and_key(
+            next_account_info(account_iter)?,
+            &quote_params.mint_key,
+            &quote_params.vault_key,
+            &quote_params.vault_key,
+        )?;
+        let base_mint =
+            MintAccountInfo::new_with_key(next_account_info(account_iter)?, &base_params.mint_key)?;
+        let quote_mint = MintAccountInfo::new_with_key(
+            next_account_info(account_iter)?,
+            &quote_params.mint_key,
+        )?;
+        // The token programs of the pool are the owners of the vaults
+        let base_token_program = Program::new(next_account_info(account_iter)?, base_vault.owner)?;
+        let quote_token_program =
+            Program::new(next_account_info(account_iter)?, quote_vault.owner)?;
         Ok(Self {
-            base_account: TokenAccountInfo::new_with_owner(
-                next_account_info(account_iter)?,
-                &base_params.mint_key,
-                trader_key,
-            )?,
-            quote_account: TokenAccountInfo::new_with_owner(
-                next_account_info(account_iter)?,
-                &quote_params.mint_key,
-                trader_key,
-            )?,
-            base_vault: TokenAccountInfo::new_with_owner_and_key(
-                next_account_info(account_iter)?,
-                &base_params.mint_key,
-                &base_params.vault_key,
-                &base_params.vault_key,
-            )?,
-            quote_vault: TokenAccountInfo::new_with_owner_and_key(
-                next_account_info(account_iter)?,
-                &quote_params.mint_key,
-                &quote_params.vault_key,
-                &quote_params.vault_key,
-            )?,
-            token_program: Program::new(next_account_info(account_iter)?, &spl_token::id())?,
+            base_account,
+            quote_account,
+            base_vault,
+            quote_vault,
+            base_mint,
+            quote_mint,
+            base_token_program,
+            quote_token_program,
         })
     }
+
+    /// Creates the trader's temporary wSOL account if it was passed in place of one of their
+    /// token accounts
+    pub(crate) fn maybe_create_temporary_wsol_account(
+        &self,
+        trader: &AccountInfo<'info>,
+        system_program: Option<&AccountInfo<'info>>,
+    ) -> Result<Option<TemporaryWsolAccount<'info>>, ProgramError> {
+        maybe_create_temporary_wsol_account(
+            [
+                (
+                    self.base_account.info,
+                    self.base_mint.info,
+                    self.base_token_program.as_ref(),
+                ),
+                (
+                    self.quote_account.info,
+                    self.quote_mint.info,
+                    self.quote_token_program.as_ref(),
+                ),
+            ],
+            trader,
+            system_program,
+        )
+    }
 }
 
 pub(crate) struct InitializePoolContext<'a, 'info> {
@@ -193,34 +423,116 @@ pub(crate) struct InitializePoolContext<'a, 'info> {
     pub(crate) base_vault: EmptyAccount<'a, 'info>,
     pub(crate) quote_vault: EmptyAccount<'a, 'info>,
     pub(crate) system_program: Program<'a, 'info>,
-    pub(crate) token_program: Program<'a, 'info>,
+    pub(crate) base_token_program: Program<'a, 'info>,
+    pub(crate) quote_token_program: Program<'a, 'info>,
+    pub(crate) fee_tier_config: FeeTierConfigAccountInfo<'a, 'info>,
 }
 
 impl<'a, 'info> InitializePoolContext<'a, 'info> {
     pub(crate) fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
         let account_iter = &mut accounts.iter();
+        let base_mint = MintAccountInfo::new(next_account_info(account_iter)?)?;
+        let quote_mint = MintAccountInfo::new(next_account_info(account_iter)?)?;
         let ctx = Self {
-            base_mint: MintAccountInfo::new(next_account_info(account_iter)?)?,
-            quote_mint: MintAccountInfo::new(next_account_info(account_iter)?)?,
             base_vault: EmptyAccount::new(next_account_info(account_iter)?)?,
             quote_vault: EmptyAccount::new(next_account_info(account_iter)?)?,
             system_program: Program::new(next_account_info(account_iter)?, &system_program::id())?,
-            token_program: Program::new(next_account_info(account_iter)?, &spl_token::id())?,
+            // Each token program must be the owner of the corresponding mint
+            base_token_program: Program::new(
+                next_account_info(account_iter)?,
+                base_mint.info.owner,
+            )?,
+            quote_token_program: Program::new(
+                next_account_info(account_iter)?,
+                quote_mint.info.owner,
+            )?,
+            fee_tier_config: FeeTierConfigAccountInfo::new(next_account_info(account_iter)?)?,
+            base_mint,
+            quote_mint,
         };
 
         assert_with_msg(
             ctx.base_mint.info.key != ctx.quote_mint.info.key,
-            ProgramError::InvalidArgument,
+            PlasmaError::IdenticalBaseAndQuoteMints,
             "Base mint and quote mint must be different",
         )?;
         Ok(ctx)
     }
 }
 
+pub(crate) struct ClosePoolContext<'a, 'info> {
+    pub(crate) base_account: TokenAccountInfo<'a, 'info>,
+    pub(crate) quote_account: TokenAccountInfo<'a, 'info>,
+    pub(crate) base_vault: TokenAccountInfo<'a, 'info>,
+    pub(crate) quote_vault: TokenAccountInfo<'a, 'info>,
+    pub(crate) base_mint: MintAccountInfo<'a, 'info>,
+    pub(crate) quote_mint: MintAccountInfo<'a, 'info>,
+    pub(crate) base_token_program: Program<'a, 'info>,
+    pub(crate) quote_token_program: Program<'a, 'info>,
+}
+
+impl<'a, 'info> ClosePoolContext<'a, 'info> {
+    pub(crate) fn load(
+        pool_context: &PlasmaPoolContext<'a, 'info>,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let (base_params, quote_params) = {
+            let header = pool_context.pool_info.get_header()?;
+            (header.base_params, header.quote_params)
+        };
+        let pool_authority = pool_context.signer.key;
+        let account_iter = &mut accounts.iter();
+        // The remaining balances of the vaults are swept to the pool authority
+        let base_account = TokenAccountInfo::new_with_owner(
+            next_account_info(account_iter)?,
+            &base_params.mint_key,
+            pool_authority,
+        )?;
+        let quote_account = TokenAccountInfo::new_with_owner(
+            next_account_info(account_iter)?,
+            &quote_params.mint_key,
+            pool_authority,
+        )?;
+        let base_vault = TokenAccountInfo::new_with_owner_and_key(
+            next_account_info(account_iter)?,
+            &base_params.mint_key,
+            &base_params.vault_key,
+            &base_params.vault_key,
+        )?;
+        let quote_vault = TokenAccountInfo::new_with_owner_and_key(
+            next_account_info(account_iter)?,
+            &quote_params.mint_key,
+            &quote_params.vault_key,
+            &quote_params.vault_key,
+        )?;
+        let base_mint =
+            MintAccountInfo::new_with_key(next_account_info(account_iter)?, &base_params.mint_key)?;
+        let quote_mint = MintAccountInfo::new_with_key(
+            next_account_info(account_iter)?,
+            &quote_params.mint_key,
+        )?;
+        let base_token_program = Program::new(next_account_info(account_iter)?, base_vault.owner)?;
+        let quote_token_program =
+            Program::new(next_account_info(account_iter)?, quote_vault.owner)?;
+        Ok(Self {
+            base_account,
+            quote_account,
+            base_vault,
+            quote_vault,
+            base_mint,
+            quote_mint,
+            base_token_program,
+            quote_token_program,
+        })
+    }
+}
+
 pub(crate) struct LiquidityActionContext<'a, 'info> {
     // This is only used for limit order instructions
     pub(crate) lp_position: LpPositionAccountInfo<'a, 'info>,
     pub(crate) vault_context: PlasmaVaultContext<'a, 'info>,
+    /// Only required to wrap native SOL or to create the temporary wSOL account
+    pub(crate) system_program: Option<Program<'a, 'info>>,
 }
 
 impl<'a, 'info> LiquidityActionContext<'a, 'info> {
@@ -249,10 +561,182 @@ impl<'a, 'info> LiquidityActionContext<'a, 'info> {
             &quote_params,
             trader.key,
         )?;
+        let system_program = account_iter
+            .next()
+            .map(|info| Program::new(info, &system_program::id()))
+            .transpose()?;
 
         Ok(Self {
             lp_position,
             vault_context,
+            system_program,
+        })
+    }
+}
+
+pub(crate) struct InitializeLpMintContext<'a, 'info> {
+    pub(crate) lp_mint: EmptyAccount<'a, 'info>,
+    pub(crate) lp_escrow: EmptyAccount<'a, 'info>,
+    pub(crate) token_program: Program<'a, 'info>,
+    pub(crate) system_program: Program<'a, 'info>,
+}
+
+impl<'a, 'info> InitializeLpMintContext<'a, 'info> {
+    pub(crate) fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_iter = &mut accounts.iter();
+        Ok(Self {
+            lp_mint: EmptyAccount::new(next_account_info(account_iter)?)?,
+            lp_escrow: EmptyAccount::new(next_account_info(account_iter)?)?,
+            token_program: Program::new(next_account_info(account_iter)?, &spl_token::id())?,
+            system_program: Program::new(next_account_info(account_iter)?, &system_program::id())?,
+        })
+    }
+}
+
+/// Accounts of the instructions that convert LP shares to LP tokens and back
+pub(crate) struct LpTokenContext<'a, 'info> {
+    pub(crate) lp_position: LpPositionAccountInfo<'a, 'info>,
+    pub(crate) lp_escrow: LpPositionAccountInfo<'a, 'info>,
+    pub(crate) lp_mint: MintAccountInfo<'a, 'info>,
+    pub(crate) lp_token_account: TokenAccountInfo<'a, 'info>,
+    pub(crate) token_program: Program<'a, 'info>,
+}
+
+impl<'a, 'info> LpTokenContext<'a, 'info> {
+    pub(crate) fn load(
+        pool_context: &PlasmaPoolContext<'a, 'info>,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let PlasmaPoolContext {
+            pool_info,
+            signer: trader,
+        } = pool_context;
+        let lp_mint_key = pool_info.get_header()?.lp_mint;
+        assert_with_msg(
+            lp_mint_key != Pubkey::default(),
+            PlasmaError::LpTokensNotEnabled,
+            "LP tokens are not enabled for this pool",
+        )?;
+        let account_iter = &mut accounts.iter();
+        Ok(Self {
+            lp_position: LpPositionAccountInfo::new(
+                next_account_info(account_iter)?,
+                pool_info.key,
+                trader.key,
+            )?,
+            lp_escrow: LpPositionAccountInfo::new_lp_escrow(
+                next_account_info(account_iter)?,
+                pool_info.key,
+                &lp_mint_key,
+            )?,
+            lp_mint: MintAccountInfo::new_with_key(next_account_info(account_iter)?, &lp_mint_key)?,
+            lp_token_account: TokenAccountInfo::new_with_owner(
+                next_account_info(account_iter)?,
+                &lp_mint_key,
+                trader.key,
+            )?,
+            token_program: Program::new(next_account_info(account_iter)?, &spl_token::id())?,
+        })
+    }
+}
+
+pub(crate) struct TransferLpPositionContext<'a, 'info> {
+    pub(crate) lp_position: LpPositionAccountInfo<'a, 'info>,
+    pub(crate) recipient: &'a AccountInfo<'info>,
+    /// Created by the instruction if it does not exist, so it is validated by the processor
+    pub(crate) recipient_lp_position: &'a AccountInfo<'info>,
+    pub(crate) system_program: Program<'a, 'info>,
+}
+
+impl<'a, 'info> TransferLpPositionContext<'a, 'info> {
+    pub(crate) fn load(
+        pool_context: &PlasmaPoolContext<'a, 'info>,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let PlasmaPoolContext {
+            pool_info,
+            signer: trader,
+        } = pool_context;
+        let account_iter = &mut accounts.iter();
+        let ctx = Self {
+            lp_position: LpPositionAccountInfo::new(
+                next_account_info(account_iter)?,
+                pool_info.key,
+                trader.key,
+            )?,
+            recipient: next_account_info(account_iter)?,
+            recipient_lp_position: next_account_info(account_iter)?,
+            system_program: Program::new(next_account_info(account_iter)?, &system_program::id())?,
+        };
+        assert_with_msg(
+            ctx.recipient.key != trader.key,
+            PlasmaError::InvalidLpPositionRecipient,
+            "Cannot transfer an LP position to its owner",
+        )?;
+        Ok(ctx)
+    }
+}
+
+pub(crate) struct MigrateLpPositionContext<'a, 'info> {
+    /// Legacy accounts do not deserialize as `LpPositionAccount`, so they are validated by the
+    /// processor
+    pub(crate) lp_position: &'a AccountInfo<'info>,
+    pub(crate) system_program: Program<'a, 'info>,
+}
+
+impl<'a, 'info> MigrateLpPositionContext<'a, 'info> {
+    pub(crate) fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_iter = &mut accounts.iter();
+        Ok(Self {
+            lp_position: next_account_info(account_iter)?,
+            system_program: Program::new(next_account_info(account_iter)?, &system_program::id())?,
+        })
+    }
+}
+
+pub(crate) struct MigratePoolContext<'a, 'info> {
+    pub(crate) system_program: Program<'a, 'info>,
+}
+
+impl<'a, 'info> MigratePoolContext<'a, 'info> {
+    pub(crate) fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_iter = &mut accounts.iter();
+        Ok(Self {
+            system_program: Program::new(next_account_info(account_iter)?, &system_program::id())?,
+        })
+    }
+}
+
+pub(crate) struct UpdatePoolConfigContext<'a, 'info> {
+    /// Only required to update the fees
+    pub(crate) fee_tier_config: Option<FeeTierConfigAccountInfo<'a, 'info>>,
+}
+
+impl<'a, 'info> UpdatePoolConfigContext<'a, 'info> {
+    pub(crate) fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_iter = &mut accounts.iter();
+        Ok(Self {
+            fee_tier_config: account_iter
+                .next()
+                .map(FeeTierConfigAccountInfo::new)
+                .transpose()?,
+        })
+    }
+}
+
+pub(crate) struct SetPoolStatusContext<'a, 'info> {
+    /// Only required when the signer is the fee tier config admin
+    pub(crate) fee_tier_config: Option<FeeTierConfigAccountInfo<'a, 'info>>,
+}
+
+impl<'a, 'info> SetPoolStatusContext<'a, 'info> {
+    pub(crate) fn load(accounts: &'a [AccountInfo<'info>]) -> Result<Self, ProgramError> {
+        let account_iter = &mut accounts.iter();
+        Ok(Self {
+            fee_tier_config: account_iter
+                .next()
+                .map(FeeTierConfigAccountInfo::new)
+                .transpose()?,
         })
     }
 }
@@ -280,6 +764,7 @@ pub(crate) struct WithdrawLpFeesContext<'a, 'info> {
     pub(crate) lp_position: LpPositionAccountInfo<'a, 'info>,
     pub(crate) quote_account: TokenAccountInfo<'a, 'info>,
     pub(crate) quote_vault: TokenAccountInfo<'a, 'info>,
+    pub(crate) quote_mint: MintAccountInfo<'a, 'info>,
     pub(crate) token_program: Program<'a, 'info>,
 }
 
@@ -314,19 +799,49 @@ impl<'a, 'info> WithdrawLpFeesContext<'a, 'info> {
             &quote_params.vault_key,
             &quote_params.vault_key,
         )?;
-        let token_program = Program::new(next_account_info(account_iter)?, &spl_token::id())?;
+        let quote_mint = MintAccountInfo::new_with_key(
+            next_account_info(account_iter)?,
+            &quote_params.mint_key,
+        )?;
+        let token_program = Program::new(next_account_info(account_iter)?, quote_vault.owner)?;
         Ok(Self {
             lp_position,
             quote_account,
             quote_vault,
+            quote_mint,
             token_program,
         })
     }
 }
 
+pub(crate) struct CompoundLpFeesContext<'a, 'info> {
+    pub(crate) lp_position: LpPositionAccountInfo<'a, 'info>,
+}
+
+impl<'a, 'info> CompoundLpFeesContext<'a, 'info> {
+    pub(crate) fn load(
+        pool_context: &PlasmaPoolContext<'a, 'info>,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let PlasmaPoolContext {
+            pool_info,
+            signer: trader,
+        } = pool_context;
+        let account_iter = &mut accounts.iter();
+        Ok(Self {
+            lp_position: LpPositionAccountInfo::new(
+                next_account_info(account_iter)?,
+                pool_info.key,
+                trader.key,
+            )?,
+        })
+    }
+}
+
 pub(crate) struct WithdrawProtocolFeesContext<'a, 'info> {
     pub(crate) quote_account: TokenAccountInfo<'a, 'info>,
     pub(crate) quote_vault: TokenAccountInfo<'a, 'info>,
+    pub(crate) quote_mint: MintAccountInfo<'a, 'info>,
     pub(crate) token_program: Program<'a, 'info>,
 }
 
@@ -353,13 +868,244 @@ impl<'a, 'info> WithdrawProtocolFeesContext<'a, 'info> {
             &quote_params.vault_key,
             &quote_params.vault_key,
         )?;
-        let token_program = Program::new(next_account_info(account_iter)?, &spl_token::id())?;
+        let quote_mint = MintAccountInfo::new_with_key(
+            next_account_info(account_iter)?,
+            &quote_params.mint_key,
+        )?;
+        let token_program = Program::new(next_account_info(account_iter)?, quote_vault.owner)?;
 
         // Assert that signer is one of the protocol fee recipients
         Ok(Self {
             quote_account,
             quote_vault,
+            quote_mint,
             token_program,
         })
     }
 }
+
+/// A single pool of a swap route along with its vaults
+pub(crate) struct SwapRouteHop<'a, 'info> {
+    pub(crate) pool_context: PlasmaPoolContext<'a, 'info>,
+    pub(crate) side: Side,
+    pub(crate) base_params: TokenParams,
+    pub(crate) quote_params: TokenParams,
+    pub(crate) base_vault: TokenAccountInfo<'a, 'info>,
+    pub(crate) quote_vault: TokenAccountInfo<'a, 'info>,
+    pub(crate) base_mint: MintAccountInfo<'a, 'info>,
+    pub(crate) quote_mint: MintAccountInfo<'a, 'info>,
+    pub(crate) base_token_program: Program<'a, 'info>,
+    pub(crate) quote_token_program: Program<'a, 'info>,
+}
+
+impl<'a, 'info> SwapRouteHop<'a, 'info> {
+    pub(crate) fn input_mint(&self) -> &Pubkey {
+        match self.side {
+            Side::Buy => &self.quote_params.mint_key,
+            Side::Sell => &self.base_params.mint_key,
+        }
+    }
+
+    pub(crate) fn output_mint(&self) -> &Pubkey {
+        match self.side {
+            Side::Buy => &self.base_params.mint_key,
+            Side::Sell => &self.quote_params.mint_key,
+        }
+    }
+
+    /// The vault, mint and token program of the token sent to this pool
+    pub(crate) fn input_accounts(
+        &self,
+    ) -> (
+        &TokenAccountInfo<'a, 'info>,
+        &MintAccountInfo<'a, 'info>,
+        &Program<'a, 'info>,
+    ) {
+        match self.side {
+            Side::Buy => (
+                &self.quote_vault,
+                &self.quote_mint,
+                &self.quote_token_program,
+            ),
+            Side::Sell => (&self.base_vault, &self.base_mint, &self.base_token_program),
+        }
+    }
+
+    /// The token params, vault, mint and token program of the token received from this pool
+    pub(crate) fn output_accounts(
+        &self,
+    ) -> (
+        &TokenParams,
+        &TokenAccountInfo<'a, 'info>,
+        &MintAccountInfo<'a, 'info>,
+        &Program<'a, 'info>,
+    ) {
+        match self.side {
+            Side::Buy => (
+                &self.base_params,
+                &self.base_vault,
+                &self.base_mint,
+                &self.base_token_program,
+            ),
+            Side::Sell => (
+                &self.quote_params,
+                &self.quote_vault,
+                &self.quote_mint,
+                &self.quote_token_program,
+            ),
+        }
+    }
+
+    fn load(
+        pool_context: PlasmaPoolContext<'a, 'info>,
+        side: Side,
+        account_iter: &mut Iter<'a, AccountInfo<'info>>,
+    ) -> Result<Self, ProgramError> {
+        let (base_params, quote_params) = {
+            let header = pool_context.pool_info.get_header()?;
+            (header.base_params, header.quote_params)
+        };
+        let base_vault = TokenAccountInfo::new_with_owner_and_key(
+            next_account_info(account_iter)?,
+            &base_params.mint_key,
+            &base_params.vault_key,
+            &base_params.vault_key,
+        )?;
+        let quote_vault = TokenAccountInfo::new_with_owner_and_key(
+            next_account_info(account_iter)?,
+            &quote_params.mint_key,
+            &quote_params.vault_key,
+            &quote_params.vault_key,
+        )?;
+        let base_mint =
+            MintAccountInfo::new_with_key(next_account_info(account_iter)?, &base_params.mint_key)?;
+        let quote_mint = MintAccountInfo::new_with_key(
+            next_account_info(account_iter)?,
+            &quote_params.mint_key,
+        )?;
+        let base_token_program = Program::new(next_account_info(account_iter)?, base_vault.owner)?;
+        let quote_token_program =
+            Program::new(next_account_info(account_iter)?, quote_vault.owner)?;
+        Ok(Self {
+            pool_context,
+            side,
+            base_params,
+            quote_params,
+            base_vault,
+            quote_vault,
+            base_mint,
+            quote_mint,
+            base_token_program,
+            quote_token_program,
+        })
+    }
+}
+
+/// The accounts of a swap route. The first hop uses the pool loaded with the `PlasmaPoolContext`,
+/// every following hop is described by the accounts
+/// [pool, base_vault, quote_vault, base_mint, quote_mint, base_token_program, quote_token_program].
+/// The system program can follow the accounts of the last hop.
+pub(crate) struct SwapRouteContext<'a, 'info> {
+    pub(crate) input_account: TokenAccountInfo<'a, 'info>,
+    pub(crate) output_account: TokenAccountInfo<'a, 'info>,
+    pub(crate) hops: Vec<SwapRouteHop<'a, 'info>>,
+    /// Only required to wrap native SOL or to create the temporary wSOL account
+    pub(crate) system_program: Option<Program<'a, 'info>>,
+}
+
+impl<'a, 'info> SwapRouteContext<'a, 'info> {
+    pub(crate) fn load(
+        pool_context: &PlasmaPoolContext<'a, 'info>,
+        accounts: &'a [AccountInfo<'info>],
+        sides: &[Side],
+    ) -> Result<Self, ProgramError> {
+        assert_with_msg(
+            !sides.is_empty(),
+            PlasmaError::EmptySwapRoute,
+            "Swap route must contain at least one hop",
+        )?;
+        let account_iter = &mut accounts.iter();
+        let input_account_info = next_account_info(account_iter)?;
+        let output_account_info = next_account_info(account_iter)?;
+
+        let mut hops: Vec<SwapRouteHop<'a, 'info>> = Vec::with_capacity(sides.len());
+        hops.push(SwapRouteHop::load(
+            PlasmaPoolContext {
+                pool_info: pool_context.pool_info.clone(),
+                signer: pool_context.signer.clone(),
+            },
+            sides[0],
+            account_iter,
+        )?);
+
+        for side in sides[1..].iter() {
+            let pool_info = PoolAccountInfo::new(next_account_info(account_iter)?)?;
+            assert_with_msg(
+                hops.iter()
+                    .all(|hop| hop.pool_context.pool_info.key != pool_info.key),
+                PlasmaError::DuplicatePoolInSwapRoute,
+                "A pool can only appear once in a swap route",
+            )?;
+            let hop = SwapRouteHop::load(
+                PlasmaPoolContext {
+                    pool_info,
+                    signer: pool_context.signer.clone(),
+                },
+                *side,
+                account_iter,
+            )?;
+            let previous_hop = &hops[hops.len() - 1];
+            assert_with_msg(
+                previous_hop.output_mint() == hop.input_mint(),
+                PlasmaError::SwapRouteMintMismatch,
+                "The output mint of each hop must be the input mint of the next hop",
+            )?;
+            hops.push(hop);
+        }
+
+        let trader_key = pool_context.signer.key;
+        let input_account =
+            load_trader_token_account(input_account_info, hops[0].input_mint(), trader_key)?;
+        let output_account = load_trader_token_account(
+            output_account_info,
+            hops[hops.len() - 1].output_mint(),
+            trader_key,
+        )?;
+        let system_program = account_iter
+            .next()
+            .map(|info| Program::new(info, &system_program::id()))
+            .transpose()?;
+
+        Ok(Self {
+            input_account,
+            output_account,
+            hops,
+            system_program,
+        })
+    }
+}
+
+pub(crate) struct GrowObservationsContext<'a, 'info> {
+    pub(crate) observations: &'a AccountInfo<'info>,
+    pub(crate) system_program: Program<'a, 'info>,
+}
+
+impl<'a, 'info> GrowObservationsContext<'a, 'info> {
+    pub(crate) fn load(
+        pool_context: &PlasmaPoolContext<'a, 'info>,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError> {
+        let account_iter = &mut accounts.iter();
+        let ctx = Self {
+            observations: next_account_info(account_iter)?,
+            system_program: Program::new(next_account_info(account_iter)?, &system_program::id())?,
+        };
+        let (observations_address, _) = get_observations_address(pool_context.pool_info.key);
+        assert_with_msg(
+            &observations_address == ctx.observations.key,
+            PlasmaError::InvalidObservationsAddress,
+            "Invalid address for observations account",
+        )?;
+        Ok(ctx)
+    }
+}